@@ -552,12 +552,28 @@ public protocol DocProtocol : AnyObject {
     
     func applyEncodedChangesWithPatches(changes: [UInt8]) throws  -> [Patch]
     
-    func changeByHash(hash: ChangeHash)  -> Change?
+    func beginTransaction()  -> Transaction
+    
+    func blameProperty(obj: ObjId, prop: Prop) throws  -> Attribution?
+    
+    func blameText(obj: ObjId) throws  -> [TextSpan]
+    
+    func changeByHash(hash: ChangeHash) throws  -> Change?
     
     func changes()  -> [ChangeHash]
     
+    func checkout(name: String) throws  -> Doc
+    
+    func cherryPick(other: Doc, hashes: [ChangeHash]) throws  -> CherryPick
+    
+    func cherryPickEncoded(changes: [UInt8], hashes: [ChangeHash]) throws  -> CherryPick
+    
+    func clearPatchObserver() 
+    
     func commitWith(msg: String?, time: Int64) 
     
+    func createBranch(name: String, heads: [ChangeHash]) throws 
+    
     func cursor(obj: ObjId, position: UInt64) throws  -> Cursor
     
     func cursorAt(obj: ObjId, position: UInt64, heads: [ChangeHash]) throws  -> Cursor
@@ -566,11 +582,17 @@ public protocol DocProtocol : AnyObject {
     
     func cursorPositionAt(obj: ObjId, cursor: Cursor, heads: [ChangeHash]) throws  -> UInt64
     
+    func deleteBranch(name: String) throws 
+    
     func deleteInList(obj: ObjId, index: UInt64) throws 
     
     func deleteInMap(obj: ObjId, key: String) throws 
     
-    func difference(before: [ChangeHash], after: [ChangeHash])  -> [Patch]
+    func difference(before: [ChangeHash], after: [ChangeHash]) throws  -> [Patch]
+    
+    func differenceIn(obj: ObjId, before: [ChangeHash], after: [ChangeHash], recursive: Bool) throws  -> [Patch]
+    
+    func differenceSummary(obj: ObjId, before: [ChangeHash], after: [ChangeHash], recursive: Bool) throws  -> [DiffSummary]
     
     func encodeChangesSince(heads: [ChangeHash]) throws  -> [UInt8]
     
@@ -594,33 +616,47 @@ public protocol DocProtocol : AnyObject {
     
     func getAtInMap(obj: ObjId, key: String, heads: [ChangeHash]) throws  -> Value?
     
+    func getChanges(sinceHeads: [ChangeHash]) throws  -> [Change]
+    
+    func getConflicts(obj: ObjId, prop: Prop, heads: [ChangeHash]?) throws  -> [Conflict]
+    
     func getInList(obj: ObjId, index: UInt64) throws  -> Value?
     
     func getInMap(obj: ObjId, key: String) throws  -> Value?
     
     func heads()  -> [ChangeHash]
     
+    func hydrate(obj: ObjId, heads: [ChangeHash]?) throws  -> HydrateValue
+    
     func incrementInList(obj: ObjId, index: UInt64, by: Int64) throws 
     
     func incrementInMap(obj: ObjId, key: String, by: Int64) throws 
     
+    func insertHydrated(obj: ObjId, index: UInt64, value: HydrateValue) throws  -> ObjId?
+    
     func insertInList(obj: ObjId, index: UInt64, value: ScalarValue) throws 
     
     func insertObjectInList(obj: ObjId, index: UInt64, objType: ObjType) throws  -> ObjId
     
     func joinBlock(obj: ObjId, index: UInt32) throws 
     
-    func length(obj: ObjId)  -> UInt64
+    func length(obj: ObjId) throws  -> UInt64
+    
+    func lengthAt(obj: ObjId, heads: [ChangeHash]) throws  -> UInt64
     
-    func lengthAt(obj: ObjId, heads: [ChangeHash])  -> UInt64
+    func listBranches()  -> [Branch]
+    
+    func listRange(obj: ObjId, start: UInt64, end: UInt64, heads: [ChangeHash]?) throws  -> [Value]
     
     func mapEntries(obj: ObjId) throws  -> [KeyValue]
     
     func mapEntriesAt(obj: ObjId, heads: [ChangeHash]) throws  -> [KeyValue]
     
-    func mapKeys(obj: ObjId)  -> [String]
+    func mapKeys(obj: ObjId) throws  -> [String]
+    
+    func mapKeysAt(obj: ObjId, heads: [ChangeHash]) throws  -> [String]
     
-    func mapKeysAt(obj: ObjId, heads: [ChangeHash])  -> [String]
+    func mapRange(obj: ObjId, fromKey: String?, toKey: String?, limit: UInt64?, heads: [ChangeHash]?) throws  -> [KeyValue]
     
     func mark(obj: ObjId, start: UInt64, end: UInt64, expand: ExpandMark, name: String, value: ScalarValue) throws 
     
@@ -632,16 +668,22 @@ public protocol DocProtocol : AnyObject {
     
     func merge(other: Doc) throws 
     
+    func mergeBranch(name: String, other: Doc) throws 
+    
     func mergeWithPatches(other: Doc) throws  -> [Patch]
     
-    func objectType(obj: ObjId)  -> ObjType
+    func objectType(obj: ObjId) throws  -> ObjType
     
     func path(obj: ObjId) throws  -> [PathElement]
     
+    func putHydrated(obj: ObjId, prop: Prop, value: HydrateValue) throws  -> ObjId?
+    
     func putInList(obj: ObjId, index: UInt64, value: ScalarValue) throws 
     
     func putInMap(obj: ObjId, key: String, value: ScalarValue) throws 
     
+    func putJson(obj: ObjId, key: String, json: String) throws 
+    
     func putObjectInList(obj: ObjId, index: UInt64, objType: ObjType) throws  -> ObjId
     
     func putObjectInMap(obj: ObjId, key: String, objType: ObjType) throws  -> ObjId
@@ -650,10 +692,20 @@ public protocol DocProtocol : AnyObject {
     
     func receiveSyncMessageWithPatches(state: SyncState, msg: [UInt8]) throws  -> [Patch]
     
+    func resolveConflict(obj: ObjId, prop: Prop, chosenOpId: String) throws  -> ObjId?
+    
+    func revertTo(heads: [ChangeHash], obj: ObjId?, expand: ExpandMark) throws 
+    
     func save()  -> [UInt8]
     
+    func saveIncremental()  -> [UInt8]
+    
+    func saveNoCompress()  -> [UInt8]
+    
     func setActor(actor: ActorId) 
     
+    func setPatchObserver(observer: PatchObserver, batchPerCommit: Bool) 
+    
     func splice(obj: ObjId, start: UInt64, delete: Int64, values: [ScalarValue]) throws 
     
     func spliceText(obj: ObjId, start: UInt64, delete: Int64, chars: String) throws 
@@ -666,6 +718,8 @@ public protocol DocProtocol : AnyObject {
     
     func textEncoding()  -> TextEncoding
     
+    func toJson(obj: ObjId, heads: [ChangeHash]?) throws  -> String
+    
     func updateText(obj: ObjId, chars: String) throws 
     
     func values(obj: ObjId) throws  -> [Value]
@@ -737,6 +791,15 @@ public static func load(bytes: [UInt8])throws  -> Doc {
 })
 }
     
+public static func loadWithOptions(bytes: [UInt8], options: LoadOptions)throws  -> Doc {
+    return try  FfiConverterTypeDoc.lift(try rustCallWithError(FfiConverterTypeLoadError.lift) {
+    uniffi_uniffi_automerge_fn_constructor_doc_load_with_options(
+        FfiConverterSequenceUInt8.lower(bytes),
+        FfiConverterTypeLoadOptions.lower(options),$0
+    )
+})
+}
+    
 public static func newWithActor(actor: ActorId) -> Doc {
     return try!  FfiConverterTypeDoc.lift(try! rustCall() {
     uniffi_uniffi_automerge_fn_constructor_doc_new_with_actor(
@@ -777,8 +840,32 @@ open func applyEncodedChangesWithPatches(changes: [UInt8])throws  -> [Patch] {
 })
 }
     
-open func changeByHash(hash: ChangeHash) -> Change? {
-    return try!  FfiConverterOptionTypeChange.lift(try! rustCall() {
+open func beginTransaction() -> Transaction {
+    return try!  FfiConverterTypeTransaction.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_doc_begin_transaction(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func blameProperty(obj: ObjId, prop: Prop)throws  -> Attribution? {
+    return try  FfiConverterOptionTypeAttribution.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_blame_property(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterTypeProp.lower(prop),$0
+    )
+})
+}
+    
+open func blameText(obj: ObjId)throws  -> [TextSpan] {
+    return try  FfiConverterSequenceTypeTextSpan.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_blame_text(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),$0
+    )
+})
+}
+    
+open func changeByHash(hash: ChangeHash)throws  -> Change? {
+    return try  FfiConverterOptionTypeChange.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
     uniffi_uniffi_automerge_fn_method_doc_change_by_hash(self.uniffiClonePointer(),
         FfiConverterTypeChangeHash.lower(hash),$0
     )
@@ -792,6 +879,38 @@ open func changes() -> [ChangeHash] {
 })
 }
     
+open func checkout(name: String)throws  -> Doc {
+    return try  FfiConverterTypeDoc.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_checkout(self.uniffiClonePointer(),
+        FfiConverterString.lower(name),$0
+    )
+})
+}
+    
+open func cherryPick(other: Doc, hashes: [ChangeHash])throws  -> CherryPick {
+    return try  FfiConverterTypeCherryPick.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_cherry_pick(self.uniffiClonePointer(),
+        FfiConverterTypeDoc.lower(other),
+        FfiConverterSequenceTypeChangeHash.lower(hashes),$0
+    )
+})
+}
+    
+open func cherryPickEncoded(changes: [UInt8], hashes: [ChangeHash])throws  -> CherryPick {
+    return try  FfiConverterTypeCherryPick.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_cherry_pick_encoded(self.uniffiClonePointer(),
+        FfiConverterSequenceUInt8.lower(changes),
+        FfiConverterSequenceTypeChangeHash.lower(hashes),$0
+    )
+})
+}
+    
+open func clearPatchObserver() {try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_doc_clear_patch_observer(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
 open func commitWith(msg: String?, time: Int64) {try! rustCall() {
     uniffi_uniffi_automerge_fn_method_doc_commit_with(self.uniffiClonePointer(),
         FfiConverterOptionString.lower(msg),
@@ -800,6 +919,14 @@ open func commitWith(msg: String?, time: Int64) {try! rustCall() {
 }
 }
     
+open func createBranch(name: String, heads: [ChangeHash])throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_create_branch(self.uniffiClonePointer(),
+        FfiConverterString.lower(name),
+        FfiConverterSequenceTypeChangeHash.lower(heads),$0
+    )
+}
+}
+    
 open func cursor(obj: ObjId, position: UInt64)throws  -> Cursor {
     return try  FfiConverterTypeCursor.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
     uniffi_uniffi_automerge_fn_method_doc_cursor(self.uniffiClonePointer(),
@@ -838,6 +965,13 @@ open func cursorPositionAt(obj: ObjId, cursor: Cursor, heads: [ChangeHash])throw
 })
 }
     
+open func deleteBranch(name: String)throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_delete_branch(self.uniffiClonePointer(),
+        FfiConverterString.lower(name),$0
+    )
+}
+}
+    
 open func deleteInList(obj: ObjId, index: UInt64)throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
     uniffi_uniffi_automerge_fn_method_doc_delete_in_list(self.uniffiClonePointer(),
         FfiConverterTypeObjId.lower(obj),
@@ -854,8 +988,8 @@ open func deleteInMap(obj: ObjId, key: String)throws  {try rustCallWithError(Ffi
 }
 }
     
-open func difference(before: [ChangeHash], after: [ChangeHash]) -> [Patch] {
-    return try!  FfiConverterSequenceTypePatch.lift(try! rustCall() {
+open func difference(before: [ChangeHash], after: [ChangeHash])throws  -> [Patch] {
+    return try  FfiConverterSequenceTypePatch.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
     uniffi_uniffi_automerge_fn_method_doc_difference(self.uniffiClonePointer(),
         FfiConverterSequenceTypeChangeHash.lower(before),
         FfiConverterSequenceTypeChangeHash.lower(after),$0
@@ -863,6 +997,28 @@ open func difference(before: [ChangeHash], after: [ChangeHash]) -> [Patch] {
 })
 }
     
+open func differenceIn(obj: ObjId, before: [ChangeHash], after: [ChangeHash], recursive: Bool)throws  -> [Patch] {
+    return try  FfiConverterSequenceTypePatch.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_difference_in(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterSequenceTypeChangeHash.lower(before),
+        FfiConverterSequenceTypeChangeHash.lower(after),
+        FfiConverterBool.lower(recursive),$0
+    )
+})
+}
+    
+open func differenceSummary(obj: ObjId, before: [ChangeHash], after: [ChangeHash], recursive: Bool)throws  -> [DiffSummary] {
+    return try  FfiConverterSequenceTypeDiffSummary.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_difference_summary(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterSequenceTypeChangeHash.lower(before),
+        FfiConverterSequenceTypeChangeHash.lower(after),
+        FfiConverterBool.lower(recursive),$0
+    )
+})
+}
+    
 open func encodeChangesSince(heads: [ChangeHash])throws  -> [UInt8] {
     return try  FfiConverterSequenceUInt8.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
     uniffi_uniffi_automerge_fn_method_doc_encode_changes_since(self.uniffiClonePointer(),
@@ -959,6 +1115,24 @@ open func getAtInMap(obj: ObjId, key: String, heads: [ChangeHash])throws  -> Val
 })
 }
     
+open func getChanges(sinceHeads: [ChangeHash])throws  -> [Change] {
+    return try  FfiConverterSequenceTypeChange.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_get_changes(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeChangeHash.lower(sinceHeads),$0
+    )
+})
+}
+    
+open func getConflicts(obj: ObjId, prop: Prop, heads: [ChangeHash]?)throws  -> [Conflict] {
+    return try  FfiConverterSequenceTypeConflict.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_get_conflicts(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterTypeProp.lower(prop),
+        FfiConverterOptionSequenceTypeChangeHash.lower(heads),$0
+    )
+})
+}
+    
 open func getInList(obj: ObjId, index: UInt64)throws  -> Value? {
     return try  FfiConverterOptionTypeValue.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
     uniffi_uniffi_automerge_fn_method_doc_get_in_list(self.uniffiClonePointer(),
@@ -984,6 +1158,15 @@ open func heads() -> [ChangeHash] {
 })
 }
     
+open func hydrate(obj: ObjId, heads: [ChangeHash]?)throws  -> HydrateValue {
+    return try  FfiConverterTypeHydrateValue.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_hydrate(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterOptionSequenceTypeChangeHash.lower(heads),$0
+    )
+})
+}
+    
 open func incrementInList(obj: ObjId, index: UInt64, by: Int64)throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
     uniffi_uniffi_automerge_fn_method_doc_increment_in_list(self.uniffiClonePointer(),
         FfiConverterTypeObjId.lower(obj),
@@ -1002,6 +1185,16 @@ open func incrementInMap(obj: ObjId, key: String, by: Int64)throws  {try rustCal
 }
 }
     
+open func insertHydrated(obj: ObjId, index: UInt64, value: HydrateValue)throws  -> ObjId? {
+    return try  FfiConverterOptionTypeObjId.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_insert_hydrated(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterUInt64.lower(index),
+        FfiConverterTypeHydrateValue.lower(value),$0
+    )
+})
+}
+    
 open func insertInList(obj: ObjId, index: UInt64, value: ScalarValue)throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
     uniffi_uniffi_automerge_fn_method_doc_insert_in_list(self.uniffiClonePointer(),
         FfiConverterTypeObjId.lower(obj),
@@ -1029,16 +1222,16 @@ open func joinBlock(obj: ObjId, index: UInt32)throws  {try rustCallWithError(Ffi
 }
 }
     
-open func length(obj: ObjId) -> UInt64 {
-    return try!  FfiConverterUInt64.lift(try! rustCall() {
+open func length(obj: ObjId)throws  -> UInt64 {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
     uniffi_uniffi_automerge_fn_method_doc_length(self.uniffiClonePointer(),
         FfiConverterTypeObjId.lower(obj),$0
     )
 })
 }
     
-open func lengthAt(obj: ObjId, heads: [ChangeHash]) -> UInt64 {
-    return try!  FfiConverterUInt64.lift(try! rustCall() {
+open func lengthAt(obj: ObjId, heads: [ChangeHash])throws  -> UInt64 {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
     uniffi_uniffi_automerge_fn_method_doc_length_at(self.uniffiClonePointer(),
         FfiConverterTypeObjId.lower(obj),
         FfiConverterSequenceTypeChangeHash.lower(heads),$0
@@ -1046,6 +1239,24 @@ open func lengthAt(obj: ObjId, heads: [ChangeHash]) -> UInt64 {
 })
 }
     
+open func listBranches() -> [Branch] {
+    return try!  FfiConverterSequenceTypeBranch.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_doc_list_branches(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func listRange(obj: ObjId, start: UInt64, end: UInt64, heads: [ChangeHash]?)throws  -> [Value] {
+    return try  FfiConverterSequenceTypeValue.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_list_range(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterUInt64.lower(start),
+        FfiConverterUInt64.lower(end),
+        FfiConverterOptionSequenceTypeChangeHash.lower(heads),$0
+    )
+})
+}
+    
 open func mapEntries(obj: ObjId)throws  -> [KeyValue] {
     return try  FfiConverterSequenceTypeKeyValue.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
     uniffi_uniffi_automerge_fn_method_doc_map_entries(self.uniffiClonePointer(),
@@ -1063,16 +1274,16 @@ open func mapEntriesAt(obj: ObjId, heads: [ChangeHash])throws  -> [KeyValue] {
 })
 }
     
-open func mapKeys(obj: ObjId) -> [String] {
-    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+open func mapKeys(obj: ObjId)throws  -> [String] {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
     uniffi_uniffi_automerge_fn_method_doc_map_keys(self.uniffiClonePointer(),
         FfiConverterTypeObjId.lower(obj),$0
     )
 })
 }
     
-open func mapKeysAt(obj: ObjId, heads: [ChangeHash]) -> [String] {
-    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+open func mapKeysAt(obj: ObjId, heads: [ChangeHash])throws  -> [String] {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
     uniffi_uniffi_automerge_fn_method_doc_map_keys_at(self.uniffiClonePointer(),
         FfiConverterTypeObjId.lower(obj),
         FfiConverterSequenceTypeChangeHash.lower(heads),$0
@@ -1080,6 +1291,18 @@ open func mapKeysAt(obj: ObjId, heads: [ChangeHash]) -> [String] {
 })
 }
     
+open func mapRange(obj: ObjId, fromKey: String?, toKey: String?, limit: UInt64?, heads: [ChangeHash]?)throws  -> [KeyValue] {
+    return try  FfiConverterSequenceTypeKeyValue.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_map_range(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterOptionString.lower(fromKey),
+        FfiConverterOptionString.lower(toKey),
+        FfiConverterOptionUInt64.lower(limit),
+        FfiConverterOptionSequenceTypeChangeHash.lower(heads),$0
+    )
+})
+}
+    
 open func mark(obj: ObjId, start: UInt64, end: UInt64, expand: ExpandMark, name: String, value: ScalarValue)throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
     uniffi_uniffi_automerge_fn_method_doc_mark(self.uniffiClonePointer(),
         FfiConverterTypeObjId.lower(obj),
@@ -1126,6 +1349,14 @@ open func merge(other: Doc)throws  {try rustCallWithError(FfiConverterTypeDocErr
 }
 }
     
+open func mergeBranch(name: String, other: Doc)throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_merge_branch(self.uniffiClonePointer(),
+        FfiConverterString.lower(name),
+        FfiConverterTypeDoc.lower(other),$0
+    )
+}
+}
+    
 open func mergeWithPatches(other: Doc)throws  -> [Patch] {
     return try  FfiConverterSequenceTypePatch.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
     uniffi_uniffi_automerge_fn_method_doc_merge_with_patches(self.uniffiClonePointer(),
@@ -1134,8 +1365,8 @@ open func mergeWithPatches(other: Doc)throws  -> [Patch] {
 })
 }
     
-open func objectType(obj: ObjId) -> ObjType {
-    return try!  FfiConverterTypeObjType.lift(try! rustCall() {
+open func objectType(obj: ObjId)throws  -> ObjType {
+    return try  FfiConverterTypeObjType.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
     uniffi_uniffi_automerge_fn_method_doc_object_type(self.uniffiClonePointer(),
         FfiConverterTypeObjId.lower(obj),$0
     )
@@ -1150,6 +1381,16 @@ open func path(obj: ObjId)throws  -> [PathElement] {
 })
 }
     
+open func putHydrated(obj: ObjId, prop: Prop, value: HydrateValue)throws  -> ObjId? {
+    return try  FfiConverterOptionTypeObjId.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_put_hydrated(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterTypeProp.lower(prop),
+        FfiConverterTypeHydrateValue.lower(value),$0
+    )
+})
+}
+    
 open func putInList(obj: ObjId, index: UInt64, value: ScalarValue)throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
     uniffi_uniffi_automerge_fn_method_doc_put_in_list(self.uniffiClonePointer(),
         FfiConverterTypeObjId.lower(obj),
@@ -1168,6 +1409,15 @@ open func putInMap(obj: ObjId, key: String, value: ScalarValue)throws  {try rust
 }
 }
     
+open func putJson(obj: ObjId, key: String, json: String)throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_put_json(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterString.lower(key),
+        FfiConverterString.lower(json),$0
+    )
+}
+}
+    
 open func putObjectInList(obj: ObjId, index: UInt64, objType: ObjType)throws  -> ObjId {
     return try  FfiConverterTypeObjId.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
     uniffi_uniffi_automerge_fn_method_doc_put_object_in_list(self.uniffiClonePointer(),
@@ -1205,6 +1455,25 @@ open func receiveSyncMessageWithPatches(state: SyncState, msg: [UInt8])throws  -
 })
 }
     
+open func resolveConflict(obj: ObjId, prop: Prop, chosenOpId: String)throws  -> ObjId? {
+    return try  FfiConverterOptionTypeObjId.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_resolve_conflict(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterTypeProp.lower(prop),
+        FfiConverterString.lower(chosenOpId),$0
+    )
+})
+}
+    
+open func revertTo(heads: [ChangeHash], obj: ObjId?, expand: ExpandMark)throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_revert_to(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeChangeHash.lower(heads),
+        FfiConverterOptionTypeObjId.lower(obj),
+        FfiConverterTypeExpandMark.lower(expand),$0
+    )
+}
+}
+    
 open func save() -> [UInt8] {
     return try!  FfiConverterSequenceUInt8.lift(try! rustCall() {
     uniffi_uniffi_automerge_fn_method_doc_save(self.uniffiClonePointer(),$0
@@ -1212,6 +1481,20 @@ open func save() -> [UInt8] {
 })
 }
     
+open func saveIncremental() -> [UInt8] {
+    return try!  FfiConverterSequenceUInt8.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_doc_save_incremental(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func saveNoCompress() -> [UInt8] {
+    return try!  FfiConverterSequenceUInt8.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_doc_save_no_compress(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
 open func setActor(actor: ActorId) {try! rustCall() {
     uniffi_uniffi_automerge_fn_method_doc_set_actor(self.uniffiClonePointer(),
         FfiConverterTypeActorId.lower(actor),$0
@@ -1219,6 +1502,14 @@ open func setActor(actor: ActorId) {try! rustCall() {
 }
 }
     
+open func setPatchObserver(observer: PatchObserver, batchPerCommit: Bool = true) {try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_doc_set_patch_observer(self.uniffiClonePointer(),
+        FfiConverterCallbackInterfacePatchObserver.lower(observer),
+        FfiConverterBool.lower(batchPerCommit),$0
+    )
+}
+}
+    
 open func splice(obj: ObjId, start: UInt64, delete: Int64, values: [ScalarValue])throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
     uniffi_uniffi_automerge_fn_method_doc_splice(self.uniffiClonePointer(),
         FfiConverterTypeObjId.lower(obj),
@@ -1272,6 +1563,15 @@ open func textEncoding() -> TextEncoding {
 })
 }
     
+open func toJson(obj: ObjId, heads: [ChangeHash]?)throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_doc_to_json(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterOptionSequenceTypeChangeHash.lower(heads),$0
+    )
+})
+}
+    
 open func updateText(obj: ObjId, chars: String)throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
     uniffi_uniffi_automerge_fn_method_doc_update_text(self.uniffiClonePointer(),
         FfiConverterTypeObjId.lower(obj),
@@ -1354,18 +1654,22 @@ public func FfiConverterTypeDoc_lower(_ value: Doc) -> UnsafeMutableRawPointer {
 
 
 
-public protocol SyncStateProtocol : AnyObject {
+public protocol PresenceProtocol : AnyObject {
     
-    func encode()  -> [UInt8]
+    func encodeLeave(timestamp: Int64)  -> [UInt8]
     
-    func reset() 
+    func encodeUpdate(payload: [String: PresenceValue], timestamp: Int64) throws  -> [UInt8]
     
-    func theirHeads()  -> [ChangeHash]?
+    func prune(now: Int64)  -> [ActorId]
+    
+    func receive(message: [UInt8]) throws 
+    
+    func states(now: Int64)  -> [PresenceState]
     
 }
 
-open class SyncState:
-    SyncStateProtocol {
+open class Presence:
+    PresenceProtocol {
     fileprivate let pointer: UnsafeMutableRawPointer!
 
     /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
@@ -1399,12 +1703,14 @@ open class SyncState:
     @_documentation(visibility: private)
 #endif
     public func uniffiClonePointer() -> UnsafeMutableRawPointer {
-        return try! rustCall { uniffi_uniffi_automerge_fn_clone_syncstate(self.pointer, $0) }
+        return try! rustCall { uniffi_uniffi_automerge_fn_clone_presence(self.pointer, $0) }
     }
-public convenience init() {
+public convenience init(actor: ActorId, ttl: Int64) {
     let pointer =
         try! rustCall() {
-    uniffi_uniffi_automerge_fn_constructor_syncstate_new($0
+    uniffi_uniffi_automerge_fn_constructor_presence_new(
+        FfiConverterTypeActorId.lower(actor),
+        FfiConverterInt64.lower(ttl),$0
     )
 }
     self.init(unsafeFromRawPointer: pointer)
@@ -1415,36 +1721,48 @@ public convenience init() {
             return
         }
 
-        try! rustCall { uniffi_uniffi_automerge_fn_free_syncstate(pointer, $0) }
+        try! rustCall { uniffi_uniffi_automerge_fn_free_presence(pointer, $0) }
     }
 
     
-public static func decode(bytes: [UInt8])throws  -> SyncState {
-    return try  FfiConverterTypeSyncState.lift(try rustCallWithError(FfiConverterTypeDecodeSyncStateError.lift) {
-    uniffi_uniffi_automerge_fn_constructor_syncstate_decode(
-        FfiConverterSequenceUInt8.lower(bytes),$0
+
+    
+open func encodeLeave(timestamp: Int64) -> [UInt8] {
+    return try!  FfiConverterSequenceUInt8.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_presence_encode_leave(self.uniffiClonePointer(),
+        FfiConverterInt64.lower(timestamp),$0
     )
 })
 }
     
-
+open func encodeUpdate(payload: [String: PresenceValue], timestamp: Int64)throws  -> [UInt8] {
+    return try  FfiConverterSequenceUInt8.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_presence_encode_update(self.uniffiClonePointer(),
+        FfiConverterDictionaryStringTypePresenceValue.lower(payload),
+        FfiConverterInt64.lower(timestamp),$0
+    )
+})
+}
     
-open func encode() -> [UInt8] {
-    return try!  FfiConverterSequenceUInt8.lift(try! rustCall() {
-    uniffi_uniffi_automerge_fn_method_syncstate_encode(self.uniffiClonePointer(),$0
+open func prune(now: Int64) -> [ActorId] {
+    return try!  FfiConverterSequenceTypeActorId.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_presence_prune(self.uniffiClonePointer(),
+        FfiConverterInt64.lower(now),$0
     )
 })
 }
     
-open func reset() {try! rustCall() {
-    uniffi_uniffi_automerge_fn_method_syncstate_reset(self.uniffiClonePointer(),$0
+open func receive(message: [UInt8])throws  {try rustCallWithError(FfiConverterTypeDecodePresenceError.lift) {
+    uniffi_uniffi_automerge_fn_method_presence_receive(self.uniffiClonePointer(),
+        FfiConverterSequenceUInt8.lower(message),$0
     )
 }
 }
     
-open func theirHeads() -> [ChangeHash]? {
-    return try!  FfiConverterOptionSequenceTypeChangeHash.lift(try! rustCall() {
-    uniffi_uniffi_automerge_fn_method_syncstate_their_heads(self.uniffiClonePointer(),$0
+open func states(now: Int64) -> [PresenceState] {
+    return try!  FfiConverterSequenceTypePresenceState.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_presence_states(self.uniffiClonePointer(),
+        FfiConverterInt64.lower(now),$0
     )
 })
 }
@@ -1455,20 +1773,20 @@ open func theirHeads() -> [ChangeHash]? {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSyncState: FfiConverter {
+public struct FfiConverterTypePresence: FfiConverter {
 
     typealias FfiType = UnsafeMutableRawPointer
-    typealias SwiftType = SyncState
+    typealias SwiftType = Presence
 
-    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> SyncState {
-        return SyncState(unsafeFromRawPointer: pointer)
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> Presence {
+        return Presence(unsafeFromRawPointer: pointer)
     }
 
-    public static func lower(_ value: SyncState) -> UnsafeMutableRawPointer {
+    public static func lower(_ value: Presence) -> UnsafeMutableRawPointer {
         return value.uniffiClonePointer()
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SyncState {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Presence {
         let v: UInt64 = try readInt(&buf)
         // The Rust code won't compile if a pointer won't fit in a UInt64.
         // We have to go via `UInt` because that's the thing that's the size of a pointer.
@@ -1479,7 +1797,7 @@ public struct FfiConverterTypeSyncState: FfiConverter {
         return try lift(ptr!)
     }
 
-    public static func write(_ value: SyncState, into buf: inout [UInt8]) {
+    public static func write(_ value: Presence, into buf: inout [UInt8]) {
         // This fiddling is because `Int` is the thing that's the same size as a pointer.
         // The Rust code won't compile if a pointer won't fit in a `UInt64`.
         writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
@@ -1492,692 +1810,4629 @@ public struct FfiConverterTypeSyncState: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSyncState_lift(_ pointer: UnsafeMutableRawPointer) throws -> SyncState {
-    return try FfiConverterTypeSyncState.lift(pointer)
+public func FfiConverterTypePresence_lift(_ pointer: UnsafeMutableRawPointer) throws -> Presence {
+    return try FfiConverterTypePresence.lift(pointer)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSyncState_lower(_ value: SyncState) -> UnsafeMutableRawPointer {
-    return FfiConverterTypeSyncState.lower(value)
+public func FfiConverterTypePresence_lower(_ value: Presence) -> UnsafeMutableRawPointer {
+    return FfiConverterTypePresence.lower(value)
 }
 
 
-public struct Change {
-    public var actorId: ActorId
-    public var message: String?
-    public var deps: [ChangeHash]
-    public var timestamp: Int64
-    public var bytes: [UInt8]
-    public var hash: ChangeHash
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(actorId: ActorId, message: String?, deps: [ChangeHash], timestamp: Int64, bytes: [UInt8], hash: ChangeHash) {
-        self.actorId = actorId
-        self.message = message
-        self.deps = deps
-        self.timestamp = timestamp
-        self.bytes = bytes
-        self.hash = hash
-    }
-}
-
 
 
-extension Change: Equatable, Hashable {
-    public static func ==(lhs: Change, rhs: Change) -> Bool {
-        if lhs.actorId != rhs.actorId {
-            return false
-        }
-        if lhs.message != rhs.message {
-            return false
-        }
-        if lhs.deps != rhs.deps {
-            return false
-        }
-        if lhs.timestamp != rhs.timestamp {
-            return false
-        }
-        if lhs.bytes != rhs.bytes {
-            return false
-        }
-        if lhs.hash != rhs.hash {
-            return false
-        }
-        return true
-    }
-
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(actorId)
-        hasher.combine(message)
-        hasher.combine(deps)
-        hasher.combine(timestamp)
-        hasher.combine(bytes)
-        hasher.combine(hash)
-    }
+public protocol RepoProtocol : AnyObject {
+    
+    func addPeer(peerId: String) 
+    
+    func broadcast(documentId: DocumentId, data: [UInt8]) throws 
+    
+    func compact(documentId: DocumentId) throws 
+    
+    func create()  -> DocumentId
+    
+    func delete(documentId: DocumentId) throws 
+    
+    func documentIds()  -> [DocumentId]
+    
+    func find(documentId: DocumentId) throws  -> Doc?
+    
+    func `import`(bytes: [UInt8]) throws  -> DocumentId
+    
+    func join()  -> [UInt8]
+    
+    func loadSyncState(documentId: DocumentId, storageId: String) throws  -> SyncState?
+    
+    func peerId()  -> String
+    
+    func peers()  -> [String]
+    
+    func phase(documentId: DocumentId) throws  -> DocumentSyncPhase?
+    
+    func receiveMessage(message: [UInt8]) throws  -> EphemeralMessage?
+    
+    func removePeer(peerId: String) 
+    
+    func request(documentId: DocumentId) throws 
+    
+    func save(documentId: DocumentId) throws 
+    
+    func saveSyncState(documentId: DocumentId, storageId: String, state: SyncState) throws 
+    
+    func setNetworkAdapter(network: NetworkAdapter) 
+    
+    func storedDocumentIds()  -> [DocumentId]
+    
+    func sync(documentId: DocumentId) throws 
+    
+    func syncAll() 
+    
 }
 
+open class Repo:
+    RepoProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
 
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeChange: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Change {
-        return
-            try Change(
-                actorId: FfiConverterTypeActorId.read(from: &buf), 
-                message: FfiConverterOptionString.read(from: &buf), 
-                deps: FfiConverterSequenceTypeChangeHash.read(from: &buf), 
-                timestamp: FfiConverterInt64.read(from: &buf), 
-                bytes: FfiConverterSequenceUInt8.read(from: &buf), 
-                hash: FfiConverterTypeChangeHash.read(from: &buf)
-        )
+    public struct NoPointer {
+        public init() {}
     }
 
-    public static func write(_ value: Change, into buf: inout [UInt8]) {
-        FfiConverterTypeActorId.write(value.actorId, into: &buf)
-        FfiConverterOptionString.write(value.message, into: &buf)
-        FfiConverterSequenceTypeChangeHash.write(value.deps, into: &buf)
-        FfiConverterInt64.write(value.timestamp, into: &buf)
-        FfiConverterSequenceUInt8.write(value.bytes, into: &buf)
-        FfiConverterTypeChangeHash.write(value.hash, into: &buf)
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
     }
-}
-
 
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeChange_lift(_ buf: RustBuffer) throws -> Change {
-    return try FfiConverterTypeChange.lift(buf)
-}
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
 
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeChange_lower(_ value: Change) -> RustBuffer {
-    return FfiConverterTypeChange.lower(value)
-}
-
-
-public struct KeyValue {
-    public var key: String
-    public var value: Value
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(key: String, value: Value) {
-        self.key = key
-        self.value = value
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_uniffi_automerge_fn_clone_repo(self.pointer, $0) }
     }
+public convenience init(peerId: String, peerMetadata: PeerMetadata, storage: StorageAdapter) {
+    let pointer =
+        try! rustCall() {
+    uniffi_uniffi_automerge_fn_constructor_repo_new(
+        FfiConverterString.lower(peerId),
+        FfiConverterTypePeerMetadata.lower(peerMetadata),
+        FfiConverterCallbackInterfaceStorageAdapter.lower(storage),$0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
 }
 
-
-
-extension KeyValue: Equatable, Hashable {
-    public static func ==(lhs: KeyValue, rhs: KeyValue) -> Bool {
-        if lhs.key != rhs.key {
-            return false
-        }
-        if lhs.value != rhs.value {
-            return false
+    deinit {
+        guard let pointer = pointer else {
+            return
         }
-        return true
-    }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(key)
-        hasher.combine(value)
+        try! rustCall { uniffi_uniffi_automerge_fn_free_repo(pointer, $0) }
     }
+
+    
+
+    
+open func addPeer(peerId: String) {try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_repo_add_peer(self.uniffiClonePointer(),
+        FfiConverterString.lower(peerId),$0
+    )
+}
+}
+    
+open func broadcast(documentId: DocumentId, data: [UInt8])throws  {try rustCallWithError(FfiConverterTypeRepoError.lift) {
+    uniffi_uniffi_automerge_fn_method_repo_broadcast(self.uniffiClonePointer(),
+        FfiConverterTypeDocumentId.lower(documentId),
+        FfiConverterSequenceUInt8.lower(data),$0
+    )
+}
+}
+    
+open func compact(documentId: DocumentId)throws  {try rustCallWithError(FfiConverterTypeRepoError.lift) {
+    uniffi_uniffi_automerge_fn_method_repo_compact(self.uniffiClonePointer(),
+        FfiConverterTypeDocumentId.lower(documentId),$0
+    )
+}
+}
+    
+open func create() -> DocumentId {
+    return try!  FfiConverterTypeDocumentId.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_repo_create(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func delete(documentId: DocumentId)throws  {try rustCallWithError(FfiConverterTypeRepoError.lift) {
+    uniffi_uniffi_automerge_fn_method_repo_delete(self.uniffiClonePointer(),
+        FfiConverterTypeDocumentId.lower(documentId),$0
+    )
+}
+}
+    
+open func documentIds() -> [DocumentId] {
+    return try!  FfiConverterSequenceTypeDocumentId.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_repo_document_ids(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func find(documentId: DocumentId)throws  -> Doc? {
+    return try  FfiConverterOptionTypeDoc.lift(try rustCallWithError(FfiConverterTypeRepoError.lift) {
+    uniffi_uniffi_automerge_fn_method_repo_find(self.uniffiClonePointer(),
+        FfiConverterTypeDocumentId.lower(documentId),$0
+    )
+})
+}
+    
+open func `import`(bytes: [UInt8])throws  -> DocumentId {
+    return try  FfiConverterTypeDocumentId.lift(try rustCallWithError(FfiConverterTypeRepoError.lift) {
+    uniffi_uniffi_automerge_fn_method_repo_import(self.uniffiClonePointer(),
+        FfiConverterSequenceUInt8.lower(bytes),$0
+    )
+})
+}
+    
+open func join() -> [UInt8] {
+    return try!  FfiConverterSequenceUInt8.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_repo_join(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func loadSyncState(documentId: DocumentId, storageId: String)throws  -> SyncState? {
+    return try  FfiConverterOptionTypeSyncState.lift(try rustCallWithError(FfiConverterTypeRepoError.lift) {
+    uniffi_uniffi_automerge_fn_method_repo_load_sync_state(self.uniffiClonePointer(),
+        FfiConverterTypeDocumentId.lower(documentId),
+        FfiConverterString.lower(storageId),$0
+    )
+})
+}
+    
+open func peerId() -> String {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_repo_peer_id(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func peers() -> [String] {
+    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_repo_peers(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func phase(documentId: DocumentId)throws  -> DocumentSyncPhase? {
+    return try  FfiConverterOptionTypeDocumentSyncPhase.lift(try rustCallWithError(FfiConverterTypeRepoError.lift) {
+    uniffi_uniffi_automerge_fn_method_repo_phase(self.uniffiClonePointer(),
+        FfiConverterTypeDocumentId.lower(documentId),$0
+    )
+})
+}
+    
+open func receiveMessage(message: [UInt8])throws  -> EphemeralMessage? {
+    return try  FfiConverterOptionTypeEphemeralMessage.lift(try rustCallWithError(FfiConverterTypeRepoError.lift) {
+    uniffi_uniffi_automerge_fn_method_repo_receive_message(self.uniffiClonePointer(),
+        FfiConverterSequenceUInt8.lower(message),$0
+    )
+})
+}
+    
+open func removePeer(peerId: String) {try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_repo_remove_peer(self.uniffiClonePointer(),
+        FfiConverterString.lower(peerId),$0
+    )
+}
+}
+    
+open func request(documentId: DocumentId)throws  {try rustCallWithError(FfiConverterTypeRepoError.lift) {
+    uniffi_uniffi_automerge_fn_method_repo_request(self.uniffiClonePointer(),
+        FfiConverterTypeDocumentId.lower(documentId),$0
+    )
+}
+}
+    
+open func save(documentId: DocumentId)throws  {try rustCallWithError(FfiConverterTypeRepoError.lift) {
+    uniffi_uniffi_automerge_fn_method_repo_save(self.uniffiClonePointer(),
+        FfiConverterTypeDocumentId.lower(documentId),$0
+    )
 }
+}
+    
+open func saveSyncState(documentId: DocumentId, storageId: String, state: SyncState)throws  {try rustCallWithError(FfiConverterTypeRepoError.lift) {
+    uniffi_uniffi_automerge_fn_method_repo_save_sync_state(self.uniffiClonePointer(),
+        FfiConverterTypeDocumentId.lower(documentId),
+        FfiConverterString.lower(storageId),
+        FfiConverterTypeSyncState.lower(state),$0
+    )
+}
+}
+    
+open func setNetworkAdapter(network: NetworkAdapter) {try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_repo_set_network_adapter(self.uniffiClonePointer(),
+        FfiConverterCallbackInterfaceNetworkAdapter.lower(network),$0
+    )
+}
+}
+    
+open func storedDocumentIds() -> [DocumentId] {
+    return try!  FfiConverterSequenceTypeDocumentId.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_repo_stored_document_ids(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func sync(documentId: DocumentId)throws  {try rustCallWithError(FfiConverterTypeRepoError.lift) {
+    uniffi_uniffi_automerge_fn_method_repo_sync(self.uniffiClonePointer(),
+        FfiConverterTypeDocumentId.lower(documentId),$0
+    )
+}
+}
+    
+open func syncAll() {try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_repo_sync_all(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
 
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeKeyValue: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> KeyValue {
-        return
-            try KeyValue(
-                key: FfiConverterString.read(from: &buf), 
-                value: FfiConverterTypeValue.read(from: &buf)
-        )
+public struct FfiConverterTypeRepo: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = Repo
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> Repo {
+        return Repo(unsafeFromRawPointer: pointer)
     }
 
-    public static func write(_ value: KeyValue, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.key, into: &buf)
-        FfiConverterTypeValue.write(value.value, into: &buf)
+    public static func lower(_ value: Repo) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Repo {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: Repo, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
     }
 }
 
 
+
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeKeyValue_lift(_ buf: RustBuffer) throws -> KeyValue {
-    return try FfiConverterTypeKeyValue.lift(buf)
+public func FfiConverterTypeRepo_lift(_ pointer: UnsafeMutableRawPointer) throws -> Repo {
+    return try FfiConverterTypeRepo.lift(pointer)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeKeyValue_lower(_ value: KeyValue) -> RustBuffer {
-    return FfiConverterTypeKeyValue.lower(value)
+public func FfiConverterTypeRepo_lower(_ value: Repo) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeRepo.lower(value)
 }
 
 
-public struct Mark {
-    public var start: UInt64
-    public var end: UInt64
-    public var name: String
-    public var value: ScalarValue
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(start: UInt64, end: UInt64, name: String, value: ScalarValue) {
-        self.start = start
-        self.end = end
-        self.name = name
-        self.value = value
-    }
-}
-
-
-
-extension Mark: Equatable, Hashable {
-    public static func ==(lhs: Mark, rhs: Mark) -> Bool {
-        if lhs.start != rhs.start {
-            return false
-        }
-        if lhs.end != rhs.end {
-            return false
-        }
-        if lhs.name != rhs.name {
-            return false
-        }
-        if lhs.value != rhs.value {
-            return false
-        }
-        return true
-    }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(start)
-        hasher.combine(end)
-        hasher.combine(name)
-        hasher.combine(value)
-    }
+public protocol SyncSessionProtocol : AnyObject {
+    
+    func addPeer(peer: String) 
+    
+    func addPeerWithState(peer: String, state: SyncState) 
+    
+    func doc()  -> Doc
+    
+    func encode()  -> [UInt8]
+    
+    func generateMessage(peer: String) throws  -> [UInt8]?
+    
+    func generateMessages()  -> [PeerMessage]
+    
+    func peerState(peer: String)  -> SyncState?
+    
+    func peers()  -> [String]
+    
+    func receiveMessage(peer: String, message: [UInt8]) throws 
+    
+    func removePeer(peer: String) 
+    
 }
 
+open class SyncSession:
+    SyncSessionProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
 
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeMark: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Mark {
-        return
-            try Mark(
-                start: FfiConverterUInt64.read(from: &buf), 
-                end: FfiConverterUInt64.read(from: &buf), 
-                name: FfiConverterString.read(from: &buf), 
-                value: FfiConverterTypeScalarValue.read(from: &buf)
-        )
+    public struct NoPointer {
+        public init() {}
     }
 
-    public static func write(_ value: Mark, into buf: inout [UInt8]) {
-        FfiConverterUInt64.write(value.start, into: &buf)
-        FfiConverterUInt64.write(value.end, into: &buf)
-        FfiConverterString.write(value.name, into: &buf)
-        FfiConverterTypeScalarValue.write(value.value, into: &buf)
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
     }
-}
-
 
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeMark_lift(_ buf: RustBuffer) throws -> Mark {
-    return try FfiConverterTypeMark.lift(buf)
-}
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
 
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeMark_lower(_ value: Mark) -> RustBuffer {
-    return FfiConverterTypeMark.lower(value)
-}
-
-
-public struct Patch {
-    public var path: [PathElement]
-    public var action: PatchAction
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(path: [PathElement], action: PatchAction) {
-        self.path = path
-        self.action = action
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_uniffi_automerge_fn_clone_syncsession(self.pointer, $0) }
     }
+public convenience init(doc: Doc) {
+    let pointer =
+        try! rustCall() {
+    uniffi_uniffi_automerge_fn_constructor_syncsession_new(
+        FfiConverterTypeDoc.lower(doc),$0
+    )
 }
-
-
-
-extension Patch: Equatable, Hashable {
-    public static func ==(lhs: Patch, rhs: Patch) -> Bool {
-        if lhs.path != rhs.path {
-            return false
-        }
-        if lhs.action != rhs.action {
-            return false
-        }
-        return true
-    }
-
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(path)
-        hasher.combine(action)
-    }
+    self.init(unsafeFromRawPointer: pointer)
 }
 
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
 
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypePatch: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Patch {
-        return
-            try Patch(
-                path: FfiConverterSequenceTypePathElement.read(from: &buf), 
-                action: FfiConverterTypePatchAction.read(from: &buf)
-        )
+        try! rustCall { uniffi_uniffi_automerge_fn_free_syncsession(pointer, $0) }
     }
 
-    public static func write(_ value: Patch, into buf: inout [UInt8]) {
-        FfiConverterSequenceTypePathElement.write(value.path, into: &buf)
-        FfiConverterTypePatchAction.write(value.action, into: &buf)
-    }
+    
+public static func restore(doc: Doc, bytes: [UInt8])throws  -> SyncSession {
+    return try  FfiConverterTypeSyncSession.lift(try rustCallWithError(FfiConverterTypeSyncSessionError.lift) {
+    uniffi_uniffi_automerge_fn_constructor_syncsession_restore(
+        FfiConverterTypeDoc.lower(doc),
+        FfiConverterSequenceUInt8.lower(bytes),$0
+    )
+})
 }
+    
 
+    
+open func addPeer(peer: String) {try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_syncsession_add_peer(self.uniffiClonePointer(),
+        FfiConverterString.lower(peer),$0
+    )
+}
+}
+    
+open func addPeerWithState(peer: String, state: SyncState) {try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_syncsession_add_peer_with_state(self.uniffiClonePointer(),
+        FfiConverterString.lower(peer),
+        FfiConverterTypeSyncState.lower(state),$0
+    )
+}
+}
+    
+open func doc() -> Doc {
+    return try!  FfiConverterTypeDoc.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_syncsession_doc(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func encode() -> [UInt8] {
+    return try!  FfiConverterSequenceUInt8.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_syncsession_encode(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func generateMessage(peer: String)throws  -> [UInt8]? {
+    return try  FfiConverterOptionSequenceUInt8.lift(try rustCallWithError(FfiConverterTypeSyncSessionError.lift) {
+    uniffi_uniffi_automerge_fn_method_syncsession_generate_message(self.uniffiClonePointer(),
+        FfiConverterString.lower(peer),$0
+    )
+})
+}
+    
+open func generateMessages() -> [PeerMessage] {
+    return try!  FfiConverterSequenceTypePeerMessage.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_syncsession_generate_messages(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func peerState(peer: String) -> SyncState? {
+    return try!  FfiConverterOptionTypeSyncState.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_syncsession_peer_state(self.uniffiClonePointer(),
+        FfiConverterString.lower(peer),$0
+    )
+})
+}
+    
+open func peers() -> [String] {
+    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_syncsession_peers(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func receiveMessage(peer: String, message: [UInt8])throws  {try rustCallWithError(FfiConverterTypeSyncSessionError.lift) {
+    uniffi_uniffi_automerge_fn_method_syncsession_receive_message(self.uniffiClonePointer(),
+        FfiConverterString.lower(peer),
+        FfiConverterSequenceUInt8.lower(message),$0
+    )
+}
+}
+    
+open func removePeer(peer: String) {try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_syncsession_remove_peer(self.uniffiClonePointer(),
+        FfiConverterString.lower(peer),$0
+    )
+}
+}
+    
 
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypePatch_lift(_ buf: RustBuffer) throws -> Patch {
-    return try FfiConverterTypePatch.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypePatch_lower(_ value: Patch) -> RustBuffer {
-    return FfiConverterTypePatch.lower(value)
-}
+public struct FfiConverterTypeSyncSession: FfiConverter {
 
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = SyncSession
 
-public struct PathElement {
-    public var prop: Prop
-    public var obj: ObjId
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(prop: Prop, obj: ObjId) {
-        self.prop = prop
-        self.obj = obj
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> SyncSession {
+        return SyncSession(unsafeFromRawPointer: pointer)
     }
-}
-
 
+    public static func lower(_ value: SyncSession) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
 
-extension PathElement: Equatable, Hashable {
-    public static func ==(lhs: PathElement, rhs: PathElement) -> Bool {
-        if lhs.prop != rhs.prop {
-            return false
-        }
-        if lhs.obj != rhs.obj {
-            return false
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SyncSession {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
         }
-        return true
+        return try lift(ptr!)
     }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(prop)
-        hasher.combine(obj)
+    public static func write(_ value: SyncSession, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
     }
 }
 
 
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypePathElement: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PathElement {
-        return
-            try PathElement(
-                prop: FfiConverterTypeProp.read(from: &buf), 
-                obj: FfiConverterTypeObjId.read(from: &buf)
-        )
-    }
-
-    public static func write(_ value: PathElement, into buf: inout [UInt8]) {
-        FfiConverterTypeProp.write(value.prop, into: &buf)
-        FfiConverterTypeObjId.write(value.obj, into: &buf)
-    }
-}
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypePathElement_lift(_ buf: RustBuffer) throws -> PathElement {
-    return try FfiConverterTypePathElement.lift(buf)
+public func FfiConverterTypeSyncSession_lift(_ pointer: UnsafeMutableRawPointer) throws -> SyncSession {
+    return try FfiConverterTypeSyncSession.lift(pointer)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypePathElement_lower(_ value: PathElement) -> RustBuffer {
-    return FfiConverterTypePathElement.lower(value)
+public func FfiConverterTypeSyncSession_lower(_ value: SyncSession) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeSyncSession.lower(value)
 }
 
 
-public enum DecodeSyncStateError {
 
+
+public protocol SyncStateProtocol : AnyObject {
+    
+    func encode()  -> [UInt8]
     
+    func inFlight()  -> Bool
     
-    case Internal(message: String)
+    func lastSentHeads()  -> [ChangeHash]
+    
+    func reset() 
+    
+    func sentHashesCount()  -> UInt64
+    
+    func sharedHeads()  -> [ChangeHash]
+    
+    func theirHave()  -> [SyncHave]?
+    
+    func theirHeads()  -> [ChangeHash]?
+    
+    func theirNeed()  -> [ChangeHash]?
     
 }
 
+open class SyncState:
+    SyncStateProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
 
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDecodeSyncStateError: FfiConverterRustBuffer {
-    typealias SwiftType = DecodeSyncStateError
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DecodeSyncStateError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-
-        
-
-        
-        case 1: return .Internal(
-            message: try FfiConverterString.read(from: &buf)
-        )
-        
-
-        default: throw UniffiInternalError.unexpectedEnumCase
-        }
+    public struct NoPointer {
+        public init() {}
     }
 
-    public static func write(_ value: DecodeSyncStateError, into buf: inout [UInt8]) {
-        switch value {
-
-        
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
 
-        
-        case .Internal(_ /* message is ignored*/):
-            writeInt(&buf, Int32(1))
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
 
-        
-        }
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_uniffi_automerge_fn_clone_syncstate(self.pointer, $0) }
     }
+public convenience init() {
+    let pointer =
+        try! rustCall() {
+    uniffi_uniffi_automerge_fn_constructor_syncstate_new($0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
 }
 
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
 
-extension DecodeSyncStateError: Equatable, Hashable {}
-
-extension DecodeSyncStateError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
+        try! rustCall { uniffi_uniffi_automerge_fn_free_syncstate(pointer, $0) }
     }
-}
 
-
-public enum DocError {
+    
+public static func decode(bytes: [UInt8])throws  -> SyncState {
+    return try  FfiConverterTypeSyncState.lift(try rustCallWithError(FfiConverterTypeDecodeSyncStateError.lift) {
+    uniffi_uniffi_automerge_fn_constructor_syncstate_decode(
+        FfiConverterSequenceUInt8.lower(bytes),$0
+    )
+})
+}
+    
 
     
+open func encode() -> [UInt8] {
+    return try!  FfiConverterSequenceUInt8.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_syncstate_encode(self.uniffiClonePointer(),$0
+    )
+})
+}
     
-    case WrongObjectType(message: String)
+open func inFlight() -> Bool {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_syncstate_in_flight(self.uniffiClonePointer(),$0
+    )
+})
+}
     
-    case Internal(message: String)
+open func lastSentHeads() -> [ChangeHash] {
+    return try!  FfiConverterSequenceTypeChangeHash.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_syncstate_last_sent_heads(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func reset() {try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_syncstate_reset(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+open func sentHashesCount() -> UInt64 {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_syncstate_sent_hashes_count(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func sharedHeads() -> [ChangeHash] {
+    return try!  FfiConverterSequenceTypeChangeHash.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_syncstate_shared_heads(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func theirHave() -> [SyncHave]? {
+    return try!  FfiConverterOptionSequenceTypeSyncHave.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_syncstate_their_have(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func theirHeads() -> [ChangeHash]? {
+    return try!  FfiConverterOptionSequenceTypeChangeHash.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_syncstate_their_heads(self.uniffiClonePointer(),$0
+    )
+})
+}
     
+open func theirNeed() -> [ChangeHash]? {
+    return try!  FfiConverterOptionSequenceTypeChangeHash.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_syncstate_their_need(self.uniffiClonePointer(),$0
+    )
+})
 }
+    
 
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDocError: FfiConverterRustBuffer {
-    typealias SwiftType = DocError
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DocError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-
-        
+public struct FfiConverterTypeSyncState: FfiConverter {
 
-        
-        case 1: return .WrongObjectType(
-            message: try FfiConverterString.read(from: &buf)
-        )
-        
-        case 2: return .Internal(
-            message: try FfiConverterString.read(from: &buf)
-        )
-        
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = SyncState
 
-        default: throw UniffiInternalError.unexpectedEnumCase
-        }
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> SyncState {
+        return SyncState(unsafeFromRawPointer: pointer)
     }
 
-    public static func write(_ value: DocError, into buf: inout [UInt8]) {
-        switch value {
-
-        
-
-        
-        case .WrongObjectType(_ /* message is ignored*/):
-            writeInt(&buf, Int32(1))
-        case .Internal(_ /* message is ignored*/):
-            writeInt(&buf, Int32(2))
+    public static func lower(_ value: SyncState) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
 
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SyncState {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
         }
+        return try lift(ptr!)
     }
-}
-
-
-extension DocError: Equatable, Hashable {}
 
-extension DocError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
+    public static func write(_ value: SyncState, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
     }
 }
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 
-public enum ExpandMark {
-    
-    case before
-    case after
-    case none
-    case both
-}
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeExpandMark: FfiConverterRustBuffer {
-    typealias SwiftType = ExpandMark
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ExpandMark {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .before
-        
-        case 2: return .after
-        
-        case 3: return .none
-        
-        case 4: return .both
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
-        }
-    }
-
-    public static func write(_ value: ExpandMark, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .before:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .after:
-            writeInt(&buf, Int32(2))
-        
-        
-        case .none:
-            writeInt(&buf, Int32(3))
-        
-        
-        case .both:
-            writeInt(&buf, Int32(4))
-        
-        }
-    }
+public func FfiConverterTypeSyncState_lift(_ pointer: UnsafeMutableRawPointer) throws -> SyncState {
+    return try FfiConverterTypeSyncState.lift(pointer)
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeExpandMark_lift(_ buf: RustBuffer) throws -> ExpandMark {
+public func FfiConverterTypeSyncState_lower(_ value: SyncState) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeSyncState.lower(value)
+}
+
+
+
+
+public protocol TransactionProtocol : AnyObject {
+    
+    func commit(msg: String?, time: Int64) throws  -> ChangeHash?
+    
+    func deleteInList(obj: ObjId, index: UInt64) throws 
+    
+    func deleteInMap(obj: ObjId, key: String) throws 
+    
+    func incrementInList(obj: ObjId, index: UInt64, by: Int64) throws 
+    
+    func incrementInMap(obj: ObjId, key: String, by: Int64) throws 
+    
+    func insertInList(obj: ObjId, index: UInt64, value: ScalarValue) throws 
+    
+    func insertObjectInList(obj: ObjId, index: UInt64, objType: ObjType) throws  -> ObjId
+    
+    func mark(obj: ObjId, start: UInt64, end: UInt64, expand: ExpandMark, name: String, value: ScalarValue) throws 
+    
+    func putInList(obj: ObjId, index: UInt64, value: ScalarValue) throws 
+    
+    func putInMap(obj: ObjId, key: String, value: ScalarValue) throws 
+    
+    func putObjectInList(obj: ObjId, index: UInt64, objType: ObjType) throws  -> ObjId
+    
+    func putObjectInMap(obj: ObjId, key: String, objType: ObjType) throws  -> ObjId
+    
+    func rollback() 
+    
+    func splice(obj: ObjId, start: UInt64, delete: Int64, values: [ScalarValue]) throws 
+    
+    func spliceText(obj: ObjId, start: UInt64, delete: Int64, chars: String) throws 
+    
+    func updateText(obj: ObjId, chars: String) throws 
+    
+}
+
+open class Transaction:
+    TransactionProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_uniffi_automerge_fn_clone_transaction(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_uniffi_automerge_fn_free_transaction(pointer, $0) }
+    }
+
+    
+
+    
+open func commit(msg: String?, time: Int64)throws  -> ChangeHash? {
+    return try  FfiConverterOptionTypeChangeHash.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_transaction_commit(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(msg),
+        FfiConverterInt64.lower(time),$0
+    )
+})
+}
+    
+open func deleteInList(obj: ObjId, index: UInt64)throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_transaction_delete_in_list(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterUInt64.lower(index),$0
+    )
+}
+}
+    
+open func deleteInMap(obj: ObjId, key: String)throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_transaction_delete_in_map(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterString.lower(key),$0
+    )
+}
+}
+    
+open func incrementInList(obj: ObjId, index: UInt64, by: Int64)throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_transaction_increment_in_list(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterUInt64.lower(index),
+        FfiConverterInt64.lower(by),$0
+    )
+}
+}
+    
+open func incrementInMap(obj: ObjId, key: String, by: Int64)throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_transaction_increment_in_map(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterString.lower(key),
+        FfiConverterInt64.lower(by),$0
+    )
+}
+}
+    
+open func insertInList(obj: ObjId, index: UInt64, value: ScalarValue)throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_transaction_insert_in_list(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterUInt64.lower(index),
+        FfiConverterTypeScalarValue.lower(value),$0
+    )
+}
+}
+    
+open func insertObjectInList(obj: ObjId, index: UInt64, objType: ObjType)throws  -> ObjId {
+    return try  FfiConverterTypeObjId.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_transaction_insert_object_in_list(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterUInt64.lower(index),
+        FfiConverterTypeObjType.lower(objType),$0
+    )
+})
+}
+    
+open func mark(obj: ObjId, start: UInt64, end: UInt64, expand: ExpandMark, name: String, value: ScalarValue)throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_transaction_mark(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterUInt64.lower(start),
+        FfiConverterUInt64.lower(end),
+        FfiConverterTypeExpandMark.lower(expand),
+        FfiConverterString.lower(name),
+        FfiConverterTypeScalarValue.lower(value),$0
+    )
+}
+}
+    
+open func putInList(obj: ObjId, index: UInt64, value: ScalarValue)throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_transaction_put_in_list(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterUInt64.lower(index),
+        FfiConverterTypeScalarValue.lower(value),$0
+    )
+}
+}
+    
+open func putInMap(obj: ObjId, key: String, value: ScalarValue)throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_transaction_put_in_map(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterString.lower(key),
+        FfiConverterTypeScalarValue.lower(value),$0
+    )
+}
+}
+    
+open func putObjectInList(obj: ObjId, index: UInt64, objType: ObjType)throws  -> ObjId {
+    return try  FfiConverterTypeObjId.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_transaction_put_object_in_list(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterUInt64.lower(index),
+        FfiConverterTypeObjType.lower(objType),$0
+    )
+})
+}
+    
+open func putObjectInMap(obj: ObjId, key: String, objType: ObjType)throws  -> ObjId {
+    return try  FfiConverterTypeObjId.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_transaction_put_object_in_map(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterString.lower(key),
+        FfiConverterTypeObjType.lower(objType),$0
+    )
+})
+}
+    
+open func rollback() {try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_transaction_rollback(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+open func splice(obj: ObjId, start: UInt64, delete: Int64, values: [ScalarValue])throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_transaction_splice(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterUInt64.lower(start),
+        FfiConverterInt64.lower(delete),
+        FfiConverterSequenceTypeScalarValue.lower(values),$0
+    )
+}
+}
+    
+open func spliceText(obj: ObjId, start: UInt64, delete: Int64, chars: String)throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_transaction_splice_text(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterUInt64.lower(start),
+        FfiConverterInt64.lower(delete),
+        FfiConverterString.lower(chars),$0
+    )
+}
+}
+    
+open func updateText(obj: ObjId, chars: String)throws  {try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_transaction_update_text(self.uniffiClonePointer(),
+        FfiConverterTypeObjId.lower(obj),
+        FfiConverterString.lower(chars),$0
+    )
+}
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTransaction: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = Transaction
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> Transaction {
+        return Transaction(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: Transaction) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Transaction {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: Transaction, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTransaction_lift(_ pointer: UnsafeMutableRawPointer) throws -> Transaction {
+    return try FfiConverterTypeTransaction.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTransaction_lower(_ value: Transaction) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeTransaction.lower(value)
+}
+
+
+
+
+public protocol UndoManagerProtocol : AnyObject {
+    
+    func beginGroup() 
+    
+    func canRedo()  -> Bool
+    
+    func canUndo()  -> Bool
+    
+    func clear() 
+    
+    func endGroup() 
+    
+    func record() 
+    
+    func redo() throws  -> Bool
+    
+    func undo() throws  -> Bool
+    
+}
+
+open class UndoManager:
+    UndoManagerProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_uniffi_automerge_fn_clone_undomanager(self.pointer, $0) }
+    }
+public convenience init(doc: Doc, scope: ObjId?, expand: ExpandMark)throws  {
+    let pointer =
+        try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_constructor_undomanager_new(
+        FfiConverterTypeDoc.lower(doc),
+        FfiConverterOptionTypeObjId.lower(scope),
+        FfiConverterTypeExpandMark.lower(expand),$0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_uniffi_automerge_fn_free_undomanager(pointer, $0) }
+    }
+
+    
+
+    
+open func beginGroup() {try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_undomanager_begin_group(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+open func canRedo() -> Bool {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_undomanager_can_redo(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func canUndo() -> Bool {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_undomanager_can_undo(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func clear() {try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_undomanager_clear(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+open func endGroup() {try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_undomanager_end_group(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+open func record() {try! rustCall() {
+    uniffi_uniffi_automerge_fn_method_undomanager_record(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+open func redo()throws  -> Bool {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_undomanager_redo(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func undo()throws  -> Bool {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeDocError.lift) {
+    uniffi_uniffi_automerge_fn_method_undomanager_undo(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUndoManager: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = UndoManager
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> UndoManager {
+        return UndoManager(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: UndoManager) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UndoManager {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: UndoManager, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUndoManager_lift(_ pointer: UnsafeMutableRawPointer) throws -> UndoManager {
+    return try FfiConverterTypeUndoManager.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUndoManager_lower(_ value: UndoManager) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeUndoManager.lower(value)
+}
+
+
+public struct Attribution {
+    public var change: ChangeHash
+    public var actor: ActorId
+    public var seq: UInt64
+    public var timestamp: Int64
+    public var message: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(change: ChangeHash, actor: ActorId, seq: UInt64, timestamp: Int64, message: String?) {
+        self.change = change
+        self.actor = actor
+        self.seq = seq
+        self.timestamp = timestamp
+        self.message = message
+    }
+}
+
+
+
+extension Attribution: Equatable, Hashable {
+    public static func ==(lhs: Attribution, rhs: Attribution) -> Bool {
+        if lhs.change != rhs.change {
+            return false
+        }
+        if lhs.actor != rhs.actor {
+            return false
+        }
+        if lhs.seq != rhs.seq {
+            return false
+        }
+        if lhs.timestamp != rhs.timestamp {
+            return false
+        }
+        if lhs.message != rhs.message {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(change)
+        hasher.combine(actor)
+        hasher.combine(seq)
+        hasher.combine(timestamp)
+        hasher.combine(message)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAttribution: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Attribution {
+        return
+            try Attribution(
+                change: FfiConverterTypeChangeHash.read(from: &buf), 
+                actor: FfiConverterTypeActorId.read(from: &buf), 
+                seq: FfiConverterUInt64.read(from: &buf), 
+                timestamp: FfiConverterInt64.read(from: &buf), 
+                message: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Attribution, into buf: inout [UInt8]) {
+        FfiConverterTypeChangeHash.write(value.change, into: &buf)
+        FfiConverterTypeActorId.write(value.actor, into: &buf)
+        FfiConverterUInt64.write(value.seq, into: &buf)
+        FfiConverterInt64.write(value.timestamp, into: &buf)
+        FfiConverterOptionString.write(value.message, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAttribution_lift(_ buf: RustBuffer) throws -> Attribution {
+    return try FfiConverterTypeAttribution.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAttribution_lower(_ value: Attribution) -> RustBuffer {
+    return FfiConverterTypeAttribution.lower(value)
+}
+
+
+public struct Branch {
+    public var name: String
+    public var heads: [ChangeHash]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(name: String, heads: [ChangeHash]) {
+        self.name = name
+        self.heads = heads
+    }
+}
+
+
+
+extension Branch: Equatable, Hashable {
+    public static func ==(lhs: Branch, rhs: Branch) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.heads != rhs.heads {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(heads)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeBranch: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Branch {
+        return
+            try Branch(
+                name: FfiConverterString.read(from: &buf), 
+                heads: FfiConverterSequenceTypeChangeHash.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Branch, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterSequenceTypeChangeHash.write(value.heads, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBranch_lift(_ buf: RustBuffer) throws -> Branch {
+    return try FfiConverterTypeBranch.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBranch_lower(_ value: Branch) -> RustBuffer {
+    return FfiConverterTypeBranch.lower(value)
+}
+
+
+public struct Change {
+    public var actorId: ActorId
+    public var seq: UInt64
+    public var message: String?
+    public var deps: [ChangeHash]
+    public var timestamp: Int64
+    public var bytes: [UInt8]
+    public var hash: ChangeHash
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(actorId: ActorId, seq: UInt64, message: String?, deps: [ChangeHash], timestamp: Int64, bytes: [UInt8], hash: ChangeHash) {
+        self.actorId = actorId
+        self.seq = seq
+        self.message = message
+        self.deps = deps
+        self.timestamp = timestamp
+        self.bytes = bytes
+        self.hash = hash
+    }
+}
+
+
+
+extension Change: Equatable, Hashable {
+    public static func ==(lhs: Change, rhs: Change) -> Bool {
+        if lhs.actorId != rhs.actorId {
+            return false
+        }
+        if lhs.seq != rhs.seq {
+            return false
+        }
+        if lhs.message != rhs.message {
+            return false
+        }
+        if lhs.deps != rhs.deps {
+            return false
+        }
+        if lhs.timestamp != rhs.timestamp {
+            return false
+        }
+        if lhs.bytes != rhs.bytes {
+            return false
+        }
+        if lhs.hash != rhs.hash {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(actorId)
+        hasher.combine(seq)
+        hasher.combine(message)
+        hasher.combine(deps)
+        hasher.combine(timestamp)
+        hasher.combine(bytes)
+        hasher.combine(hash)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeChange: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Change {
+        return
+            try Change(
+                actorId: FfiConverterTypeActorId.read(from: &buf), 
+                seq: FfiConverterUInt64.read(from: &buf), 
+                message: FfiConverterOptionString.read(from: &buf), 
+                deps: FfiConverterSequenceTypeChangeHash.read(from: &buf), 
+                timestamp: FfiConverterInt64.read(from: &buf), 
+                bytes: FfiConverterSequenceUInt8.read(from: &buf), 
+                hash: FfiConverterTypeChangeHash.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Change, into buf: inout [UInt8]) {
+        FfiConverterTypeActorId.write(value.actorId, into: &buf)
+        FfiConverterUInt64.write(value.seq, into: &buf)
+        FfiConverterOptionString.write(value.message, into: &buf)
+        FfiConverterSequenceTypeChangeHash.write(value.deps, into: &buf)
+        FfiConverterInt64.write(value.timestamp, into: &buf)
+        FfiConverterSequenceUInt8.write(value.bytes, into: &buf)
+        FfiConverterTypeChangeHash.write(value.hash, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeChange_lift(_ buf: RustBuffer) throws -> Change {
+    return try FfiConverterTypeChange.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeChange_lower(_ value: Change) -> RustBuffer {
+    return FfiConverterTypeChange.lower(value)
+}
+
+
+public struct CherryPick {
+    public var applied: [ChangeHash]
+    public var dependencies: [ChangeHash]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(applied: [ChangeHash], dependencies: [ChangeHash]) {
+        self.applied = applied
+        self.dependencies = dependencies
+    }
+}
+
+
+
+extension CherryPick: Equatable, Hashable {
+    public static func ==(lhs: CherryPick, rhs: CherryPick) -> Bool {
+        if lhs.applied != rhs.applied {
+            return false
+        }
+        if lhs.dependencies != rhs.dependencies {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(applied)
+        hasher.combine(dependencies)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCherryPick: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CherryPick {
+        return
+            try CherryPick(
+                applied: FfiConverterSequenceTypeChangeHash.read(from: &buf), 
+                dependencies: FfiConverterSequenceTypeChangeHash.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: CherryPick, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeChangeHash.write(value.applied, into: &buf)
+        FfiConverterSequenceTypeChangeHash.write(value.dependencies, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCherryPick_lift(_ buf: RustBuffer) throws -> CherryPick {
+    return try FfiConverterTypeCherryPick.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCherryPick_lower(_ value: CherryPick) -> RustBuffer {
+    return FfiConverterTypeCherryPick.lower(value)
+}
+
+
+public struct Conflict {
+    public var value: Value
+    public var opId: String
+    public var actor: ActorId
+    public var change: ChangeHash?
+    public var winner: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(value: Value, opId: String, actor: ActorId, change: ChangeHash?, winner: Bool) {
+        self.value = value
+        self.opId = opId
+        self.actor = actor
+        self.change = change
+        self.winner = winner
+    }
+}
+
+
+
+extension Conflict: Equatable, Hashable {
+    public static func ==(lhs: Conflict, rhs: Conflict) -> Bool {
+        if lhs.value != rhs.value {
+            return false
+        }
+        if lhs.opId != rhs.opId {
+            return false
+        }
+        if lhs.actor != rhs.actor {
+            return false
+        }
+        if lhs.change != rhs.change {
+            return false
+        }
+        if lhs.winner != rhs.winner {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(value)
+        hasher.combine(opId)
+        hasher.combine(actor)
+        hasher.combine(change)
+        hasher.combine(winner)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeConflict: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Conflict {
+        return
+            try Conflict(
+                value: FfiConverterTypeValue.read(from: &buf), 
+                opId: FfiConverterString.read(from: &buf), 
+                actor: FfiConverterTypeActorId.read(from: &buf), 
+                change: FfiConverterOptionTypeChangeHash.read(from: &buf), 
+                winner: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Conflict, into buf: inout [UInt8]) {
+        FfiConverterTypeValue.write(value.value, into: &buf)
+        FfiConverterString.write(value.opId, into: &buf)
+        FfiConverterTypeActorId.write(value.actor, into: &buf)
+        FfiConverterOptionTypeChangeHash.write(value.change, into: &buf)
+        FfiConverterBool.write(value.winner, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeConflict_lift(_ buf: RustBuffer) throws -> Conflict {
+    return try FfiConverterTypeConflict.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeConflict_lower(_ value: Conflict) -> RustBuffer {
+    return FfiConverterTypeConflict.lower(value)
+}
+
+
+public struct DiffSummary {
+    public var obj: ObjId
+    public var path: [PathElement]
+    public var changedKeys: [String]
+    public var inserted: UInt64
+    public var removed: UInt64
+    public var updated: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(obj: ObjId, path: [PathElement], changedKeys: [String], inserted: UInt64, removed: UInt64, updated: UInt64) {
+        self.obj = obj
+        self.path = path
+        self.changedKeys = changedKeys
+        self.inserted = inserted
+        self.removed = removed
+        self.updated = updated
+    }
+}
+
+
+
+extension DiffSummary: Equatable, Hashable {
+    public static func ==(lhs: DiffSummary, rhs: DiffSummary) -> Bool {
+        if lhs.obj != rhs.obj {
+            return false
+        }
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.changedKeys != rhs.changedKeys {
+            return false
+        }
+        if lhs.inserted != rhs.inserted {
+            return false
+        }
+        if lhs.removed != rhs.removed {
+            return false
+        }
+        if lhs.updated != rhs.updated {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(obj)
+        hasher.combine(path)
+        hasher.combine(changedKeys)
+        hasher.combine(inserted)
+        hasher.combine(removed)
+        hasher.combine(updated)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDiffSummary: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DiffSummary {
+        return
+            try DiffSummary(
+                obj: FfiConverterTypeObjId.read(from: &buf), 
+                path: FfiConverterSequenceTypePathElement.read(from: &buf), 
+                changedKeys: FfiConverterSequenceString.read(from: &buf), 
+                inserted: FfiConverterUInt64.read(from: &buf), 
+                removed: FfiConverterUInt64.read(from: &buf), 
+                updated: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: DiffSummary, into buf: inout [UInt8]) {
+        FfiConverterTypeObjId.write(value.obj, into: &buf)
+        FfiConverterSequenceTypePathElement.write(value.path, into: &buf)
+        FfiConverterSequenceString.write(value.changedKeys, into: &buf)
+        FfiConverterUInt64.write(value.inserted, into: &buf)
+        FfiConverterUInt64.write(value.removed, into: &buf)
+        FfiConverterUInt64.write(value.updated, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDiffSummary_lift(_ buf: RustBuffer) throws -> DiffSummary {
+    return try FfiConverterTypeDiffSummary.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDiffSummary_lower(_ value: DiffSummary) -> RustBuffer {
+    return FfiConverterTypeDiffSummary.lower(value)
+}
+
+
+public struct EphemeralMessage {
+    public var senderId: String
+    public var documentId: DocumentId
+    public var data: [UInt8]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(senderId: String, documentId: DocumentId, data: [UInt8]) {
+        self.senderId = senderId
+        self.documentId = documentId
+        self.data = data
+    }
+}
+
+
+
+extension EphemeralMessage: Equatable, Hashable {
+    public static func ==(lhs: EphemeralMessage, rhs: EphemeralMessage) -> Bool {
+        if lhs.senderId != rhs.senderId {
+            return false
+        }
+        if lhs.documentId != rhs.documentId {
+            return false
+        }
+        if lhs.data != rhs.data {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(senderId)
+        hasher.combine(documentId)
+        hasher.combine(data)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeEphemeralMessage: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> EphemeralMessage {
+        return
+            try EphemeralMessage(
+                senderId: FfiConverterString.read(from: &buf), 
+                documentId: FfiConverterTypeDocumentId.read(from: &buf), 
+                data: FfiConverterSequenceUInt8.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: EphemeralMessage, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.senderId, into: &buf)
+        FfiConverterTypeDocumentId.write(value.documentId, into: &buf)
+        FfiConverterSequenceUInt8.write(value.data, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEphemeralMessage_lift(_ buf: RustBuffer) throws -> EphemeralMessage {
+    return try FfiConverterTypeEphemeralMessage.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEphemeralMessage_lower(_ value: EphemeralMessage) -> RustBuffer {
+    return FfiConverterTypeEphemeralMessage.lower(value)
+}
+
+
+public struct KeyValue {
+    public var key: String
+    public var value: Value
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(key: String, value: Value) {
+        self.key = key
+        self.value = value
+    }
+}
+
+
+
+extension KeyValue: Equatable, Hashable {
+    public static func ==(lhs: KeyValue, rhs: KeyValue) -> Bool {
+        if lhs.key != rhs.key {
+            return false
+        }
+        if lhs.value != rhs.value {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(key)
+        hasher.combine(value)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeKeyValue: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> KeyValue {
+        return
+            try KeyValue(
+                key: FfiConverterString.read(from: &buf), 
+                value: FfiConverterTypeValue.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: KeyValue, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.key, into: &buf)
+        FfiConverterTypeValue.write(value.value, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeKeyValue_lift(_ buf: RustBuffer) throws -> KeyValue {
+    return try FfiConverterTypeKeyValue.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeKeyValue_lower(_ value: KeyValue) -> RustBuffer {
+    return FfiConverterTypeKeyValue.lower(value)
+}
+
+
+public struct LoadOptions {
+    public var onPartialLoad: OnPartialLoad
+    public var verificationMode: VerificationMode
+    public var stringMigration: StringMigration
+    public var textEncoding: TextEncoding?
+    public var actor: ActorId?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(onPartialLoad: OnPartialLoad = .error, verificationMode: VerificationMode = .check, stringMigration: StringMigration = .noMigration, textEncoding: TextEncoding? = nil, actor: ActorId? = nil) {
+        self.onPartialLoad = onPartialLoad
+        self.verificationMode = verificationMode
+        self.stringMigration = stringMigration
+        self.textEncoding = textEncoding
+        self.actor = actor
+    }
+}
+
+
+
+extension LoadOptions: Equatable, Hashable {
+    public static func ==(lhs: LoadOptions, rhs: LoadOptions) -> Bool {
+        if lhs.onPartialLoad != rhs.onPartialLoad {
+            return false
+        }
+        if lhs.verificationMode != rhs.verificationMode {
+            return false
+        }
+        if lhs.stringMigration != rhs.stringMigration {
+            return false
+        }
+        if lhs.textEncoding != rhs.textEncoding {
+            return false
+        }
+        if lhs.actor != rhs.actor {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(onPartialLoad)
+        hasher.combine(verificationMode)
+        hasher.combine(stringMigration)
+        hasher.combine(textEncoding)
+        hasher.combine(actor)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLoadOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LoadOptions {
+        return
+            try LoadOptions(
+                onPartialLoad: FfiConverterTypeOnPartialLoad.read(from: &buf), 
+                verificationMode: FfiConverterTypeVerificationMode.read(from: &buf), 
+                stringMigration: FfiConverterTypeStringMigration.read(from: &buf), 
+                textEncoding: FfiConverterOptionTypeTextEncoding.read(from: &buf), 
+                actor: FfiConverterOptionTypeActorId.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: LoadOptions, into buf: inout [UInt8]) {
+        FfiConverterTypeOnPartialLoad.write(value.onPartialLoad, into: &buf)
+        FfiConverterTypeVerificationMode.write(value.verificationMode, into: &buf)
+        FfiConverterTypeStringMigration.write(value.stringMigration, into: &buf)
+        FfiConverterOptionTypeTextEncoding.write(value.textEncoding, into: &buf)
+        FfiConverterOptionTypeActorId.write(value.actor, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLoadOptions_lift(_ buf: RustBuffer) throws -> LoadOptions {
+    return try FfiConverterTypeLoadOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLoadOptions_lower(_ value: LoadOptions) -> RustBuffer {
+    return FfiConverterTypeLoadOptions.lower(value)
+}
+
+
+public struct Mark {
+    public var start: UInt64
+    public var end: UInt64
+    public var name: String
+    public var value: ScalarValue
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(start: UInt64, end: UInt64, name: String, value: ScalarValue) {
+        self.start = start
+        self.end = end
+        self.name = name
+        self.value = value
+    }
+}
+
+
+
+extension Mark: Equatable, Hashable {
+    public static func ==(lhs: Mark, rhs: Mark) -> Bool {
+        if lhs.start != rhs.start {
+            return false
+        }
+        if lhs.end != rhs.end {
+            return false
+        }
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.value != rhs.value {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(start)
+        hasher.combine(end)
+        hasher.combine(name)
+        hasher.combine(value)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeMark: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Mark {
+        return
+            try Mark(
+                start: FfiConverterUInt64.read(from: &buf), 
+                end: FfiConverterUInt64.read(from: &buf), 
+                name: FfiConverterString.read(from: &buf), 
+                value: FfiConverterTypeScalarValue.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Mark, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.start, into: &buf)
+        FfiConverterUInt64.write(value.end, into: &buf)
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterTypeScalarValue.write(value.value, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMark_lift(_ buf: RustBuffer) throws -> Mark {
+    return try FfiConverterTypeMark.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMark_lower(_ value: Mark) -> RustBuffer {
+    return FfiConverterTypeMark.lower(value)
+}
+
+
+public struct Patch {
+    public var path: [PathElement]
+    public var action: PatchAction
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(path: [PathElement], action: PatchAction) {
+        self.path = path
+        self.action = action
+    }
+}
+
+
+
+extension Patch: Equatable, Hashable {
+    public static func ==(lhs: Patch, rhs: Patch) -> Bool {
+        if lhs.path != rhs.path {
+            return false
+        }
+        if lhs.action != rhs.action {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(path)
+        hasher.combine(action)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePatch: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Patch {
+        return
+            try Patch(
+                path: FfiConverterSequenceTypePathElement.read(from: &buf), 
+                action: FfiConverterTypePatchAction.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Patch, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypePathElement.write(value.path, into: &buf)
+        FfiConverterTypePatchAction.write(value.action, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePatch_lift(_ buf: RustBuffer) throws -> Patch {
+    return try FfiConverterTypePatch.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePatch_lower(_ value: Patch) -> RustBuffer {
+    return FfiConverterTypePatch.lower(value)
+}
+
+
+public struct PathElement {
+    public var prop: Prop
+    public var obj: ObjId
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(prop: Prop, obj: ObjId) {
+        self.prop = prop
+        self.obj = obj
+    }
+}
+
+
+
+extension PathElement: Equatable, Hashable {
+    public static func ==(lhs: PathElement, rhs: PathElement) -> Bool {
+        if lhs.prop != rhs.prop {
+            return false
+        }
+        if lhs.obj != rhs.obj {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(prop)
+        hasher.combine(obj)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePathElement: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PathElement {
+        return
+            try PathElement(
+                prop: FfiConverterTypeProp.read(from: &buf), 
+                obj: FfiConverterTypeObjId.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: PathElement, into buf: inout [UInt8]) {
+        FfiConverterTypeProp.write(value.prop, into: &buf)
+        FfiConverterTypeObjId.write(value.obj, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePathElement_lift(_ buf: RustBuffer) throws -> PathElement {
+    return try FfiConverterTypePathElement.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePathElement_lower(_ value: PathElement) -> RustBuffer {
+    return FfiConverterTypePathElement.lower(value)
+}
+
+
+public struct PeerMessage {
+    public var peer: String
+    public var message: [UInt8]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(peer: String, message: [UInt8]) {
+        self.peer = peer
+        self.message = message
+    }
+}
+
+
+
+extension PeerMessage: Equatable, Hashable {
+    public static func ==(lhs: PeerMessage, rhs: PeerMessage) -> Bool {
+        if lhs.peer != rhs.peer {
+            return false
+        }
+        if lhs.message != rhs.message {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(peer)
+        hasher.combine(message)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePeerMessage: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PeerMessage {
+        return
+            try PeerMessage(
+                peer: FfiConverterString.read(from: &buf), 
+                message: FfiConverterSequenceUInt8.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: PeerMessage, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.peer, into: &buf)
+        FfiConverterSequenceUInt8.write(value.message, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePeerMessage_lift(_ buf: RustBuffer) throws -> PeerMessage {
+    return try FfiConverterTypePeerMessage.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePeerMessage_lower(_ value: PeerMessage) -> RustBuffer {
+    return FfiConverterTypePeerMessage.lower(value)
+}
+
+
+public struct PeerMetadata {
+    public var storageId: String?
+    public var isEphemeral: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(storageId: String? = nil, isEphemeral: Bool = false) {
+        self.storageId = storageId
+        self.isEphemeral = isEphemeral
+    }
+}
+
+
+
+extension PeerMetadata: Equatable, Hashable {
+    public static func ==(lhs: PeerMetadata, rhs: PeerMetadata) -> Bool {
+        if lhs.storageId != rhs.storageId {
+            return false
+        }
+        if lhs.isEphemeral != rhs.isEphemeral {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(storageId)
+        hasher.combine(isEphemeral)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePeerMetadata: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PeerMetadata {
+        return
+            try PeerMetadata(
+                storageId: FfiConverterOptionString.read(from: &buf), 
+                isEphemeral: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: PeerMetadata, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.storageId, into: &buf)
+        FfiConverterBool.write(value.isEphemeral, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePeerMetadata_lift(_ buf: RustBuffer) throws -> PeerMetadata {
+    return try FfiConverterTypePeerMetadata.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePeerMetadata_lower(_ value: PeerMetadata) -> RustBuffer {
+    return FfiConverterTypePeerMetadata.lower(value)
+}
+
+
+public struct PresenceState {
+    public var actor: ActorId
+    public var timestamp: Int64
+    public var expiresAt: Int64
+    public var payload: [String: PresenceValue]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(actor: ActorId, timestamp: Int64, expiresAt: Int64, payload: [String: PresenceValue]) {
+        self.actor = actor
+        self.timestamp = timestamp
+        self.expiresAt = expiresAt
+        self.payload = payload
+    }
+}
+
+
+
+extension PresenceState: Equatable, Hashable {
+    public static func ==(lhs: PresenceState, rhs: PresenceState) -> Bool {
+        if lhs.actor != rhs.actor {
+            return false
+        }
+        if lhs.timestamp != rhs.timestamp {
+            return false
+        }
+        if lhs.expiresAt != rhs.expiresAt {
+            return false
+        }
+        if lhs.payload != rhs.payload {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(actor)
+        hasher.combine(timestamp)
+        hasher.combine(expiresAt)
+        hasher.combine(payload)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePresenceState: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PresenceState {
+        return
+            try PresenceState(
+                actor: FfiConverterTypeActorId.read(from: &buf), 
+                timestamp: FfiConverterInt64.read(from: &buf), 
+                expiresAt: FfiConverterInt64.read(from: &buf), 
+                payload: FfiConverterDictionaryStringTypePresenceValue.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: PresenceState, into buf: inout [UInt8]) {
+        FfiConverterTypeActorId.write(value.actor, into: &buf)
+        FfiConverterInt64.write(value.timestamp, into: &buf)
+        FfiConverterInt64.write(value.expiresAt, into: &buf)
+        FfiConverterDictionaryStringTypePresenceValue.write(value.payload, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePresenceState_lift(_ buf: RustBuffer) throws -> PresenceState {
+    return try FfiConverterTypePresenceState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePresenceState_lower(_ value: PresenceState) -> RustBuffer {
+    return FfiConverterTypePresenceState.lower(value)
+}
+
+
+public struct SyncHave {
+    public var lastSync: [ChangeHash]
+    public var bloom: [UInt8]
+    public var bloomEntries: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(lastSync: [ChangeHash], bloom: [UInt8], bloomEntries: UInt64) {
+        self.lastSync = lastSync
+        self.bloom = bloom
+        self.bloomEntries = bloomEntries
+    }
+}
+
+
+
+extension SyncHave: Equatable, Hashable {
+    public static func ==(lhs: SyncHave, rhs: SyncHave) -> Bool {
+        if lhs.lastSync != rhs.lastSync {
+            return false
+        }
+        if lhs.bloom != rhs.bloom {
+            return false
+        }
+        if lhs.bloomEntries != rhs.bloomEntries {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(lastSync)
+        hasher.combine(bloom)
+        hasher.combine(bloomEntries)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSyncHave: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SyncHave {
+        return
+            try SyncHave(
+                lastSync: FfiConverterSequenceTypeChangeHash.read(from: &buf), 
+                bloom: FfiConverterSequenceUInt8.read(from: &buf), 
+                bloomEntries: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SyncHave, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeChangeHash.write(value.lastSync, into: &buf)
+        FfiConverterSequenceUInt8.write(value.bloom, into: &buf)
+        FfiConverterUInt64.write(value.bloomEntries, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSyncHave_lift(_ buf: RustBuffer) throws -> SyncHave {
+    return try FfiConverterTypeSyncHave.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSyncHave_lower(_ value: SyncHave) -> RustBuffer {
+    return FfiConverterTypeSyncHave.lower(value)
+}
+
+
+public struct SyncMessage {
+    public var version: SyncMessageVersion
+    public var heads: [ChangeHash]
+    public var need: [ChangeHash]
+    public var have: [SyncHave]
+    public var changes: [Change]
+    public var supportedCapabilities: [SyncCapability]?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(version: SyncMessageVersion, heads: [ChangeHash], need: [ChangeHash], have: [SyncHave], changes: [Change], supportedCapabilities: [SyncCapability]?) {
+        self.version = version
+        self.heads = heads
+        self.need = need
+        self.have = have
+        self.changes = changes
+        self.supportedCapabilities = supportedCapabilities
+    }
+}
+
+
+
+extension SyncMessage: Equatable, Hashable {
+    public static func ==(lhs: SyncMessage, rhs: SyncMessage) -> Bool {
+        if lhs.version != rhs.version {
+            return false
+        }
+        if lhs.heads != rhs.heads {
+            return false
+        }
+        if lhs.need != rhs.need {
+            return false
+        }
+        if lhs.have != rhs.have {
+            return false
+        }
+        if lhs.changes != rhs.changes {
+            return false
+        }
+        if lhs.supportedCapabilities != rhs.supportedCapabilities {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(version)
+        hasher.combine(heads)
+        hasher.combine(need)
+        hasher.combine(have)
+        hasher.combine(changes)
+        hasher.combine(supportedCapabilities)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSyncMessage: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SyncMessage {
+        return
+            try SyncMessage(
+                version: FfiConverterTypeSyncMessageVersion.read(from: &buf), 
+                heads: FfiConverterSequenceTypeChangeHash.read(from: &buf), 
+                need: FfiConverterSequenceTypeChangeHash.read(from: &buf), 
+                have: FfiConverterSequenceTypeSyncHave.read(from: &buf), 
+                changes: FfiConverterSequenceTypeChange.read(from: &buf), 
+                supportedCapabilities: FfiConverterOptionSequenceTypeSyncCapability.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SyncMessage, into buf: inout [UInt8]) {
+        FfiConverterTypeSyncMessageVersion.write(value.version, into: &buf)
+        FfiConverterSequenceTypeChangeHash.write(value.heads, into: &buf)
+        FfiConverterSequenceTypeChangeHash.write(value.need, into: &buf)
+        FfiConverterSequenceTypeSyncHave.write(value.have, into: &buf)
+        FfiConverterSequenceTypeChange.write(value.changes, into: &buf)
+        FfiConverterOptionSequenceTypeSyncCapability.write(value.supportedCapabilities, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSyncMessage_lift(_ buf: RustBuffer) throws -> SyncMessage {
+    return try FfiConverterTypeSyncMessage.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSyncMessage_lower(_ value: SyncMessage) -> RustBuffer {
+    return FfiConverterTypeSyncMessage.lower(value)
+}
+
+
+public struct TextSpan {
+    public var start: UInt64
+    public var end: UInt64
+    public var text: String
+    public var attribution: Attribution?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(start: UInt64, end: UInt64, text: String, attribution: Attribution?) {
+        self.start = start
+        self.end = end
+        self.text = text
+        self.attribution = attribution
+    }
+}
+
+
+
+extension TextSpan: Equatable, Hashable {
+    public static func ==(lhs: TextSpan, rhs: TextSpan) -> Bool {
+        if lhs.start != rhs.start {
+            return false
+        }
+        if lhs.end != rhs.end {
+            return false
+        }
+        if lhs.text != rhs.text {
+            return false
+        }
+        if lhs.attribution != rhs.attribution {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(start)
+        hasher.combine(end)
+        hasher.combine(text)
+        hasher.combine(attribution)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTextSpan: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TextSpan {
+        return
+            try TextSpan(
+                start: FfiConverterUInt64.read(from: &buf), 
+                end: FfiConverterUInt64.read(from: &buf), 
+                text: FfiConverterString.read(from: &buf), 
+                attribution: FfiConverterOptionTypeAttribution.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TextSpan, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.start, into: &buf)
+        FfiConverterUInt64.write(value.end, into: &buf)
+        FfiConverterString.write(value.text, into: &buf)
+        FfiConverterOptionTypeAttribution.write(value.attribution, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTextSpan_lift(_ buf: RustBuffer) throws -> TextSpan {
+    return try FfiConverterTypeTextSpan.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTextSpan_lower(_ value: TextSpan) -> RustBuffer {
+    return FfiConverterTypeTextSpan.lower(value)
+}
+
+
+public enum DecodeChangeError {
+
+    
+    
+    case Internal(message: String)
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDecodeChangeError: FfiConverterRustBuffer {
+    typealias SwiftType = DecodeChangeError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DecodeChangeError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .Internal(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DecodeChangeError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        case .Internal(_ /* message is ignored*/):
+            writeInt(&buf, Int32(1))
+
+        
+        }
+    }
+}
+
+
+extension DecodeChangeError: Equatable, Hashable {}
+
+extension DecodeChangeError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+
+public enum DecodePresenceError {
+
+    
+    
+    case InvalidMessage(message: String)
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDecodePresenceError: FfiConverterRustBuffer {
+    typealias SwiftType = DecodePresenceError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DecodePresenceError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .InvalidMessage(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DecodePresenceError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        case .InvalidMessage(_ /* message is ignored*/):
+            writeInt(&buf, Int32(1))
+
+        
+        }
+    }
+}
+
+
+extension DecodePresenceError: Equatable, Hashable {}
+
+extension DecodePresenceError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+
+public enum DecodeSyncMessageError {
+
+    
+    
+    case InvalidMessage(message: String)
+    
+    case InvalidChanges(message: String)
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDecodeSyncMessageError: FfiConverterRustBuffer {
+    typealias SwiftType = DecodeSyncMessageError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DecodeSyncMessageError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .InvalidMessage(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 2: return .InvalidChanges(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DecodeSyncMessageError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        case .InvalidMessage(_ /* message is ignored*/):
+            writeInt(&buf, Int32(1))
+        case .InvalidChanges(_ /* message is ignored*/):
+            writeInt(&buf, Int32(2))
+
+        
+        }
+    }
+}
+
+
+extension DecodeSyncMessageError: Equatable, Hashable {}
+
+extension DecodeSyncMessageError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+
+public enum DecodeSyncStateError {
+
+    
+    
+    case Internal(message: String)
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDecodeSyncStateError: FfiConverterRustBuffer {
+    typealias SwiftType = DecodeSyncStateError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DecodeSyncStateError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .Internal(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DecodeSyncStateError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        case .Internal(_ /* message is ignored*/):
+            writeInt(&buf, Int32(1))
+
+        
+        }
+    }
+}
+
+
+extension DecodeSyncStateError: Equatable, Hashable {}
+
+extension DecodeSyncStateError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+
+public enum DocError {
+
+    
+    
+    case WrongObjectType(obj: ObjId, expected: ObjType, actual: ObjType
+    )
+    case ObjectNotFound(obj: ObjId
+    )
+    case IndexOutOfBounds(obj: ObjId, index: UInt64, length: UInt64
+    )
+    case InvalidHeads(hashes: [ChangeHash]
+    )
+    case MissingDependencies(hashes: [ChangeHash]
+    )
+    case CorruptData(message: String
+    )
+    case DuplicateSeqNumber(actor: String, seq: UInt64
+    )
+    case InvalidObjId(message: String
+    )
+    case InvalidChangeHash(message: String
+    )
+    case InvalidCursor(message: String
+    )
+    case TransactionClosed
+    case InvalidJson(message: String
+    )
+    case ConflictNotFound(opId: String
+    )
+    case ChangeNotFound(hashes: [ChangeHash]
+    )
+    case BranchNotFound(name: String
+    )
+    case ReservedKey(key: String
+    )
+    case Internal(message: String
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDocError: FfiConverterRustBuffer {
+    typealias SwiftType = DocError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DocError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .WrongObjectType(
+            obj: try FfiConverterTypeObjId.read(from: &buf), 
+            expected: try FfiConverterTypeObjType.read(from: &buf), 
+            actual: try FfiConverterTypeObjType.read(from: &buf)
+            )
+        case 2: return .ObjectNotFound(
+            obj: try FfiConverterTypeObjId.read(from: &buf)
+            )
+        case 3: return .IndexOutOfBounds(
+            obj: try FfiConverterTypeObjId.read(from: &buf), 
+            index: try FfiConverterUInt64.read(from: &buf), 
+            length: try FfiConverterUInt64.read(from: &buf)
+            )
+        case 4: return .InvalidHeads(
+            hashes: try FfiConverterSequenceTypeChangeHash.read(from: &buf)
+            )
+        case 5: return .MissingDependencies(
+            hashes: try FfiConverterSequenceTypeChangeHash.read(from: &buf)
+            )
+        case 6: return .CorruptData(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 7: return .DuplicateSeqNumber(
+            actor: try FfiConverterString.read(from: &buf), 
+            seq: try FfiConverterUInt64.read(from: &buf)
+            )
+        case 8: return .InvalidObjId(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 9: return .InvalidChangeHash(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 10: return .InvalidCursor(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 11: return .TransactionClosed
+        case 12: return .InvalidJson(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 13: return .ConflictNotFound(
+            opId: try FfiConverterString.read(from: &buf)
+            )
+        case 14: return .ChangeNotFound(
+            hashes: try FfiConverterSequenceTypeChangeHash.read(from: &buf)
+            )
+        case 15: return .BranchNotFound(
+            name: try FfiConverterString.read(from: &buf)
+            )
+        case 16: return .ReservedKey(
+            key: try FfiConverterString.read(from: &buf)
+            )
+        case 17: return .Internal(
+            message: try FfiConverterString.read(from: &buf)
+            )
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DocError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case let .WrongObjectType(obj,expected,actual):
+            writeInt(&buf, Int32(1))
+            FfiConverterTypeObjId.write(obj, into: &buf)
+            FfiConverterTypeObjType.write(expected, into: &buf)
+            FfiConverterTypeObjType.write(actual, into: &buf)
+            
+        
+        case let .ObjectNotFound(obj):
+            writeInt(&buf, Int32(2))
+            FfiConverterTypeObjId.write(obj, into: &buf)
+            
+        
+        case let .IndexOutOfBounds(obj,index,length):
+            writeInt(&buf, Int32(3))
+            FfiConverterTypeObjId.write(obj, into: &buf)
+            FfiConverterUInt64.write(index, into: &buf)
+            FfiConverterUInt64.write(length, into: &buf)
+            
+        
+        case let .InvalidHeads(hashes):
+            writeInt(&buf, Int32(4))
+            FfiConverterSequenceTypeChangeHash.write(hashes, into: &buf)
+            
+        
+        case let .MissingDependencies(hashes):
+            writeInt(&buf, Int32(5))
+            FfiConverterSequenceTypeChangeHash.write(hashes, into: &buf)
+            
+        
+        case let .CorruptData(message):
+            writeInt(&buf, Int32(6))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .DuplicateSeqNumber(actor,seq):
+            writeInt(&buf, Int32(7))
+            FfiConverterString.write(actor, into: &buf)
+            FfiConverterUInt64.write(seq, into: &buf)
+            
+        
+        case let .InvalidObjId(message):
+            writeInt(&buf, Int32(8))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .InvalidChangeHash(message):
+            writeInt(&buf, Int32(9))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .InvalidCursor(message):
+            writeInt(&buf, Int32(10))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case .TransactionClosed:
+            writeInt(&buf, Int32(11))
+        
+        
+        case let .InvalidJson(message):
+            writeInt(&buf, Int32(12))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .ConflictNotFound(opId):
+            writeInt(&buf, Int32(13))
+            FfiConverterString.write(opId, into: &buf)
+            
+        
+        case let .ChangeNotFound(hashes):
+            writeInt(&buf, Int32(14))
+            FfiConverterSequenceTypeChangeHash.write(hashes, into: &buf)
+            
+        
+        case let .BranchNotFound(name):
+            writeInt(&buf, Int32(15))
+            FfiConverterString.write(name, into: &buf)
+            
+        
+        case let .ReservedKey(key):
+            writeInt(&buf, Int32(16))
+            FfiConverterString.write(key, into: &buf)
+            
+        
+        case let .Internal(message):
+            writeInt(&buf, Int32(17))
+            FfiConverterString.write(message, into: &buf)
+            
+        }
+    }
+}
+
+
+extension DocError: Equatable, Hashable {}
+
+extension DocError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum DocumentSyncPhase {
+    
+    case requesting
+    case syncing
+    case unavailable
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDocumentSyncPhase: FfiConverterRustBuffer {
+    typealias SwiftType = DocumentSyncPhase
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DocumentSyncPhase {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .requesting
+        
+        case 2: return .syncing
+        
+        case 3: return .unavailable
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DocumentSyncPhase, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .requesting:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .syncing:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .unavailable:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDocumentSyncPhase_lift(_ buf: RustBuffer) throws -> DocumentSyncPhase {
+    return try FfiConverterTypeDocumentSyncPhase.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDocumentSyncPhase_lower(_ value: DocumentSyncPhase) -> RustBuffer {
+    return FfiConverterTypeDocumentSyncPhase.lower(value)
+}
+
+
+
+extension DocumentSyncPhase: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ExpandMark {
+    
+    case before
+    case after
+    case none
+    case both
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeExpandMark: FfiConverterRustBuffer {
+    typealias SwiftType = ExpandMark
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ExpandMark {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .before
+        
+        case 2: return .after
+        
+        case 3: return .none
+        
+        case 4: return .both
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ExpandMark, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .before:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .after:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .none:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .both:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeExpandMark_lift(_ buf: RustBuffer) throws -> ExpandMark {
     return try FfiConverterTypeExpandMark.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeExpandMark_lower(_ value: ExpandMark) -> RustBuffer {
-    return FfiConverterTypeExpandMark.lower(value)
+public func FfiConverterTypeExpandMark_lower(_ value: ExpandMark) -> RustBuffer {
+    return FfiConverterTypeExpandMark.lower(value)
+}
+
+
+
+extension ExpandMark: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum HydrateValue {
+    
+    case map(value: [String: HydrateValue]
+    )
+    case list(value: [HydrateValue]
+    )
+    case text(value: String, marks: [Mark], expand: ExpandMark
+    )
+    case scalar(value: ScalarValue
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHydrateValue: FfiConverterRustBuffer {
+    typealias SwiftType = HydrateValue
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HydrateValue {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .map(value: try FfiConverterDictionaryStringTypeHydrateValue.read(from: &buf)
+        )
+        
+        case 2: return .list(value: try FfiConverterSequenceTypeHydrateValue.read(from: &buf)
+        )
+        
+        case 3: return .text(value: try FfiConverterString.read(from: &buf), marks: try FfiConverterSequenceTypeMark.read(from: &buf), expand: try FfiConverterTypeExpandMark.read(from: &buf)
+        )
+        
+        case 4: return .scalar(value: try FfiConverterTypeScalarValue.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: HydrateValue, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .map(value):
+            writeInt(&buf, Int32(1))
+            FfiConverterDictionaryStringTypeHydrateValue.write(value, into: &buf)
+            
+        
+        case let .list(value):
+            writeInt(&buf, Int32(2))
+            FfiConverterSequenceTypeHydrateValue.write(value, into: &buf)
+            
+        
+        case let .text(value,marks,expand):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(value, into: &buf)
+            FfiConverterSequenceTypeMark.write(marks, into: &buf)
+            FfiConverterTypeExpandMark.write(expand, into: &buf)
+            
+        
+        case let .scalar(value):
+            writeInt(&buf, Int32(4))
+            FfiConverterTypeScalarValue.write(value, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHydrateValue_lift(_ buf: RustBuffer) throws -> HydrateValue {
+    return try FfiConverterTypeHydrateValue.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHydrateValue_lower(_ value: HydrateValue) -> RustBuffer {
+    return FfiConverterTypeHydrateValue.lower(value)
+}
+
+
+
+extension HydrateValue: Equatable, Hashable {}
+
+
+
+
+public enum LoadError {
+
+    
+    
+    case CorruptData(message: String
+    )
+    case MissingDependencies(hashes: [ChangeHash]
+    )
+    case DuplicateSeqNumber(actor: String, seq: UInt64
+    )
+    case Internal(message: String
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLoadError: FfiConverterRustBuffer {
+    typealias SwiftType = LoadError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LoadError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .CorruptData(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 2: return .MissingDependencies(
+            hashes: try FfiConverterSequenceTypeChangeHash.read(from: &buf)
+            )
+        case 3: return .DuplicateSeqNumber(
+            actor: try FfiConverterString.read(from: &buf), 
+            seq: try FfiConverterUInt64.read(from: &buf)
+            )
+        case 4: return .Internal(
+            message: try FfiConverterString.read(from: &buf)
+            )
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: LoadError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case let .CorruptData(message):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .MissingDependencies(hashes):
+            writeInt(&buf, Int32(2))
+            FfiConverterSequenceTypeChangeHash.write(hashes, into: &buf)
+            
+        
+        case let .DuplicateSeqNumber(actor,seq):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(actor, into: &buf)
+            FfiConverterUInt64.write(seq, into: &buf)
+            
+        
+        case let .Internal(message):
+            writeInt(&buf, Int32(4))
+            FfiConverterString.write(message, into: &buf)
+            
+        }
+    }
+}
+
+
+extension LoadError: Equatable, Hashable {}
+
+extension LoadError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ObjType {
+    
+    case map
+    case list
+    case text
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeObjType: FfiConverterRustBuffer {
+    typealias SwiftType = ObjType
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ObjType {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .map
+        
+        case 2: return .list
+        
+        case 3: return .text
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ObjType, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .map:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .list:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .text:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeObjType_lift(_ buf: RustBuffer) throws -> ObjType {
+    return try FfiConverterTypeObjType.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeObjType_lower(_ value: ObjType) -> RustBuffer {
+    return FfiConverterTypeObjType.lower(value)
+}
+
+
+
+extension ObjType: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum OnPartialLoad {
+    
+    case ignore
+    case error
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOnPartialLoad: FfiConverterRustBuffer {
+    typealias SwiftType = OnPartialLoad
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OnPartialLoad {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .ignore
+        
+        case 2: return .error
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: OnPartialLoad, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .ignore:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .error:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOnPartialLoad_lift(_ buf: RustBuffer) throws -> OnPartialLoad {
+    return try FfiConverterTypeOnPartialLoad.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOnPartialLoad_lower(_ value: OnPartialLoad) -> RustBuffer {
+    return FfiConverterTypeOnPartialLoad.lower(value)
+}
+
+
+
+extension OnPartialLoad: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum PatchAction {
+    
+    case put(obj: ObjId, prop: Prop, value: Value
+    )
+    case insert(obj: ObjId, index: UInt64, values: [Value]
+    )
+    case spliceText(obj: ObjId, index: UInt64, value: String, marks: [String: Value]
+    )
+    case increment(obj: ObjId, prop: Prop, value: Int64
+    )
+    case conflict(obj: ObjId, prop: Prop
+    )
+    case deleteMap(obj: ObjId, key: String
+    )
+    case deleteSeq(obj: ObjId, index: UInt64, length: UInt64
+    )
+    case marks(obj: ObjId, marks: [Mark]
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePatchAction: FfiConverterRustBuffer {
+    typealias SwiftType = PatchAction
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PatchAction {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .put(obj: try FfiConverterTypeObjId.read(from: &buf), prop: try FfiConverterTypeProp.read(from: &buf), value: try FfiConverterTypeValue.read(from: &buf)
+        )
+        
+        case 2: return .insert(obj: try FfiConverterTypeObjId.read(from: &buf), index: try FfiConverterUInt64.read(from: &buf), values: try FfiConverterSequenceTypeValue.read(from: &buf)
+        )
+        
+        case 3: return .spliceText(obj: try FfiConverterTypeObjId.read(from: &buf), index: try FfiConverterUInt64.read(from: &buf), value: try FfiConverterString.read(from: &buf), marks: try FfiConverterDictionaryStringTypeValue.read(from: &buf)
+        )
+        
+        case 4: return .increment(obj: try FfiConverterTypeObjId.read(from: &buf), prop: try FfiConverterTypeProp.read(from: &buf), value: try FfiConverterInt64.read(from: &buf)
+        )
+        
+        case 5: return .conflict(obj: try FfiConverterTypeObjId.read(from: &buf), prop: try FfiConverterTypeProp.read(from: &buf)
+        )
+        
+        case 6: return .deleteMap(obj: try FfiConverterTypeObjId.read(from: &buf), key: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 7: return .deleteSeq(obj: try FfiConverterTypeObjId.read(from: &buf), index: try FfiConverterUInt64.read(from: &buf), length: try FfiConverterUInt64.read(from: &buf)
+        )
+        
+        case 8: return .marks(obj: try FfiConverterTypeObjId.read(from: &buf), marks: try FfiConverterSequenceTypeMark.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: PatchAction, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .put(obj,prop,value):
+            writeInt(&buf, Int32(1))
+            FfiConverterTypeObjId.write(obj, into: &buf)
+            FfiConverterTypeProp.write(prop, into: &buf)
+            FfiConverterTypeValue.write(value, into: &buf)
+            
+        
+        case let .insert(obj,index,values):
+            writeInt(&buf, Int32(2))
+            FfiConverterTypeObjId.write(obj, into: &buf)
+            FfiConverterUInt64.write(index, into: &buf)
+            FfiConverterSequenceTypeValue.write(values, into: &buf)
+            
+        
+        case let .spliceText(obj,index,value,marks):
+            writeInt(&buf, Int32(3))
+            FfiConverterTypeObjId.write(obj, into: &buf)
+            FfiConverterUInt64.write(index, into: &buf)
+            FfiConverterString.write(value, into: &buf)
+            FfiConverterDictionaryStringTypeValue.write(marks, into: &buf)
+            
+        
+        case let .increment(obj,prop,value):
+            writeInt(&buf, Int32(4))
+            FfiConverterTypeObjId.write(obj, into: &buf)
+            FfiConverterTypeProp.write(prop, into: &buf)
+            FfiConverterInt64.write(value, into: &buf)
+            
+        
+        case let .conflict(obj,prop):
+            writeInt(&buf, Int32(5))
+            FfiConverterTypeObjId.write(obj, into: &buf)
+            FfiConverterTypeProp.write(prop, into: &buf)
+            
+        
+        case let .deleteMap(obj,key):
+            writeInt(&buf, Int32(6))
+            FfiConverterTypeObjId.write(obj, into: &buf)
+            FfiConverterString.write(key, into: &buf)
+            
+        
+        case let .deleteSeq(obj,index,length):
+            writeInt(&buf, Int32(7))
+            FfiConverterTypeObjId.write(obj, into: &buf)
+            FfiConverterUInt64.write(index, into: &buf)
+            FfiConverterUInt64.write(length, into: &buf)
+            
+        
+        case let .marks(obj,marks):
+            writeInt(&buf, Int32(8))
+            FfiConverterTypeObjId.write(obj, into: &buf)
+            FfiConverterSequenceTypeMark.write(marks, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePatchAction_lift(_ buf: RustBuffer) throws -> PatchAction {
+    return try FfiConverterTypePatchAction.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePatchAction_lower(_ value: PatchAction) -> RustBuffer {
+    return FfiConverterTypePatchAction.lower(value)
+}
+
+
+
+extension PatchAction: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum Position {
+    
+    case cursor(position: Cursor
+    )
+    case index(position: UInt64
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePosition: FfiConverterRustBuffer {
+    typealias SwiftType = Position
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Position {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .cursor(position: try FfiConverterTypeCursor.read(from: &buf)
+        )
+        
+        case 2: return .index(position: try FfiConverterUInt64.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: Position, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .cursor(position):
+            writeInt(&buf, Int32(1))
+            FfiConverterTypeCursor.write(position, into: &buf)
+            
+        
+        case let .index(position):
+            writeInt(&buf, Int32(2))
+            FfiConverterUInt64.write(position, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePosition_lift(_ buf: RustBuffer) throws -> Position {
+    return try FfiConverterTypePosition.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePosition_lower(_ value: Position) -> RustBuffer {
+    return FfiConverterTypePosition.lower(value)
+}
+
+
+
+extension Position: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum PresenceValue {
+    
+    case scalar(value: ScalarValue
+    )
+    case cursor(value: Cursor
+    )
+    case map(value: [String: PresenceValue]
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePresenceValue: FfiConverterRustBuffer {
+    typealias SwiftType = PresenceValue
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PresenceValue {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .scalar(value: try FfiConverterTypeScalarValue.read(from: &buf)
+        )
+        
+        case 2: return .cursor(value: try FfiConverterTypeCursor.read(from: &buf)
+        )
+        
+        case 3: return .map(value: try FfiConverterDictionaryStringTypePresenceValue.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: PresenceValue, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .scalar(value):
+            writeInt(&buf, Int32(1))
+            FfiConverterTypeScalarValue.write(value, into: &buf)
+            
+        
+        case let .cursor(value):
+            writeInt(&buf, Int32(2))
+            FfiConverterTypeCursor.write(value, into: &buf)
+            
+        
+        case let .map(value):
+            writeInt(&buf, Int32(3))
+            FfiConverterDictionaryStringTypePresenceValue.write(value, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePresenceValue_lift(_ buf: RustBuffer) throws -> PresenceValue {
+    return try FfiConverterTypePresenceValue.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePresenceValue_lower(_ value: PresenceValue) -> RustBuffer {
+    return FfiConverterTypePresenceValue.lower(value)
+}
+
+
+
+extension PresenceValue: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum Prop {
+    
+    case key(value: String
+    )
+    case index(value: UInt64
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeProp: FfiConverterRustBuffer {
+    typealias SwiftType = Prop
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Prop {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .key(value: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 2: return .index(value: try FfiConverterUInt64.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: Prop, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .key(value):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(value, into: &buf)
+            
+        
+        case let .index(value):
+            writeInt(&buf, Int32(2))
+            FfiConverterUInt64.write(value, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProp_lift(_ buf: RustBuffer) throws -> Prop {
+    return try FfiConverterTypeProp.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProp_lower(_ value: Prop) -> RustBuffer {
+    return FfiConverterTypeProp.lower(value)
+}
+
+
+
+extension Prop: Equatable, Hashable {}
+
+
+
+
+public enum ReceiveSyncError {
+
+    
+    
+    case MissingDependencies(hashes: [ChangeHash]
+    )
+    case Internal(message: String
+    )
+    case InvalidMessage(message: String
+    )
+    case DuplicateSeqNumber(actor: String, seq: UInt64
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeReceiveSyncError: FfiConverterRustBuffer {
+    typealias SwiftType = ReceiveSyncError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ReceiveSyncError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .MissingDependencies(
+            hashes: try FfiConverterSequenceTypeChangeHash.read(from: &buf)
+            )
+        case 2: return .Internal(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 3: return .InvalidMessage(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 4: return .DuplicateSeqNumber(
+            actor: try FfiConverterString.read(from: &buf), 
+            seq: try FfiConverterUInt64.read(from: &buf)
+            )
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ReceiveSyncError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case let .MissingDependencies(hashes):
+            writeInt(&buf, Int32(1))
+            FfiConverterSequenceTypeChangeHash.write(hashes, into: &buf)
+            
+        
+        case let .Internal(message):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .InvalidMessage(message):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .DuplicateSeqNumber(actor,seq):
+            writeInt(&buf, Int32(4))
+            FfiConverterString.write(actor, into: &buf)
+            FfiConverterUInt64.write(seq, into: &buf)
+            
+        }
+    }
+}
+
+
+extension ReceiveSyncError: Equatable, Hashable {}
+
+extension ReceiveSyncError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+
+public enum RepoError {
+
+    
+    
+    case InvalidDocumentId(message: String
+    )
+    case DocumentNotFound(documentId: DocumentId
+    )
+    case CorruptData(message: String
+    )
+    case MissingDependencies(hashes: [ChangeHash]
+    )
+    case InvalidMessage(message: String
+    )
+    case PeerError(message: String
+    )
+    case Internal(message: String
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRepoError: FfiConverterRustBuffer {
+    typealias SwiftType = RepoError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RepoError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .InvalidDocumentId(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 2: return .DocumentNotFound(
+            documentId: try FfiConverterTypeDocumentId.read(from: &buf)
+            )
+        case 3: return .CorruptData(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 4: return .MissingDependencies(
+            hashes: try FfiConverterSequenceTypeChangeHash.read(from: &buf)
+            )
+        case 5: return .InvalidMessage(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 6: return .PeerError(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 7: return .Internal(
+            message: try FfiConverterString.read(from: &buf)
+            )
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: RepoError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case let .InvalidDocumentId(message):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .DocumentNotFound(documentId):
+            writeInt(&buf, Int32(2))
+            FfiConverterTypeDocumentId.write(documentId, into: &buf)
+            
+        
+        case let .CorruptData(message):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .MissingDependencies(hashes):
+            writeInt(&buf, Int32(4))
+            FfiConverterSequenceTypeChangeHash.write(hashes, into: &buf)
+            
+        
+        case let .InvalidMessage(message):
+            writeInt(&buf, Int32(5))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .PeerError(message):
+            writeInt(&buf, Int32(6))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .Internal(message):
+            writeInt(&buf, Int32(7))
+            FfiConverterString.write(message, into: &buf)
+            
+        }
+    }
+}
+
+
+extension RepoError: Equatable, Hashable {}
+
+extension RepoError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum RepoMessage {
+    
+    case join(senderId: String, peerMetadata: PeerMetadata, supportedProtocolVersions: [String]
+    )
+    case peer(senderId: String, targetId: String, peerMetadata: PeerMetadata, selectedProtocolVersion: String
+    )
+    case leave(senderId: String
+    )
+    case error(message: String
+    )
+    case request(senderId: String, targetId: String, documentId: DocumentId, data: [UInt8]
+    )
+    case sync(senderId: String, targetId: String, documentId: DocumentId, data: [UInt8]
+    )
+    case docUnavailable(senderId: String, targetId: String, documentId: DocumentId
+    )
+    case ephemeral(senderId: String, targetId: String, documentId: DocumentId, sessionId: String, count: UInt64, data: [UInt8]
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRepoMessage: FfiConverterRustBuffer {
+    typealias SwiftType = RepoMessage
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RepoMessage {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .join(senderId: try FfiConverterString.read(from: &buf), peerMetadata: try FfiConverterTypePeerMetadata.read(from: &buf), supportedProtocolVersions: try FfiConverterSequenceString.read(from: &buf)
+        )
+        
+        case 2: return .peer(senderId: try FfiConverterString.read(from: &buf), targetId: try FfiConverterString.read(from: &buf), peerMetadata: try FfiConverterTypePeerMetadata.read(from: &buf), selectedProtocolVersion: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 3: return .leave(senderId: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 4: return .error(message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 5: return .request(senderId: try FfiConverterString.read(from: &buf), targetId: try FfiConverterString.read(from: &buf), documentId: try FfiConverterTypeDocumentId.read(from: &buf), data: try FfiConverterSequenceUInt8.read(from: &buf)
+        )
+        
+        case 6: return .sync(senderId: try FfiConverterString.read(from: &buf), targetId: try FfiConverterString.read(from: &buf), documentId: try FfiConverterTypeDocumentId.read(from: &buf), data: try FfiConverterSequenceUInt8.read(from: &buf)
+        )
+        
+        case 7: return .docUnavailable(senderId: try FfiConverterString.read(from: &buf), targetId: try FfiConverterString.read(from: &buf), documentId: try FfiConverterTypeDocumentId.read(from: &buf)
+        )
+        
+        case 8: return .ephemeral(senderId: try FfiConverterString.read(from: &buf), targetId: try FfiConverterString.read(from: &buf), documentId: try FfiConverterTypeDocumentId.read(from: &buf), sessionId: try FfiConverterString.read(from: &buf), count: try FfiConverterUInt64.read(from: &buf), data: try FfiConverterSequenceUInt8.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: RepoMessage, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .join(senderId,peerMetadata,supportedProtocolVersions):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(senderId, into: &buf)
+            FfiConverterTypePeerMetadata.write(peerMetadata, into: &buf)
+            FfiConverterSequenceString.write(supportedProtocolVersions, into: &buf)
+            
+        
+        case let .peer(senderId,targetId,peerMetadata,selectedProtocolVersion):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(senderId, into: &buf)
+            FfiConverterString.write(targetId, into: &buf)
+            FfiConverterTypePeerMetadata.write(peerMetadata, into: &buf)
+            FfiConverterString.write(selectedProtocolVersion, into: &buf)
+            
+        
+        case let .leave(senderId):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(senderId, into: &buf)
+            
+        
+        case let .error(message):
+            writeInt(&buf, Int32(4))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .request(senderId,targetId,documentId,data):
+            writeInt(&buf, Int32(5))
+            FfiConverterString.write(senderId, into: &buf)
+            FfiConverterString.write(targetId, into: &buf)
+            FfiConverterTypeDocumentId.write(documentId, into: &buf)
+            FfiConverterSequenceUInt8.write(data, into: &buf)
+            
+        
+        case let .sync(senderId,targetId,documentId,data):
+            writeInt(&buf, Int32(6))
+            FfiConverterString.write(senderId, into: &buf)
+            FfiConverterString.write(targetId, into: &buf)
+            FfiConverterTypeDocumentId.write(documentId, into: &buf)
+            FfiConverterSequenceUInt8.write(data, into: &buf)
+            
+        
+        case let .docUnavailable(senderId,targetId,documentId):
+            writeInt(&buf, Int32(7))
+            FfiConverterString.write(senderId, into: &buf)
+            FfiConverterString.write(targetId, into: &buf)
+            FfiConverterTypeDocumentId.write(documentId, into: &buf)
+            
+        
+        case let .ephemeral(senderId,targetId,documentId,sessionId,count,data):
+            writeInt(&buf, Int32(8))
+            FfiConverterString.write(senderId, into: &buf)
+            FfiConverterString.write(targetId, into: &buf)
+            FfiConverterTypeDocumentId.write(documentId, into: &buf)
+            FfiConverterString.write(sessionId, into: &buf)
+            FfiConverterUInt64.write(count, into: &buf)
+            FfiConverterSequenceUInt8.write(data, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRepoMessage_lift(_ buf: RustBuffer) throws -> RepoMessage {
+    return try FfiConverterTypeRepoMessage.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRepoMessage_lower(_ value: RepoMessage) -> RustBuffer {
+    return FfiConverterTypeRepoMessage.lower(value)
+}
+
+
+
+extension RepoMessage: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ScalarValue {
+    
+    case bytes(value: [UInt8]
+    )
+    case string(value: String
+    )
+    case uint(value: UInt64
+    )
+    case int(value: Int64
+    )
+    case f64(value: Double
+    )
+    case counter(value: Int64
+    )
+    case timestamp(value: Int64
+    )
+    case boolean(value: Bool
+    )
+    case unknown(typeCode: UInt8, data: [UInt8]
+    )
+    case null
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeScalarValue: FfiConverterRustBuffer {
+    typealias SwiftType = ScalarValue
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ScalarValue {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .bytes(value: try FfiConverterSequenceUInt8.read(from: &buf)
+        )
+        
+        case 2: return .string(value: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 3: return .uint(value: try FfiConverterUInt64.read(from: &buf)
+        )
+        
+        case 4: return .int(value: try FfiConverterInt64.read(from: &buf)
+        )
+        
+        case 5: return .f64(value: try FfiConverterDouble.read(from: &buf)
+        )
+        
+        case 6: return .counter(value: try FfiConverterInt64.read(from: &buf)
+        )
+        
+        case 7: return .timestamp(value: try FfiConverterInt64.read(from: &buf)
+        )
+        
+        case 8: return .boolean(value: try FfiConverterBool.read(from: &buf)
+        )
+        
+        case 9: return .unknown(typeCode: try FfiConverterUInt8.read(from: &buf), data: try FfiConverterSequenceUInt8.read(from: &buf)
+        )
+        
+        case 10: return .null
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ScalarValue, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .bytes(value):
+            writeInt(&buf, Int32(1))
+            FfiConverterSequenceUInt8.write(value, into: &buf)
+            
+        
+        case let .string(value):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(value, into: &buf)
+            
+        
+        case let .uint(value):
+            writeInt(&buf, Int32(3))
+            FfiConverterUInt64.write(value, into: &buf)
+            
+        
+        case let .int(value):
+            writeInt(&buf, Int32(4))
+            FfiConverterInt64.write(value, into: &buf)
+            
+        
+        case let .f64(value):
+            writeInt(&buf, Int32(5))
+            FfiConverterDouble.write(value, into: &buf)
+            
+        
+        case let .counter(value):
+            writeInt(&buf, Int32(6))
+            FfiConverterInt64.write(value, into: &buf)
+            
+        
+        case let .timestamp(value):
+            writeInt(&buf, Int32(7))
+            FfiConverterInt64.write(value, into: &buf)
+            
+        
+        case let .boolean(value):
+            writeInt(&buf, Int32(8))
+            FfiConverterBool.write(value, into: &buf)
+            
+        
+        case let .unknown(typeCode,data):
+            writeInt(&buf, Int32(9))
+            FfiConverterUInt8.write(typeCode, into: &buf)
+            FfiConverterSequenceUInt8.write(data, into: &buf)
+            
+        
+        case .null:
+            writeInt(&buf, Int32(10))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeScalarValue_lift(_ buf: RustBuffer) throws -> ScalarValue {
+    return try FfiConverterTypeScalarValue.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeScalarValue_lower(_ value: ScalarValue) -> RustBuffer {
+    return FfiConverterTypeScalarValue.lower(value)
+}
+
+
+
+extension ScalarValue: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum StringMigration {
+    
+    case noMigration
+    case convertToText
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeStringMigration: FfiConverterRustBuffer {
+    typealias SwiftType = StringMigration
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StringMigration {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .noMigration
+        
+        case 2: return .convertToText
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: StringMigration, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .noMigration:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .convertToText:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
 }
 
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStringMigration_lift(_ buf: RustBuffer) throws -> StringMigration {
+    return try FfiConverterTypeStringMigration.lift(buf)
+}
 
-extension ExpandMark: Equatable, Hashable {}
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStringMigration_lower(_ value: StringMigration) -> RustBuffer {
+    return FfiConverterTypeStringMigration.lower(value)
+}
 
 
 
+extension StringMigration: Equatable, Hashable {}
 
-public enum LoadError {
 
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum SyncCapability {
     
-    
-    case Internal(message: String)
-    
+    case messageV1
+    case messageV2
+    case unknown(code: UInt8
+    )
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeLoadError: FfiConverterRustBuffer {
-    typealias SwiftType = LoadError
+public struct FfiConverterTypeSyncCapability: FfiConverterRustBuffer {
+    typealias SwiftType = SyncCapability
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LoadError {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SyncCapability {
         let variant: Int32 = try readInt(&buf)
         switch variant {
-
         
-
+        case 1: return .messageV1
         
-        case 1: return .Internal(
-            message: try FfiConverterString.read(from: &buf)
+        case 2: return .messageV2
+        
+        case 3: return .unknown(code: try FfiConverterUInt8.read(from: &buf)
         )
         
-
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: LoadError, into buf: inout [UInt8]) {
+    public static func write(_ value: SyncCapability, into buf: inout [UInt8]) {
         switch value {
-
         
-
         
-        case .Internal(_ /* message is ignored*/):
+        case .messageV1:
             writeInt(&buf, Int32(1))
-
         
+        
+        case .messageV2:
+            writeInt(&buf, Int32(2))
+        
+        
+        case let .unknown(code):
+            writeInt(&buf, Int32(3))
+            FfiConverterUInt8.write(code, into: &buf)
+            
         }
     }
 }
 
 
-extension LoadError: Equatable, Hashable {}
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSyncCapability_lift(_ buf: RustBuffer) throws -> SyncCapability {
+    return try FfiConverterTypeSyncCapability.lift(buf)
+}
 
-extension LoadError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
-    }
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSyncCapability_lower(_ value: SyncCapability) -> RustBuffer {
+    return FfiConverterTypeSyncCapability.lower(value)
 }
 
+
+
+extension SyncCapability: Equatable, Hashable {}
+
+
+
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 
-public enum ObjType {
+public enum SyncMessageVersion {
     
-    case map
-    case list
-    case text
+    case v1
+    case v2
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeObjType: FfiConverterRustBuffer {
-    typealias SwiftType = ObjType
+public struct FfiConverterTypeSyncMessageVersion: FfiConverterRustBuffer {
+    typealias SwiftType = SyncMessageVersion
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ObjType {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SyncMessageVersion {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .map
-        
-        case 2: return .list
+        case 1: return .v1
         
-        case 3: return .text
+        case 2: return .v2
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: ObjType, into buf: inout [UInt8]) {
+    public static func write(_ value: SyncMessageVersion, into buf: inout [UInt8]) {
         switch value {
         
         
-        case .map:
+        case .v1:
             writeInt(&buf, Int32(1))
         
         
-        case .list:
+        case .v2:
             writeInt(&buf, Int32(2))
         
-        
-        case .text:
-            writeInt(&buf, Int32(3))
-        
         }
     }
 }
@@ -2186,43 +6441,37 @@ public struct FfiConverterTypeObjType: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeObjType_lift(_ buf: RustBuffer) throws -> ObjType {
-    return try FfiConverterTypeObjType.lift(buf)
+public func FfiConverterTypeSyncMessageVersion_lift(_ buf: RustBuffer) throws -> SyncMessageVersion {
+    return try FfiConverterTypeSyncMessageVersion.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeObjType_lower(_ value: ObjType) -> RustBuffer {
-    return FfiConverterTypeObjType.lower(value)
+public func FfiConverterTypeSyncMessageVersion_lower(_ value: SyncMessageVersion) -> RustBuffer {
+    return FfiConverterTypeSyncMessageVersion.lower(value)
 }
 
 
 
-extension ObjType: Equatable, Hashable {}
+extension SyncMessageVersion: Equatable, Hashable {}
 
 
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 
-public enum PatchAction {
+public enum SyncSessionError {
+
     
-    case put(obj: ObjId, prop: Prop, value: Value
-    )
-    case insert(obj: ObjId, index: UInt64, values: [Value]
-    )
-    case spliceText(obj: ObjId, index: UInt64, value: String, marks: [String: Value]
-    )
-    case increment(obj: ObjId, prop: Prop, value: Int64
+    
+    case UnknownPeer(peer: String
     )
-    case conflict(obj: ObjId, prop: Prop
+    case InvalidMessage(message: String
     )
-    case deleteMap(obj: ObjId, key: String
+    case MissingDependencies(hashes: [ChangeHash]
     )
-    case deleteSeq(obj: ObjId, index: UInt64, length: UInt64
+    case InvalidPeerStates(message: String
     )
-    case marks(obj: ObjId, marks: [Mark]
+    case Internal(message: String
     )
 }
 
@@ -2230,98 +6479,133 @@ public enum PatchAction {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypePatchAction: FfiConverterRustBuffer {
-    typealias SwiftType = PatchAction
+public struct FfiConverterTypeSyncSessionError: FfiConverterRustBuffer {
+    typealias SwiftType = SyncSessionError
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PatchAction {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SyncSessionError {
         let variant: Int32 = try readInt(&buf)
         switch variant {
+
         
-        case 1: return .put(obj: try FfiConverterTypeObjId.read(from: &buf), prop: try FfiConverterTypeProp.read(from: &buf), value: try FfiConverterTypeValue.read(from: &buf)
-        )
-        
-        case 2: return .insert(obj: try FfiConverterTypeObjId.read(from: &buf), index: try FfiConverterUInt64.read(from: &buf), values: try FfiConverterSequenceTypeValue.read(from: &buf)
-        )
-        
-        case 3: return .spliceText(obj: try FfiConverterTypeObjId.read(from: &buf), index: try FfiConverterUInt64.read(from: &buf), value: try FfiConverterString.read(from: &buf), marks: try FfiConverterDictionaryStringTypeValue.read(from: &buf)
-        )
-        
-        case 4: return .increment(obj: try FfiConverterTypeObjId.read(from: &buf), prop: try FfiConverterTypeProp.read(from: &buf), value: try FfiConverterInt64.read(from: &buf)
-        )
-        
-        case 5: return .conflict(obj: try FfiConverterTypeObjId.read(from: &buf), prop: try FfiConverterTypeProp.read(from: &buf)
-        )
-        
-        case 6: return .deleteMap(obj: try FfiConverterTypeObjId.read(from: &buf), key: try FfiConverterString.read(from: &buf)
-        )
-        
-        case 7: return .deleteSeq(obj: try FfiConverterTypeObjId.read(from: &buf), index: try FfiConverterUInt64.read(from: &buf), length: try FfiConverterUInt64.read(from: &buf)
-        )
-        
-        case 8: return .marks(obj: try FfiConverterTypeObjId.read(from: &buf), marks: try FfiConverterSequenceTypeMark.read(from: &buf)
-        )
+
         
-        default: throw UniffiInternalError.unexpectedEnumCase
+        case 1: return .UnknownPeer(
+            peer: try FfiConverterString.read(from: &buf)
+            )
+        case 2: return .InvalidMessage(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 3: return .MissingDependencies(
+            hashes: try FfiConverterSequenceTypeChangeHash.read(from: &buf)
+            )
+        case 4: return .InvalidPeerStates(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 5: return .Internal(
+            message: try FfiConverterString.read(from: &buf)
+            )
+
+         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: PatchAction, into buf: inout [UInt8]) {
+    public static func write(_ value: SyncSessionError, into buf: inout [UInt8]) {
         switch value {
+
         
+
         
-        case let .put(obj,prop,value):
+        
+        case let .UnknownPeer(peer):
             writeInt(&buf, Int32(1))
-            FfiConverterTypeObjId.write(obj, into: &buf)
-            FfiConverterTypeProp.write(prop, into: &buf)
-            FfiConverterTypeValue.write(value, into: &buf)
+            FfiConverterString.write(peer, into: &buf)
             
         
-        case let .insert(obj,index,values):
+        case let .InvalidMessage(message):
             writeInt(&buf, Int32(2))
-            FfiConverterTypeObjId.write(obj, into: &buf)
-            FfiConverterUInt64.write(index, into: &buf)
-            FfiConverterSequenceTypeValue.write(values, into: &buf)
+            FfiConverterString.write(message, into: &buf)
             
         
-        case let .spliceText(obj,index,value,marks):
+        case let .MissingDependencies(hashes):
             writeInt(&buf, Int32(3))
-            FfiConverterTypeObjId.write(obj, into: &buf)
-            FfiConverterUInt64.write(index, into: &buf)
-            FfiConverterString.write(value, into: &buf)
-            FfiConverterDictionaryStringTypeValue.write(marks, into: &buf)
+            FfiConverterSequenceTypeChangeHash.write(hashes, into: &buf)
             
         
-        case let .increment(obj,prop,value):
+        case let .InvalidPeerStates(message):
             writeInt(&buf, Int32(4))
-            FfiConverterTypeObjId.write(obj, into: &buf)
-            FfiConverterTypeProp.write(prop, into: &buf)
-            FfiConverterInt64.write(value, into: &buf)
+            FfiConverterString.write(message, into: &buf)
             
         
-        case let .conflict(obj,prop):
+        case let .Internal(message):
             writeInt(&buf, Int32(5))
-            FfiConverterTypeObjId.write(obj, into: &buf)
-            FfiConverterTypeProp.write(prop, into: &buf)
+            FfiConverterString.write(message, into: &buf)
             
+        }
+    }
+}
+
+
+extension SyncSessionError: Equatable, Hashable {}
+
+extension SyncSessionError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum TextEncoding {
+    
+    case unicodeCodePoint
+    case utf8CodeUnit
+    case utf16CodeUnit
+    case graphemeCluster
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTextEncoding: FfiConverterRustBuffer {
+    typealias SwiftType = TextEncoding
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TextEncoding {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
         
-        case let .deleteMap(obj,key):
-            writeInt(&buf, Int32(6))
-            FfiConverterTypeObjId.write(obj, into: &buf)
-            FfiConverterString.write(key, into: &buf)
-            
+        case 1: return .unicodeCodePoint
         
-        case let .deleteSeq(obj,index,length):
-            writeInt(&buf, Int32(7))
-            FfiConverterTypeObjId.write(obj, into: &buf)
-            FfiConverterUInt64.write(index, into: &buf)
-            FfiConverterUInt64.write(length, into: &buf)
-            
+        case 2: return .utf8CodeUnit
+        
+        case 3: return .utf16CodeUnit
+        
+        case 4: return .graphemeCluster
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TextEncoding, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .unicodeCodePoint:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .utf8CodeUnit:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .utf16CodeUnit:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .graphemeCluster:
+            writeInt(&buf, Int32(4))
         
-        case let .marks(obj,marks):
-            writeInt(&buf, Int32(8))
-            FfiConverterTypeObjId.write(obj, into: &buf)
-            FfiConverterSequenceTypeMark.write(marks, into: &buf)
-            
         }
     }
 }
@@ -2330,31 +6614,31 @@ public struct FfiConverterTypePatchAction: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypePatchAction_lift(_ buf: RustBuffer) throws -> PatchAction {
-    return try FfiConverterTypePatchAction.lift(buf)
+public func FfiConverterTypeTextEncoding_lift(_ buf: RustBuffer) throws -> TextEncoding {
+    return try FfiConverterTypeTextEncoding.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypePatchAction_lower(_ value: PatchAction) -> RustBuffer {
-    return FfiConverterTypePatchAction.lower(value)
+public func FfiConverterTypeTextEncoding_lower(_ value: TextEncoding) -> RustBuffer {
+    return FfiConverterTypeTextEncoding.lower(value)
 }
 
 
 
-extension PatchAction: Equatable, Hashable {}
+extension TextEncoding: Equatable, Hashable {}
 
 
 
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 
-public enum Position {
+public enum Value {
     
-    case cursor(position: Cursor
+    case object(typ: ObjType, id: ObjId
     )
-    case index(position: UInt64
+    case scalar(value: ScalarValue
     )
 }
 
@@ -2362,35 +6646,36 @@ public enum Position {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypePosition: FfiConverterRustBuffer {
-    typealias SwiftType = Position
+public struct FfiConverterTypeValue: FfiConverterRustBuffer {
+    typealias SwiftType = Value
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Position {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Value {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .cursor(position: try FfiConverterTypeCursor.read(from: &buf)
+        case 1: return .object(typ: try FfiConverterTypeObjType.read(from: &buf), id: try FfiConverterTypeObjId.read(from: &buf)
         )
         
-        case 2: return .index(position: try FfiConverterUInt64.read(from: &buf)
+        case 2: return .scalar(value: try FfiConverterTypeScalarValue.read(from: &buf)
         )
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: Position, into buf: inout [UInt8]) {
+    public static func write(_ value: Value, into buf: inout [UInt8]) {
         switch value {
         
         
-        case let .cursor(position):
+        case let .object(typ,id):
             writeInt(&buf, Int32(1))
-            FfiConverterTypeCursor.write(position, into: &buf)
+            FfiConverterTypeObjType.write(typ, into: &buf)
+            FfiConverterTypeObjId.write(id, into: &buf)
             
         
-        case let .index(position):
+        case let .scalar(value):
             writeInt(&buf, Int32(2))
-            FfiConverterUInt64.write(position, into: &buf)
+            FfiConverterTypeScalarValue.write(value, into: &buf)
             
         }
     }
@@ -2400,68 +6685,62 @@ public struct FfiConverterTypePosition: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypePosition_lift(_ buf: RustBuffer) throws -> Position {
-    return try FfiConverterTypePosition.lift(buf)
+public func FfiConverterTypeValue_lift(_ buf: RustBuffer) throws -> Value {
+    return try FfiConverterTypeValue.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypePosition_lower(_ value: Position) -> RustBuffer {
-    return FfiConverterTypePosition.lower(value)
+public func FfiConverterTypeValue_lower(_ value: Value) -> RustBuffer {
+    return FfiConverterTypeValue.lower(value)
 }
 
 
 
-extension Position: Equatable, Hashable {}
+extension Value: Equatable, Hashable {}
 
 
 
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 
-public enum Prop {
+public enum VerificationMode {
     
-    case key(value: String
-    )
-    case index(value: UInt64
-    )
+    case check
+    case dontCheck
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeProp: FfiConverterRustBuffer {
-    typealias SwiftType = Prop
+public struct FfiConverterTypeVerificationMode: FfiConverterRustBuffer {
+    typealias SwiftType = VerificationMode
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Prop {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VerificationMode {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .key(value: try FfiConverterString.read(from: &buf)
-        )
+        case 1: return .check
         
-        case 2: return .index(value: try FfiConverterUInt64.read(from: &buf)
-        )
+        case 2: return .dontCheck
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: Prop, into buf: inout [UInt8]) {
+    public static func write(_ value: VerificationMode, into buf: inout [UInt8]) {
         switch value {
         
         
-        case let .key(value):
+        case .check:
             writeInt(&buf, Int32(1))
-            FfiConverterString.write(value, into: &buf)
-            
         
-        case let .index(value):
+        
+        case .dontCheck:
             writeInt(&buf, Int32(2))
-            FfiConverterUInt64.write(value, into: &buf)
-            
+        
         }
     }
 }
@@ -2470,388 +6749,701 @@ public struct FfiConverterTypeProp: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeProp_lift(_ buf: RustBuffer) throws -> Prop {
-    return try FfiConverterTypeProp.lift(buf)
+public func FfiConverterTypeVerificationMode_lift(_ buf: RustBuffer) throws -> VerificationMode {
+    return try FfiConverterTypeVerificationMode.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeProp_lower(_ value: Prop) -> RustBuffer {
-    return FfiConverterTypeProp.lower(value)
+public func FfiConverterTypeVerificationMode_lower(_ value: VerificationMode) -> RustBuffer {
+    return FfiConverterTypeVerificationMode.lower(value)
 }
 
 
 
-extension Prop: Equatable, Hashable {}
+extension VerificationMode: Equatable, Hashable {}
+
 
 
 
 
-public enum ReceiveSyncError {
 
+public protocol NetworkAdapter : AnyObject {
     
+    func send(peerId: String, message: [UInt8]) 
     
-    case Internal(message: String)
+}
+
+// Magic number for the Rust proxy to call using the same mechanism as every other method,
+// to free the callback once it's dropped by Rust.
+private let IDX_CALLBACK_FREE: Int32 = 0
+// Callback return codes
+private let UNIFFI_CALLBACK_SUCCESS: Int32 = 0
+private let UNIFFI_CALLBACK_ERROR: Int32 = 1
+private let UNIFFI_CALLBACK_UNEXPECTED_ERROR: Int32 = 2
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceNetworkAdapter {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    static var vtable: UniffiVTableCallbackInterfaceNetworkAdapter = UniffiVTableCallbackInterfaceNetworkAdapter(
+        send: { (
+            uniffiHandle: UInt64,
+            peerId: RustBuffer,
+            message: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceNetworkAdapter.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.send(
+                     peerId: try FfiConverterString.lift(peerId),
+                     message: try FfiConverterSequenceUInt8.lift(message)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterCallbackInterfaceNetworkAdapter.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface NetworkAdapter: handle missing in uniffiFree")
+            }
+        }
+    )
+}
+
+private func uniffiCallbackInitNetworkAdapter() {
+    uniffi_uniffi_automerge_fn_init_callback_vtable_networkadapter(&UniffiCallbackInterfaceNetworkAdapter.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceNetworkAdapter {
+    fileprivate static var handleMap = UniffiHandleMap<NetworkAdapter>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceNetworkAdapter : FfiConverter {
+    typealias SwiftType = NetworkAdapter
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+
+
+
+public protocol PatchObserver : AnyObject {
     
-    case InvalidMessage(message: String)
+    func onPatches(patches: [Patch]) 
     
 }
 
 
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfacePatchObserver {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    static var vtable: UniffiVTableCallbackInterfacePatchObserver = UniffiVTableCallbackInterfacePatchObserver(
+        onPatches: { (
+            uniffiHandle: UInt64,
+            patches: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfacePatchObserver.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onPatches(
+                     patches: try FfiConverterSequenceTypePatch.lift(patches)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterCallbackInterfacePatchObserver.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface PatchObserver: handle missing in uniffiFree")
+            }
+        }
+    )
+}
+
+private func uniffiCallbackInitPatchObserver() {
+    uniffi_uniffi_automerge_fn_init_callback_vtable_patchobserver(&UniffiCallbackInterfacePatchObserver.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeReceiveSyncError: FfiConverterRustBuffer {
-    typealias SwiftType = ReceiveSyncError
+fileprivate struct FfiConverterCallbackInterfacePatchObserver {
+    fileprivate static var handleMap = UniffiHandleMap<PatchObserver>()
+}
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ReceiveSyncError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfacePatchObserver : FfiConverter {
+    typealias SwiftType = PatchObserver
+    typealias FfiType = UInt64
 
-        
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
 
-        
-        case 1: return .Internal(
-            message: try FfiConverterString.read(from: &buf)
-        )
-        
-        case 2: return .InvalidMessage(
-            message: try FfiConverterString.read(from: &buf)
-        )
-        
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
 
-        default: throw UniffiInternalError.unexpectedEnumCase
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+
+
+
+public protocol StorageAdapter : AnyObject {
+    
+    func load(key: [String])  -> [UInt8]?
+    
+    func save(key: [String], data: [UInt8]) 
+    
+    func remove(key: [String]) 
+    
+    func list(prefix: [String])  -> [[String]]
+    
+}
+
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceStorageAdapter {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    static var vtable: UniffiVTableCallbackInterfaceStorageAdapter = UniffiVTableCallbackInterfaceStorageAdapter(
+        load: { (
+            uniffiHandle: UInt64,
+            key: RustBuffer,
+            uniffiOutReturn: UnsafeMutablePointer<RustBuffer>,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> [UInt8]? in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceStorageAdapter.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.load(
+                     key: try FfiConverterSequenceString.lift(key)
+                )
+            }
+
+            
+            let writeReturn = { uniffiOutReturn.pointee = FfiConverterOptionSequenceUInt8.lower($0) }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        save: { (
+            uniffiHandle: UInt64,
+            key: RustBuffer,
+            data: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceStorageAdapter.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.save(
+                     key: try FfiConverterSequenceString.lift(key),
+                     data: try FfiConverterSequenceUInt8.lift(data)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        remove: { (
+            uniffiHandle: UInt64,
+            key: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceStorageAdapter.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.remove(
+                     key: try FfiConverterSequenceString.lift(key)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        list: { (
+            uniffiHandle: UInt64,
+            prefix: RustBuffer,
+            uniffiOutReturn: UnsafeMutablePointer<RustBuffer>,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> [[String]] in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceStorageAdapter.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.list(
+                     prefix: try FfiConverterSequenceString.lift(prefix)
+                )
+            }
+
+            
+            let writeReturn = { uniffiOutReturn.pointee = FfiConverterSequenceSequenceString.lower($0) }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterCallbackInterfaceStorageAdapter.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface StorageAdapter: handle missing in uniffiFree")
+            }
         }
+    )
+}
+
+private func uniffiCallbackInitStorageAdapter() {
+    uniffi_uniffi_automerge_fn_init_callback_vtable_storageadapter(&UniffiCallbackInterfaceStorageAdapter.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceStorageAdapter {
+    fileprivate static var handleMap = UniffiHandleMap<StorageAdapter>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceStorageAdapter : FfiConverter {
+    typealias SwiftType = StorageAdapter
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
     }
 
-    public static func write(_ value: ReceiveSyncError, into buf: inout [UInt8]) {
-        switch value {
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
 
-        
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
 
-        
-        case .Internal(_ /* message is ignored*/):
-            writeInt(&buf, Int32(1))
-        case .InvalidMessage(_ /* message is ignored*/):
-            writeInt(&buf, Int32(2))
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = UInt64?
 
-        
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt64.write(value, into: &buf)
     }
-}
-
 
-extension ReceiveSyncError: Equatable, Hashable {}
-
-extension ReceiveSyncError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-
-public enum ScalarValue {
-    
-    case bytes(value: [UInt8]
-    )
-    case string(value: String
-    )
-    case uint(value: UInt64
-    )
-    case int(value: Int64
-    )
-    case f64(value: Double
-    )
-    case counter(value: Int64
-    )
-    case timestamp(value: Int64
-    )
-    case boolean(value: Bool
-    )
-    case unknown(typeCode: UInt8, data: [UInt8]
-    )
-    case null
-}
-
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeScalarValue: FfiConverterRustBuffer {
-    typealias SwiftType = ScalarValue
+fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
+    typealias SwiftType = String?
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ScalarValue {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .bytes(value: try FfiConverterSequenceUInt8.read(from: &buf)
-        )
-        
-        case 2: return .string(value: try FfiConverterString.read(from: &buf)
-        )
-        
-        case 3: return .uint(value: try FfiConverterUInt64.read(from: &buf)
-        )
-        
-        case 4: return .int(value: try FfiConverterInt64.read(from: &buf)
-        )
-        
-        case 5: return .f64(value: try FfiConverterDouble.read(from: &buf)
-        )
-        
-        case 6: return .counter(value: try FfiConverterInt64.read(from: &buf)
-        )
-        
-        case 7: return .timestamp(value: try FfiConverterInt64.read(from: &buf)
-        )
-        
-        case 8: return .boolean(value: try FfiConverterBool.read(from: &buf)
-        )
-        
-        case 9: return .unknown(typeCode: try FfiConverterUInt8.read(from: &buf), data: try FfiConverterSequenceUInt8.read(from: &buf)
-        )
-        
-        case 10: return .null
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
+        writeInt(&buf, Int8(1))
+        FfiConverterString.write(value, into: &buf)
     }
 
-    public static func write(_ value: ScalarValue, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case let .bytes(value):
-            writeInt(&buf, Int32(1))
-            FfiConverterSequenceUInt8.write(value, into: &buf)
-            
-        
-        case let .string(value):
-            writeInt(&buf, Int32(2))
-            FfiConverterString.write(value, into: &buf)
-            
-        
-        case let .uint(value):
-            writeInt(&buf, Int32(3))
-            FfiConverterUInt64.write(value, into: &buf)
-            
-        
-        case let .int(value):
-            writeInt(&buf, Int32(4))
-            FfiConverterInt64.write(value, into: &buf)
-            
-        
-        case let .f64(value):
-            writeInt(&buf, Int32(5))
-            FfiConverterDouble.write(value, into: &buf)
-            
-        
-        case let .counter(value):
-            writeInt(&buf, Int32(6))
-            FfiConverterInt64.write(value, into: &buf)
-            
-        
-        case let .timestamp(value):
-            writeInt(&buf, Int32(7))
-            FfiConverterInt64.write(value, into: &buf)
-            
-        
-        case let .boolean(value):
-            writeInt(&buf, Int32(8))
-            FfiConverterBool.write(value, into: &buf)
-            
-        
-        case let .unknown(typeCode,data):
-            writeInt(&buf, Int32(9))
-            FfiConverterUInt8.write(typeCode, into: &buf)
-            FfiConverterSequenceUInt8.write(data, into: &buf)
-            
-        
-        case .null:
-            writeInt(&buf, Int32(10))
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeScalarValue_lift(_ buf: RustBuffer) throws -> ScalarValue {
-    return try FfiConverterTypeScalarValue.lift(buf)
+fileprivate struct FfiConverterOptionTypeDoc: FfiConverterRustBuffer {
+    typealias SwiftType = Doc?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeDoc.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeDoc.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeScalarValue_lower(_ value: ScalarValue) -> RustBuffer {
-    return FfiConverterTypeScalarValue.lower(value)
-}
+fileprivate struct FfiConverterOptionTypeSyncState: FfiConverterRustBuffer {
+    typealias SwiftType = SyncState?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeSyncState.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeSyncState.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
-extension ScalarValue: Equatable, Hashable {}
-
-
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeAttribution: FfiConverterRustBuffer {
+    typealias SwiftType = Attribution?
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeAttribution.write(value, into: &buf)
+    }
 
-public enum TextEncoding {
-    
-    case unicodeCodePoint
-    case utf8CodeUnit
-    case utf16CodeUnit
-    case graphemeCluster
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeAttribution.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTextEncoding: FfiConverterRustBuffer {
-    typealias SwiftType = TextEncoding
+fileprivate struct FfiConverterOptionTypeChange: FfiConverterRustBuffer {
+    typealias SwiftType = Change?
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TextEncoding {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .unicodeCodePoint
-        
-        case 2: return .utf8CodeUnit
-        
-        case 3: return .utf16CodeUnit
-        
-        case 4: return .graphemeCluster
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeChange.write(value, into: &buf)
     }
 
-    public static func write(_ value: TextEncoding, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .unicodeCodePoint:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .utf8CodeUnit:
-            writeInt(&buf, Int32(2))
-        
-        
-        case .utf16CodeUnit:
-            writeInt(&buf, Int32(3))
-        
-        
-        case .graphemeCluster:
-            writeInt(&buf, Int32(4))
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeChange.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTextEncoding_lift(_ buf: RustBuffer) throws -> TextEncoding {
-    return try FfiConverterTypeTextEncoding.lift(buf)
+fileprivate struct FfiConverterOptionTypeEphemeralMessage: FfiConverterRustBuffer {
+    typealias SwiftType = EphemeralMessage?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeEphemeralMessage.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeEphemeralMessage.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTextEncoding_lower(_ value: TextEncoding) -> RustBuffer {
-    return FfiConverterTypeTextEncoding.lower(value)
-}
-
-
+fileprivate struct FfiConverterOptionTypeDocumentSyncPhase: FfiConverterRustBuffer {
+    typealias SwiftType = DocumentSyncPhase?
 
-extension TextEncoding: Equatable, Hashable {}
-
-
-
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeDocumentSyncPhase.write(value, into: &buf)
+    }
 
-public enum Value {
-    
-    case object(typ: ObjType, id: ObjId
-    )
-    case scalar(value: ScalarValue
-    )
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeDocumentSyncPhase.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeValue: FfiConverterRustBuffer {
-    typealias SwiftType = Value
+fileprivate struct FfiConverterOptionTypeTextEncoding: FfiConverterRustBuffer {
+    typealias SwiftType = TextEncoding?
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Value {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .object(typ: try FfiConverterTypeObjType.read(from: &buf), id: try FfiConverterTypeObjId.read(from: &buf)
-        )
-        
-        case 2: return .scalar(value: try FfiConverterTypeScalarValue.read(from: &buf)
-        )
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeTextEncoding.write(value, into: &buf)
     }
 
-    public static func write(_ value: Value, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case let .object(typ,id):
-            writeInt(&buf, Int32(1))
-            FfiConverterTypeObjType.write(typ, into: &buf)
-            FfiConverterTypeObjId.write(id, into: &buf)
-            
-        
-        case let .scalar(value):
-            writeInt(&buf, Int32(2))
-            FfiConverterTypeScalarValue.write(value, into: &buf)
-            
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeTextEncoding.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeValue_lift(_ buf: RustBuffer) throws -> Value {
-    return try FfiConverterTypeValue.lift(buf)
+fileprivate struct FfiConverterOptionTypeValue: FfiConverterRustBuffer {
+    typealias SwiftType = Value?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeValue.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeValue.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeValue_lower(_ value: Value) -> RustBuffer {
-    return FfiConverterTypeValue.lower(value)
-}
+fileprivate struct FfiConverterOptionSequenceUInt8: FfiConverterRustBuffer {
+    typealias SwiftType = [UInt8]?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceUInt8.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceUInt8.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
-extension Value: Equatable, Hashable {}
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionSequenceTypeSyncHave: FfiConverterRustBuffer {
+    typealias SwiftType = [SyncHave]?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceTypeSyncHave.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceTypeSyncHave.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
-    typealias SwiftType = String?
+fileprivate struct FfiConverterOptionSequenceTypeSyncCapability: FfiConverterRustBuffer {
+    typealias SwiftType = [SyncCapability]?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -2859,13 +7451,13 @@ fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterString.write(value, into: &buf)
+        FfiConverterSequenceTypeSyncCapability.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterString.read(from: &buf)
+        case 1: return try FfiConverterSequenceTypeSyncCapability.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -2874,8 +7466,8 @@ fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeChange: FfiConverterRustBuffer {
-    typealias SwiftType = Change?
+fileprivate struct FfiConverterOptionSequenceTypeChangeHash: FfiConverterRustBuffer {
+    typealias SwiftType = [ChangeHash]?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -2883,13 +7475,13 @@ fileprivate struct FfiConverterOptionTypeChange: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypeChange.write(value, into: &buf)
+        FfiConverterSequenceTypeChangeHash.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypeChange.read(from: &buf)
+        case 1: return try FfiConverterSequenceTypeChangeHash.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -2898,8 +7490,8 @@ fileprivate struct FfiConverterOptionTypeChange: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeValue: FfiConverterRustBuffer {
-    typealias SwiftType = Value?
+fileprivate struct FfiConverterOptionTypeActorId: FfiConverterRustBuffer {
+    typealias SwiftType = ActorId?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -2907,13 +7499,13 @@ fileprivate struct FfiConverterOptionTypeValue: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypeValue.write(value, into: &buf)
+        FfiConverterTypeActorId.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypeValue.read(from: &buf)
+        case 1: return try FfiConverterTypeActorId.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -2922,8 +7514,8 @@ fileprivate struct FfiConverterOptionTypeValue: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionSequenceUInt8: FfiConverterRustBuffer {
-    typealias SwiftType = [UInt8]?
+fileprivate struct FfiConverterOptionTypeChangeHash: FfiConverterRustBuffer {
+    typealias SwiftType = ChangeHash?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -2931,13 +7523,13 @@ fileprivate struct FfiConverterOptionSequenceUInt8: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterSequenceUInt8.write(value, into: &buf)
+        FfiConverterTypeChangeHash.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterSequenceUInt8.read(from: &buf)
+        case 1: return try FfiConverterTypeChangeHash.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -2946,8 +7538,8 @@ fileprivate struct FfiConverterOptionSequenceUInt8: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionSequenceTypeChangeHash: FfiConverterRustBuffer {
-    typealias SwiftType = [ChangeHash]?
+fileprivate struct FfiConverterOptionTypeObjId: FfiConverterRustBuffer {
+    typealias SwiftType = ObjId?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -2955,13 +7547,13 @@ fileprivate struct FfiConverterOptionSequenceTypeChangeHash: FfiConverterRustBuf
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterSequenceTypeChangeHash.write(value, into: &buf)
+        FfiConverterTypeObjId.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterSequenceTypeChangeHash.read(from: &buf)
+        case 1: return try FfiConverterTypeObjId.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -2970,23 +7562,123 @@ fileprivate struct FfiConverterOptionSequenceTypeChangeHash: FfiConverterRustBuf
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceUInt8: FfiConverterRustBuffer {
-    typealias SwiftType = [UInt8]
+fileprivate struct FfiConverterSequenceUInt8: FfiConverterRustBuffer {
+    typealias SwiftType = [UInt8]
+
+    public static func write(_ value: [UInt8], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterUInt8.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UInt8] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UInt8]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterUInt8.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
+    typealias SwiftType = [String]
+
+    public static func write(_ value: [String], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterString.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [String]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterString.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeBranch: FfiConverterRustBuffer {
+    typealias SwiftType = [Branch]
+
+    public static func write(_ value: [Branch], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeBranch.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Branch] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Branch]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeBranch.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeChange: FfiConverterRustBuffer {
+    typealias SwiftType = [Change]
+
+    public static func write(_ value: [Change], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeChange.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Change] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Change]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeChange.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeConflict: FfiConverterRustBuffer {
+    typealias SwiftType = [Conflict]
 
-    public static func write(_ value: [UInt8], into buf: inout [UInt8]) {
+    public static func write(_ value: [Conflict], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterUInt8.write(item, into: &buf)
+            FfiConverterTypeConflict.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UInt8] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Conflict] {
         let len: Int32 = try readInt(&buf)
-        var seq = [UInt8]()
+        var seq = [Conflict]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterUInt8.read(from: &buf))
+            seq.append(try FfiConverterTypeConflict.read(from: &buf))
         }
         return seq
     }
@@ -2995,23 +7687,23 @@ fileprivate struct FfiConverterSequenceUInt8: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
-    typealias SwiftType = [String]
+fileprivate struct FfiConverterSequenceTypeDiffSummary: FfiConverterRustBuffer {
+    typealias SwiftType = [DiffSummary]
 
-    public static func write(_ value: [String], into buf: inout [UInt8]) {
+    public static func write(_ value: [DiffSummary], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterString.write(item, into: &buf)
+            FfiConverterTypeDiffSummary.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DiffSummary] {
         let len: Int32 = try readInt(&buf)
-        var seq = [String]()
+        var seq = [DiffSummary]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterString.read(from: &buf))
+            seq.append(try FfiConverterTypeDiffSummary.read(from: &buf))
         }
         return seq
     }
@@ -3117,6 +7809,131 @@ fileprivate struct FfiConverterSequenceTypePathElement: FfiConverterRustBuffer {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypePeerMessage: FfiConverterRustBuffer {
+    typealias SwiftType = [PeerMessage]
+
+    public static func write(_ value: [PeerMessage], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypePeerMessage.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [PeerMessage] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [PeerMessage]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypePeerMessage.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypePresenceState: FfiConverterRustBuffer {
+    typealias SwiftType = [PresenceState]
+
+    public static func write(_ value: [PresenceState], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypePresenceState.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [PresenceState] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [PresenceState]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypePresenceState.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeSyncHave: FfiConverterRustBuffer {
+    typealias SwiftType = [SyncHave]
+
+    public static func write(_ value: [SyncHave], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeSyncHave.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SyncHave] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [SyncHave]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeSyncHave.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeTextSpan: FfiConverterRustBuffer {
+    typealias SwiftType = [TextSpan]
+
+    public static func write(_ value: [TextSpan], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTextSpan.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TextSpan] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TextSpan]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTextSpan.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeHydrateValue: FfiConverterRustBuffer {
+    typealias SwiftType = [HydrateValue]
+
+    public static func write(_ value: [HydrateValue], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeHydrateValue.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [HydrateValue] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [HydrateValue]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeHydrateValue.read(from: &buf))
+        }
+        return seq
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -3142,6 +7959,31 @@ fileprivate struct FfiConverterSequenceTypeScalarValue: FfiConverterRustBuffer {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeSyncCapability: FfiConverterRustBuffer {
+    typealias SwiftType = [SyncCapability]
+
+    public static func write(_ value: [SyncCapability], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeSyncCapability.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SyncCapability] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [SyncCapability]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeSyncCapability.read(from: &buf))
+        }
+        return seq
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -3167,6 +8009,56 @@ fileprivate struct FfiConverterSequenceTypeValue: FfiConverterRustBuffer {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceSequenceString: FfiConverterRustBuffer {
+    typealias SwiftType = [[String]]
+
+    public static func write(_ value: [[String]], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterSequenceString.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [[String]] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [[String]]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterSequenceString.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeActorId: FfiConverterRustBuffer {
+    typealias SwiftType = [ActorId]
+
+    public static func write(_ value: [ActorId], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeActorId.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ActorId] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ActorId]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeActorId.read(from: &buf))
+        }
+        return seq
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -3192,6 +8084,83 @@ fileprivate struct FfiConverterSequenceTypeChangeHash: FfiConverterRustBuffer {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeDocumentId: FfiConverterRustBuffer {
+    typealias SwiftType = [DocumentId]
+
+    public static func write(_ value: [DocumentId], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeDocumentId.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DocumentId] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [DocumentId]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeDocumentId.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDictionaryStringTypeHydrateValue: FfiConverterRustBuffer {
+    public static func write(_ value: [String: HydrateValue], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for (key, value) in value {
+            FfiConverterString.write(key, into: &buf)
+            FfiConverterTypeHydrateValue.write(value, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String: HydrateValue] {
+        let len: Int32 = try readInt(&buf)
+        var dict = [String: HydrateValue]()
+        dict.reserveCapacity(Int(len))
+        for _ in 0..<len {
+            let key = try FfiConverterString.read(from: &buf)
+            let value = try FfiConverterTypeHydrateValue.read(from: &buf)
+            dict[key] = value
+        }
+        return dict
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDictionaryStringTypePresenceValue: FfiConverterRustBuffer {
+    public static func write(_ value: [String: PresenceValue], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for (key, value) in value {
+            FfiConverterString.write(key, into: &buf)
+            FfiConverterTypePresenceValue.write(value, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String: PresenceValue] {
+        let len: Int32 = try readInt(&buf)
+        var dict = [String: PresenceValue]()
+        dict.reserveCapacity(Int(len))
+        for _ in 0..<len {
+            let key = try FfiConverterString.read(from: &buf)
+            let value = try FfiConverterTypePresenceValue.read(from: &buf)
+            dict[key] = value
+        }
+        return dict
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -3321,16 +8290,60 @@ public struct FfiConverterTypeCursor: FfiConverter {
         return try FfiConverterSequenceUInt8.read(from: &buf)
     }
 
-    public static func write(_ value: Cursor, into buf: inout [UInt8]) {
-        return FfiConverterSequenceUInt8.write(value, into: &buf)
+    public static func write(_ value: Cursor, into buf: inout [UInt8]) {
+        return FfiConverterSequenceUInt8.write(value, into: &buf)
+    }
+
+    public static func lift(_ value: RustBuffer) throws -> Cursor {
+        return try FfiConverterSequenceUInt8.lift(value)
+    }
+
+    public static func lower(_ value: Cursor) -> RustBuffer {
+        return FfiConverterSequenceUInt8.lower(value)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCursor_lift(_ value: RustBuffer) throws -> Cursor {
+    return try FfiConverterTypeCursor.lift(value)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCursor_lower(_ value: Cursor) -> RustBuffer {
+    return FfiConverterTypeCursor.lower(value)
+}
+
+
+
+/**
+ * Typealias from the type name used in the UDL file to the builtin type.  This
+ * is needed because the UDL type name is used in function/method signatures.
+ */
+public typealias DocumentId = String
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDocumentId: FfiConverter {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DocumentId {
+        return try FfiConverterString.read(from: &buf)
+    }
+
+    public static func write(_ value: DocumentId, into buf: inout [UInt8]) {
+        return FfiConverterString.write(value, into: &buf)
     }
 
-    public static func lift(_ value: RustBuffer) throws -> Cursor {
-        return try FfiConverterSequenceUInt8.lift(value)
+    public static func lift(_ value: RustBuffer) throws -> DocumentId {
+        return try FfiConverterString.lift(value)
     }
 
-    public static func lower(_ value: Cursor) -> RustBuffer {
-        return FfiConverterSequenceUInt8.lower(value)
+    public static func lower(_ value: DocumentId) -> RustBuffer {
+        return FfiConverterString.lower(value)
     }
 }
 
@@ -3338,15 +8351,15 @@ public struct FfiConverterTypeCursor: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCursor_lift(_ value: RustBuffer) throws -> Cursor {
-    return try FfiConverterTypeCursor.lift(value)
+public func FfiConverterTypeDocumentId_lift(_ value: RustBuffer) throws -> DocumentId {
+    return try FfiConverterTypeDocumentId.lift(value)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCursor_lower(_ value: Cursor) -> RustBuffer {
-    return FfiConverterTypeCursor.lower(value)
+public func FfiConverterTypeDocumentId_lower(_ value: DocumentId) -> RustBuffer {
+    return FfiConverterTypeDocumentId.lower(value)
 }
 
 
@@ -3393,6 +8406,47 @@ public func FfiConverterTypeObjId_lower(_ value: ObjId) -> RustBuffer {
     return FfiConverterTypeObjId.lower(value)
 }
 
+public func automergeUrl(documentId: DocumentId)throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeRepoError.lift) {
+    uniffi_uniffi_automerge_fn_func_automerge_url(
+        FfiConverterTypeDocumentId.lower(documentId),$0
+    )
+})
+}
+public func decodeChange(bytes: [UInt8])throws  -> Change {
+    return try  FfiConverterTypeChange.lift(try rustCallWithError(FfiConverterTypeDecodeChangeError.lift) {
+    uniffi_uniffi_automerge_fn_func_decode_change(
+        FfiConverterSequenceUInt8.lower(bytes),$0
+    )
+})
+}
+public func decodeRepoMessage(bytes: [UInt8])throws  -> RepoMessage {
+    return try  FfiConverterTypeRepoMessage.lift(try rustCallWithError(FfiConverterTypeRepoError.lift) {
+    uniffi_uniffi_automerge_fn_func_decode_repo_message(
+        FfiConverterSequenceUInt8.lower(bytes),$0
+    )
+})
+}
+public func decodeSyncMessage(bytes: [UInt8])throws  -> SyncMessage {
+    return try  FfiConverterTypeSyncMessage.lift(try rustCallWithError(FfiConverterTypeDecodeSyncMessageError.lift) {
+    uniffi_uniffi_automerge_fn_func_decode_sync_message(
+        FfiConverterSequenceUInt8.lower(bytes),$0
+    )
+})
+}
+public func encodeRepoMessage(message: RepoMessage)throws  -> [UInt8] {
+    return try  FfiConverterSequenceUInt8.lift(try rustCallWithError(FfiConverterTypeRepoError.lift) {
+    uniffi_uniffi_automerge_fn_func_encode_repo_message(
+        FfiConverterTypeRepoMessage.lower(message),$0
+    )
+})
+}
+public func generateDocumentId() -> DocumentId {
+    return try!  FfiConverterTypeDocumentId.lift(try! rustCall() {
+    uniffi_uniffi_automerge_fn_func_generate_document_id($0
+    )
+})
+}
 public func root() -> ObjId {
     return try!  FfiConverterTypeObjId.lift(try! rustCall() {
     uniffi_uniffi_automerge_fn_func_root($0
@@ -3415,6 +8469,24 @@ private var initializationResult: InitializationResult = {
     if bindings_contract_version != scaffolding_contract_version {
         return InitializationResult.contractVersionMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_func_automerge_url() != 12851) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_func_decode_change() != 9720) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_func_decode_repo_message() != 23522) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_func_decode_sync_message() != 6690) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_func_encode_repo_message() != 60042) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_func_generate_document_id() != 45578) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_uniffi_automerge_checksum_func_root() != 19647) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -3427,15 +8499,39 @@ private var initializationResult: InitializationResult = {
     if (uniffi_uniffi_automerge_checksum_method_doc_apply_encoded_changes_with_patches() != 63928) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_uniffi_automerge_checksum_method_doc_change_by_hash() != 44577) {
+    if (uniffi_uniffi_automerge_checksum_method_doc_begin_transaction() != 16317) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_doc_blame_property() != 33065) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_doc_blame_text() != 20956) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_doc_change_by_hash() != 49878) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_uniffi_automerge_checksum_method_doc_changes() != 1878) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_method_doc_checkout() != 55519) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_doc_cherry_pick() != 65437) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_doc_cherry_pick_encoded() != 42834) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_doc_clear_patch_observer() != 27148) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_uniffi_automerge_checksum_method_doc_commit_with() != 65319) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_method_doc_create_branch() != 34278) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_uniffi_automerge_checksum_method_doc_cursor() != 18441) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -3448,13 +8544,22 @@ private var initializationResult: InitializationResult = {
     if (uniffi_uniffi_automerge_checksum_method_doc_cursor_position_at() != 35233) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_method_doc_delete_branch() != 56101) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_uniffi_automerge_checksum_method_doc_delete_in_list() != 36066) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_uniffi_automerge_checksum_method_doc_delete_in_map() != 1721) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_uniffi_automerge_checksum_method_doc_difference() != 13614) {
+    if (uniffi_uniffi_automerge_checksum_method_doc_difference() != 17412) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_doc_difference_in() != 64750) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_doc_difference_summary() != 3023) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_uniffi_automerge_checksum_method_doc_encode_changes_since() != 49806) {
@@ -3490,6 +8595,12 @@ private var initializationResult: InitializationResult = {
     if (uniffi_uniffi_automerge_checksum_method_doc_get_at_in_map() != 41003) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_method_doc_get_changes() != 21447) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_doc_get_conflicts() != 449) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_uniffi_automerge_checksum_method_doc_get_in_list() != 55210) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -3499,12 +8610,18 @@ private var initializationResult: InitializationResult = {
     if (uniffi_uniffi_automerge_checksum_method_doc_heads() != 44667) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_method_doc_hydrate() != 8454) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_uniffi_automerge_checksum_method_doc_increment_in_list() != 6803) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_uniffi_automerge_checksum_method_doc_increment_in_map() != 24542) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_method_doc_insert_hydrated() != 11968) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_uniffi_automerge_checksum_method_doc_insert_in_list() != 26167) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -3514,10 +8631,16 @@ private var initializationResult: InitializationResult = {
     if (uniffi_uniffi_automerge_checksum_method_doc_join_block() != 37348) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_uniffi_automerge_checksum_method_doc_length() != 30352) {
+    if (uniffi_uniffi_automerge_checksum_method_doc_length() != 29611) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_doc_length_at() != 23746) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_uniffi_automerge_checksum_method_doc_length_at() != 64377) {
+    if (uniffi_uniffi_automerge_checksum_method_doc_list_branches() != 31475) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_doc_list_range() != 22538) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_uniffi_automerge_checksum_method_doc_map_entries() != 3918) {
@@ -3526,10 +8649,13 @@ private var initializationResult: InitializationResult = {
     if (uniffi_uniffi_automerge_checksum_method_doc_map_entries_at() != 35589) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_uniffi_automerge_checksum_method_doc_map_keys() != 45893) {
+    if (uniffi_uniffi_automerge_checksum_method_doc_map_keys() != 57004) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_doc_map_keys_at() != 19359) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_uniffi_automerge_checksum_method_doc_map_keys_at() != 36273) {
+    if (uniffi_uniffi_automerge_checksum_method_doc_map_range() != 64212) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_uniffi_automerge_checksum_method_doc_mark() != 5875) {
@@ -3547,21 +8673,30 @@ private var initializationResult: InitializationResult = {
     if (uniffi_uniffi_automerge_checksum_method_doc_merge() != 8598) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_method_doc_merge_branch() != 40407) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_uniffi_automerge_checksum_method_doc_merge_with_patches() != 63992) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_uniffi_automerge_checksum_method_doc_object_type() != 15479) {
+    if (uniffi_uniffi_automerge_checksum_method_doc_object_type() != 41135) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_uniffi_automerge_checksum_method_doc_path() != 29434) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_method_doc_put_hydrated() != 44756) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_uniffi_automerge_checksum_method_doc_put_in_list() != 39558) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_uniffi_automerge_checksum_method_doc_put_in_map() != 3891) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_method_doc_put_json() != 47213) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_uniffi_automerge_checksum_method_doc_put_object_in_list() != 29333) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -3574,12 +8709,27 @@ private var initializationResult: InitializationResult = {
     if (uniffi_uniffi_automerge_checksum_method_doc_receive_sync_message_with_patches() != 42532) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_method_doc_resolve_conflict() != 25536) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_doc_revert_to() != 52430) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_uniffi_automerge_checksum_method_doc_save() != 20308) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_method_doc_save_incremental() != 20948) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_doc_save_no_compress() != 47855) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_uniffi_automerge_checksum_method_doc_set_actor() != 64337) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_method_doc_set_patch_observer() != 9657) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_uniffi_automerge_checksum_method_doc_splice() != 29894) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -3598,6 +8748,9 @@ private var initializationResult: InitializationResult = {
     if (uniffi_uniffi_automerge_checksum_method_doc_text_encoding() != 58521) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_method_doc_to_json() != 32106) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_uniffi_automerge_checksum_method_doc_update_text() != 26364) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -3607,18 +8760,222 @@ private var initializationResult: InitializationResult = {
     if (uniffi_uniffi_automerge_checksum_method_doc_values_at() != 16206) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_method_presence_encode_leave() != 55147) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_presence_encode_update() != 57210) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_presence_prune() != 6037) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_presence_receive() != 44682) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_presence_states() != 19093) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_add_peer() != 25621) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_broadcast() != 34238) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_compact() != 1519) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_create() != 3627) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_delete() != 58188) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_document_ids() != 22406) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_find() != 43804) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_import() != 40227) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_join() != 15029) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_load_sync_state() != 34040) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_peer_id() != 59338) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_peers() != 5267) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_phase() != 41954) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_receive_message() != 10957) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_remove_peer() != 40315) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_request() != 38723) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_save() != 27334) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_save_sync_state() != 22791) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_set_network_adapter() != 17254) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_stored_document_ids() != 1113) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_sync() != 49152) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_repo_sync_all() != 18911) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_syncsession_add_peer() != 36232) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_syncsession_add_peer_with_state() != 44335) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_syncsession_doc() != 19573) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_syncsession_encode() != 33113) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_syncsession_generate_message() != 39397) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_syncsession_generate_messages() != 17401) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_syncsession_peer_state() != 16691) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_syncsession_peers() != 52323) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_syncsession_receive_message() != 32873) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_syncsession_remove_peer() != 63841) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_uniffi_automerge_checksum_method_syncstate_encode() != 34911) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_method_syncstate_in_flight() != 7685) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_syncstate_last_sent_heads() != 46124) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_uniffi_automerge_checksum_method_syncstate_reset() != 57480) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_method_syncstate_sent_hashes_count() != 22856) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_syncstate_shared_heads() != 18285) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_syncstate_their_have() != 31973) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_uniffi_automerge_checksum_method_syncstate_their_heads() != 39870) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_method_syncstate_their_need() != 23206) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_transaction_commit() != 32808) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_transaction_delete_in_list() != 3761) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_transaction_delete_in_map() != 55077) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_transaction_increment_in_list() != 60111) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_transaction_increment_in_map() != 8768) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_transaction_insert_in_list() != 13928) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_transaction_insert_object_in_list() != 38389) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_transaction_mark() != 51435) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_transaction_put_in_list() != 54306) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_transaction_put_in_map() != 51955) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_transaction_put_object_in_list() != 46510) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_transaction_put_object_in_map() != 36307) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_transaction_rollback() != 22386) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_transaction_splice() != 46275) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_transaction_splice_text() != 24996) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_transaction_update_text() != 14627) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_undomanager_begin_group() != 61600) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_undomanager_can_redo() != 55641) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_undomanager_can_undo() != 46404) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_undomanager_clear() != 9013) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_undomanager_end_group() != 14758) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_undomanager_record() != 22152) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_undomanager_redo() != 57066) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_undomanager_undo() != 22566) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_uniffi_automerge_checksum_constructor_doc_load() != 20048) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_constructor_doc_load_with_options() != 39213) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_uniffi_automerge_checksum_constructor_doc_new() != 9447) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -3628,13 +8985,49 @@ private var initializationResult: InitializationResult = {
     if (uniffi_uniffi_automerge_checksum_constructor_doc_new_with_text_encoding() != 28053) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_constructor_presence_new() != 5591) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_constructor_repo_new() != 58483) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_constructor_syncsession_new() != 62612) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_constructor_syncsession_restore() != 16509) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_uniffi_automerge_checksum_constructor_syncstate_decode() != 17966) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_uniffi_automerge_checksum_constructor_syncstate_new() != 37569) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_uniffi_automerge_checksum_constructor_undomanager_new() != 44110) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_networkadapter_send() != 2249) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_patchobserver_on_patches() != 23796) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_storageadapter_load() != 43416) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_storageadapter_save() != 3843) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_storageadapter_remove() != 15949) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_uniffi_automerge_checksum_method_storageadapter_list() != 7749) {
+        return InitializationResult.apiChecksumMismatch
+    }
 
+    uniffiCallbackInitNetworkAdapter()
+    uniffiCallbackInitPatchObserver()
+    uniffiCallbackInitStorageAdapter()
     return InitializationResult.ok
 }()
 
@@ -67,10 +67,10 @@ benchmark.addSimple(
     let doc = Automerge.Document()
     let text = try! doc.putObject(obj: ObjId.ROOT, key: "text", ty: .Text)
 
-    var stringLength = doc.length(obj: text)
+    var stringLength = try! doc.length(obj: text)
     for strChar in input {
         try! doc.spliceText(obj: text, start: stringLength, delete: 0, value: strChar)
-        stringLength = doc.length(obj: text)
+        stringLength = try! doc.length(obj: text)
     }
     // precondition(stringLength == input.count) // NOT VALID - difference in UTF-8 codepoints and how strings represent
     // lengths
@@ -101,10 +101,10 @@ benchmark.addSimple(
     let doc = Automerge.Document()
     let text = try! doc.putObject(obj: ObjId.ROOT, key: "text", ty: .Text)
 
-    var stringLength = doc.length(obj: text)
+    var stringLength = try! doc.length(obj: text)
     for strChar in input {
         try! doc.spliceText(obj: text, start: stringLength, delete: 0, value: strChar)
-        stringLength = doc.length(obj: text)
+        stringLength = try! doc.length(obj: text)
     }
     let resultingString = try! doc.text(obj: text)
     // precondition(stringLength == input.count) // NOT VALID - difference in UTF-8 codepoints and how strings represent
@@ -139,7 +139,7 @@ benchmark.addSimple(
     let numList = try! doc.putObject(obj: ObjId.ROOT, key: "numberlist", ty: .List)
 
     for intValue in integerInput {
-        let listLength = doc.length(obj: numList)
+        let listLength = try! doc.length(obj: numList)
         try! doc.insert(obj: numList, index: listLength, value: .Int(Int64(exactly: intValue)!))
     }
     // precondition(stringLength == input.count) // NOT VALID - difference in UTF-8 codepoints and how strings represent
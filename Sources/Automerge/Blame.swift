@@ -0,0 +1,50 @@
+import struct AutomergeUniffi.Attribution
+import struct AutomergeUniffi.TextSpan
+import Foundation
+
+typealias FfiAttribution = AutomergeUniffi.Attribution
+typealias FfiTextSpan = AutomergeUniffi.TextSpan
+
+/// The change that wrote a value or a run of text.
+///
+/// Get the change that last set a property with ``Document/blame(obj:key:)`` or ``Document/blame(obj:index:)``,
+/// and the changes that inserted the text of a text object with ``Document/blameText(obj:)``.
+public struct Attribution: Equatable, Hashable, Sendable {
+    /// The identity of the change.
+    public let change: ChangeHash
+    /// The identity of the actor that made the change.
+    public let actor: ActorId
+    /// The sequence number of the change among the changes made by its actor, starting at 1.
+    public let seq: UInt64
+    /// The timestamp of the change.
+    public let timestamp: Date
+    /// An optional message associated with the change.
+    public let message: String?
+
+    init(_ ffi: FfiAttribution) {
+        change = ChangeHash(bytes: ffi.change)
+        actor = ActorId(ffi: ffi.actor)
+        seq = ffi.seq
+        timestamp = Date(timeIntervalSince1970: TimeInterval(ffi.timestamp))
+        message = ffi.message
+    }
+}
+
+/// A run of text inserted by a single change.
+public struct TextSpan: Equatable, Hashable, Sendable {
+    /// The distance from the start of the text where the span starts, in the document's text encoding.
+    public let start: UInt64
+    /// The distance from the start of the text where the span ends, in the document's text encoding.
+    public let end: UInt64
+    /// The text of the span.
+    public let text: String
+    /// The change that inserted the text, or `nil` if the change hasn't been committed yet.
+    public let attribution: Attribution?
+
+    init(_ ffi: FfiTextSpan) {
+        start = ffi.start
+        end = ffi.end
+        text = ffi.text
+        attribution = ffi.attribution.map(Attribution.init)
+    }
+}
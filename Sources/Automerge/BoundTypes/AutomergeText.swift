@@ -121,7 +121,7 @@ public final class AutomergeText: Codable, @unchecked Sendable {
 
     public convenience init(doc: Document, objId: ObjId) throws {
         self.init()
-        if doc.objectType(obj: objId) == .Text {
+        if try doc.objectType(obj: objId) == .Text {
             sync {
                 self.doc = doc
                 self.objId = objId
@@ -230,7 +230,7 @@ public final class AutomergeText: Codable, @unchecked Sendable {
         // I don't want to second guess it at the moment.
         //
         // assert(self.doc == nil && self.objId == nil)
-        if doc.objectType(obj: id) == .Text {
+        if try doc.objectType(obj: id) == .Text {
             sync {
                 self.doc = doc
                 objId = id
@@ -0,0 +1,21 @@
+import struct AutomergeUniffi.Branch
+import Foundation
+
+typealias FfiBranch = AutomergeUniffi.Branch
+
+/// A named point in time of a document, stored in the document so that it's saved and synchronised along with it.
+///
+/// Create or move a branch with ``Document/createBranch(_:heads:)``, fork the document at a branch with
+/// ``Document/checkout(branch:)``, and bring the edits made to the fork back with
+/// ``Document/mergeBranch(_:from:)``.
+public struct Branch: Equatable, Hashable, Sendable {
+    /// The name of the branch.
+    public let name: String
+    /// The set of ``ChangeHash`` that represents the point in time the branch points at.
+    public let heads: Set<ChangeHash>
+
+    init(_ ffi: FfiBranch) {
+        name = ffi.name
+        heads = Set(ffi.heads.map { ChangeHash(bytes: $0) })
+    }
+}
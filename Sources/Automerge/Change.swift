@@ -1,4 +1,5 @@
 import struct AutomergeUniffi.Change
+import func AutomergeUniffi.decodeChange
 import Foundation
 
 typealias FfiChange = AutomergeUniffi.Change
@@ -7,6 +8,8 @@ typealias FfiChange = AutomergeUniffi.Change
 public struct Change: Equatable {
     /// The identity of the actor that made the change.
     public let actorId: ActorId
+    /// The sequence number of the change among the changes made by its actor, starting at 1.
+    public let seq: UInt64
     /// An optional message associated with the change.
     public let message: String?
     /// The list of changes that this change depends upon.
@@ -18,8 +21,17 @@ public struct Change: Equatable {
     /// The identity of the change, its hash.
     public let hash: ChangeHash
 
+    /// Decodes a change from its encoded bytes.
+    ///
+    /// - Parameter bytes: The encoded bytes of a single change, such as ``bytes``.
+    /// - Throws: ``DecodeChangeError`` if the bytes aren't a valid change.
+    public init(decoding bytes: Data) throws {
+        try self.init(wrappedErrors { try decodeChange(bytes: Array(bytes)) })
+    }
+
     init(_ ffi: FfiChange) {
         actorId = ActorId(ffi: ffi.actorId)
+        seq = ffi.seq
         message = ffi.message
         deps = ffi.deps.map(ChangeHash.init(bytes:))
         timestamp = Date(timeIntervalSince1970: TimeInterval(ffi.timestamp))
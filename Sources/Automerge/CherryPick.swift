@@ -0,0 +1,20 @@
+import struct AutomergeUniffi.CherryPick
+import Foundation
+
+typealias FfiCherryPick = AutomergeUniffi.CherryPick
+
+/// The changes that a cherry-pick applied to a document.
+///
+/// Pick changes from another document with ``Document/cherryPick(from:changes:)``, or from encoded changes with
+/// ``Document/cherryPick(encoded:changes:)``.
+public struct CherryPick: Equatable, Hashable, Sendable {
+    /// The changes you asked for, in the order you asked for them, leaving out any the document already had.
+    public let applied: [ChangeHash]
+    /// The changes you didn't ask for, but which the document needed because the changes you asked for depend on them.
+    public let dependencies: [ChangeHash]
+
+    init(_ ffi: FfiCherryPick) {
+        applied = ffi.applied.map { ChangeHash(bytes: $0) }
+        dependencies = ffi.dependencies.map { ChangeHash(bytes: $0) }
+    }
+}
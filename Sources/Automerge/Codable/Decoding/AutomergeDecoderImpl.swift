@@ -57,7 +57,7 @@ extension AutomergeDecoderImpl: Decoder {
         )
         switch result {
         case let .success(objectId):
-            let objectType = doc.objectType(obj: objectId)
+            let objectType = try doc.objectType(obj: objectId)
             guard case .Map = objectType else {
                 throw DecodingError.typeMismatch([String: Value].self, DecodingError.Context(
                     codingPath: codingPath,
@@ -65,7 +65,7 @@ extension AutomergeDecoderImpl: Decoder {
                 ))
             }
 
-            let container = AutomergeKeyedDecodingContainer<Key>(
+            let container = try AutomergeKeyedDecodingContainer<Key>(
                 impl: self,
                 codingPath: codingPath,
                 objectId: objectId
@@ -84,7 +84,7 @@ extension AutomergeDecoderImpl: Decoder {
         )
         switch result {
         case let .success(objectId):
-            let objectType = doc.objectType(obj: objectId)
+            let objectType = try doc.objectType(obj: objectId)
             guard case .List = objectType else {
                 throw DecodingError.typeMismatch([String: Value].self, DecodingError.Context(
                     codingPath: codingPath,
@@ -92,7 +92,7 @@ extension AutomergeDecoderImpl: Decoder {
                 ))
             }
 
-            return AutomergeUnkeyedDecodingContainer(
+            return try AutomergeUnkeyedDecodingContainer(
                 impl: self,
                 codingPath: codingPath,
                 objectId: objectId
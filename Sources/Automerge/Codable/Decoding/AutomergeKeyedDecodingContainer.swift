@@ -8,11 +8,11 @@ struct AutomergeKeyedDecodingContainer<K: CodingKey>: KeyedDecodingContainerProt
     let objectId: ObjId
     let keys: [String]
 
-    init(impl: AutomergeDecoderImpl, codingPath: [CodingKey], objectId: ObjId) {
+    init(impl: AutomergeDecoderImpl, codingPath: [CodingKey], objectId: ObjId) throws {
         self.impl = impl
         self.codingPath = codingPath
         self.objectId = objectId
-        keys = impl.doc.keys(obj: objectId)
+        keys = try impl.doc.keys(obj: objectId)
     }
 
     private func pathWithKey(_ key: K) -> String {
@@ -9,11 +9,11 @@ struct AutomergeUnkeyedDecodingContainer: UnkeyedDecodingContainer {
     var isAtEnd: Bool { currentIndex >= (count ?? 0) }
     var currentIndex = 0
 
-    init(impl: AutomergeDecoderImpl, codingPath: [CodingKey], objectId: ObjId) {
+    init(impl: AutomergeDecoderImpl, codingPath: [CodingKey], objectId: ObjId) throws {
         self.impl = impl
         self.codingPath = codingPath
         self.objectId = objectId
-        count = Int(impl.doc.length(obj: objectId))
+        count = try Int(impl.doc.length(obj: objectId))
     }
 
     mutating func decodeNil() throws -> Bool {
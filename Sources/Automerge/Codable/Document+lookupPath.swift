@@ -24,7 +24,7 @@ public extension Document {
             }
             // get any existing value - type of the `get` call is based on the key type
             if let indexValue = finalCodingKey.intValue {
-                let indexSize = length(obj: objectId)
+                let indexSize = try length(obj: objectId)
                 if indexValue > indexSize {
                     throw CodingKeyLookupError
                         .IndexOutOfBounds("Attempted to look up index \(indexValue) from a list of size \(indexSize).")
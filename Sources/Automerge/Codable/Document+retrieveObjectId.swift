@@ -144,19 +144,25 @@ extension Document {
             if let indexValue = path[position].intValue {
                 tracePrint(indent: position, "Checking against index position \(indexValue).")
                 // If it's an index, verify that it doesn't represent an element beyond the end of an existing list.
-                if indexValue > length(obj: previousObjectId) {
+                let listLength: UInt64
+                do {
+                    listLength = try length(obj: previousObjectId)
+                } catch {
+                    return .failure(.AutomergeDocError(error))
+                }
+                if indexValue > listLength {
                     if strategy == .readonly {
                         return .failure(
                             CodingKeyLookupError
                                 .IndexOutOfBounds(
-                                    "Index value \(indexValue) is beyond the length: \(length(obj: previousObjectId)) and schema is read-only"
+                                    "Index value \(indexValue) is beyond the length: \(listLength) and schema is read-only"
                                 )
                         )
-                    } else if indexValue > (length(obj: previousObjectId) + 1) {
+                    } else if indexValue > (listLength + 1) {
                         return .failure(
                             CodingKeyLookupError
                                 .IndexOutOfBounds(
-                                    "Index value \(indexValue) is too far beyond the length: \(length(obj: previousObjectId)) to append a new item."
+                                    "Index value \(indexValue) is too far beyond the length: \(listLength) to append a new item."
                                 )
                         )
                     }
@@ -368,19 +374,25 @@ extension Document {
                     "Final piece of the path is '\(finalpiece)', index \(indexValue) of a List."
                 )
                 // short circuit beyond-length of array
-                if indexValue > length(obj: previousObjectId) {
+                let listLength: UInt64
+                do {
+                    listLength = try length(obj: previousObjectId)
+                } catch {
+                    return .failure(.AutomergeDocError(error))
+                }
+                if indexValue > listLength {
                     if strategy == .readonly {
                         return .failure(
                             CodingKeyLookupError
                                 .IndexOutOfBounds(
-                                    "Index value \(indexValue) is beyond the length: \(length(obj: previousObjectId)) and schema is read-only"
+                                    "Index value \(indexValue) is beyond the length: \(listLength) and schema is read-only"
                                 )
                         )
-                    } else if indexValue > (length(obj: previousObjectId) + 1) {
+                    } else if indexValue > (listLength + 1) {
                         return .failure(
                             CodingKeyLookupError
                                 .IndexOutOfBounds(
-                                    "Index value \(indexValue) is too far beyond the length: \(length(obj: previousObjectId)) to append a new item."
+                                    "Index value \(indexValue) is too far beyond the length: \(listLength) to append a new item."
                                 )
                         )
                     }
@@ -63,7 +63,7 @@ public struct AutomergeEncoder {
         default:
             try value.encode(to: encoder)
         }
-        encoder.postencodeCleanup()
+        try encoder.postencodeCleanup()
     }
 
     /// Encodes a value you provide into a specific location within the encoder's Automerge document.
@@ -106,6 +106,6 @@ public struct AutomergeEncoder {
         default:
             try value.encode(to: encoder)
         }
-        encoder.postencodeCleanup(below: path.map { AnyCodingKey($0) })
+        try encoder.postencodeCleanup(below: path.map { AnyCodingKey($0) })
     }
 }
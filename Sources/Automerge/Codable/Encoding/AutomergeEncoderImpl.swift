@@ -44,7 +44,7 @@ final class AutomergeEncoderImpl {
         reportingLogLevel = logLevel
     }
 
-    func postencodeCleanup(below prefix: [AnyCodingKey] = []) {
+    func postencodeCleanup(below prefix: [AnyCodingKey] = []) throws {
         precondition(objectIdForContainer != nil)
         precondition(containerType != nil)
         guard let objectIdForContainer, let containerType else {
@@ -55,7 +55,7 @@ final class AutomergeEncoderImpl {
             case .Key:
                 // Remove keys that exist in this objectId that weren't
                 // written during encode. (clean up 'dead' keys from maps)
-                let extraAutomergeKeys = document.keys(obj: objectIdForContainer)
+                let extraAutomergeKeys = try document.keys(obj: objectIdForContainer)
                     .filter { keyValue in
                         !mapKeysWritten.contains(keyValue)
                     }
@@ -73,7 +73,7 @@ final class AutomergeEncoderImpl {
                     // with no items within it.
                     highestIndexWritten = Int64(highestUnkeyedIndexWritten)
                 }
-                let lengthOfAutomergeContainer = document.length(obj: objectIdForContainer)
+                let lengthOfAutomergeContainer = try document.length(obj: objectIdForContainer)
                 if lengthOfAutomergeContainer > 0 {
                     var highestAutomergeIndex = Int64(lengthOfAutomergeContainer - 1)
                     // Remove index elements that exist in this objectId beyond
@@ -96,7 +96,7 @@ final class AutomergeEncoderImpl {
         }
         // Recursively walk the encoded tree doing "cleanup".
         for child in childEncoders {
-            child.postencodeCleanup()
+            try child.postencodeCleanup()
         }
     }
 }
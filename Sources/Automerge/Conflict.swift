@@ -0,0 +1,30 @@
+import struct AutomergeUniffi.Conflict
+import Foundation
+
+typealias FfiConflict = AutomergeUniffi.Conflict
+
+/// One of the values written concurrently to a property, along with who wrote it.
+///
+/// Get the conflicting values of a property with ``Document/conflicts(obj:key:heads:)`` or
+/// ``Document/conflicts(obj:index:heads:)``, and keep one of them with ``Document/resolveConflict(obj:key:chosen:)``
+/// or ``Document/resolveConflict(obj:index:chosen:)``.
+public struct Conflict: Equatable, Hashable, Sendable {
+    /// The value that was written.
+    public let value: Value
+    /// The identifier of the operation that wrote the value.
+    public let opId: String
+    /// The actor that wrote the value.
+    public let actor: ActorId
+    /// The change that wrote the value, or `nil` if the change hasn't been committed yet.
+    public let change: ChangeHash?
+    /// Whether this is the value that reading the property returns.
+    public let isWinner: Bool
+
+    init(_ ffi: FfiConflict) {
+        value = Value.fromFfi(value: ffi.value)
+        opId = ffi.opId
+        actor = ActorId(ffi: ffi.actor)
+        change = ffi.change.map { ChangeHash(bytes: $0) }
+        isWinner = ffi.winner
+    }
+}
@@ -0,0 +1,32 @@
+import struct AutomergeUniffi.DiffSummary
+import Foundation
+
+typealias FfiDiffSummary = AutomergeUniffi.DiffSummary
+
+/// A summary of how one object changed between two points in the history of a document.
+///
+/// Get the summaries for an object, and optionally the objects inside it, with
+/// ``Document/differenceSummary(of:from:to:recursive:)``.
+public struct DiffSummary: Equatable {
+    /// The identifier of the object that changed.
+    public let obj: ObjId
+    /// The path to the object within the document.
+    public let path: [PathElement]
+    /// The dictionary keys that were set, deleted or incremented, in the order they first changed.
+    public let changedKeys: [String]
+    /// The number of array elements or text characters inserted.
+    public let inserted: UInt64
+    /// The number of array elements or text characters deleted.
+    public let removed: UInt64
+    /// The number of array elements overwritten or incremented, plus the number of marks changed.
+    public let updated: UInt64
+
+    init(_ ffi: FfiDiffSummary) {
+        obj = ObjId(bytes: ffi.obj)
+        path = ffi.path.map(PathElement.fromFfi)
+        changedKeys = ffi.changedKeys
+        inserted = ffi.inserted
+        removed = ffi.removed
+        updated = ffi.updated
+    }
+}
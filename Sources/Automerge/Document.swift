@@ -100,7 +100,18 @@ public final class Document: @unchecked Sendable {
         self.reportingLogLevel = logLevel
     }
 
-    private init(doc: Doc, logLevel: LogVerbosity = .errorOnly) {
+    /// Creates a new document from the data that you provide, with options that control how it's loaded.
+    ///
+    /// - Parameters:
+    ///   - bytes: A data buffer of encoded automerge changes.
+    ///   - options: The options for loading the data.
+    ///   - logLevel: The level at which to generate logs into unified logging from actions within this document.
+    public init(_ bytes: Data, options: LoadOptions, logLevel: LogVerbosity = .errorOnly) throws {
+        doc = try WrappedDoc { try Doc.loadWithOptions(bytes: Array(bytes), options: options.toFfi()) }
+        self.reportingLogLevel = logLevel
+    }
+
+    init(doc: Doc, logLevel: LogVerbosity = .errorOnly) {
         self.doc = WrappedDoc(doc)
         self.reportingLogLevel = logLevel
     }
@@ -224,6 +235,68 @@ public final class Document: @unchecked Sendable {
         }
     }
 
+    /// Set or update a value, and the contents of any objects inside it, within a dictionary object you specify.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the dictionary object to update.
+    ///   - key: The key of the property to update.
+    ///   - value: The value to set for the key you provide.
+    /// - Returns: The object Id that references the object added, or `nil` if the value is a scalar.
+    ///
+    /// The document creates every object in the value within the same change.
+    @discardableResult
+    public func putHydrated(obj: ObjId, key: String, value: HydrateValue) throws -> ObjId? {
+        try lock {
+            sendObjectWillChange()
+            defer { sendObjectDidChange() }
+            return try self.doc.wrapErrors {
+                try $0.putHydrated(obj: obj.bytes, prop: .key(value: key), value: value.toFfi())
+                    .map(ObjId.init(bytes:))
+            }
+        }
+    }
+
+    /// Set or update a value, and the contents of any objects inside it, within an array object you specify.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the array object to update.
+    ///   - index: The index value of the array to update.
+    ///   - value: The value to set for the index you provide.
+    /// - Returns: The object Id that references the object added, or `nil` if the value is a scalar.
+    ///
+    /// If the index position doesn't yet exist within the array, this method will throw an error.
+    /// To add a value that extends the array, use the method ``insertHydrated(obj:index:value:)``.
+    @discardableResult
+    public func putHydrated(obj: ObjId, index: UInt64, value: HydrateValue) throws -> ObjId? {
+        try lock {
+            sendObjectWillChange()
+            defer { sendObjectDidChange() }
+            return try self.doc.wrapErrors {
+                try $0.putHydrated(obj: obj.bytes, prop: .index(value: index), value: value.toFfi())
+                    .map(ObjId.init(bytes:))
+            }
+        }
+    }
+
+    /// Insert a value, and the contents of any objects inside it, at the index you provide, into the array object
+    /// you specify.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the array object to update.
+    ///   - index: The index value of the array to update.
+    ///   - value: The value to insert for the index you provide.
+    /// - Returns: The object Id that references the object added, or `nil` if the value is a scalar.
+    @discardableResult
+    public func insertHydrated(obj: ObjId, index: UInt64, value: HydrateValue) throws -> ObjId? {
+        try lock {
+            sendObjectWillChange()
+            defer { sendObjectDidChange() }
+            return try self.doc.wrapErrors {
+                try $0.insertHydrated(obj: obj.bytes, index: index, value: value.toFfi()).map(ObjId.init(bytes:))
+            }
+        }
+    }
+
     /// Deletes the key you provide, and its associated value or object, from the dictionary object you specify.
     /// - Parameters:
     ///   - obj: The identifier of the dictionary to update.
@@ -444,13 +517,131 @@ public final class Document: @unchecked Sendable {
         }
     }
 
+    /// Get every value written concurrently to the key you provide, in the dictionary object you specify, along with
+    /// who wrote each one.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the dictionary object.
+    ///   - key: The key within the dictionary.
+    ///   - heads: The set of ``ChangeHash`` that represents a point of time in the history the document, or `nil`
+    ///   for the current state.
+    /// - Returns: The conflicting values, with the winning value last, or an empty array if the key isn't set.
+    public func conflicts(obj: ObjId, key: String, heads: Set<ChangeHash>? = nil) throws -> [Conflict] {
+        try conflicts(obj: obj, prop: .Key(key), heads: heads)
+    }
+
+    /// Get every value written concurrently to the index you provide, in the array object you specify, along with
+    /// who wrote each one.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the array object.
+    ///   - index: The index within the array.
+    ///   - heads: The set of ``ChangeHash`` that represents a point of time in the history the document, or `nil`
+    ///   for the current state.
+    /// - Returns: The conflicting values, with the winning value last.
+    public func conflicts(obj: ObjId, index: UInt64, heads: Set<ChangeHash>? = nil) throws -> [Conflict] {
+        try conflicts(obj: obj, prop: .Index(index), heads: heads)
+    }
+
+    /// Resolve a conflict on the key you provide, in the dictionary object you specify, by writing the value you
+    /// choose again so that it replaces every other value.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the dictionary object.
+    ///   - key: The key within the dictionary.
+    ///   - chosen: The value to keep, from ``conflicts(obj:key:heads:)``.
+    /// - Returns: The object Id that references the chosen value if it's an object, otherwise `nil`.
+    ///
+    /// If the chosen value is an object, the document copies it along with everything it contains, and returns the
+    /// identifier of the copy. If the key has no conflict, the document doesn't change.
+    @discardableResult
+    public func resolveConflict(obj: ObjId, key: String, chosen: Conflict) throws -> ObjId? {
+        try resolveConflict(obj: obj, prop: .Key(key), chosen: chosen)
+    }
+
+    /// Resolve a conflict on the index you provide, in the array object you specify, by writing the value you
+    /// choose again so that it replaces every other value.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the array object.
+    ///   - index: The index within the array.
+    ///   - chosen: The value to keep, from ``conflicts(obj:index:heads:)``.
+    /// - Returns: The object Id that references the chosen value if it's an object, otherwise `nil`.
+    ///
+    /// If the chosen value is an object, the document copies it along with everything it contains, and returns the
+    /// identifier of the copy. If the index has no conflict, the document doesn't change.
+    @discardableResult
+    public func resolveConflict(obj: ObjId, index: UInt64, chosen: Conflict) throws -> ObjId? {
+        try resolveConflict(obj: obj, prop: .Index(index), chosen: chosen)
+    }
+
+    private func conflicts(obj: ObjId, prop: Prop, heads: Set<ChangeHash>?) throws -> [Conflict] {
+        try lock {
+            let conflicts = try self.doc.wrapErrors {
+                try $0.getConflicts(obj: obj.bytes, prop: prop.toFfi(), heads: heads?.map(\.bytes))
+            }
+            return conflicts.map(Conflict.init)
+        }
+    }
+
+    private func resolveConflict(obj: ObjId, prop: Prop, chosen: Conflict) throws -> ObjId? {
+        try lock {
+            sendObjectWillChange()
+            defer { sendObjectDidChange() }
+            return try self.doc.wrapErrors {
+                try $0.resolveConflict(obj: obj.bytes, prop: prop.toFfi(), chosenOpId: chosen.opId)
+                    .map(ObjId.init(bytes:))
+            }
+        }
+    }
+
+    /// Get the change that last set the key you provide, in the dictionary object you specify.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the dictionary object.
+    ///   - key: The key within the dictionary.
+    /// - Returns: The change, or `nil` if the key isn't set or the change that set it hasn't been committed yet.
+    ///
+    /// Edits are committed when you call ``commitWith(message:timestamp:)``, ``save()``, or ``heads()``.
+    public func blame(obj: ObjId, key: String) throws -> Attribution? {
+        try blame(obj: obj, prop: .Key(key))
+    }
+
+    /// Get the change that last set the index you provide, in the array object you specify.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the array object.
+    ///   - index: The index within the array.
+    /// - Returns: The change, or `nil` if the change that set the index hasn't been committed yet.
+    ///
+    /// Edits are committed when you call ``commitWith(message:timestamp:)``, ``save()``, or ``heads()``.
+    public func blame(obj: ObjId, index: UInt64) throws -> Attribution? {
+        try blame(obj: obj, prop: .Index(index))
+    }
+
+    /// Split the text object you specify into runs of text inserted by the same change.
+    ///
+    /// - Parameter obj: The identifier of the text object.
+    /// - Returns: The runs of text, in order, which together make up the whole text.
+    public func blameText(obj: ObjId) throws -> [TextSpan] {
+        try lock {
+            try self.doc.wrapErrors { try $0.blameText(obj: obj.bytes) }.map(TextSpan.init)
+        }
+    }
+
+    private func blame(obj: ObjId, prop: Prop) throws -> Attribution? {
+        try lock {
+            try self.doc.wrapErrors { try $0.blameProperty(obj: obj.bytes, prop: prop.toFfi()) }.map(Attribution.init)
+        }
+    }
+
     /// Get a list of all the current keys available for the dictionary object you specify.
     ///
     /// - Parameter obj: The identifier of the dictionary object.
     /// - Returns: The keys for that dictionary.
-    public func keys(obj: ObjId) -> [String] {
-        lock {
-            self.doc.wrapErrors { $0.mapKeys(obj: obj.bytes) }
+    public func keys(obj: ObjId) throws -> [String] {
+        try lock {
+            try self.doc.wrapErrors { try $0.mapKeys(obj: obj.bytes) }
         }
     }
 
@@ -462,9 +653,9 @@ public final class Document: @unchecked Sendable {
     /// - Returns: The set of keys for the dictionary at the point in time you specify.
     ///
     /// Use the method ``heads()`` to capture a specific point in time in order to use this method.
-    public func keysAt(obj: ObjId, heads: Set<ChangeHash>) -> [String] {
-        lock {
-            self.doc.wrapErrors { $0.mapKeysAt(obj: obj.bytes, heads: heads.map(\.bytes)) }
+    public func keysAt(obj: ObjId, heads: Set<ChangeHash>) throws -> [String] {
+        try lock {
+            try self.doc.wrapErrors { try $0.mapKeysAt(obj: obj.bytes, heads: heads.map(\.bytes)) }
         }
     }
 
@@ -527,12 +718,64 @@ public final class Document: @unchecked Sendable {
         }
     }
 
+    /// Get the values within a range of indices of the array object you specify.
+    ///
+    /// Use this method to read a page of a long array without reading all of its values.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the array object.
+    ///   - range: The indices of the values to get. Indices beyond the end of the array are ignored.
+    ///   - heads: The set of ``ChangeHash`` that represents a point of time in the history the document, or `nil`
+    ///   for the current state.
+    /// - Returns: The values in the range, in order.
+    public func listRange(obj: ObjId, range: Range<UInt64>, heads: Set<ChangeHash>? = nil) throws -> [Value] {
+        try lock {
+            let vals = try self.doc.wrapErrors {
+                try $0.listRange(
+                    obj: obj.bytes,
+                    start: range.lowerBound,
+                    end: range.upperBound,
+                    heads: heads?.map(\.bytes)
+                )
+            }
+            return vals.map { Value.fromFfi(value: $0) }
+        }
+    }
+
+    /// Get the keys and values within a range of keys of the dictionary object you specify.
+    ///
+    /// Use this method to read a page of a large dictionary without reading all of its entries.
+    /// To read the next page, pass the last key you read as `from` and skip the first entry.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the dictionary object.
+    ///   - from: The first key to include, or `nil` to start at the first key.
+    ///   - to: The key to stop before, or `nil` to continue to the last key.
+    ///   - limit: The maximum number of entries to return, or `nil` for no limit.
+    ///   - heads: The set of ``ChangeHash`` that represents a point of time in the history the document, or `nil`
+    ///   for the current state.
+    /// - Returns: An array of `(String, Value)` in key order.
+    public func mapRange(
+        obj: ObjId,
+        from: String? = nil,
+        to: String? = nil,
+        limit: UInt64? = nil,
+        heads: Set<ChangeHash>? = nil
+    ) throws -> [(String, Value)] {
+        try lock {
+            let entries = try self.doc.wrapErrors {
+                try $0.mapRange(obj: obj.bytes, fromKey: from, toKey: to, limit: limit, heads: heads?.map(\.bytes))
+            }
+            return entries.map { ($0.key, Value.fromFfi(value: $0.value)) }
+        }
+    }
+
     /// Returns the current length of the array, dictionary, or text object you specify.
     ///
     /// - Parameter obj: The identifier of an array, dictionary, or text object.
-    public func length(obj: ObjId) -> UInt64 {
-        lock {
-            self.doc.wrapErrors { $0.length(obj: obj.bytes) }
+    public func length(obj: ObjId) throws -> UInt64 {
+        try lock {
+            try self.doc.wrapErrors { try $0.length(obj: obj.bytes) }
         }
     }
 
@@ -541,19 +784,19 @@ public final class Document: @unchecked Sendable {
     /// - Parameters:
     ///   - obj: The identifier of an array, dictionary, or text object.
     ///   - heads: The set of ``ChangeHash`` that represents a point of time in the history the document.
-    public func lengthAt(obj: ObjId, heads: Set<ChangeHash>) -> UInt64 {
-        lock {
-            self.doc.wrapErrors { $0.lengthAt(obj: obj.bytes, heads: heads.map(\.bytes)) }
+    public func lengthAt(obj: ObjId, heads: Set<ChangeHash>) throws -> UInt64 {
+        try lock {
+            try self.doc.wrapErrors { try $0.lengthAt(obj: obj.bytes, heads: heads.map(\.bytes)) }
         }
     }
 
     /// Returns the object type for the object Id that you provide.
     ///
     /// - Parameter obj: The identifier of an array, dictionary, or text object.
-    public func objectType(obj: ObjId) -> ObjType {
-        lock {
-            self.doc.wrapErrors {
-                ObjType.fromFfi(ty: $0.objectType(obj: obj.bytes))
+    public func objectType(obj: ObjId) throws -> ObjType {
+        try lock {
+            try self.doc.wrapErrors {
+                try ObjType.fromFfi(ty: $0.objectType(obj: obj.bytes))
             }
         }
     }
@@ -580,6 +823,55 @@ public final class Document: @unchecked Sendable {
         }
     }
 
+    /// Get a JSON representation of the object you specify and everything it contains.
+    ///
+    /// Values that JSON can't represent directly, such as text objects and counters, are written as an object with
+    /// a single key that starts with `$`, for example `{"$text": "hello"}`.
+    /// Dictionary keys that start with `$` are escaped by doubling the `$`.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the object to represent.
+    ///   - heads: The set of ``ChangeHash`` that represents a point of time in the history the document, or `nil`
+    ///   for the current state.
+    /// - Returns: The JSON encoded string.
+    /// - Throws: An error if the object contains a floating point value that isn't finite.
+    public func toJSON(obj: ObjId, heads: Set<ChangeHash>? = nil) throws -> String {
+        try lock {
+            try self.doc.wrapErrors { try $0.toJson(obj: obj.bytes, heads: heads?.map(\.bytes)) }
+        }
+    }
+
+    /// Set the key you provide to the value of a JSON string, creating any nested objects it contains.
+    ///
+    /// The JSON uses the same representation as ``toJSON(obj:heads:)``.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the dictionary object to update.
+    ///   - key: The key of the property to update.
+    ///   - json: The JSON encoded value.
+    public func putJSON(obj: ObjId, key: String, json: String) throws {
+        try lock {
+            sendObjectWillChange()
+            defer { sendObjectDidChange() }
+            try self.doc.wrapErrors { try $0.putJson(obj: obj.bytes, key: key, json: json) }
+        }
+    }
+
+    /// Get the object you specify and everything it contains, with a single call.
+    ///
+    /// Unlike ``toJSON(obj:heads:)``, the result keeps the type of every value and the marks on text.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the object to read.
+    ///   - heads: The set of ``ChangeHash`` that represents a point of time in the history the document, or `nil`
+    ///   for the current state.
+    /// - Returns: The object and its contents.
+    public func hydrate(obj: ObjId, heads: Set<ChangeHash>? = nil) throws -> HydrateValue {
+        try lock {
+            try self.doc.wrapErrors { try HydrateValue($0.hydrate(obj: obj.bytes, heads: heads?.map(\.bytes))) }
+        }
+    }
+
     /// Establish a cursor at the position you specify in the list or text object you provide.
     ///
     /// Traditional index-based positions become outdated when you or collaborators modify the document.
@@ -998,6 +1290,77 @@ public final class Document: @unchecked Sendable {
         }
     }
 
+    /// Begins a transaction, which stages edits privately until you commit them.
+    ///
+    /// Edits made through the ``Transaction`` aren't visible in the document until you call
+    /// ``Transaction/commit(message:timestamp:)``, and ``Transaction/rollback()`` discards them.
+    /// The document can still be synced and merged while a transaction is open, but editing it directly makes the
+    /// commit throw.
+    ///
+    /// - Returns: A new transaction on this document.
+    public func beginTransaction() -> Transaction {
+        lock {
+            Transaction(document: self, ffi: self.doc.wrapErrors { $0.beginTransaction() })
+        }
+    }
+
+    /// Runs work which changes the document through another object, such as a ``Transaction``, while holding the
+    /// document's lock and sending change notifications.
+    func changing<T>(_ work: () throws -> T) rethrows -> T {
+        try lock {
+            sendObjectWillChange()
+            defer { sendObjectDidChange() }
+            return try work()
+        }
+    }
+
+    /// Runs work which reads the document through another object, such as a ``SyncSession``, while holding the
+    /// document's lock.
+    func reading<T>(_ work: () throws -> T) rethrows -> T {
+        try lock(execute: work)
+    }
+
+    /// Passes the underlying document to work that needs it, such as creating a ``SyncSession``.
+    func withFfiDoc<T>(_ work: (Doc) -> T) -> T {
+        lock {
+            self.doc.wrapErrors(f: work)
+        }
+    }
+
+    func withFfiDoc<T>(_ work: (Doc) throws -> T) throws -> T {
+        try lock {
+            try self.doc.wrapErrors(f: work)
+        }
+    }
+
+    /// Registers a closure that receives the patches for every subsequent change to the document.
+    ///
+    /// The closure receives patches for local edits as well as merges, sync messages, and applied changes.
+    /// It's called synchronously on the thread that changed the document, and may read from the document.
+    ///
+    /// - Parameters:
+    ///   - batchPerCommit: Whether to report local edits once the pending change is closed, for example by
+    ///   ``commitWith(message:timestamp:)``, ``save()``, or ``generateSyncMessage(state:)``.
+    ///   Reporting a local edit closes the pending change, so passing `false` turns every edit into a change of its
+    ///   own.
+    ///   - handler: The closure to call with the patches.
+    ///
+    /// Registering a handler replaces any existing one.
+    public func observePatches(batchPerCommit: Bool = true, _ handler: @escaping ([Patch]) -> Void) {
+        lock {
+            self.doc.wrapErrors {
+                $0.setPatchObserver(observer: PatchHandler(handler), batchPerCommit: batchPerCommit)
+            }
+        }
+    }
+
+    /// Removes the closure registered with ``observePatches(batchPerCommit:_:)``.
+    public func stopObservingPatches() {
+        lock {
+            self.doc.wrapErrors { $0.clearPatchObserver() }
+        }
+    }
+
     /// Encode the Automerge document in a compressed binary format.
     ///
     /// - Returns: The data that represents all the changes within this document.
@@ -1014,6 +1377,39 @@ public final class Document: @unchecked Sendable {
         }
     }
 
+    /// Encode the changes made since the document was loaded or last saved.
+    ///
+    /// - Returns: The data that represents the new changes, which is empty if there are none.
+    ///
+    /// Append the result to the data from a previous ``save()``, ``saveUncompressed()`` or `saveIncremental()`
+    /// to store a document without rewriting it, and load the concatenation with ``init(_:logLevel:)``.
+    /// Unlike ``encodeNewChanges()``, which tracks the changes it has already returned separately, this only
+    /// considers saves.
+    public func saveIncremental() -> Data {
+        lock {
+            sendObjectWillChange()
+            defer { sendObjectDidChange() }
+            return self.doc.wrapErrors {
+                Data($0.saveIncremental())
+            }
+        }
+    }
+
+    /// Encode the Automerge document without compressing it.
+    ///
+    /// - Returns: The data that represents all the changes within this document.
+    ///
+    /// This is faster than ``save()`` at the cost of larger output.
+    public func saveUncompressed() -> Data {
+        lock {
+            sendObjectWillChange()
+            defer { sendObjectDidChange() }
+            return self.doc.wrapErrors {
+                Data($0.saveNoCompress())
+            }
+        }
+    }
+
     /// Update the sync state you provide and return a sync message to send to a peer.
     ///
     /// - Parameter state: The instance of ``SyncState`` that represents the peer you're syncing with.
@@ -1092,6 +1488,86 @@ public final class Document: @unchecked Sendable {
         }
     }
 
+    /// Make the document, or the object you specify, look as it did at the point in time you specify.
+    ///
+    /// Unlike ``forkAt(heads:)``, the document keeps its history: the document writes the difference between its
+    /// current state and the earlier one as a single new change, which syncs like any other, and changes made
+    /// concurrently by others still merge.
+    /// Objects that have been deleted since that point in time are recreated with new identifiers.
+    ///
+    /// - Parameters:
+    ///   - heads: The set of ``ChangeHash`` that represents a point of time in the history the document.
+    ///   - obj: The identifier of the object to revert, along with the objects inside it, or `nil` to revert the
+    ///   whole document.
+    ///   - expand: How the marks that reverting puts back expand when text is inserted at their edges.
+    public func revert(to heads: Set<ChangeHash>, obj: ObjId? = nil, expand: ExpandMark = .after) throws {
+        try lock {
+            sendObjectWillChange()
+            defer { sendObjectDidChange() }
+            try self.doc.wrapErrors {
+                try $0.revertTo(heads: heads.map(\.bytes), obj: obj?.bytes, expand: expand.toFfi())
+            }
+        }
+    }
+
+    /// Point the branch you name at the point in time you specify, replacing any branch with the same name.
+    ///
+    /// - Parameters:
+    ///   - name: The name of the branch.
+    ///   - heads: The set of ``ChangeHash`` that represents a point of time in the history the document.
+    ///
+    /// Branches are stored in the document under the reserved root key `automerge-swift:branches`, so they're saved
+    /// and synchronised along with it. The document leaves that key out of its values and patches, and writing to it
+    /// throws an error. Creating a branch also throws an error if another peer has put a value other than the branches
+    /// under that key.
+    public func createBranch(_ name: String, heads: Set<ChangeHash>) throws {
+        try lock {
+            try self.doc.wrapErrors { try $0.createBranch(name: name, heads: heads.map(\.bytes)) }
+        }
+    }
+
+    /// Remove the branch you name.
+    ///
+    /// - Parameter name: The name of the branch.
+    public func deleteBranch(_ name: String) throws {
+        try lock {
+            try self.doc.wrapErrors { try $0.deleteBranch(name: name) }
+        }
+    }
+
+    /// Returns the branches of the document, sorted by name.
+    public func branches() -> [Branch] {
+        lock {
+            self.doc.wrapErrors { $0.listBranches().map { Branch($0) } }
+        }
+    }
+
+    /// Fork the document at the point in time the branch you name points at.
+    ///
+    /// - Parameter branch: The name of the branch.
+    /// - Returns: A copy of the document with a new actor ID that contains the changes up to the branch.
+    public func checkout(branch: String) throws -> Document {
+        try lock {
+            try self.doc.wrapErrors {
+                try Document(doc: $0.checkout(name: branch))
+            }
+        }
+    }
+
+    /// Merge a document, usually one returned by ``checkout(branch:)`` and then edited, and move the branch you
+    /// name to its heads.
+    ///
+    /// - Parameters:
+    ///   - name: The name of the branch.
+    ///   - other: The ``Document`` to merge.
+    public func mergeBranch(_ name: String, from other: Document) throws {
+        try lock {
+            sendObjectWillChange()
+            defer { sendObjectDidChange() }
+            try self.doc.wrapErrorsWithOther(other: other.doc) { try $0.mergeBranch(name: name, other: $1) }
+        }
+    }
+
     /// Merge this document with another.
     ///
     /// - Parameter other: another ``Document``
@@ -1121,6 +1597,48 @@ public final class Document: @unchecked Sendable {
         }
     }
 
+    /// Apply the changes you specify from another document, along with the changes they depend on, without merging
+    /// the rest of its history.
+    ///
+    /// - Parameters:
+    ///   - other: The ``Document`` to pick changes from.
+    ///   - changes: The changes to apply.
+    /// - Returns: A ``CherryPick`` that lists the changes you asked for and the dependencies that were applied.
+    ///
+    /// Picking a change the other document doesn't have throws an error, and leaves this document unchanged.
+    @discardableResult
+    public func cherryPick(from other: Document, changes: [ChangeHash]) throws -> CherryPick {
+        try lock {
+            sendObjectWillChange()
+            defer { sendObjectDidChange() }
+            let picked = try self.doc.wrapErrorsWithOther(other: other.doc) {
+                try $0.cherryPick(other: $1, hashes: changes.map(\.bytes))
+            }
+            return CherryPick(picked)
+        }
+    }
+
+    /// Apply the changes you specify from a collection of encoded changes, along with the changes they depend on.
+    ///
+    /// - Parameters:
+    ///   - encoded: Encoded changes to pick from.
+    ///   - changes: The changes to apply.
+    /// - Returns: A ``CherryPick`` that lists the changes you asked for and the dependencies that were applied.
+    ///
+    /// The encoded changes can be anything returned by ``save()``, ``encodeNewChanges()``,
+    /// ``encodeChangesSince(heads:)`` or any concatenation of those.
+    @discardableResult
+    public func cherryPick(encoded: Data, changes: [ChangeHash]) throws -> CherryPick {
+        try lock {
+            sendObjectWillChange()
+            defer { sendObjectDidChange() }
+            let picked = try self.doc.wrapErrors {
+                try $0.cherryPickEncoded(changes: Array(encoded), hashes: changes.map(\.bytes))
+            }
+            return CherryPick(picked)
+        }
+    }
+
     /// Returns a set of change hashes that represents the current state of the document.
     ///
     /// The number of change hashes in the returned set represents the number of concurrent changes the document tracks.
@@ -1157,10 +1675,21 @@ public final class Document: @unchecked Sendable {
         }
     }
 
+    /// Returns the changes that are in the document but not in the history up to the heads you provide.
+    ///
+    /// - Parameter heads: The set of ``ChangeHash`` that represents a point of time in the history the document.
+    /// Pass an empty set to get every change.
+    /// - Returns: The changes, each one after the changes it depends upon.
+    public func changes(since heads: Set<ChangeHash>) throws -> [Change] {
+        try lock {
+            try self.doc.wrapErrors { try $0.getChanges(sinceHeads: heads.map(\.bytes)).map(Change.init) }
+        }
+    }
+
     /// Returns the contents of the change associated with the change hash you provide.
-    public func change(hash: ChangeHash) -> Change? {
-        lock {
-            guard let change = self.doc.wrapErrors(f: { $0.changeByHash(hash: hash.bytes) }) else {
+    public func change(hash: ChangeHash) throws -> Change? {
+        try lock {
+            guard let change = try self.doc.wrapErrors(f: { try $0.changeByHash(hash: hash.bytes) }) else {
                 return nil
             }
             return .init(change)
@@ -1178,7 +1707,7 @@ public final class Document: @unchecked Sendable {
     /// try doc.spliceText(obj: textId, start: 0, delete: 0, value: "Hello")
     /// let after = doc.heads()
     ///
-    /// let patches = doc.difference(from: before, to: after)
+    /// let patches = try doc.difference(from: before, to: after)
     /// ```
     ///
     /// - Parameters:
@@ -1186,10 +1715,10 @@ public final class Document: @unchecked Sendable {
     ///   - after: The set of heads at ending point in the documents history.
     /// - Note: `from` and `to` do not have to be chronological. Document state can move backward.
     /// - Returns: The difference needed to produce a document at `to` when it is set at `from` in history.
-    public func difference(from before: Set<ChangeHash>, to after: Set<ChangeHash>) -> [Patch] {
-        lock {
-            let patches = self.doc.wrapErrors { doc in
-                doc.difference(before: before.map(\.bytes), after: after.map(\.bytes))
+    public func difference(from before: Set<ChangeHash>, to after: Set<ChangeHash>) throws -> [Patch] {
+        try lock {
+            let patches = try self.doc.wrapErrors { doc in
+                try doc.difference(before: before.map(\.bytes), after: after.map(\.bytes))
             }
             return patches.map { Patch($0) }
         }
@@ -1200,15 +1729,15 @@ public final class Document: @unchecked Sendable {
     /// Use:
     /// ```
     /// let doc = Document()
-    /// doc.difference(since: doc.heads())
+    /// try doc.difference(since: doc.heads())
     /// ```
     ///
     /// - Parameters:
     ///     - lhs: The set of heads at the point in the documents history to compare to.
     /// - Returns: The difference needed to produce current document given an arbitrary
     /// point in the history.
-    public func difference(since lhs: Set<ChangeHash>) -> [Patch] {
-        difference(from: lhs, to: heads())
+    public func difference(since lhs: Set<ChangeHash>) throws -> [Patch] {
+        try difference(from: lhs, to: heads())
     }
 
     /// Generates patches **to** a given point in the document history.
@@ -1216,15 +1745,76 @@ public final class Document: @unchecked Sendable {
     /// Use:
     /// ```
     /// let doc = Document()
-    /// doc.difference(to: doc.heads())
+    /// try doc.difference(to: doc.heads())
     /// ```
     ///
     /// - Parameters:
     ///     - rhs: The set of heads at ending point in the documents history.
     /// - Returns: The difference needed to move current document to a previous point
     /// in the history.
-    public func difference(to rhs: Set<ChangeHash>) -> [Patch] {
-        difference(from: heads(), to: rhs)
+    public func difference(to rhs: Set<ChangeHash>) throws -> [Patch] {
+        try difference(from: heads(), to: rhs)
+    }
+
+    /// Generates the patches to the object you specify between two points in the document history.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the object.
+    ///   - before: The set of heads at beginning point in the documents history.
+    ///   - after: The set of heads at ending point in the documents history.
+    ///   - recursive: Whether to include the patches to the objects inside `obj`.
+    /// - Returns: The patches to the object, in the order ``difference(from:to:)`` returns them.
+    ///
+    /// This method costs as much as ``difference(from:to:)``, because the document diffs every object and then
+    /// filters the patches. The document caches the last diff it made, so getting the patches to several objects
+    /// for the same heads only diffs the document once.
+    public func difference(
+        in obj: ObjId,
+        from before: Set<ChangeHash>,
+        to after: Set<ChangeHash>,
+        recursive: Bool = true
+    ) throws -> [Patch] {
+        try lock {
+            let patches = try self.doc.wrapErrors {
+                try $0.differenceIn(
+                    obj: obj.bytes,
+                    before: before.map(\.bytes),
+                    after: after.map(\.bytes),
+                    recursive: recursive
+                )
+            }
+            return patches.map { Patch($0) }
+        }
+    }
+
+    /// Summarizes how the object you specify changed between two points in the document history.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the object.
+    ///   - before: The set of heads at beginning point in the documents history.
+    ///   - after: The set of heads at ending point in the documents history.
+    ///   - recursive: Whether to include the objects inside `obj`.
+    /// - Returns: A summary for each object that changed, in the order the objects first changed.
+    ///
+    /// This method diffs the document in the same way as ``difference(in:from:to:recursive:)``, but avoids
+    /// returning every patch.
+    public func differenceSummary(
+        of obj: ObjId,
+        from before: Set<ChangeHash>,
+        to after: Set<ChangeHash>,
+        recursive: Bool = true
+    ) throws -> [DiffSummary] {
+        try lock {
+            let summaries = try self.doc.wrapErrors {
+                try $0.differenceSummary(
+                    obj: obj.bytes,
+                    before: before.map(\.bytes),
+                    after: after.map(\.bytes),
+                    recursive: recursive
+                )
+            }
+            return summaries.map(DiffSummary.init)
+        }
     }
 
     /// Get the path to an object within the document.
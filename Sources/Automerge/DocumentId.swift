@@ -0,0 +1,41 @@
+import func AutomergeUniffi.automergeUrl
+import func AutomergeUniffi.generateDocumentId
+import Foundation
+
+/// The identifier of a document in a ``Repo``.
+///
+/// Document identifiers use the automerge-repo format, so you can share them with JavaScript peers.
+public struct DocumentId: Hashable, Sendable, CustomStringConvertible {
+    let id: String
+
+    init(id: String) {
+        self.id = id
+    }
+
+    /// Creates a new, random document identifier.
+    public init() {
+        id = generateDocumentId()
+    }
+
+    /// Creates a document identifier from its string form or from an `automerge:` URL.
+    ///
+    /// - Parameter string: The identifier or URL.
+    /// Returns `nil` if the string isn't a valid document identifier.
+    public init?(_ string: String) {
+        guard let url = try? automergeUrl(documentId: string) else {
+            return nil
+        }
+        id = String(url.dropFirst(Self.urlPrefix.count))
+    }
+
+    /// The `automerge:` URL of the document.
+    public var url: String {
+        Self.urlPrefix + id
+    }
+
+    public var description: String {
+        id
+    }
+
+    private static let urlPrefix = "automerge:"
+}
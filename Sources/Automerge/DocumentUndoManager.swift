@@ -0,0 +1,92 @@
+import class AutomergeUniffi.UndoManager
+import Foundation
+
+typealias FfiUndoManager = AutomergeUniffi.UndoManager
+
+/// Undo and redo for the edits you make to a document.
+///
+/// The undo manager records the edits made with the document's actor since it last recorded, as one undo step.
+/// It records when you call ``record()``, and before it checks, undoes, or redoes a step, so call ``record()``
+/// wherever one undo step should end, for example after each edit the person using your app makes.
+/// To make several edits a single step, make them between ``beginGroup()`` and ``endGroup()``.
+///
+/// Edits from other actors, such as those merged from collaborators, are never undone, so undoing only reverts
+/// your own edits. Undoing and redoing write the inverse edits as a new change, which syncs like any other.
+///
+/// ```swift
+/// let undoManager = try DocumentUndoManager(document: doc)
+/// try doc.put(obj: ObjId.ROOT, key: "title", value: .String("Notes"))
+/// undoManager.record()
+/// try undoManager.undo()
+/// ```
+public final class DocumentUndoManager: @unchecked Sendable {
+    /// The document whose edits this undo manager records.
+    public let document: Document
+    private let ffi: FfiUndoManager
+
+    /// Creates an undo manager with no steps.
+    ///
+    /// - Parameters:
+    ///   - document: The document whose edits to undo.
+    ///   - scope: The object whose edits to undo, along with the objects inside it, or `nil` to undo edits
+    ///   anywhere in the document.
+    ///   - expand: How the marks that undoing and redoing put back expand when text is inserted at their edges.
+    /// - Throws: An error if `scope` isn't an object in the document.
+    public init(document: Document, scope: ObjId? = nil, expand: ExpandMark = .after) throws {
+        self.document = document
+        ffi = try document.withFfiDoc {
+            try FfiUndoManager(doc: $0, scope: scope?.bytes, expand: expand.toFfi())
+        }
+    }
+
+    /// Records the edits made since the last time the undo manager recorded as one undo step, or adds them to the
+    /// current group.
+    public func record() {
+        document.reading { ffi.record() }
+    }
+
+    /// Starts grouping edits into a single undo step.
+    ///
+    /// Groups may be nested, in which case the outermost group makes the step.
+    public func beginGroup() {
+        document.reading { ffi.beginGroup() }
+    }
+
+    /// Ends the group started by the matching call to ``beginGroup()``.
+    public func endGroup() {
+        document.reading { ffi.endGroup() }
+    }
+
+    /// A Boolean value that indicates whether there's a step to undo.
+    public var canUndo: Bool {
+        document.reading { ffi.canUndo() }
+    }
+
+    /// A Boolean value that indicates whether there's a step to redo.
+    public var canRedo: Bool {
+        document.reading { ffi.canRedo() }
+    }
+
+    /// Reverts the most recent undo step, ending any open group first.
+    ///
+    /// - Returns: `false` if there was nothing to undo.
+    @discardableResult
+    public func undo() throws -> Bool {
+        try document.changing { try wrappedErrors { try ffi.undo() } }
+    }
+
+    /// Reapplies the most recently undone step.
+    ///
+    /// Recording new edits discards the steps that could be redone.
+    ///
+    /// - Returns: `false` if there was nothing to redo.
+    @discardableResult
+    public func redo() throws -> Bool {
+        try document.changing { try wrappedErrors { try ffi.redo() } }
+    }
+
+    /// Forgets every undo and redo step.
+    public func clear() {
+        document.reading { ffi.clear() }
+    }
+}
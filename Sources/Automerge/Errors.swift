@@ -1,13 +1,23 @@
+import enum AutomergeUniffi.DecodeChangeError
+import enum AutomergeUniffi.DecodePresenceError
+import enum AutomergeUniffi.DecodeSyncMessageError
 import enum AutomergeUniffi.DecodeSyncStateError
 import enum AutomergeUniffi.DocError
 import enum AutomergeUniffi.LoadError
 import enum AutomergeUniffi.ReceiveSyncError
+import enum AutomergeUniffi.RepoError
+import enum AutomergeUniffi.SyncSessionError
 import Foundation
 
 typealias FfiDocError = AutomergeUniffi.DocError
+typealias FfiDecodeChangeError = AutomergeUniffi.DecodeChangeError
+typealias FfiDecodePresenceError = AutomergeUniffi.DecodePresenceError
+typealias FfiDecodeSyncMessageError = AutomergeUniffi.DecodeSyncMessageError
 typealias FfiDecodeSyncStateError = AutomergeUniffi.DecodeSyncStateError
 typealias FfiLoadError = AutomergeUniffi.LoadError
 typealias FfiReceiveSyncError = AutomergeUniffi.ReceiveSyncError
+typealias FfiRepoError = AutomergeUniffi.RepoError
+typealias FfiSyncSessionError = AutomergeUniffi.SyncSessionError
 
 // This file contains a bunch of wrappers for the errors generated by
 // UniFFI. The reasoning is that these errors are quite specific to the
@@ -47,6 +57,44 @@ public struct DecodeSyncStateError: LocalizedError {
     }
 }
 
+/// An error that indicates a change could not be decoded.
+///
+/// The error is specific to the Rust language binding infrastructure.
+public struct DecodeChangeError: LocalizedError {
+    let inner: FfiDecodeChangeError
+
+    init(_ inner: FfiDecodeChangeError) {
+        self.inner = inner
+    }
+
+    public var errorDescription: String? {
+        switch inner {
+        case let .Internal(message: msg):
+            return "Error decoding change: \(msg)"
+        }
+    }
+}
+
+/// An error that indicates a sync message could not be decoded.
+///
+/// The error is specific to the Rust language binding infrastructure.
+public struct DecodeSyncMessageError: LocalizedError {
+    let inner: FfiDecodeSyncMessageError
+
+    init(_ inner: FfiDecodeSyncMessageError) {
+        self.inner = inner
+    }
+
+    public var errorDescription: String? {
+        switch inner {
+        case let .InvalidMessage(message: msg):
+            return "Error decoding sync message: \(msg)"
+        case let .InvalidChanges(message: msg):
+            return "Error decoding the changes in a sync message: \(msg)"
+        }
+    }
+}
+
 /// An error that indicates a problem loading the document.
 ///
 /// The error is specific to the Rust language binding infrastructure.
@@ -59,6 +107,12 @@ public struct LoadError: LocalizedError {
 
     public var errorDescription: String? {
         switch inner {
+        case let .CorruptData(message: msg):
+            return "Error loading a document, the data is corrupt: \(msg)"
+        case let .MissingDependencies(hashes: hashes):
+            return "Error loading a document, \(hashes.count) changes depend on changes which are missing"
+        case let .DuplicateSeqNumber(actor: actor, seq: seq):
+            return "Error loading a document, actor \(actor) has two different changes with sequence number \(seq)"
         case let .Internal(message: msg):
             return "Error loading a document: \(msg)"
         }
@@ -77,10 +131,88 @@ public struct ReceiveSyncError: LocalizedError {
 
     public var errorDescription: String? {
         switch inner {
+        case let .MissingDependencies(hashes: hashes):
+            return "ReceiveSyncError: \(hashes.count) changes depend on changes which are missing"
         case let .Internal(message: msg):
             return "ReceiveSyncError: \(msg)"
         case let .InvalidMessage(message: message):
             return "Invalid message: \(message)"
+        case let .DuplicateSeqNumber(actor: actor, seq: seq):
+            return "ReceiveSyncError: actor \(actor) has two different changes with sequence number \(seq)"
+        }
+    }
+}
+
+/// An error that indicates a problem syncing a document with one of the peers of a ``SyncSession``.
+///
+/// The error is specific to the Rust language binding infrastructure.
+public struct SyncSessionError: LocalizedError {
+    let inner: FfiSyncSessionError
+
+    init(_ inner: FfiSyncSessionError) {
+        self.inner = inner
+    }
+
+    public var errorDescription: String? {
+        switch inner {
+        case let .UnknownPeer(peer: peer):
+            return "SyncSessionError: no peer named \(peer)"
+        case let .InvalidMessage(message: message):
+            return "SyncSessionError: invalid message: \(message)"
+        case let .MissingDependencies(hashes: hashes):
+            return "SyncSessionError: \(hashes.count) changes depend on changes which are missing"
+        case let .InvalidPeerStates(message: message):
+            return "SyncSessionError: invalid peer states: \(message)"
+        case let .Internal(message: message):
+            return "SyncSessionError: \(message)"
+        }
+    }
+}
+
+/// An error that indicates a repo couldn't load, save, or sync a document, or received an invalid message.
+///
+/// The error is specific to the Rust language binding infrastructure.
+public struct RepoError: LocalizedError {
+    let inner: FfiRepoError
+
+    init(_ inner: FfiRepoError) {
+        self.inner = inner
+    }
+
+    public var errorDescription: String? {
+        switch inner {
+        case let .InvalidDocumentId(message: message):
+            return "RepoError: invalid document id: \(message)"
+        case let .DocumentNotFound(documentId: documentId):
+            return "RepoError: no document with id \(documentId)"
+        case let .CorruptData(message: message):
+            return "RepoError: corrupt data: \(message)"
+        case let .MissingDependencies(hashes: hashes):
+            return "RepoError: \(hashes.count) changes depend on changes which are missing"
+        case let .InvalidMessage(message: message):
+            return "RepoError: invalid message: \(message)"
+        case let .PeerError(message: message):
+            return "RepoError: peer reported an error: \(message)"
+        case let .Internal(message: message):
+            return "RepoError: \(message)"
+        }
+    }
+}
+
+/// An error that indicates a presence update couldn't be decoded.
+///
+/// The error is specific to the Rust language binding infrastructure.
+public struct DecodePresenceError: LocalizedError {
+    let inner: FfiDecodePresenceError
+
+    init(_ inner: FfiDecodePresenceError) {
+        self.inner = inner
+    }
+
+    public var errorDescription: String? {
+        switch inner {
+        case let .InvalidMessage(message: message):
+            return "DecodePresenceError: invalid message: \(message)"
         }
     }
 }
@@ -96,5 +228,15 @@ func wrappedErrors<T>(_ f: () throws -> T) throws -> T {
         throw ReceiveSyncError(error)
     } catch let error as FfiDecodeSyncStateError {
         throw DecodeSyncStateError(error)
+    } catch let error as FfiDecodeChangeError {
+        throw DecodeChangeError(error)
+    } catch let error as FfiDecodeSyncMessageError {
+        throw DecodeSyncMessageError(error)
+    } catch let error as FfiSyncSessionError {
+        throw SyncSessionError(error)
+    } catch let error as FfiRepoError {
+        throw RepoError(error)
+    } catch let error as FfiDecodePresenceError {
+        throw DecodePresenceError(error)
     }
 }
@@ -0,0 +1,56 @@
+import enum AutomergeUniffi.HydrateValue
+
+typealias FfiHydrateValue = AutomergeUniffi.HydrateValue
+
+/// A value together with the contents of any objects inside it.
+///
+/// Use a hydrated value to create a populated object with a single call, rather than creating the object and then
+/// setting each of its properties.
+/// Read an object and everything it contains with ``Document/hydrate(obj:heads:)``.
+///
+/// ```swift
+/// let contact = try doc.putHydrated(obj: ObjId.ROOT, key: "contact", value: .Map([
+///     "name": .Scalar(.String("Alice")),
+///     "tags": .List([.Scalar(.String("friend"))]),
+///     "notes": .Text("Met at the conference", marks: []),
+/// ]))
+/// ```
+public indirect enum HydrateValue: Equatable, Sendable {
+    /// A dictionary object and its properties.
+    case Map([String: HydrateValue])
+    /// A list object and its elements.
+    case List([HydrateValue])
+    /// A text object, its content, and the marks on that content.
+    ///
+    /// `expand` sets how the marks expand when text is inserted at their edges. The document doesn't store it, so
+    /// text you read with ``Document/hydrate(obj:heads:)`` always has ``ExpandMark/after``.
+    case Text(String, marks: [Mark], expand: ExpandMark = .after)
+    /// A primitive value.
+    case Scalar(ScalarValue)
+
+    init(_ ffi: FfiHydrateValue) {
+        switch ffi {
+        case let .map(value):
+            self = .Map(value.mapValues(HydrateValue.init))
+        case let .list(value):
+            self = .List(value.map(HydrateValue.init))
+        case let .text(value, marks, expand):
+            self = .Text(value, marks: marks.map(Mark.fromFfi), expand: ExpandMark.fromFfi(expand))
+        case let .scalar(value):
+            self = .Scalar(ScalarValue.fromFfi(value: value))
+        }
+    }
+
+    func toFfi() -> FfiHydrateValue {
+        switch self {
+        case let .Map(value):
+            return .map(value: value.mapValues { $0.toFfi() })
+        case let .List(value):
+            return .list(value: value.map { $0.toFfi() })
+        case let .Text(value, marks, expand):
+            return .text(value: value, marks: marks.map { $0.toFfi() }, expand: expand.toFfi())
+        case let .Scalar(value):
+            return .scalar(value: value.toFfi())
+        }
+    }
+}
@@ -0,0 +1,83 @@
+import enum AutomergeUniffi.OnPartialLoad
+import enum AutomergeUniffi.StringMigration
+import enum AutomergeUniffi.VerificationMode
+import struct AutomergeUniffi.LoadOptions
+import Foundation
+
+/// Options that control how a document is loaded from data.
+///
+/// Use load options with ``Document/init(_:options:logLevel:)`` to load data that may be incomplete, skip
+/// verification of data you trust, or set the actor and text encoding of the loaded document.
+public struct LoadOptions: Equatable {
+    /// What to do when the data contains changes whose dependencies are missing.
+    public enum OnPartialLoad: Equatable {
+        /// Load the changes that can be applied, and hold back the rest until their dependencies arrive.
+        case ignore
+        /// Throw an error.
+        case error
+    }
+
+    /// Whether to verify the data when loading it.
+    public enum VerificationMode: Equatable {
+        /// Check that the heads in the data match the changes it contains.
+        case check
+        /// Skip the check, which is faster for data you've saved yourself.
+        case dontCheck
+    }
+
+    /// How to treat string values written by older versions of Automerge, which stored text as strings.
+    public enum StringMigration: Equatable {
+        /// Leave string values as they are.
+        case noMigration
+        /// Convert string values into text objects.
+        case convertToText
+    }
+
+    /// What to do when the data contains changes whose dependencies are missing.
+    public var onPartialLoad: OnPartialLoad
+    /// Whether to verify the data when loading it.
+    public var verificationMode: VerificationMode
+    /// How to treat string values written by older versions of Automerge.
+    public var stringMigration: StringMigration
+    /// The text encoding of the loaded document.
+    public var textEncoding: TextEncoding
+    /// The actor of the loaded document, or `nil` to use a new random actor.
+    public var actor: ActorId?
+
+    /// Creates a set of load options.
+    ///
+    /// - Parameters:
+    ///   - onPartialLoad: What to do when the data contains changes whose dependencies are missing.
+    ///   - verificationMode: Whether to verify the data when loading it.
+    ///   - stringMigration: How to treat string values written by older versions of Automerge.
+    ///   - textEncoding: The text encoding of the loaded document.
+    ///   - actor: The actor of the loaded document, or `nil` to use a new random actor.
+    public init(
+        onPartialLoad: OnPartialLoad = .error,
+        verificationMode: VerificationMode = .check,
+        stringMigration: StringMigration = .noMigration,
+        textEncoding: TextEncoding = .unicodeScalar,
+        actor: ActorId? = nil
+    ) {
+        self.onPartialLoad = onPartialLoad
+        self.verificationMode = verificationMode
+        self.stringMigration = stringMigration
+        self.textEncoding = textEncoding
+        self.actor = actor
+    }
+}
+
+// MARK: - Adapters
+typealias FfiLoadOptions = AutomergeUniffi.LoadOptions
+
+extension LoadOptions {
+    func toFfi() -> FfiLoadOptions {
+        FfiLoadOptions(
+            onPartialLoad: onPartialLoad == .ignore ? .ignore : .error,
+            verificationMode: verificationMode == .check ? .check : .dontCheck,
+            stringMigration: stringMigration == .noMigration ? .noMigration : .convertToText,
+            textEncoding: textEncoding.ffi_textEncoding,
+            actor: actor.map { [UInt8]($0.data) }
+        )
+    }
+}
@@ -68,6 +68,10 @@ public struct Mark: Equatable, Hashable, Sendable {
             value: ScalarValue.fromFfi(value: ffiMark.value)
         )
     }
+
+    func toFfi() -> FfiMark {
+        FfiMark(start: start, end: end, name: name, value: value.toFfi())
+    }
 }
 
 /// A type that indicates how a mark should expand when adding characters at the ends of the mark.
@@ -1,8 +1,10 @@
 import struct AutomergeUniffi.Patch
 import enum AutomergeUniffi.PatchAction
+import protocol AutomergeUniffi.PatchObserver
 
 typealias FfiPatchAction = AutomergeUniffi.PatchAction
 typealias FfiPatch = AutomergeUniffi.Patch
+typealias FfiPatchObserver = AutomergeUniffi.PatchObserver
 
 /// A collection of updates applied to an Automerge document.
 ///
@@ -117,3 +119,16 @@ public struct DeleteSeq: Equatable {
         self.length = length
     }
 }
+
+/// Forwards the patches reported by the Rust document to a closure.
+final class PatchHandler: FfiPatchObserver {
+    private let handler: ([Patch]) -> Void
+
+    init(_ handler: @escaping ([Patch]) -> Void) {
+        self.handler = handler
+    }
+
+    func onPatches(patches: [FfiPatch]) {
+        handler(patches.map(Patch.init))
+    }
+}
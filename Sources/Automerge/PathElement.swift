@@ -48,4 +48,13 @@ public enum Prop: Equatable, Sendable {
             return .Key(value)
         }
     }
+
+    func toFfi() -> FfiProp {
+        switch self {
+        case let .Index(value):
+            return .index(value: value)
+        case let .Key(value):
+            return .key(value: value)
+        }
+    }
 }
@@ -0,0 +1,139 @@
+import class AutomergeUniffi.Presence
+import enum AutomergeUniffi.PresenceValue
+import struct AutomergeUniffi.PresenceState
+import Foundation
+
+typealias FfiPresence = AutomergeUniffi.Presence
+typealias FfiPresenceValue = AutomergeUniffi.PresenceValue
+typealias FfiPresenceState = AutomergeUniffi.PresenceState
+
+/// A value that a peer shares as part of its presence.
+public indirect enum PresenceValue: Equatable, Sendable {
+    /// A primitive value.
+    case Scalar(ScalarValue)
+    /// A location within a text or list object, such as the position of a caret.
+    case Cursor(Cursor)
+    /// A dictionary of values, such as the anchor and head of a selection.
+    case Map([String: PresenceValue])
+
+    init(_ ffi: FfiPresenceValue) {
+        switch ffi {
+        case let .scalar(value):
+            self = .Scalar(ScalarValue.fromFfi(value: value))
+        case let .cursor(value):
+            self = .Cursor(Automerge.Cursor(bytes: value))
+        case let .map(value):
+            self = .Map(value.mapValues(PresenceValue.init))
+        }
+    }
+
+    func toFfi() -> FfiPresenceValue {
+        switch self {
+        case let .Scalar(value):
+            return .scalar(value: value.toFfi())
+        case let .Cursor(value):
+            return .cursor(value: value.bytes)
+        case let .Map(value):
+            return .map(value: value.mapValues { $0.toFfi() })
+        }
+    }
+}
+
+/// The latest presence of a peer.
+public struct PresenceState: Equatable, Sendable {
+    /// The actor of the peer.
+    public let actor: ActorId
+    /// When the peer sent its presence.
+    public let timestamp: Date
+    /// When the presence stops being valid, unless the peer sends a new one.
+    public let expiresAt: Date
+    /// The values the peer shares.
+    public let payload: [String: PresenceValue]
+
+    init(_ ffi: FfiPresenceState) {
+        actor = ActorId(ffi: ffi.actor)
+        timestamp = Date(milliseconds: ffi.timestamp)
+        expiresAt = Date(milliseconds: ffi.expiresAt)
+        payload = ffi.payload.mapValues(PresenceValue.init)
+    }
+}
+
+/// The presence of the peers editing a document, such as their cursors and selections.
+///
+/// Presence is never saved in the document.
+/// Encode your own presence with ``encodeUpdate(_:at:)``, send it to your peers with ``Repo/broadcast(_:for:)``,
+/// and pass the ephemeral messages you receive to ``receive(_:)``.
+/// Each update replaces the previous one from the same actor, and expires after the time to live you provide,
+/// so send updates more often than that.
+///
+/// ```swift
+/// let presence = Presence(actor: doc.actor, ttl: 30)
+/// let update = try presence.encodeUpdate(["caret": .Cursor(caret)])
+/// try repo.broadcast(update, for: id)
+/// ```
+public final class Presence: @unchecked Sendable {
+    private let ffi: FfiPresence
+
+    /// Creates a presence for an actor.
+    ///
+    /// - Parameters:
+    ///   - actor: The actor whose presence you share.
+    ///   - ttl: The number of seconds each update stays valid.
+    public init(actor: ActorId, ttl: TimeInterval) {
+        ffi = FfiPresence(actor: Array(actor.data), ttl: Int64(ttl * 1000))
+    }
+
+    /// Encodes an update that shares the values you provide.
+    ///
+    /// - Parameters:
+    ///   - payload: The values to share.
+    ///   - date: When the update is sent, defaulting to now.
+    /// - Returns: The encoded update.
+    public func encodeUpdate(_ payload: [String: PresenceValue], at date: Date = Date()) throws -> Data {
+        try wrappedErrors {
+            try Data(ffi.encodeUpdate(payload: payload.mapValues { $0.toFfi() }, timestamp: date.milliseconds))
+        }
+    }
+
+    /// Encodes an update that removes your presence from your peers.
+    ///
+    /// - Parameter date: When the update is sent, defaulting to now.
+    public func encodeLeave(at date: Date = Date()) -> Data {
+        Data(ffi.encodeLeave(timestamp: date.milliseconds))
+    }
+
+    /// Records an update from a peer.
+    ///
+    /// Updates that are older than the latest one from the same actor, and your own updates, are ignored.
+    ///
+    /// - Parameter message: The encoded update.
+    public func receive(_ message: Data) throws {
+        try wrappedErrors { try ffi.receive(message: Array(message)) }
+    }
+
+    /// Returns the presence of every peer whose latest update hasn't expired, oldest first.
+    ///
+    /// - Parameter date: The time to check expiry against, defaulting to now.
+    public func states(at date: Date = Date()) -> [PresenceState] {
+        ffi.states(now: date.milliseconds).map(PresenceState.init)
+    }
+
+    /// Forgets every peer whose latest update has expired.
+    ///
+    /// - Parameter date: The time to check expiry against, defaulting to now.
+    /// - Returns: The actors of the peers that were removed.
+    @discardableResult
+    public func prune(at date: Date = Date()) -> [ActorId] {
+        ffi.prune(now: date.milliseconds).map { ActorId(ffi: $0) }
+    }
+}
+
+private extension Date {
+    init(milliseconds: Int64) {
+        self.init(timeIntervalSince1970: TimeInterval(milliseconds) / 1000)
+    }
+
+    var milliseconds: Int64 {
+        Int64(timeIntervalSince1970 * 1000)
+    }
+}
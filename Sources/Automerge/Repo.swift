@@ -0,0 +1,357 @@
+import class AutomergeUniffi.Repo
+import enum AutomergeUniffi.DocumentSyncPhase
+import enum AutomergeUniffi.RepoMessage
+import struct AutomergeUniffi.EphemeralMessage
+import struct AutomergeUniffi.PeerMetadata
+import func AutomergeUniffi.decodeRepoMessage
+import protocol AutomergeUniffi.NetworkAdapter
+import protocol AutomergeUniffi.StorageAdapter
+import Foundation
+
+typealias FfiRepo = AutomergeUniffi.Repo
+typealias FfiDocumentSyncPhase = AutomergeUniffi.DocumentSyncPhase
+typealias FfiPeerMetadata = AutomergeUniffi.PeerMetadata
+typealias FfiRepoMessage = AutomergeUniffi.RepoMessage
+typealias FfiEphemeralMessage = AutomergeUniffi.EphemeralMessage
+typealias FfiNetworkAdapter = AutomergeUniffi.NetworkAdapter
+typealias FfiStorageAdapter = AutomergeUniffi.StorageAdapter
+
+/// Persists the documents of a ``Repo``.
+///
+/// Keys are arrays of strings in the automerge-repo storage layout, starting with the document identifier,
+/// so the same store can be read by a JavaScript repo.
+public protocol StorageAdapter: AnyObject {
+    /// Returns the data saved for a key, or `nil` if there is none.
+    func load(key: [String]) -> Data?
+    /// Saves data for a key, replacing any existing data.
+    func save(key: [String], data: Data)
+    /// Removes the data for a key.
+    func remove(key: [String])
+    /// Returns every key that starts with the prefix you provide.
+    func list(prefix: [String]) -> [[String]]
+}
+
+/// Delivers the messages of a ``Repo`` to its peers.
+public protocol NetworkAdapter: AnyObject {
+    /// Sends an encoded automerge-repo message to a peer.
+    ///
+    /// - Parameters:
+    ///   - message: The message to send.
+    ///   - peer: The identifier of the peer to send it to.
+    func send(_ message: Data, to peer: String)
+}
+
+/// Where a ``Repo`` is in syncing a document.
+public enum DocumentSyncPhase: Equatable, Sendable {
+    /// The repo doesn't have the document and has asked its peers for it.
+    case requesting
+    /// The repo has the document and syncs it with its peers.
+    case syncing
+    /// None of the peers the repo asked has the document.
+    case unavailable
+
+    init(_ ffi: FfiDocumentSyncPhase) {
+        switch ffi {
+        case .requesting:
+            self = .requesting
+        case .syncing:
+            self = .syncing
+        case .unavailable:
+            self = .unavailable
+        }
+    }
+}
+
+/// A message that a peer broadcast about a document, such as a presence update, which isn't part of the document.
+public struct EphemeralMessage: Equatable, Sendable {
+    /// The identifier of the peer that broadcast the message.
+    public let sender: String
+    /// The identifier of the document the message is about.
+    public let documentId: DocumentId
+    /// The data the peer broadcast.
+    public let data: Data
+
+    init(_ ffi: FfiEphemeralMessage) {
+        sender = ffi.senderId
+        documentId = DocumentId(id: ffi.documentId)
+        data = Data(ffi.data)
+    }
+}
+
+/// A collection of documents, identified by ``DocumentId``, that are saved through a ``StorageAdapter`` and synced
+/// with every connected peer through a ``NetworkAdapter``.
+///
+/// A repo exchanges automerge-repo messages with its peers, so it can sync with a JavaScript automerge-repo sync
+/// server. When your network adapter connects to a server, send it the message from ``join()``; the server answers
+/// and the repo adds it as a peer. Send the messages the repo passes to its network adapter, and pass each message you
+/// receive to ``receiveMessage(_:)``.
+///
+/// Peers can also exchange data that isn't saved in a document, such as the ``Presence`` of the people editing it.
+/// Send it with ``broadcast(_:for:)``; ``receiveMessage(_:)`` returns the data that peers broadcast.
+///
+/// ```swift
+/// let repo = Repo(peerId: "alice", storage: storage)
+/// repo.setNetworkAdapter(network)
+/// try await connection.send(repo.join())
+/// let id = repo.create()
+/// let doc = try repo.find(id)!
+/// try doc.put(obj: ObjId.ROOT, key: "title", value: .String("Notes"))
+/// try repo.save(id)
+/// try repo.sync(id)
+/// ```
+public final class Repo: @unchecked Sendable {
+    private let ffi: FfiRepo
+    private var documents: [DocumentId: Document] = [:]
+
+    #if !os(WASI)
+    private let lock = NSLock()
+    private func lock<T>(execute work: () throws -> T) rethrows -> T {
+        lock.lock()
+        defer { lock.unlock() }
+        return try work()
+    }
+    #else
+    private func lock<T>(execute work: () throws -> T) rethrows -> T {
+        try work()
+    }
+    #endif
+
+    /// Creates a repo with no documents loaded.
+    ///
+    /// - Parameters:
+    ///   - peerId: The identifier this repo sends its messages as.
+    ///   - storage: The storage for the repo's documents.
+    ///   - storageId: An identifier for the storage, which peers use to save their sync state with this repo.
+    ///   - isEphemeral: Whether the repo discards its documents, which tells peers not to keep sync state for it.
+    public init(peerId: String, storage: StorageAdapter, storageId: String? = nil, isEphemeral: Bool = false) {
+        ffi = FfiRepo(
+            peerId: peerId,
+            peerMetadata: FfiPeerMetadata(storageId: storageId, isEphemeral: isEphemeral),
+            storage: StorageBridge(storage)
+        )
+    }
+
+    /// The identifier this repo sends its messages as.
+    public var peerId: String {
+        ffi.peerId()
+    }
+
+    /// Sets the network adapter that delivers messages to the repo's peers.
+    public func setNetworkAdapter(_ network: NetworkAdapter) {
+        ffi.setNetworkAdapter(network: NetworkBridge(network))
+    }
+
+    /// The message that introduces the repo to a peer whose identifier isn't known yet.
+    ///
+    /// Send this message when your network adapter connects to a sync server.
+    public func join() -> Data {
+        Data(ffi.join())
+    }
+
+    /// Creates an empty document with a new identifier.
+    public func create() -> DocumentId {
+        DocumentId(id: ffi.create())
+    }
+
+    /// Adds a document from the data you provide, with a new identifier.
+    ///
+    /// - Parameter bytes: The saved document, such as the data from ``Document/save()``.
+    public func `import`(_ bytes: Data) throws -> DocumentId {
+        try wrappedErrors { try DocumentId(id: ffi.`import`(bytes: Array(bytes))) }
+    }
+
+    /// Returns a document, loading it from storage if necessary.
+    ///
+    /// - Parameter id: The identifier of the document.
+    /// - Returns: The document, or `nil` if the repo doesn't have it.
+    ///
+    /// Finding the same document again returns the same ``Document`` instance.
+    public func find(_ id: DocumentId) throws -> Document? {
+        if let document = lock(execute: { documents[id] }) {
+            return document
+        }
+        guard let doc = try wrappedErrors({ try ffi.find(documentId: id.id) }) else {
+            return nil
+        }
+        return lock {
+            if let document = documents[id] {
+                return document
+            }
+            let document = Document(doc: doc)
+            documents[id] = document
+            return document
+        }
+    }
+
+    /// Asks every peer for a document the repo doesn't have.
+    ///
+    /// The repo adds the document when a peer sends it, after which ``find(_:)`` returns it.
+    /// Until then, ``phase(of:)`` is ``DocumentSyncPhase/requesting``.
+    ///
+    /// - Parameter id: The identifier of the document.
+    public func request(_ id: DocumentId) throws {
+        try wrappedErrors { try ffi.request(documentId: id.id) }
+    }
+
+    /// Returns where the repo is in syncing a document.
+    ///
+    /// - Parameter id: The identifier of the document.
+    /// - Returns: The phase, or `nil` if the repo neither has the document nor has asked for it.
+    public func phase(of id: DocumentId) throws -> DocumentSyncPhase? {
+        try wrappedErrors { try ffi.phase(documentId: id.id).map(DocumentSyncPhase.init) }
+    }
+
+    /// The identifiers of the documents that are loaded.
+    public var documentIds: [DocumentId] {
+        ffi.documentIds().map(DocumentId.init(id:))
+    }
+
+    /// The identifiers of every document in storage.
+    public var storedDocumentIds: [DocumentId] {
+        ffi.storedDocumentIds().map(DocumentId.init(id:))
+    }
+
+    /// Saves the changes made to a document since it was last saved or loaded.
+    public func save(_ id: DocumentId) throws {
+        try wrappedErrors { try ffi.save(documentId: id.id) }
+    }
+
+    /// Replaces the stored data of a document with a single snapshot.
+    public func compact(_ id: DocumentId) throws {
+        try wrappedErrors { try ffi.compact(documentId: id.id) }
+    }
+
+    /// Removes a document from the repo and from storage.
+    public func delete(_ id: DocumentId) throws {
+        lock { documents[id] = nil }
+        try wrappedErrors { try ffi.delete(documentId: id.id) }
+    }
+
+    /// Saves the sync state of a document with a peer.
+    ///
+    /// - Parameters:
+    ///   - state: The sync state to save.
+    ///   - id: The identifier of the document.
+    ///   - storageId: The storage identifier of the peer.
+    public func saveSyncState(_ state: SyncState, for id: DocumentId, storageId: String) throws {
+        try wrappedErrors { try ffi.saveSyncState(documentId: id.id, storageId: storageId, state: state.ffi_state) }
+    }
+
+    /// Loads the sync state of a document with a peer.
+    ///
+    /// - Parameters:
+    ///   - id: The identifier of the document.
+    ///   - storageId: The storage identifier of the peer.
+    /// - Returns: The sync state, or `nil` if none was saved.
+    public func loadSyncState(for id: DocumentId, storageId: String) throws -> SyncState? {
+        try wrappedErrors {
+            try ffi.loadSyncState(documentId: id.id, storageId: storageId).map { SyncState(ffi_state: $0) }
+        }
+    }
+
+    /// Starts syncing every document with a peer.
+    public func addPeer(_ peer: String) {
+        ffi.addPeer(peerId: peer)
+    }
+
+    /// Stops syncing with a peer.
+    public func removePeer(_ peer: String) {
+        ffi.removePeer(peerId: peer)
+    }
+
+    /// The identifiers of the peers the repo syncs with.
+    public var peers: [String] {
+        ffi.peers()
+    }
+
+    /// Sends any outstanding sync messages for a document to every peer.
+    public func sync(_ id: DocumentId) throws {
+        try wrappedErrors { try ffi.sync(documentId: id.id) }
+    }
+
+    /// Sends any outstanding sync messages for every loaded document.
+    public func syncAll() {
+        ffi.syncAll()
+    }
+
+    /// Sends data about a document to every peer, without changing the document.
+    ///
+    /// Peers that receive the data pass it on to their own peers, so it reaches every client of a sync server.
+    ///
+    /// - Parameters:
+    ///   - data: The data to send, such as an update from ``Presence/encodeUpdate(_:at:)``.
+    ///   - id: The identifier of the document the data is about.
+    public func broadcast(_ data: Data, for id: DocumentId) throws {
+        try wrappedErrors { try ffi.broadcast(documentId: id.id, data: Array(data)) }
+    }
+
+    /// Handles a message from a peer.
+    ///
+    /// A peer that joins is answered and added, and a peer that leaves is removed.
+    /// Changes in the message are applied to the document and saved, and any resulting messages are sent to every
+    /// peer. A request for a document the repo doesn't have is answered with a message saying it's unavailable.
+    ///
+    /// A message in which the peer reports an error throws that error.
+    ///
+    /// - Parameter message: The encoded automerge-repo message.
+    /// - Returns: The data in the message if a peer broadcast it with ``broadcast(_:for:)``, otherwise `nil`.
+    @discardableResult
+    public func receiveMessage(_ message: Data) throws -> EphemeralMessage? {
+        let bytes = Array(message)
+        let document = (try? decodeRepoMessage(bytes: bytes))
+            .flatMap(Self.documentId(of:))
+            .flatMap { id in lock { documents[id] } }
+        guard let document else {
+            return try wrappedErrors { try ffi.receiveMessage(message: bytes).map(EphemeralMessage.init) }
+        }
+        return try document.changing {
+            try wrappedErrors { try ffi.receiveMessage(message: bytes).map(EphemeralMessage.init) }
+        }
+    }
+
+    private static func documentId(of message: FfiRepoMessage) -> DocumentId? {
+        switch message {
+        case let .request(senderId: _, targetId: _, documentId: id, data: _),
+             let .sync(senderId: _, targetId: _, documentId: id, data: _):
+            return DocumentId(id: id)
+        default:
+            return nil
+        }
+    }
+}
+
+final class StorageBridge: FfiStorageAdapter {
+    private let storage: StorageAdapter
+
+    init(_ storage: StorageAdapter) {
+        self.storage = storage
+    }
+
+    func load(key: [String]) -> [UInt8]? {
+        storage.load(key: key).map(Array.init)
+    }
+
+    func save(key: [String], data: [UInt8]) {
+        storage.save(key: key, data: Data(data))
+    }
+
+    func remove(key: [String]) {
+        storage.remove(key: key)
+    }
+
+    func list(prefix: [String]) -> [[String]] {
+        storage.list(prefix: prefix)
+    }
+}
+
+final class NetworkBridge: FfiNetworkAdapter {
+    private let network: NetworkAdapter
+
+    init(_ network: NetworkAdapter) {
+        self.network = network
+    }
+
+    func send(peerId: String, message: [UInt8]) {
+        network.send(Data(message), to: peerId)
+    }
+}
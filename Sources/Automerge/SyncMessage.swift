@@ -0,0 +1,81 @@
+import enum AutomergeUniffi.SyncCapability
+import enum AutomergeUniffi.SyncMessageVersion
+import func AutomergeUniffi.decodeSyncMessage
+import struct AutomergeUniffi.SyncHave
+import struct AutomergeUniffi.SyncMessage
+import Foundation
+
+typealias FfiSyncMessage = AutomergeUniffi.SyncMessage
+typealias FfiSyncHave = AutomergeUniffi.SyncHave
+
+/// The contents of a sync message, for inspecting what a peer sent without applying it to a document.
+///
+/// Decode a message that you generated with ``Document/generateSyncMessage(state:)``, or received from a peer, to
+/// log or validate the changes it carries before calling ``Document/receiveSyncMessage(state:message:)``.
+public struct SyncMessage: Equatable {
+    /// The version of the sync protocol message format.
+    public enum Version: Equatable {
+        case v1
+        case v2
+    }
+
+    /// A capability of the sync protocol which the sender supports.
+    public enum Capability: Equatable, Hashable {
+        case messageV1
+        case messageV2
+        /// A capability this library doesn't know about.
+        case unknown(code: UInt8)
+    }
+
+    /// A summary of the changes the sender has since a point in the history it shares with the recipient.
+    public struct Have: Equatable {
+        /// The heads that the sender believes both peers share.
+        public let lastSync: Set<ChangeHash>
+        /// The encoded bloom filter of the changes the sender has since ``lastSync``.
+        public let bloom: Data
+        /// The number of changes summarized by ``bloom``.
+        public let bloomEntries: UInt64
+    }
+
+    /// The version of the message format.
+    public let version: Version
+    /// The heads of the sender's document.
+    public let heads: Set<ChangeHash>
+    /// The changes the sender is asking for.
+    public let need: Set<ChangeHash>
+    /// Summaries of the changes the sender has.
+    public let have: [Have]
+    /// The changes the message carries.
+    public let changes: [Change]
+    /// The capabilities the sender supports, or `nil` if the message doesn't say.
+    public let supportedCapabilities: [Capability]?
+
+    /// Decodes a sync message.
+    ///
+    /// - Parameter bytes: The encoded sync message.
+    /// - Throws: ``DecodeSyncMessageError`` if the bytes aren't a valid sync message.
+    public init(decoding bytes: Data) throws {
+        let ffi = try wrappedErrors { try decodeSyncMessage(bytes: Array(bytes)) }
+        switch ffi.version {
+        case .v1: version = .v1
+        case .v2: version = .v2
+        }
+        heads = Set(ffi.heads.map(ChangeHash.init(bytes:)))
+        need = Set(ffi.need.map(ChangeHash.init(bytes:)))
+        have = ffi.have.map {
+            Have(
+                lastSync: Set($0.lastSync.map(ChangeHash.init(bytes:))),
+                bloom: Data($0.bloom),
+                bloomEntries: $0.bloomEntries
+            )
+        }
+        changes = ffi.changes.map(Change.init)
+        supportedCapabilities = ffi.supportedCapabilities?.map {
+            switch $0 {
+            case .messageV1: return .messageV1
+            case .messageV2: return .messageV2
+            case let .unknown(code: code): return .unknown(code: code)
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+import class AutomergeUniffi.SyncSession
+import Foundation
+
+typealias FfiSyncSession = AutomergeUniffi.SyncSession
+
+/// Syncs one document with any number of named peers.
+///
+/// A sync session keeps a ``SyncState`` for each peer, so you don't need to track them yourself.
+/// Send the messages from ``generateMessages()`` to their peers, and pass each message you receive to
+/// ``receiveMessage(_:from:)``, until there's nothing left to send.
+///
+/// ```swift
+/// let session = SyncSession(document: doc)
+/// session.addPeer("alice")
+/// session.addPeer("bob")
+/// for (peer, message) in session.generateMessages() {
+///     await network.send(message, to: peer)
+/// }
+/// ```
+///
+/// Use ``encode()`` to persist the peers and their sync states, and ``init(document:restoring:)`` to restore them.
+public final class SyncSession: @unchecked Sendable {
+    /// The document this session syncs.
+    public let document: Document
+    private let ffi: FfiSyncSession
+
+    /// Creates a session with no peers.
+    ///
+    /// - Parameter document: The document to sync.
+    public init(document: Document) {
+        self.document = document
+        ffi = document.withFfiDoc { FfiSyncSession(doc: $0) }
+    }
+
+    /// Creates a session with the peers and sync states from data you saved with ``encode()``.
+    ///
+    /// - Parameters:
+    ///   - document: The document to sync.
+    ///   - bytes: The data that represents the saved session.
+    public init(document: Document, restoring bytes: Data) throws {
+        self.document = document
+        ffi = try document.withFfiDoc { try FfiSyncSession.restore(doc: $0, bytes: Array(bytes)) }
+    }
+
+    /// Encodes the peers and their sync states.
+    ///
+    /// As with ``SyncState/encode()``, the data doesn't include state which depends on reliable, in-order delivery.
+    public func encode() -> Data {
+        Data(ffi.encode())
+    }
+
+    /// The names of the peers in the session.
+    public var peers: [String] {
+        ffi.peers()
+    }
+
+    /// Adds a peer, replacing any existing peer with the same name.
+    ///
+    /// - Parameters:
+    ///   - peer: The name of the peer.
+    ///   - state: The sync state to continue from, or `nil` to start a new one.
+    public func addPeer(_ peer: String, state: SyncState? = nil) {
+        if let state {
+            ffi.addPeerWithState(peer: peer, state: state.ffi_state)
+        } else {
+            ffi.addPeer(peer: peer)
+        }
+    }
+
+    /// Removes a peer and its sync state.
+    ///
+    /// - Parameter peer: The name of the peer.
+    public func removePeer(_ peer: String) {
+        ffi.removePeer(peer: peer)
+    }
+
+    /// Returns the sync state of a peer.
+    ///
+    /// - Parameter peer: The name of the peer.
+    /// - Returns: The sync state, or `nil` if there's no peer with that name.
+    public func state(for peer: String) -> SyncState? {
+        ffi.peerState(peer: peer).map { SyncState(ffi_state: $0) }
+    }
+
+    /// Generates the next message for a peer.
+    ///
+    /// - Parameter peer: The name of the peer.
+    /// - Returns: The message, or `nil` if there's nothing to send.
+    public func generateMessage(for peer: String) throws -> Data? {
+        try document.reading {
+            try wrappedErrors { try ffi.generateMessage(peer: peer).map { Data($0) } }
+        }
+    }
+
+    /// Generates the next message for every peer that has something to send.
+    ///
+    /// - Returns: The messages, with the name of the peer to send each one to.
+    public func generateMessages() -> [(peer: String, message: Data)] {
+        document.reading {
+            ffi.generateMessages().map { (peer: $0.peer, message: Data($0.message)) }
+        }
+    }
+
+    /// Applies a message from a peer to the document.
+    ///
+    /// - Parameters:
+    ///   - message: The message the peer sent.
+    ///   - peer: The name of the peer.
+    public func receiveMessage(_ message: Data, from peer: String) throws {
+        try document.changing {
+            try wrappedErrors { try ffi.receiveMessage(peer: peer, message: Array(message)) }
+        }
+    }
+}
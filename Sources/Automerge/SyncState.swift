@@ -55,11 +55,63 @@ public struct SyncState: @unchecked Sendable {
         }
     }
 
+    /// The heads which both peers are known to have.
+    public var sharedHeads: Set<ChangeHash> {
+        sync {
+            Set(ffi_state.sharedHeads().map { ChangeHash(bytes: $0) })
+        }
+    }
+
+    /// The heads sent to the peer in the last sync message.
+    public var lastSentHeads: Set<ChangeHash> {
+        sync {
+            Set(ffi_state.lastSentHeads().map { ChangeHash(bytes: $0) })
+        }
+    }
+
+    /// The changes the peer last asked for, or `nil` if the peer hasn't sent a message.
+    public var theirNeed: Set<ChangeHash>? {
+        sync {
+            ffi_state.theirNeed().map { Set($0.map { ChangeHash(bytes: $0) }) }
+        }
+    }
+
+    /// The summaries of the changes the peer last said it has, or `nil` if the peer hasn't sent a message.
+    public var theirHave: [SyncMessage.Have]? {
+        sync {
+            ffi_state.theirHave()?.map {
+                SyncMessage.Have(
+                    lastSync: Set($0.lastSync.map { ChangeHash(bytes: $0) }),
+                    bloom: Data($0.bloom),
+                    bloomEntries: $0.bloomEntries
+                )
+            }
+        }
+    }
+
+    /// The number of changes sent to the peer in this session.
+    public var sentHashesCount: UInt64 {
+        sync {
+            ffi_state.sentHashesCount()
+        }
+    }
+
+    /// Whether a sync message has been sent that the peer hasn't yet responded to.
+    public var inFlight: Bool {
+        sync {
+            ffi_state.inFlight()
+        }
+    }
+
     /// Create a new, empty sync state.
     public init() {
         ffi_state = FfiSyncState()
     }
 
+    init(ffi_state: FfiSyncState) {
+        self.ffi_state = ffi_state
+    }
+
     /// Create a sync state from data.
     /// - Parameter bytes: The data that represents a serialized sync state.
     ///
@@ -0,0 +1,251 @@
+import class AutomergeUniffi.Transaction
+import Foundation
+
+typealias FfiTransaction = AutomergeUniffi.Transaction
+
+/// A set of edits to a document that you apply, or discard, as a whole.
+///
+/// Create a transaction with ``Document/beginTransaction()``.
+/// Edits you make through a transaction are staged privately, and aren't visible in the document until you call
+/// ``commit(message:timestamp:)``, which applies them as a single change.
+/// Call ``rollback()`` to discard the staged edits, for example when validating a form fails partway through.
+///
+/// The committed change belongs to the document's actor, so ``DocumentUndoManager`` can undo it and blame attributes
+/// it to the document like any other edit.
+/// Object identifiers returned while staging remain valid in the document after you commit.
+///
+/// The document can still be synced and merged while a transaction is open. Editing the document directly while a
+/// transaction is open makes ``commit(message:timestamp:)`` throw, and you need to roll the transaction back.
+///
+/// ```swift
+/// let transaction = doc.beginTransaction()
+/// let contact = try transaction.putObject(obj: ObjId.ROOT, key: "contact", ty: .Map)
+/// try transaction.put(obj: contact, key: "name", value: .String("Alice"))
+/// guard isValid(name) else {
+///     transaction.rollback()
+///     return
+/// }
+/// try transaction.commit(message: "Add a contact")
+/// ```
+public final class Transaction: @unchecked Sendable {
+    private let document: Document
+    private let ffi: FfiTransaction
+
+    init(document: Document, ffi: FfiTransaction) {
+        self.document = document
+        self.ffi = ffi
+    }
+
+    /// Set or update a value within a dictionary object you specify.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the dictionary object to update.
+    ///   - key: The key of the property to update.
+    ///   - value: The value to set for the key you provide.
+    public func put(obj: ObjId, key: String, value: ScalarValue) throws {
+        try wrappedErrors {
+            try ffi.putInMap(obj: obj.bytes, key: key, value: value.toFfi())
+        }
+    }
+
+    /// Set or update a value within an array object you specify.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the array object to update.
+    ///   - index: The index value of the array to update.
+    ///   - value: The value to set for the index you provide.
+    public func put(obj: ObjId, index: UInt64, value: ScalarValue) throws {
+        try wrappedErrors {
+            try ffi.putInList(obj: obj.bytes, index: index, value: value.toFfi())
+        }
+    }
+
+    /// Set or update an object within a dictionary object you specify.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the dictionary object to update.
+    ///   - key: The key of the property to update.
+    ///   - ty: The type of object to add to the dictionary.
+    /// - Returns: The object Id that references the object added.
+    public func putObject(obj: ObjId, key: String, ty: ObjType) throws -> ObjId {
+        try wrappedErrors {
+            try ObjId(bytes: ffi.putObjectInMap(obj: obj.bytes, key: key, objType: ty.toFfi()))
+        }
+    }
+
+    /// Set or update an object within an array object you specify.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the array object to update.
+    ///   - index: The index value of the array to update.
+    ///   - ty: The type of object to add to the array.
+    /// - Returns: The object Id that references the object added.
+    public func putObject(obj: ObjId, index: UInt64, ty: ObjType) throws -> ObjId {
+        try wrappedErrors {
+            try ObjId(bytes: ffi.putObjectInList(obj: obj.bytes, index: index, objType: ty.toFfi()))
+        }
+    }
+
+    /// Insert a value, at the index you provide, into the array object you specify.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the array object to update.
+    ///   - index: The index value of the array to update.
+    ///   - value: The value to insert for the index you provide.
+    public func insert(obj: ObjId, index: UInt64, value: ScalarValue) throws {
+        try wrappedErrors {
+            try ffi.insertInList(obj: obj.bytes, index: index, value: value.toFfi())
+        }
+    }
+
+    /// Insert an object, at the index you provide, into the array object you specify.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the array object to update.
+    ///   - index: The index value of the array to update.
+    ///   - ty: The type of object to add to the array.
+    /// - Returns: The object Id that references the object added.
+    public func insertObject(obj: ObjId, index: UInt64, ty: ObjType) throws -> ObjId {
+        try wrappedErrors {
+            try ObjId(bytes: ffi.insertObjectInList(obj: obj.bytes, index: index, objType: ty.toFfi()))
+        }
+    }
+
+    /// Deletes the key you provide, and its associated value or object, from the dictionary object you specify.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the dictionary to update.
+    ///   - key: The key to delete.
+    public func delete(obj: ObjId, key: String) throws {
+        try wrappedErrors {
+            try ffi.deleteInMap(obj: obj.bytes, key: key)
+        }
+    }
+
+    /// Deletes the object or value at the index you provide from the array object you specify.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the array to update.
+    ///   - index: The index position to remove.
+    public func delete(obj: ObjId, index: UInt64) throws {
+        try wrappedErrors {
+            try ffi.deleteInList(obj: obj.bytes, index: index)
+        }
+    }
+
+    /// Increment or decrement the counter referenced by the key you provide in the dictionary object you specify.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the dictionary object that holds the counter.
+    ///   - key: The key in the dictionary object that references the counter.
+    ///   - by: The amount to increment, or decrement, the counter.
+    public func increment(obj: ObjId, key: String, by: Int64) throws {
+        try wrappedErrors {
+            try ffi.incrementInMap(obj: obj.bytes, key: key, by: by)
+        }
+    }
+
+    /// Increment or decrement a counter referenced at the index you provide in the array object you specify.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the array object that holds the counter.
+    ///   - index: The index position in the array object that references the counter.
+    ///   - by: The amount to increment, or decrement, the counter.
+    public func increment(obj: ObjId, index: UInt64, by: Int64) throws {
+        try wrappedErrors {
+            try ffi.incrementInList(obj: obj.bytes, index: index, by: by)
+        }
+    }
+
+    /// Splice an array of values into the array object you specify.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the array object to update.
+    ///   - start: The index where the splice method begins inserting or deleting.
+    ///   - delete: The number of elements to delete from the `start` index.
+    ///   If negative, the function deletes elements preceding `start` index, rather than following it.
+    ///   - values: An array of values to insert after the `start` index.
+    public func splice(obj: ObjId, start: UInt64, delete: Int64, values: [ScalarValue]) throws {
+        try wrappedErrors {
+            try ffi.splice(obj: obj.bytes, start: start, delete: delete, values: values.map { $0.toFfi() })
+        }
+    }
+
+    /// Splice characters into the text object you specify.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the text object to update.
+    ///   - start: The distance from the start of the string where the function begins inserting or deleting.
+    ///   - delete: Text length to delete from the `start` index, in the document's ``TextEncoding``.
+    ///   If negative, the function deletes characters preceding `start` index, rather than following it.
+    ///   - value: The characters to insert after the `start` index.
+    public func spliceText(obj: ObjId, start: UInt64, delete: Int64, value: String? = nil) throws {
+        try wrappedErrors {
+            try ffi.spliceText(obj: obj.bytes, start: start, delete: delete, chars: value ?? "")
+        }
+    }
+
+    /// Updates the text object with the value you specify.
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the text object to update.
+    ///   - value: The string value for the text
+    public func updateText(obj: ObjId, value: String) throws {
+        try wrappedErrors {
+            try ffi.updateText(obj: obj.bytes, chars: value)
+        }
+    }
+
+    /// Add or remove a mark to a given range of text
+    ///
+    /// - Parameters:
+    ///   - obj: The identifier of the text object to which to add the mark.
+    ///   - start: The distance from the start of the string where the function begins inserting or deleting.
+    ///   - end: The distance from the start of the string where the function ends the mark.
+    ///   - expand: How the mark should expand when text is inserted at the beginning or end of the range
+    ///   - name: The name of the mark, for example "bold".
+    ///   - value: The scalar value to associate with the mark.
+    public func mark(
+        obj: ObjId,
+        start: UInt64,
+        end: UInt64,
+        expand: ExpandMark,
+        name: String,
+        value: ScalarValue
+    ) throws {
+        try wrappedErrors {
+            try ffi.mark(
+                obj: obj.bytes,
+                start: start,
+                end: end,
+                expand: expand.toFfi(),
+                name: name,
+                value: value.toFfi()
+            )
+        }
+    }
+
+    /// Applies the staged edits to the document as a single change.
+    ///
+    /// - Parameters:
+    ///   - message: An optional message to attach to the change.
+    ///   - timestamp: A timestamp to attach to the change, defaulting to Date().
+    /// - Returns: The hash of the new change, or `nil` if no edits were staged.
+    ///
+    /// After committing, the transaction is closed and further edits throw an error.
+    /// If the change can't be applied, the transaction stays open so that you can commit again or roll back.
+    @discardableResult
+    public func commit(message: String? = nil, timestamp: Date = Date()) throws -> ChangeHash? {
+        try document.changing {
+            try wrappedErrors {
+                try ffi.commit(msg: message, time: Int64(timestamp.timeIntervalSince1970))
+                    .map { ChangeHash(bytes: $0) }
+            }
+        }
+    }
+
+    /// Discards the staged edits and closes the transaction.
+    public func rollback() {
+        ffi.rollback()
+    }
+}
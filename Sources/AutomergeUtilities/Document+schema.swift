@@ -41,7 +41,7 @@ public extension Document {
     ///   - objId: The object identifier at which to start the parse
     /// - Returns: A tree that represents the schema and values.
     func parseToSchema(_ doc: Document, from objId: ObjId) throws -> AutomergeValue {
-        switch doc.objectType(obj: objId) {
+        switch try doc.objectType(obj: objId) {
         case .Map:
             var dictValues: [String: AutomergeValue] = [:]
             for (key, value) in try doc.mapEntries(obj: objId) {
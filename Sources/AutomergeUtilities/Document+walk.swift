@@ -13,7 +13,7 @@ extension Document {
     private func walk(_ doc: Document, from objId: ObjId, indent: Int = 1) throws {
         let indentString = String(repeating: " ", count: indent * 2)
         let whitequote = "\""
-        switch doc.objectType(obj: objId) {
+        switch try doc.objectType(obj: objId) {
         case .Map:
             print("\(indentString){")
             for (key, value) in try doc.mapEntries(obj: objId) {
@@ -30,7 +30,7 @@ extension Document {
             }
             print("\(indentString)}")
         case .List:
-            if doc.length(obj: objId) == 0 {
+            if try doc.length(obj: objId) == 0 {
                 print("\(indentString)[]")
             } else {
                 print("\(indentString)[")
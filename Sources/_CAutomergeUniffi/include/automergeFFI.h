@@ -250,6 +250,75 @@ typedef struct UniffiForeignFutureStructVoid {
 typedef void (*UniffiForeignFutureCompleteVoid)(uint64_t, UniffiForeignFutureStructVoid
     );
 
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_NETWORK_ADAPTER_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_NETWORK_ADAPTER_METHOD0
+typedef void (*UniffiCallbackInterfaceNetworkAdapterMethod0)(uint64_t, RustBuffer, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_PATCH_OBSERVER_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_PATCH_OBSERVER_METHOD0
+typedef void (*UniffiCallbackInterfacePatchObserverMethod0)(uint64_t, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_STORAGE_ADAPTER_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_STORAGE_ADAPTER_METHOD0
+typedef void (*UniffiCallbackInterfaceStorageAdapterMethod0)(uint64_t, RustBuffer, RustBuffer* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_STORAGE_ADAPTER_METHOD1
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_STORAGE_ADAPTER_METHOD1
+typedef void (*UniffiCallbackInterfaceStorageAdapterMethod1)(uint64_t, RustBuffer, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_STORAGE_ADAPTER_METHOD2
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_STORAGE_ADAPTER_METHOD2
+typedef void (*UniffiCallbackInterfaceStorageAdapterMethod2)(uint64_t, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_STORAGE_ADAPTER_METHOD3
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_STORAGE_ADAPTER_METHOD3
+typedef void (*UniffiCallbackInterfaceStorageAdapterMethod3)(uint64_t, RustBuffer, RustBuffer* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_NETWORK_ADAPTER
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_NETWORK_ADAPTER
+typedef struct UniffiVTableCallbackInterfaceNetworkAdapter {
+    UniffiCallbackInterfaceNetworkAdapterMethod0 _Nonnull send;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfaceNetworkAdapter;
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_PATCH_OBSERVER
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_PATCH_OBSERVER
+typedef struct UniffiVTableCallbackInterfacePatchObserver {
+    UniffiCallbackInterfacePatchObserverMethod0 _Nonnull onPatches;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfacePatchObserver;
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_STORAGE_ADAPTER
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_STORAGE_ADAPTER
+typedef struct UniffiVTableCallbackInterfaceStorageAdapter {
+    UniffiCallbackInterfaceStorageAdapterMethod0 _Nonnull load;
+    UniffiCallbackInterfaceStorageAdapterMethod1 _Nonnull save;
+    UniffiCallbackInterfaceStorageAdapterMethod2 _Nonnull remove;
+    UniffiCallbackInterfaceStorageAdapterMethod3 _Nonnull list;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfaceStorageAdapter;
+
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CLONE_DOC
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CLONE_DOC
@@ -266,6 +335,11 @@ void uniffi_uniffi_automerge_fn_free_doc(void*_Nonnull ptr, RustCallStatus *_Non
 void*_Nonnull uniffi_uniffi_automerge_fn_constructor_doc_load(RustBuffer bytes, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_DOC_LOAD_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_DOC_LOAD_WITH_OPTIONS
+void*_Nonnull uniffi_uniffi_automerge_fn_constructor_doc_load_with_options(RustBuffer bytes, RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_DOC_NEW
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_DOC_NEW
 void*_Nonnull uniffi_uniffi_automerge_fn_constructor_doc_new(RustCallStatus *_Nonnull out_status
@@ -297,6 +371,21 @@ void uniffi_uniffi_automerge_fn_method_doc_apply_encoded_changes(void*_Nonnull p
 RustBuffer uniffi_uniffi_automerge_fn_method_doc_apply_encoded_changes_with_patches(void*_Nonnull ptr, RustBuffer changes, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_BEGIN_TRANSACTION
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_BEGIN_TRANSACTION
+void*_Nonnull uniffi_uniffi_automerge_fn_method_doc_begin_transaction(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_BLAME_PROPERTY
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_BLAME_PROPERTY
+RustBuffer uniffi_uniffi_automerge_fn_method_doc_blame_property(void*_Nonnull ptr, RustBuffer obj, RustBuffer prop, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_BLAME_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_BLAME_TEXT
+RustBuffer uniffi_uniffi_automerge_fn_method_doc_blame_text(void*_Nonnull ptr, RustBuffer obj, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_CHANGE_BY_HASH
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_CHANGE_BY_HASH
 RustBuffer uniffi_uniffi_automerge_fn_method_doc_change_by_hash(void*_Nonnull ptr, RustBuffer hash, RustCallStatus *_Nonnull out_status
@@ -307,11 +396,36 @@ RustBuffer uniffi_uniffi_automerge_fn_method_doc_change_by_hash(void*_Nonnull pt
 RustBuffer uniffi_uniffi_automerge_fn_method_doc_changes(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_CHECKOUT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_CHECKOUT
+void*_Nonnull uniffi_uniffi_automerge_fn_method_doc_checkout(void*_Nonnull ptr, RustBuffer name, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_CHERRY_PICK
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_CHERRY_PICK
+RustBuffer uniffi_uniffi_automerge_fn_method_doc_cherry_pick(void*_Nonnull ptr, void*_Nonnull other, RustBuffer hashes, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_CHERRY_PICK_ENCODED
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_CHERRY_PICK_ENCODED
+RustBuffer uniffi_uniffi_automerge_fn_method_doc_cherry_pick_encoded(void*_Nonnull ptr, RustBuffer changes, RustBuffer hashes, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_CLEAR_PATCH_OBSERVER
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_CLEAR_PATCH_OBSERVER
+void uniffi_uniffi_automerge_fn_method_doc_clear_patch_observer(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_COMMIT_WITH
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_COMMIT_WITH
 void uniffi_uniffi_automerge_fn_method_doc_commit_with(void*_Nonnull ptr, RustBuffer msg, int64_t time, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_CREATE_BRANCH
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_CREATE_BRANCH
+void uniffi_uniffi_automerge_fn_method_doc_create_branch(void*_Nonnull ptr, RustBuffer name, RustBuffer heads, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_CURSOR
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_CURSOR
 RustBuffer uniffi_uniffi_automerge_fn_method_doc_cursor(void*_Nonnull ptr, RustBuffer obj, uint64_t position, RustCallStatus *_Nonnull out_status
@@ -332,6 +446,11 @@ uint64_t uniffi_uniffi_automerge_fn_method_doc_cursor_position(void*_Nonnull ptr
 uint64_t uniffi_uniffi_automerge_fn_method_doc_cursor_position_at(void*_Nonnull ptr, RustBuffer obj, RustBuffer cursor, RustBuffer heads, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_DELETE_BRANCH
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_DELETE_BRANCH
+void uniffi_uniffi_automerge_fn_method_doc_delete_branch(void*_Nonnull ptr, RustBuffer name, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_DELETE_IN_LIST
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_DELETE_IN_LIST
 void uniffi_uniffi_automerge_fn_method_doc_delete_in_list(void*_Nonnull ptr, RustBuffer obj, uint64_t index, RustCallStatus *_Nonnull out_status
@@ -347,6 +466,16 @@ void uniffi_uniffi_automerge_fn_method_doc_delete_in_map(void*_Nonnull ptr, Rust
 RustBuffer uniffi_uniffi_automerge_fn_method_doc_difference(void*_Nonnull ptr, RustBuffer before, RustBuffer after, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_DIFFERENCE_IN
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_DIFFERENCE_IN
+RustBuffer uniffi_uniffi_automerge_fn_method_doc_difference_in(void*_Nonnull ptr, RustBuffer obj, RustBuffer before, RustBuffer after, int8_t recursive, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_DIFFERENCE_SUMMARY
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_DIFFERENCE_SUMMARY
+RustBuffer uniffi_uniffi_automerge_fn_method_doc_difference_summary(void*_Nonnull ptr, RustBuffer obj, RustBuffer before, RustBuffer after, int8_t recursive, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_ENCODE_CHANGES_SINCE
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_ENCODE_CHANGES_SINCE
 RustBuffer uniffi_uniffi_automerge_fn_method_doc_encode_changes_since(void*_Nonnull ptr, RustBuffer heads, RustCallStatus *_Nonnull out_status
@@ -402,6 +531,16 @@ RustBuffer uniffi_uniffi_automerge_fn_method_doc_get_at_in_list(void*_Nonnull pt
 RustBuffer uniffi_uniffi_automerge_fn_method_doc_get_at_in_map(void*_Nonnull ptr, RustBuffer obj, RustBuffer key, RustBuffer heads, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_GET_CHANGES
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_GET_CHANGES
+RustBuffer uniffi_uniffi_automerge_fn_method_doc_get_changes(void*_Nonnull ptr, RustBuffer since_heads, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_GET_CONFLICTS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_GET_CONFLICTS
+RustBuffer uniffi_uniffi_automerge_fn_method_doc_get_conflicts(void*_Nonnull ptr, RustBuffer obj, RustBuffer prop, RustBuffer heads, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_GET_IN_LIST
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_GET_IN_LIST
 RustBuffer uniffi_uniffi_automerge_fn_method_doc_get_in_list(void*_Nonnull ptr, RustBuffer obj, uint64_t index, RustCallStatus *_Nonnull out_status
@@ -417,6 +556,11 @@ RustBuffer uniffi_uniffi_automerge_fn_method_doc_get_in_map(void*_Nonnull ptr, R
 RustBuffer uniffi_uniffi_automerge_fn_method_doc_heads(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_HYDRATE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_HYDRATE
+RustBuffer uniffi_uniffi_automerge_fn_method_doc_hydrate(void*_Nonnull ptr, RustBuffer obj, RustBuffer heads, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_INCREMENT_IN_LIST
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_INCREMENT_IN_LIST
 void uniffi_uniffi_automerge_fn_method_doc_increment_in_list(void*_Nonnull ptr, RustBuffer obj, uint64_t index, int64_t by, RustCallStatus *_Nonnull out_status
@@ -427,6 +571,11 @@ void uniffi_uniffi_automerge_fn_method_doc_increment_in_list(void*_Nonnull ptr,
 void uniffi_uniffi_automerge_fn_method_doc_increment_in_map(void*_Nonnull ptr, RustBuffer obj, RustBuffer key, int64_t by, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_INSERT_HYDRATED
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_INSERT_HYDRATED
+RustBuffer uniffi_uniffi_automerge_fn_method_doc_insert_hydrated(void*_Nonnull ptr, RustBuffer obj, uint64_t index, RustBuffer value, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_INSERT_IN_LIST
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_INSERT_IN_LIST
 void uniffi_uniffi_automerge_fn_method_doc_insert_in_list(void*_Nonnull ptr, RustBuffer obj, uint64_t index, RustBuffer value, RustCallStatus *_Nonnull out_status
@@ -452,6 +601,16 @@ uint64_t uniffi_uniffi_automerge_fn_method_doc_length(void*_Nonnull ptr, RustBuf
 uint64_t uniffi_uniffi_automerge_fn_method_doc_length_at(void*_Nonnull ptr, RustBuffer obj, RustBuffer heads, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_LIST_BRANCHES
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_LIST_BRANCHES
+RustBuffer uniffi_uniffi_automerge_fn_method_doc_list_branches(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_LIST_RANGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_LIST_RANGE
+RustBuffer uniffi_uniffi_automerge_fn_method_doc_list_range(void*_Nonnull ptr, RustBuffer obj, uint64_t start, uint64_t end, RustBuffer heads, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_MAP_ENTRIES
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_MAP_ENTRIES
 RustBuffer uniffi_uniffi_automerge_fn_method_doc_map_entries(void*_Nonnull ptr, RustBuffer obj, RustCallStatus *_Nonnull out_status
@@ -472,6 +631,11 @@ RustBuffer uniffi_uniffi_automerge_fn_method_doc_map_keys(void*_Nonnull ptr, Rus
 RustBuffer uniffi_uniffi_automerge_fn_method_doc_map_keys_at(void*_Nonnull ptr, RustBuffer obj, RustBuffer heads, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_MAP_RANGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_MAP_RANGE
+RustBuffer uniffi_uniffi_automerge_fn_method_doc_map_range(void*_Nonnull ptr, RustBuffer obj, RustBuffer from_key, RustBuffer to_key, RustBuffer limit, RustBuffer heads, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_MARK
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_MARK
 void uniffi_uniffi_automerge_fn_method_doc_mark(void*_Nonnull ptr, RustBuffer obj, uint64_t start, uint64_t end, RustBuffer expand, RustBuffer name, RustBuffer value, RustCallStatus *_Nonnull out_status
@@ -497,6 +661,11 @@ RustBuffer uniffi_uniffi_automerge_fn_method_doc_marks_at_position(void*_Nonnull
 void uniffi_uniffi_automerge_fn_method_doc_merge(void*_Nonnull ptr, void*_Nonnull other, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_MERGE_BRANCH
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_MERGE_BRANCH
+void uniffi_uniffi_automerge_fn_method_doc_merge_branch(void*_Nonnull ptr, RustBuffer name, void*_Nonnull other, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_MERGE_WITH_PATCHES
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_MERGE_WITH_PATCHES
 RustBuffer uniffi_uniffi_automerge_fn_method_doc_merge_with_patches(void*_Nonnull ptr, void*_Nonnull other, RustCallStatus *_Nonnull out_status
@@ -512,6 +681,11 @@ RustBuffer uniffi_uniffi_automerge_fn_method_doc_object_type(void*_Nonnull ptr,
 RustBuffer uniffi_uniffi_automerge_fn_method_doc_path(void*_Nonnull ptr, RustBuffer obj, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_PUT_HYDRATED
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_PUT_HYDRATED
+RustBuffer uniffi_uniffi_automerge_fn_method_doc_put_hydrated(void*_Nonnull ptr, RustBuffer obj, RustBuffer prop, RustBuffer value, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_PUT_IN_LIST
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_PUT_IN_LIST
 void uniffi_uniffi_automerge_fn_method_doc_put_in_list(void*_Nonnull ptr, RustBuffer obj, uint64_t index, RustBuffer value, RustCallStatus *_Nonnull out_status
@@ -522,6 +696,11 @@ void uniffi_uniffi_automerge_fn_method_doc_put_in_list(void*_Nonnull ptr, RustBu
 void uniffi_uniffi_automerge_fn_method_doc_put_in_map(void*_Nonnull ptr, RustBuffer obj, RustBuffer key, RustBuffer value, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_PUT_JSON
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_PUT_JSON
+void uniffi_uniffi_automerge_fn_method_doc_put_json(void*_Nonnull ptr, RustBuffer obj, RustBuffer key, RustBuffer json, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_PUT_OBJECT_IN_LIST
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_PUT_OBJECT_IN_LIST
 RustBuffer uniffi_uniffi_automerge_fn_method_doc_put_object_in_list(void*_Nonnull ptr, RustBuffer obj, uint64_t index, RustBuffer obj_type, RustCallStatus *_Nonnull out_status
@@ -542,16 +721,41 @@ void uniffi_uniffi_automerge_fn_method_doc_receive_sync_message(void*_Nonnull pt
 RustBuffer uniffi_uniffi_automerge_fn_method_doc_receive_sync_message_with_patches(void*_Nonnull ptr, void*_Nonnull state, RustBuffer msg, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_RESOLVE_CONFLICT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_RESOLVE_CONFLICT
+RustBuffer uniffi_uniffi_automerge_fn_method_doc_resolve_conflict(void*_Nonnull ptr, RustBuffer obj, RustBuffer prop, RustBuffer chosen_op_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_REVERT_TO
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_REVERT_TO
+void uniffi_uniffi_automerge_fn_method_doc_revert_to(void*_Nonnull ptr, RustBuffer heads, RustBuffer obj, RustBuffer expand, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_SAVE
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_SAVE
 RustBuffer uniffi_uniffi_automerge_fn_method_doc_save(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_SAVE_INCREMENTAL
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_SAVE_INCREMENTAL
+RustBuffer uniffi_uniffi_automerge_fn_method_doc_save_incremental(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_SAVE_NO_COMPRESS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_SAVE_NO_COMPRESS
+RustBuffer uniffi_uniffi_automerge_fn_method_doc_save_no_compress(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_SET_ACTOR
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_SET_ACTOR
 void uniffi_uniffi_automerge_fn_method_doc_set_actor(void*_Nonnull ptr, RustBuffer actor, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_SET_PATCH_OBSERVER
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_SET_PATCH_OBSERVER
+void uniffi_uniffi_automerge_fn_method_doc_set_patch_observer(void*_Nonnull ptr, uint64_t observer, int8_t batch_per_commit, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_SPLICE
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_SPLICE
 void uniffi_uniffi_automerge_fn_method_doc_splice(void*_Nonnull ptr, RustBuffer obj, uint64_t start, int64_t delete, RustBuffer values, RustCallStatus *_Nonnull out_status
@@ -582,6 +786,11 @@ RustBuffer uniffi_uniffi_automerge_fn_method_doc_text_at(void*_Nonnull ptr, Rust
 RustBuffer uniffi_uniffi_automerge_fn_method_doc_text_encoding(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_TO_JSON
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_TO_JSON
+RustBuffer uniffi_uniffi_automerge_fn_method_doc_to_json(void*_Nonnull ptr, RustBuffer obj, RustBuffer heads, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_UPDATE_TEXT
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_DOC_UPDATE_TEXT
 void uniffi_uniffi_automerge_fn_method_doc_update_text(void*_Nonnull ptr, RustBuffer obj, RustBuffer chars, RustCallStatus *_Nonnull out_status
@@ -597,727 +806,1783 @@ RustBuffer uniffi_uniffi_automerge_fn_method_doc_values(void*_Nonnull ptr, RustB
 RustBuffer uniffi_uniffi_automerge_fn_method_doc_values_at(void*_Nonnull ptr, RustBuffer obj, RustBuffer heads, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CLONE_SYNCSTATE
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CLONE_SYNCSTATE
-void*_Nonnull uniffi_uniffi_automerge_fn_clone_syncstate(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CLONE_PRESENCE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CLONE_PRESENCE
+void*_Nonnull uniffi_uniffi_automerge_fn_clone_presence(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FREE_SYNCSTATE
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FREE_SYNCSTATE
-void uniffi_uniffi_automerge_fn_free_syncstate(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FREE_PRESENCE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FREE_PRESENCE
+void uniffi_uniffi_automerge_fn_free_presence(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_SYNCSTATE_DECODE
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_SYNCSTATE_DECODE
-void*_Nonnull uniffi_uniffi_automerge_fn_constructor_syncstate_decode(RustBuffer bytes, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_PRESENCE_NEW
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_PRESENCE_NEW
+void*_Nonnull uniffi_uniffi_automerge_fn_constructor_presence_new(RustBuffer actor, int64_t ttl, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_SYNCSTATE_NEW
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_SYNCSTATE_NEW
-void*_Nonnull uniffi_uniffi_automerge_fn_constructor_syncstate_new(RustCallStatus *_Nonnull out_status
-    
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_PRESENCE_ENCODE_LEAVE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_PRESENCE_ENCODE_LEAVE
+RustBuffer uniffi_uniffi_automerge_fn_method_presence_encode_leave(void*_Nonnull ptr, int64_t timestamp, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_ENCODE
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_ENCODE
-RustBuffer uniffi_uniffi_automerge_fn_method_syncstate_encode(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_PRESENCE_ENCODE_UPDATE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_PRESENCE_ENCODE_UPDATE
+RustBuffer uniffi_uniffi_automerge_fn_method_presence_encode_update(void*_Nonnull ptr, RustBuffer payload, int64_t timestamp, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_RESET
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_RESET
-void uniffi_uniffi_automerge_fn_method_syncstate_reset(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_PRESENCE_PRUNE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_PRESENCE_PRUNE
+RustBuffer uniffi_uniffi_automerge_fn_method_presence_prune(void*_Nonnull ptr, int64_t now, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_THEIR_HEADS
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_THEIR_HEADS
-RustBuffer uniffi_uniffi_automerge_fn_method_syncstate_their_heads(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_PRESENCE_RECEIVE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_PRESENCE_RECEIVE
+void uniffi_uniffi_automerge_fn_method_presence_receive(void*_Nonnull ptr, RustBuffer message, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FUNC_ROOT
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FUNC_ROOT
-RustBuffer uniffi_uniffi_automerge_fn_func_root(RustCallStatus *_Nonnull out_status
-    
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_PRESENCE_STATES
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_PRESENCE_STATES
+RustBuffer uniffi_uniffi_automerge_fn_method_presence_states(void*_Nonnull ptr, int64_t now, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUSTBUFFER_ALLOC
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUSTBUFFER_ALLOC
-RustBuffer ffi_uniffi_automerge_rustbuffer_alloc(uint64_t size, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CLONE_REPO
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CLONE_REPO
+void*_Nonnull uniffi_uniffi_automerge_fn_clone_repo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUSTBUFFER_FROM_BYTES
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUSTBUFFER_FROM_BYTES
-RustBuffer ffi_uniffi_automerge_rustbuffer_from_bytes(ForeignBytes bytes, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FREE_REPO
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FREE_REPO
+void uniffi_uniffi_automerge_fn_free_repo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUSTBUFFER_FREE
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUSTBUFFER_FREE
-void ffi_uniffi_automerge_rustbuffer_free(RustBuffer buf, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_REPO_NEW
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_REPO_NEW
+void*_Nonnull uniffi_uniffi_automerge_fn_constructor_repo_new(RustBuffer peer_id, RustBuffer peer_metadata, uint64_t storage, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUSTBUFFER_RESERVE
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUSTBUFFER_RESERVE
-RustBuffer ffi_uniffi_automerge_rustbuffer_reserve(RustBuffer buf, uint64_t additional, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_ADD_PEER
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_ADD_PEER
+void uniffi_uniffi_automerge_fn_method_repo_add_peer(void*_Nonnull ptr, RustBuffer peer_id, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_U8
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_U8
-void ffi_uniffi_automerge_rust_future_poll_u8(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_BROADCAST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_BROADCAST
+void uniffi_uniffi_automerge_fn_method_repo_broadcast(void*_Nonnull ptr, RustBuffer document_id, RustBuffer data, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_U8
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_U8
-void ffi_uniffi_automerge_rust_future_cancel_u8(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_COMPACT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_COMPACT
+void uniffi_uniffi_automerge_fn_method_repo_compact(void*_Nonnull ptr, RustBuffer document_id, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_U8
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_U8
-void ffi_uniffi_automerge_rust_future_free_u8(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_CREATE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_CREATE
+RustBuffer uniffi_uniffi_automerge_fn_method_repo_create(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_U8
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_U8
-uint8_t ffi_uniffi_automerge_rust_future_complete_u8(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_DELETE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_DELETE
+void uniffi_uniffi_automerge_fn_method_repo_delete(void*_Nonnull ptr, RustBuffer document_id, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_I8
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_I8
-void ffi_uniffi_automerge_rust_future_poll_i8(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_DOCUMENT_IDS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_DOCUMENT_IDS
+RustBuffer uniffi_uniffi_automerge_fn_method_repo_document_ids(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_I8
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_I8
-void ffi_uniffi_automerge_rust_future_cancel_i8(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_FIND
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_FIND
+RustBuffer uniffi_uniffi_automerge_fn_method_repo_find(void*_Nonnull ptr, RustBuffer document_id, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_I8
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_I8
-void ffi_uniffi_automerge_rust_future_free_i8(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_IMPORT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_IMPORT
+RustBuffer uniffi_uniffi_automerge_fn_method_repo_import(void*_Nonnull ptr, RustBuffer bytes, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_I8
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_I8
-int8_t ffi_uniffi_automerge_rust_future_complete_i8(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_JOIN
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_JOIN
+RustBuffer uniffi_uniffi_automerge_fn_method_repo_join(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_U16
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_U16
-void ffi_uniffi_automerge_rust_future_poll_u16(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_LOAD_SYNC_STATE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_LOAD_SYNC_STATE
+RustBuffer uniffi_uniffi_automerge_fn_method_repo_load_sync_state(void*_Nonnull ptr, RustBuffer document_id, RustBuffer storage_id, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_U16
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_U16
-void ffi_uniffi_automerge_rust_future_cancel_u16(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_PEER_ID
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_PEER_ID
+RustBuffer uniffi_uniffi_automerge_fn_method_repo_peer_id(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_U16
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_U16
-void ffi_uniffi_automerge_rust_future_free_u16(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_PEERS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_PEERS
+RustBuffer uniffi_uniffi_automerge_fn_method_repo_peers(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_U16
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_U16
-uint16_t ffi_uniffi_automerge_rust_future_complete_u16(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_PHASE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_PHASE
+RustBuffer uniffi_uniffi_automerge_fn_method_repo_phase(void*_Nonnull ptr, RustBuffer document_id, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_I16
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_I16
-void ffi_uniffi_automerge_rust_future_poll_i16(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_RECEIVE_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_RECEIVE_MESSAGE
+RustBuffer uniffi_uniffi_automerge_fn_method_repo_receive_message(void*_Nonnull ptr, RustBuffer message, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_I16
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_I16
-void ffi_uniffi_automerge_rust_future_cancel_i16(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_REMOVE_PEER
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_REMOVE_PEER
+void uniffi_uniffi_automerge_fn_method_repo_remove_peer(void*_Nonnull ptr, RustBuffer peer_id, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_I16
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_I16
-void ffi_uniffi_automerge_rust_future_free_i16(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_REQUEST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_REQUEST
+void uniffi_uniffi_automerge_fn_method_repo_request(void*_Nonnull ptr, RustBuffer document_id, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_I16
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_I16
-int16_t ffi_uniffi_automerge_rust_future_complete_i16(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_SAVE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_SAVE
+void uniffi_uniffi_automerge_fn_method_repo_save(void*_Nonnull ptr, RustBuffer document_id, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_U32
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_U32
-void ffi_uniffi_automerge_rust_future_poll_u32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_SAVE_SYNC_STATE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_SAVE_SYNC_STATE
+void uniffi_uniffi_automerge_fn_method_repo_save_sync_state(void*_Nonnull ptr, RustBuffer document_id, RustBuffer storage_id, void*_Nonnull state, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_U32
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_U32
-void ffi_uniffi_automerge_rust_future_cancel_u32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_SET_NETWORK_ADAPTER
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_SET_NETWORK_ADAPTER
+void uniffi_uniffi_automerge_fn_method_repo_set_network_adapter(void*_Nonnull ptr, uint64_t network, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_U32
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_U32
-void ffi_uniffi_automerge_rust_future_free_u32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_STORED_DOCUMENT_IDS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_STORED_DOCUMENT_IDS
+RustBuffer uniffi_uniffi_automerge_fn_method_repo_stored_document_ids(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_U32
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_U32
-uint32_t ffi_uniffi_automerge_rust_future_complete_u32(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_SYNC
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_SYNC
+void uniffi_uniffi_automerge_fn_method_repo_sync(void*_Nonnull ptr, RustBuffer document_id, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_I32
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_I32
-void ffi_uniffi_automerge_rust_future_poll_i32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_SYNC_ALL
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_REPO_SYNC_ALL
+void uniffi_uniffi_automerge_fn_method_repo_sync_all(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_I32
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_I32
-void ffi_uniffi_automerge_rust_future_cancel_i32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CLONE_SYNCSESSION
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CLONE_SYNCSESSION
+void*_Nonnull uniffi_uniffi_automerge_fn_clone_syncsession(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_I32
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_I32
-void ffi_uniffi_automerge_rust_future_free_i32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FREE_SYNCSESSION
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FREE_SYNCSESSION
+void uniffi_uniffi_automerge_fn_free_syncsession(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_I32
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_I32
-int32_t ffi_uniffi_automerge_rust_future_complete_i32(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_SYNCSESSION_NEW
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_SYNCSESSION_NEW
+void*_Nonnull uniffi_uniffi_automerge_fn_constructor_syncsession_new(void*_Nonnull doc, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_U64
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_U64
-void ffi_uniffi_automerge_rust_future_poll_u64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_SYNCSESSION_RESTORE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_SYNCSESSION_RESTORE
+void*_Nonnull uniffi_uniffi_automerge_fn_constructor_syncsession_restore(void*_Nonnull doc, RustBuffer bytes, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_U64
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_U64
-void ffi_uniffi_automerge_rust_future_cancel_u64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSESSION_ADD_PEER
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSESSION_ADD_PEER
+void uniffi_uniffi_automerge_fn_method_syncsession_add_peer(void*_Nonnull ptr, RustBuffer peer, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_U64
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_U64
-void ffi_uniffi_automerge_rust_future_free_u64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSESSION_ADD_PEER_WITH_STATE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSESSION_ADD_PEER_WITH_STATE
+void uniffi_uniffi_automerge_fn_method_syncsession_add_peer_with_state(void*_Nonnull ptr, RustBuffer peer, void*_Nonnull state, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_U64
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_U64
-uint64_t ffi_uniffi_automerge_rust_future_complete_u64(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSESSION_DOC
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSESSION_DOC
+void*_Nonnull uniffi_uniffi_automerge_fn_method_syncsession_doc(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_I64
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_I64
-void ffi_uniffi_automerge_rust_future_poll_i64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSESSION_ENCODE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSESSION_ENCODE
+RustBuffer uniffi_uniffi_automerge_fn_method_syncsession_encode(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_I64
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_I64
-void ffi_uniffi_automerge_rust_future_cancel_i64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSESSION_GENERATE_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSESSION_GENERATE_MESSAGE
+RustBuffer uniffi_uniffi_automerge_fn_method_syncsession_generate_message(void*_Nonnull ptr, RustBuffer peer, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_I64
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_I64
-void ffi_uniffi_automerge_rust_future_free_i64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSESSION_GENERATE_MESSAGES
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSESSION_GENERATE_MESSAGES
+RustBuffer uniffi_uniffi_automerge_fn_method_syncsession_generate_messages(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_I64
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_I64
-int64_t ffi_uniffi_automerge_rust_future_complete_i64(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSESSION_PEER_STATE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSESSION_PEER_STATE
+RustBuffer uniffi_uniffi_automerge_fn_method_syncsession_peer_state(void*_Nonnull ptr, RustBuffer peer, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_F32
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_F32
-void ffi_uniffi_automerge_rust_future_poll_f32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSESSION_PEERS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSESSION_PEERS
+RustBuffer uniffi_uniffi_automerge_fn_method_syncsession_peers(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_F32
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_F32
-void ffi_uniffi_automerge_rust_future_cancel_f32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSESSION_RECEIVE_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSESSION_RECEIVE_MESSAGE
+void uniffi_uniffi_automerge_fn_method_syncsession_receive_message(void*_Nonnull ptr, RustBuffer peer, RustBuffer message, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_F32
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_F32
-void ffi_uniffi_automerge_rust_future_free_f32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSESSION_REMOVE_PEER
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSESSION_REMOVE_PEER
+void uniffi_uniffi_automerge_fn_method_syncsession_remove_peer(void*_Nonnull ptr, RustBuffer peer, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_F32
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_F32
-float ffi_uniffi_automerge_rust_future_complete_f32(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CLONE_SYNCSTATE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CLONE_SYNCSTATE
+void*_Nonnull uniffi_uniffi_automerge_fn_clone_syncstate(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_F64
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_F64
-void ffi_uniffi_automerge_rust_future_poll_f64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FREE_SYNCSTATE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FREE_SYNCSTATE
+void uniffi_uniffi_automerge_fn_free_syncstate(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_F64
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_F64
-void ffi_uniffi_automerge_rust_future_cancel_f64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_SYNCSTATE_DECODE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_SYNCSTATE_DECODE
+void*_Nonnull uniffi_uniffi_automerge_fn_constructor_syncstate_decode(RustBuffer bytes, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_F64
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_F64
-void ffi_uniffi_automerge_rust_future_free_f64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_SYNCSTATE_NEW
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_SYNCSTATE_NEW
+void*_Nonnull uniffi_uniffi_automerge_fn_constructor_syncstate_new(RustCallStatus *_Nonnull out_status
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_F64
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_F64
-double ffi_uniffi_automerge_rust_future_complete_f64(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_ENCODE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_ENCODE
+RustBuffer uniffi_uniffi_automerge_fn_method_syncstate_encode(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_POINTER
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_POINTER
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_IN_FLIGHT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_IN_FLIGHT
+int8_t uniffi_uniffi_automerge_fn_method_syncstate_in_flight(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_LAST_SENT_HEADS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_LAST_SENT_HEADS
+RustBuffer uniffi_uniffi_automerge_fn_method_syncstate_last_sent_heads(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_RESET
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_RESET
+void uniffi_uniffi_automerge_fn_method_syncstate_reset(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_SENT_HASHES_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_SENT_HASHES_COUNT
+uint64_t uniffi_uniffi_automerge_fn_method_syncstate_sent_hashes_count(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_SHARED_HEADS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_SHARED_HEADS
+RustBuffer uniffi_uniffi_automerge_fn_method_syncstate_shared_heads(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_THEIR_HAVE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_THEIR_HAVE
+RustBuffer uniffi_uniffi_automerge_fn_method_syncstate_their_have(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_THEIR_HEADS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_THEIR_HEADS
+RustBuffer uniffi_uniffi_automerge_fn_method_syncstate_their_heads(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_THEIR_NEED
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_SYNCSTATE_THEIR_NEED
+RustBuffer uniffi_uniffi_automerge_fn_method_syncstate_their_need(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CLONE_TRANSACTION
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CLONE_TRANSACTION
+void*_Nonnull uniffi_uniffi_automerge_fn_clone_transaction(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FREE_TRANSACTION
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FREE_TRANSACTION
+void uniffi_uniffi_automerge_fn_free_transaction(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_COMMIT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_COMMIT
+RustBuffer uniffi_uniffi_automerge_fn_method_transaction_commit(void*_Nonnull ptr, RustBuffer msg, int64_t time, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_DELETE_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_DELETE_IN_LIST
+void uniffi_uniffi_automerge_fn_method_transaction_delete_in_list(void*_Nonnull ptr, RustBuffer obj, uint64_t index, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_DELETE_IN_MAP
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_DELETE_IN_MAP
+void uniffi_uniffi_automerge_fn_method_transaction_delete_in_map(void*_Nonnull ptr, RustBuffer obj, RustBuffer key, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_INCREMENT_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_INCREMENT_IN_LIST
+void uniffi_uniffi_automerge_fn_method_transaction_increment_in_list(void*_Nonnull ptr, RustBuffer obj, uint64_t index, int64_t by, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_INCREMENT_IN_MAP
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_INCREMENT_IN_MAP
+void uniffi_uniffi_automerge_fn_method_transaction_increment_in_map(void*_Nonnull ptr, RustBuffer obj, RustBuffer key, int64_t by, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_INSERT_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_INSERT_IN_LIST
+void uniffi_uniffi_automerge_fn_method_transaction_insert_in_list(void*_Nonnull ptr, RustBuffer obj, uint64_t index, RustBuffer value, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_INSERT_OBJECT_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_INSERT_OBJECT_IN_LIST
+RustBuffer uniffi_uniffi_automerge_fn_method_transaction_insert_object_in_list(void*_Nonnull ptr, RustBuffer obj, uint64_t index, RustBuffer obj_type, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_MARK
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_MARK
+void uniffi_uniffi_automerge_fn_method_transaction_mark(void*_Nonnull ptr, RustBuffer obj, uint64_t start, uint64_t end, RustBuffer expand, RustBuffer name, RustBuffer value, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_PUT_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_PUT_IN_LIST
+void uniffi_uniffi_automerge_fn_method_transaction_put_in_list(void*_Nonnull ptr, RustBuffer obj, uint64_t index, RustBuffer value, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_PUT_IN_MAP
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_PUT_IN_MAP
+void uniffi_uniffi_automerge_fn_method_transaction_put_in_map(void*_Nonnull ptr, RustBuffer obj, RustBuffer key, RustBuffer value, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_PUT_OBJECT_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_PUT_OBJECT_IN_LIST
+RustBuffer uniffi_uniffi_automerge_fn_method_transaction_put_object_in_list(void*_Nonnull ptr, RustBuffer obj, uint64_t index, RustBuffer obj_type, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_PUT_OBJECT_IN_MAP
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_PUT_OBJECT_IN_MAP
+RustBuffer uniffi_uniffi_automerge_fn_method_transaction_put_object_in_map(void*_Nonnull ptr, RustBuffer obj, RustBuffer key, RustBuffer obj_type, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_ROLLBACK
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_ROLLBACK
+void uniffi_uniffi_automerge_fn_method_transaction_rollback(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_SPLICE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_SPLICE
+void uniffi_uniffi_automerge_fn_method_transaction_splice(void*_Nonnull ptr, RustBuffer obj, uint64_t start, int64_t delete, RustBuffer values, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_SPLICE_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_SPLICE_TEXT
+void uniffi_uniffi_automerge_fn_method_transaction_splice_text(void*_Nonnull ptr, RustBuffer obj, uint64_t start, int64_t delete, RustBuffer chars, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_UPDATE_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_TRANSACTION_UPDATE_TEXT
+void uniffi_uniffi_automerge_fn_method_transaction_update_text(void*_Nonnull ptr, RustBuffer obj, RustBuffer chars, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CLONE_UNDOMANAGER
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CLONE_UNDOMANAGER
+void*_Nonnull uniffi_uniffi_automerge_fn_clone_undomanager(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FREE_UNDOMANAGER
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FREE_UNDOMANAGER
+void uniffi_uniffi_automerge_fn_free_undomanager(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_UNDOMANAGER_NEW
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_CONSTRUCTOR_UNDOMANAGER_NEW
+void*_Nonnull uniffi_uniffi_automerge_fn_constructor_undomanager_new(void*_Nonnull doc, RustBuffer scope, RustBuffer expand, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_UNDOMANAGER_BEGIN_GROUP
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_UNDOMANAGER_BEGIN_GROUP
+void uniffi_uniffi_automerge_fn_method_undomanager_begin_group(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_UNDOMANAGER_CAN_REDO
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_UNDOMANAGER_CAN_REDO
+int8_t uniffi_uniffi_automerge_fn_method_undomanager_can_redo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_UNDOMANAGER_CAN_UNDO
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_UNDOMANAGER_CAN_UNDO
+int8_t uniffi_uniffi_automerge_fn_method_undomanager_can_undo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_UNDOMANAGER_CLEAR
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_UNDOMANAGER_CLEAR
+void uniffi_uniffi_automerge_fn_method_undomanager_clear(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_UNDOMANAGER_END_GROUP
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_UNDOMANAGER_END_GROUP
+void uniffi_uniffi_automerge_fn_method_undomanager_end_group(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_UNDOMANAGER_RECORD
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_UNDOMANAGER_RECORD
+void uniffi_uniffi_automerge_fn_method_undomanager_record(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_UNDOMANAGER_REDO
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_UNDOMANAGER_REDO
+int8_t uniffi_uniffi_automerge_fn_method_undomanager_redo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_UNDOMANAGER_UNDO
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_METHOD_UNDOMANAGER_UNDO
+int8_t uniffi_uniffi_automerge_fn_method_undomanager_undo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_INIT_CALLBACK_VTABLE_NETWORKADAPTER
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_INIT_CALLBACK_VTABLE_NETWORKADAPTER
+void uniffi_uniffi_automerge_fn_init_callback_vtable_networkadapter(UniffiVTableCallbackInterfaceNetworkAdapter* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_INIT_CALLBACK_VTABLE_PATCHOBSERVER
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_INIT_CALLBACK_VTABLE_PATCHOBSERVER
+void uniffi_uniffi_automerge_fn_init_callback_vtable_patchobserver(UniffiVTableCallbackInterfacePatchObserver* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_INIT_CALLBACK_VTABLE_STORAGEADAPTER
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_INIT_CALLBACK_VTABLE_STORAGEADAPTER
+void uniffi_uniffi_automerge_fn_init_callback_vtable_storageadapter(UniffiVTableCallbackInterfaceStorageAdapter* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FUNC_AUTOMERGE_URL
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FUNC_AUTOMERGE_URL
+RustBuffer uniffi_uniffi_automerge_fn_func_automerge_url(RustBuffer document_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FUNC_DECODE_CHANGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FUNC_DECODE_CHANGE
+RustBuffer uniffi_uniffi_automerge_fn_func_decode_change(RustBuffer bytes, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FUNC_DECODE_REPO_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FUNC_DECODE_REPO_MESSAGE
+RustBuffer uniffi_uniffi_automerge_fn_func_decode_repo_message(RustBuffer bytes, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FUNC_DECODE_SYNC_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FUNC_DECODE_SYNC_MESSAGE
+RustBuffer uniffi_uniffi_automerge_fn_func_decode_sync_message(RustBuffer bytes, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FUNC_ENCODE_REPO_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FUNC_ENCODE_REPO_MESSAGE
+RustBuffer uniffi_uniffi_automerge_fn_func_encode_repo_message(RustBuffer message, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FUNC_GENERATE_DOCUMENT_ID
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FUNC_GENERATE_DOCUMENT_ID
+RustBuffer uniffi_uniffi_automerge_fn_func_generate_document_id(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FUNC_ROOT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_FN_FUNC_ROOT
+RustBuffer uniffi_uniffi_automerge_fn_func_root(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUSTBUFFER_ALLOC
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUSTBUFFER_ALLOC
+RustBuffer ffi_uniffi_automerge_rustbuffer_alloc(uint64_t size, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUSTBUFFER_FROM_BYTES
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUSTBUFFER_FROM_BYTES
+RustBuffer ffi_uniffi_automerge_rustbuffer_from_bytes(ForeignBytes bytes, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUSTBUFFER_FREE
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUSTBUFFER_FREE
+void ffi_uniffi_automerge_rustbuffer_free(RustBuffer buf, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUSTBUFFER_RESERVE
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUSTBUFFER_RESERVE
+RustBuffer ffi_uniffi_automerge_rustbuffer_reserve(RustBuffer buf, uint64_t additional, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_U8
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_U8
+void ffi_uniffi_automerge_rust_future_poll_u8(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_U8
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_U8
+void ffi_uniffi_automerge_rust_future_cancel_u8(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_U8
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_U8
+void ffi_uniffi_automerge_rust_future_free_u8(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_U8
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_U8
+uint8_t ffi_uniffi_automerge_rust_future_complete_u8(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_I8
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_I8
+void ffi_uniffi_automerge_rust_future_poll_i8(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_I8
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_I8
+void ffi_uniffi_automerge_rust_future_cancel_i8(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_I8
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_I8
+void ffi_uniffi_automerge_rust_future_free_i8(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_I8
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_I8
+int8_t ffi_uniffi_automerge_rust_future_complete_i8(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_U16
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_U16
+void ffi_uniffi_automerge_rust_future_poll_u16(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_U16
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_U16
+void ffi_uniffi_automerge_rust_future_cancel_u16(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_U16
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_U16
+void ffi_uniffi_automerge_rust_future_free_u16(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_U16
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_U16
+uint16_t ffi_uniffi_automerge_rust_future_complete_u16(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_I16
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_I16
+void ffi_uniffi_automerge_rust_future_poll_i16(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_I16
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_I16
+void ffi_uniffi_automerge_rust_future_cancel_i16(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_I16
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_I16
+void ffi_uniffi_automerge_rust_future_free_i16(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_I16
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_I16
+int16_t ffi_uniffi_automerge_rust_future_complete_i16(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_U32
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_U32
+void ffi_uniffi_automerge_rust_future_poll_u32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_U32
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_U32
+void ffi_uniffi_automerge_rust_future_cancel_u32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_U32
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_U32
+void ffi_uniffi_automerge_rust_future_free_u32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_U32
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_U32
+uint32_t ffi_uniffi_automerge_rust_future_complete_u32(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_I32
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_I32
+void ffi_uniffi_automerge_rust_future_poll_i32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_I32
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_I32
+void ffi_uniffi_automerge_rust_future_cancel_i32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_I32
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_I32
+void ffi_uniffi_automerge_rust_future_free_i32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_I32
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_I32
+int32_t ffi_uniffi_automerge_rust_future_complete_i32(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_U64
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_U64
+void ffi_uniffi_automerge_rust_future_poll_u64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_U64
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_U64
+void ffi_uniffi_automerge_rust_future_cancel_u64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_U64
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_U64
+void ffi_uniffi_automerge_rust_future_free_u64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_U64
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_U64
+uint64_t ffi_uniffi_automerge_rust_future_complete_u64(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_I64
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_I64
+void ffi_uniffi_automerge_rust_future_poll_i64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_I64
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_I64
+void ffi_uniffi_automerge_rust_future_cancel_i64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_I64
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_I64
+void ffi_uniffi_automerge_rust_future_free_i64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_I64
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_I64
+int64_t ffi_uniffi_automerge_rust_future_complete_i64(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_F32
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_F32
+void ffi_uniffi_automerge_rust_future_poll_f32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_F32
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_F32
+void ffi_uniffi_automerge_rust_future_cancel_f32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_F32
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_F32
+void ffi_uniffi_automerge_rust_future_free_f32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_F32
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_F32
+float ffi_uniffi_automerge_rust_future_complete_f32(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_F64
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_F64
+void ffi_uniffi_automerge_rust_future_poll_f64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_F64
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_F64
+void ffi_uniffi_automerge_rust_future_cancel_f64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_F64
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_F64
+void ffi_uniffi_automerge_rust_future_free_f64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_F64
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_F64
+double ffi_uniffi_automerge_rust_future_complete_f64(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_POINTER
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_POINTER
 void ffi_uniffi_automerge_rust_future_poll_pointer(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_POINTER
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_POINTER
-void ffi_uniffi_automerge_rust_future_cancel_pointer(uint64_t handle
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_POINTER
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_POINTER
+void ffi_uniffi_automerge_rust_future_cancel_pointer(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_POINTER
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_POINTER
+void ffi_uniffi_automerge_rust_future_free_pointer(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_POINTER
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_POINTER
+void*_Nonnull ffi_uniffi_automerge_rust_future_complete_pointer(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_RUST_BUFFER
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_RUST_BUFFER
+void ffi_uniffi_automerge_rust_future_poll_rust_buffer(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_RUST_BUFFER
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_RUST_BUFFER
+void ffi_uniffi_automerge_rust_future_cancel_rust_buffer(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_RUST_BUFFER
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_RUST_BUFFER
+void ffi_uniffi_automerge_rust_future_free_rust_buffer(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_RUST_BUFFER
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_RUST_BUFFER
+RustBuffer ffi_uniffi_automerge_rust_future_complete_rust_buffer(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_VOID
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_VOID
+void ffi_uniffi_automerge_rust_future_poll_void(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_VOID
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_VOID
+void ffi_uniffi_automerge_rust_future_cancel_void(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_VOID
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_VOID
+void ffi_uniffi_automerge_rust_future_free_void(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_VOID
+#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_VOID
+void ffi_uniffi_automerge_rust_future_complete_void(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_FUNC_AUTOMERGE_URL
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_FUNC_AUTOMERGE_URL
+uint16_t uniffi_uniffi_automerge_checksum_func_automerge_url(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_FUNC_DECODE_CHANGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_FUNC_DECODE_CHANGE
+uint16_t uniffi_uniffi_automerge_checksum_func_decode_change(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_FUNC_DECODE_REPO_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_FUNC_DECODE_REPO_MESSAGE
+uint16_t uniffi_uniffi_automerge_checksum_func_decode_repo_message(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_FUNC_DECODE_SYNC_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_FUNC_DECODE_SYNC_MESSAGE
+uint16_t uniffi_uniffi_automerge_checksum_func_decode_sync_message(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_FUNC_ENCODE_REPO_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_FUNC_ENCODE_REPO_MESSAGE
+uint16_t uniffi_uniffi_automerge_checksum_func_encode_repo_message(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_FUNC_GENERATE_DOCUMENT_ID
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_FUNC_GENERATE_DOCUMENT_ID
+uint16_t uniffi_uniffi_automerge_checksum_func_generate_document_id(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_FUNC_ROOT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_FUNC_ROOT
+uint16_t uniffi_uniffi_automerge_checksum_func_root(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_ACTOR_ID
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_ACTOR_ID
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_actor_id(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_APPLY_ENCODED_CHANGES
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_APPLY_ENCODED_CHANGES
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_apply_encoded_changes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_APPLY_ENCODED_CHANGES_WITH_PATCHES
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_APPLY_ENCODED_CHANGES_WITH_PATCHES
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_apply_encoded_changes_with_patches(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_BEGIN_TRANSACTION
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_BEGIN_TRANSACTION
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_begin_transaction(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_BLAME_PROPERTY
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_BLAME_PROPERTY
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_blame_property(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_BLAME_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_BLAME_TEXT
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_blame_text(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CHANGE_BY_HASH
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CHANGE_BY_HASH
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_change_by_hash(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CHANGES
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CHANGES
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_changes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CHECKOUT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CHECKOUT
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_checkout(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CHERRY_PICK
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CHERRY_PICK
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_cherry_pick(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CHERRY_PICK_ENCODED
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CHERRY_PICK_ENCODED
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_cherry_pick_encoded(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CLEAR_PATCH_OBSERVER
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CLEAR_PATCH_OBSERVER
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_clear_patch_observer(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_COMMIT_WITH
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_COMMIT_WITH
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_commit_with(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CREATE_BRANCH
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CREATE_BRANCH
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_create_branch(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CURSOR
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CURSOR
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_cursor(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CURSOR_AT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CURSOR_AT
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_cursor_at(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CURSOR_POSITION
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CURSOR_POSITION
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_cursor_position(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CURSOR_POSITION_AT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CURSOR_POSITION_AT
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_cursor_position_at(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_DELETE_BRANCH
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_DELETE_BRANCH
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_delete_branch(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_DELETE_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_DELETE_IN_LIST
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_delete_in_list(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_DELETE_IN_MAP
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_DELETE_IN_MAP
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_delete_in_map(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_DIFFERENCE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_DIFFERENCE
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_difference(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_DIFFERENCE_IN
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_DIFFERENCE_IN
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_difference_in(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_DIFFERENCE_SUMMARY
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_DIFFERENCE_SUMMARY
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_difference_summary(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_ENCODE_CHANGES_SINCE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_ENCODE_CHANGES_SINCE
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_encode_changes_since(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_ENCODE_NEW_CHANGES
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_ENCODE_NEW_CHANGES
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_encode_new_changes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_FORK
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_FORK
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_fork(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_FORK_AT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_FORK_AT
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_fork_at(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GENERATE_SYNC_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GENERATE_SYNC_MESSAGE
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_generate_sync_message(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_ALL_AT_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_ALL_AT_IN_LIST
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_get_all_at_in_list(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_ALL_AT_IN_MAP
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_ALL_AT_IN_MAP
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_get_all_at_in_map(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_ALL_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_ALL_IN_LIST
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_get_all_in_list(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_ALL_IN_MAP
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_ALL_IN_MAP
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_get_all_in_map(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_AT_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_AT_IN_LIST
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_get_at_in_list(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_AT_IN_MAP
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_AT_IN_MAP
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_get_at_in_map(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_CHANGES
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_CHANGES
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_get_changes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_CONFLICTS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_CONFLICTS
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_get_conflicts(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_IN_LIST
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_get_in_list(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_IN_MAP
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_IN_MAP
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_get_in_map(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_HEADS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_HEADS
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_heads(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_HYDRATE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_HYDRATE
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_hydrate(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_INCREMENT_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_INCREMENT_IN_LIST
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_increment_in_list(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_INCREMENT_IN_MAP
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_INCREMENT_IN_MAP
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_increment_in_map(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_INSERT_HYDRATED
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_INSERT_HYDRATED
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_insert_hydrated(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_POINTER
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_POINTER
-void ffi_uniffi_automerge_rust_future_free_pointer(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_INSERT_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_INSERT_IN_LIST
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_insert_in_list(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_POINTER
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_POINTER
-void*_Nonnull ffi_uniffi_automerge_rust_future_complete_pointer(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_INSERT_OBJECT_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_INSERT_OBJECT_IN_LIST
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_insert_object_in_list(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_RUST_BUFFER
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_RUST_BUFFER
-void ffi_uniffi_automerge_rust_future_poll_rust_buffer(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_JOIN_BLOCK
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_JOIN_BLOCK
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_join_block(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_RUST_BUFFER
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_RUST_BUFFER
-void ffi_uniffi_automerge_rust_future_cancel_rust_buffer(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_LENGTH
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_LENGTH
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_length(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_RUST_BUFFER
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_RUST_BUFFER
-void ffi_uniffi_automerge_rust_future_free_rust_buffer(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_LENGTH_AT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_LENGTH_AT
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_length_at(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_RUST_BUFFER
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_RUST_BUFFER
-RustBuffer ffi_uniffi_automerge_rust_future_complete_rust_buffer(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_LIST_BRANCHES
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_LIST_BRANCHES
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_list_branches(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_VOID
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_POLL_VOID
-void ffi_uniffi_automerge_rust_future_poll_void(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_LIST_RANGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_LIST_RANGE
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_list_range(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_VOID
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_CANCEL_VOID
-void ffi_uniffi_automerge_rust_future_cancel_void(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MAP_ENTRIES
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MAP_ENTRIES
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_map_entries(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MAP_ENTRIES_AT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MAP_ENTRIES_AT
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_map_entries_at(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MAP_KEYS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MAP_KEYS
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_map_keys(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MAP_KEYS_AT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MAP_KEYS_AT
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_map_keys_at(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MAP_RANGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MAP_RANGE
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_map_range(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MARK
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MARK
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_mark(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MARKS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MARKS
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_marks(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MARKS_AT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MARKS_AT
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_marks_at(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MARKS_AT_POSITION
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MARKS_AT_POSITION
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_marks_at_position(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MERGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MERGE
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_merge(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MERGE_BRANCH
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MERGE_BRANCH
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_merge_branch(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MERGE_WITH_PATCHES
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MERGE_WITH_PATCHES
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_merge_with_patches(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_OBJECT_TYPE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_OBJECT_TYPE
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_object_type(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PATH
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PATH
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_path(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PUT_HYDRATED
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PUT_HYDRATED
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_put_hydrated(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PUT_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PUT_IN_LIST
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_put_in_list(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PUT_IN_MAP
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PUT_IN_MAP
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_put_in_map(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PUT_JSON
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PUT_JSON
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_put_json(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PUT_OBJECT_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PUT_OBJECT_IN_LIST
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_put_object_in_list(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PUT_OBJECT_IN_MAP
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PUT_OBJECT_IN_MAP
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_put_object_in_map(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_RECEIVE_SYNC_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_RECEIVE_SYNC_MESSAGE
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_receive_sync_message(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_RECEIVE_SYNC_MESSAGE_WITH_PATCHES
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_RECEIVE_SYNC_MESSAGE_WITH_PATCHES
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_receive_sync_message_with_patches(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_RESOLVE_CONFLICT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_RESOLVE_CONFLICT
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_resolve_conflict(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_REVERT_TO
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_REVERT_TO
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_revert_to(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SAVE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SAVE
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_save(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SAVE_INCREMENTAL
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SAVE_INCREMENTAL
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_save_incremental(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SAVE_NO_COMPRESS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SAVE_NO_COMPRESS
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_save_no_compress(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SET_ACTOR
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SET_ACTOR
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_set_actor(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SET_PATCH_OBSERVER
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SET_PATCH_OBSERVER
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_set_patch_observer(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SPLICE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SPLICE
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_splice(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SPLICE_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SPLICE_TEXT
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_splice_text(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SPLIT_BLOCK
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SPLIT_BLOCK
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_split_block(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_TEXT
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_text(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_TEXT_AT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_TEXT_AT
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_text_at(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_TEXT_ENCODING
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_TEXT_ENCODING
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_text_encoding(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_TO_JSON
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_TO_JSON
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_to_json(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_UPDATE_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_UPDATE_TEXT
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_update_text(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_VALUES
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_VALUES
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_values(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_VALUES_AT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_VALUES_AT
+uint16_t uniffi_uniffi_automerge_checksum_method_doc_values_at(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_PRESENCE_ENCODE_LEAVE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_PRESENCE_ENCODE_LEAVE
+uint16_t uniffi_uniffi_automerge_checksum_method_presence_encode_leave(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_VOID
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_FREE_VOID
-void ffi_uniffi_automerge_rust_future_free_void(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_PRESENCE_ENCODE_UPDATE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_PRESENCE_ENCODE_UPDATE
+uint16_t uniffi_uniffi_automerge_checksum_method_presence_encode_update(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_VOID
-#define UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_RUST_FUTURE_COMPLETE_VOID
-void ffi_uniffi_automerge_rust_future_complete_void(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_PRESENCE_PRUNE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_PRESENCE_PRUNE
+uint16_t uniffi_uniffi_automerge_checksum_method_presence_prune(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_FUNC_ROOT
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_FUNC_ROOT
-uint16_t uniffi_uniffi_automerge_checksum_func_root(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_PRESENCE_RECEIVE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_PRESENCE_RECEIVE
+uint16_t uniffi_uniffi_automerge_checksum_method_presence_receive(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_ACTOR_ID
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_ACTOR_ID
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_actor_id(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_PRESENCE_STATES
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_PRESENCE_STATES
+uint16_t uniffi_uniffi_automerge_checksum_method_presence_states(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_APPLY_ENCODED_CHANGES
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_APPLY_ENCODED_CHANGES
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_apply_encoded_changes(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_ADD_PEER
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_ADD_PEER
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_add_peer(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_APPLY_ENCODED_CHANGES_WITH_PATCHES
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_APPLY_ENCODED_CHANGES_WITH_PATCHES
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_apply_encoded_changes_with_patches(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_BROADCAST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_BROADCAST
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_broadcast(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CHANGE_BY_HASH
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CHANGE_BY_HASH
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_change_by_hash(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_COMPACT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_COMPACT
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_compact(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CHANGES
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CHANGES
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_changes(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_CREATE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_CREATE
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_create(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_COMMIT_WITH
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_COMMIT_WITH
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_commit_with(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_DELETE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_DELETE
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_delete(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CURSOR
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CURSOR
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_cursor(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_DOCUMENT_IDS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_DOCUMENT_IDS
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_document_ids(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CURSOR_AT
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CURSOR_AT
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_cursor_at(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_FIND
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_FIND
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_find(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CURSOR_POSITION
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CURSOR_POSITION
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_cursor_position(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_IMPORT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_IMPORT
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_import(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CURSOR_POSITION_AT
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_CURSOR_POSITION_AT
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_cursor_position_at(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_JOIN
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_JOIN
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_join(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_DELETE_IN_LIST
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_DELETE_IN_LIST
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_delete_in_list(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_LOAD_SYNC_STATE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_LOAD_SYNC_STATE
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_load_sync_state(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_DELETE_IN_MAP
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_DELETE_IN_MAP
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_delete_in_map(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_PEER_ID
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_PEER_ID
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_peer_id(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_DIFFERENCE
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_DIFFERENCE
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_difference(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_PEERS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_PEERS
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_peers(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_ENCODE_CHANGES_SINCE
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_ENCODE_CHANGES_SINCE
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_encode_changes_since(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_PHASE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_PHASE
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_phase(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_ENCODE_NEW_CHANGES
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_ENCODE_NEW_CHANGES
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_encode_new_changes(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_RECEIVE_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_RECEIVE_MESSAGE
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_receive_message(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_FORK
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_FORK
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_fork(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_REMOVE_PEER
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_REMOVE_PEER
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_remove_peer(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_FORK_AT
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_FORK_AT
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_fork_at(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_REQUEST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_REQUEST
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_request(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GENERATE_SYNC_MESSAGE
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GENERATE_SYNC_MESSAGE
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_generate_sync_message(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_SAVE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_SAVE
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_save(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_ALL_AT_IN_LIST
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_ALL_AT_IN_LIST
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_get_all_at_in_list(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_SAVE_SYNC_STATE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_SAVE_SYNC_STATE
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_save_sync_state(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_ALL_AT_IN_MAP
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_ALL_AT_IN_MAP
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_get_all_at_in_map(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_SET_NETWORK_ADAPTER
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_SET_NETWORK_ADAPTER
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_set_network_adapter(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_ALL_IN_LIST
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_ALL_IN_LIST
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_get_all_in_list(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_STORED_DOCUMENT_IDS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_STORED_DOCUMENT_IDS
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_stored_document_ids(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_ALL_IN_MAP
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_ALL_IN_MAP
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_get_all_in_map(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_SYNC
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_SYNC
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_sync(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_AT_IN_LIST
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_AT_IN_LIST
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_get_at_in_list(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_SYNC_ALL
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_REPO_SYNC_ALL
+uint16_t uniffi_uniffi_automerge_checksum_method_repo_sync_all(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_AT_IN_MAP
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_AT_IN_MAP
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_get_at_in_map(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSESSION_ADD_PEER
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSESSION_ADD_PEER
+uint16_t uniffi_uniffi_automerge_checksum_method_syncsession_add_peer(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_IN_LIST
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_IN_LIST
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_get_in_list(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSESSION_ADD_PEER_WITH_STATE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSESSION_ADD_PEER_WITH_STATE
+uint16_t uniffi_uniffi_automerge_checksum_method_syncsession_add_peer_with_state(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_IN_MAP
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_GET_IN_MAP
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_get_in_map(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSESSION_DOC
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSESSION_DOC
+uint16_t uniffi_uniffi_automerge_checksum_method_syncsession_doc(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_HEADS
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_HEADS
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_heads(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSESSION_ENCODE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSESSION_ENCODE
+uint16_t uniffi_uniffi_automerge_checksum_method_syncsession_encode(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_INCREMENT_IN_LIST
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_INCREMENT_IN_LIST
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_increment_in_list(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSESSION_GENERATE_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSESSION_GENERATE_MESSAGE
+uint16_t uniffi_uniffi_automerge_checksum_method_syncsession_generate_message(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_INCREMENT_IN_MAP
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_INCREMENT_IN_MAP
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_increment_in_map(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSESSION_GENERATE_MESSAGES
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSESSION_GENERATE_MESSAGES
+uint16_t uniffi_uniffi_automerge_checksum_method_syncsession_generate_messages(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_INSERT_IN_LIST
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_INSERT_IN_LIST
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_insert_in_list(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSESSION_PEER_STATE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSESSION_PEER_STATE
+uint16_t uniffi_uniffi_automerge_checksum_method_syncsession_peer_state(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_INSERT_OBJECT_IN_LIST
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_INSERT_OBJECT_IN_LIST
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_insert_object_in_list(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSESSION_PEERS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSESSION_PEERS
+uint16_t uniffi_uniffi_automerge_checksum_method_syncsession_peers(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_JOIN_BLOCK
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_JOIN_BLOCK
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_join_block(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSESSION_RECEIVE_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSESSION_RECEIVE_MESSAGE
+uint16_t uniffi_uniffi_automerge_checksum_method_syncsession_receive_message(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_LENGTH
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_LENGTH
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_length(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSESSION_REMOVE_PEER
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSESSION_REMOVE_PEER
+uint16_t uniffi_uniffi_automerge_checksum_method_syncsession_remove_peer(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_LENGTH_AT
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_LENGTH_AT
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_length_at(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_ENCODE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_ENCODE
+uint16_t uniffi_uniffi_automerge_checksum_method_syncstate_encode(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MAP_ENTRIES
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MAP_ENTRIES
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_map_entries(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_IN_FLIGHT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_IN_FLIGHT
+uint16_t uniffi_uniffi_automerge_checksum_method_syncstate_in_flight(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MAP_ENTRIES_AT
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MAP_ENTRIES_AT
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_map_entries_at(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_LAST_SENT_HEADS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_LAST_SENT_HEADS
+uint16_t uniffi_uniffi_automerge_checksum_method_syncstate_last_sent_heads(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MAP_KEYS
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MAP_KEYS
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_map_keys(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_RESET
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_RESET
+uint16_t uniffi_uniffi_automerge_checksum_method_syncstate_reset(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MAP_KEYS_AT
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MAP_KEYS_AT
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_map_keys_at(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_SENT_HASHES_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_SENT_HASHES_COUNT
+uint16_t uniffi_uniffi_automerge_checksum_method_syncstate_sent_hashes_count(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MARK
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MARK
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_mark(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_SHARED_HEADS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_SHARED_HEADS
+uint16_t uniffi_uniffi_automerge_checksum_method_syncstate_shared_heads(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MARKS
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MARKS
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_marks(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_THEIR_HAVE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_THEIR_HAVE
+uint16_t uniffi_uniffi_automerge_checksum_method_syncstate_their_have(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MARKS_AT
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MARKS_AT
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_marks_at(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_THEIR_HEADS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_THEIR_HEADS
+uint16_t uniffi_uniffi_automerge_checksum_method_syncstate_their_heads(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MARKS_AT_POSITION
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MARKS_AT_POSITION
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_marks_at_position(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_THEIR_NEED
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_THEIR_NEED
+uint16_t uniffi_uniffi_automerge_checksum_method_syncstate_their_need(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MERGE
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MERGE
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_merge(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_COMMIT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_COMMIT
+uint16_t uniffi_uniffi_automerge_checksum_method_transaction_commit(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MERGE_WITH_PATCHES
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_MERGE_WITH_PATCHES
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_merge_with_patches(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_DELETE_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_DELETE_IN_LIST
+uint16_t uniffi_uniffi_automerge_checksum_method_transaction_delete_in_list(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_OBJECT_TYPE
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_OBJECT_TYPE
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_object_type(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_DELETE_IN_MAP
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_DELETE_IN_MAP
+uint16_t uniffi_uniffi_automerge_checksum_method_transaction_delete_in_map(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PATH
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PATH
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_path(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_INCREMENT_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_INCREMENT_IN_LIST
+uint16_t uniffi_uniffi_automerge_checksum_method_transaction_increment_in_list(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PUT_IN_LIST
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PUT_IN_LIST
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_put_in_list(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_INCREMENT_IN_MAP
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_INCREMENT_IN_MAP
+uint16_t uniffi_uniffi_automerge_checksum_method_transaction_increment_in_map(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PUT_IN_MAP
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PUT_IN_MAP
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_put_in_map(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_INSERT_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_INSERT_IN_LIST
+uint16_t uniffi_uniffi_automerge_checksum_method_transaction_insert_in_list(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PUT_OBJECT_IN_LIST
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PUT_OBJECT_IN_LIST
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_put_object_in_list(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_INSERT_OBJECT_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_INSERT_OBJECT_IN_LIST
+uint16_t uniffi_uniffi_automerge_checksum_method_transaction_insert_object_in_list(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PUT_OBJECT_IN_MAP
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_PUT_OBJECT_IN_MAP
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_put_object_in_map(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_MARK
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_MARK
+uint16_t uniffi_uniffi_automerge_checksum_method_transaction_mark(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_RECEIVE_SYNC_MESSAGE
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_RECEIVE_SYNC_MESSAGE
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_receive_sync_message(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_PUT_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_PUT_IN_LIST
+uint16_t uniffi_uniffi_automerge_checksum_method_transaction_put_in_list(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_RECEIVE_SYNC_MESSAGE_WITH_PATCHES
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_RECEIVE_SYNC_MESSAGE_WITH_PATCHES
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_receive_sync_message_with_patches(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_PUT_IN_MAP
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_PUT_IN_MAP
+uint16_t uniffi_uniffi_automerge_checksum_method_transaction_put_in_map(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SAVE
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SAVE
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_save(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_PUT_OBJECT_IN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_PUT_OBJECT_IN_LIST
+uint16_t uniffi_uniffi_automerge_checksum_method_transaction_put_object_in_list(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SET_ACTOR
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SET_ACTOR
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_set_actor(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_PUT_OBJECT_IN_MAP
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_PUT_OBJECT_IN_MAP
+uint16_t uniffi_uniffi_automerge_checksum_method_transaction_put_object_in_map(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SPLICE
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SPLICE
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_splice(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_ROLLBACK
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_ROLLBACK
+uint16_t uniffi_uniffi_automerge_checksum_method_transaction_rollback(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SPLICE_TEXT
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SPLICE_TEXT
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_splice_text(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_SPLICE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_SPLICE
+uint16_t uniffi_uniffi_automerge_checksum_method_transaction_splice(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SPLIT_BLOCK
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_SPLIT_BLOCK
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_split_block(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_SPLICE_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_SPLICE_TEXT
+uint16_t uniffi_uniffi_automerge_checksum_method_transaction_splice_text(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_TEXT
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_TEXT
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_text(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_UPDATE_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_TRANSACTION_UPDATE_TEXT
+uint16_t uniffi_uniffi_automerge_checksum_method_transaction_update_text(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_TEXT_AT
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_TEXT_AT
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_text_at(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_UNDOMANAGER_BEGIN_GROUP
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_UNDOMANAGER_BEGIN_GROUP
+uint16_t uniffi_uniffi_automerge_checksum_method_undomanager_begin_group(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_TEXT_ENCODING
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_TEXT_ENCODING
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_text_encoding(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_UNDOMANAGER_CAN_REDO
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_UNDOMANAGER_CAN_REDO
+uint16_t uniffi_uniffi_automerge_checksum_method_undomanager_can_redo(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_UPDATE_TEXT
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_UPDATE_TEXT
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_update_text(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_UNDOMANAGER_CAN_UNDO
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_UNDOMANAGER_CAN_UNDO
+uint16_t uniffi_uniffi_automerge_checksum_method_undomanager_can_undo(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_VALUES
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_VALUES
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_values(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_UNDOMANAGER_CLEAR
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_UNDOMANAGER_CLEAR
+uint16_t uniffi_uniffi_automerge_checksum_method_undomanager_clear(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_VALUES_AT
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_DOC_VALUES_AT
-uint16_t uniffi_uniffi_automerge_checksum_method_doc_values_at(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_UNDOMANAGER_END_GROUP
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_UNDOMANAGER_END_GROUP
+uint16_t uniffi_uniffi_automerge_checksum_method_undomanager_end_group(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_ENCODE
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_ENCODE
-uint16_t uniffi_uniffi_automerge_checksum_method_syncstate_encode(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_UNDOMANAGER_RECORD
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_UNDOMANAGER_RECORD
+uint16_t uniffi_uniffi_automerge_checksum_method_undomanager_record(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_RESET
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_RESET
-uint16_t uniffi_uniffi_automerge_checksum_method_syncstate_reset(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_UNDOMANAGER_REDO
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_UNDOMANAGER_REDO
+uint16_t uniffi_uniffi_automerge_checksum_method_undomanager_redo(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_THEIR_HEADS
-#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_SYNCSTATE_THEIR_HEADS
-uint16_t uniffi_uniffi_automerge_checksum_method_syncstate_their_heads(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_UNDOMANAGER_UNDO
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_UNDOMANAGER_UNDO
+uint16_t uniffi_uniffi_automerge_checksum_method_undomanager_undo(void
     
 );
 #endif
@@ -1325,6 +2590,12 @@ uint16_t uniffi_uniffi_automerge_checksum_method_syncstate_their_heads(void
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_CONSTRUCTOR_DOC_LOAD
 uint16_t uniffi_uniffi_automerge_checksum_constructor_doc_load(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_CONSTRUCTOR_DOC_LOAD_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_CONSTRUCTOR_DOC_LOAD_WITH_OPTIONS
+uint16_t uniffi_uniffi_automerge_checksum_constructor_doc_load_with_options(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_CONSTRUCTOR_DOC_NEW
@@ -1343,6 +2614,30 @@ uint16_t uniffi_uniffi_automerge_checksum_constructor_doc_new_with_actor(void
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_CONSTRUCTOR_DOC_NEW_WITH_TEXT_ENCODING
 uint16_t uniffi_uniffi_automerge_checksum_constructor_doc_new_with_text_encoding(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_CONSTRUCTOR_PRESENCE_NEW
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_CONSTRUCTOR_PRESENCE_NEW
+uint16_t uniffi_uniffi_automerge_checksum_constructor_presence_new(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_CONSTRUCTOR_REPO_NEW
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_CONSTRUCTOR_REPO_NEW
+uint16_t uniffi_uniffi_automerge_checksum_constructor_repo_new(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_CONSTRUCTOR_SYNCSESSION_NEW
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_CONSTRUCTOR_SYNCSESSION_NEW
+uint16_t uniffi_uniffi_automerge_checksum_constructor_syncsession_new(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_CONSTRUCTOR_SYNCSESSION_RESTORE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_CONSTRUCTOR_SYNCSESSION_RESTORE
+uint16_t uniffi_uniffi_automerge_checksum_constructor_syncsession_restore(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_CONSTRUCTOR_SYNCSTATE_DECODE
@@ -1355,6 +2650,48 @@ uint16_t uniffi_uniffi_automerge_checksum_constructor_syncstate_decode(void
 #define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_CONSTRUCTOR_SYNCSTATE_NEW
 uint16_t uniffi_uniffi_automerge_checksum_constructor_syncstate_new(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_CONSTRUCTOR_UNDOMANAGER_NEW
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_CONSTRUCTOR_UNDOMANAGER_NEW
+uint16_t uniffi_uniffi_automerge_checksum_constructor_undomanager_new(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_NETWORKADAPTER_SEND
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_NETWORKADAPTER_SEND
+uint16_t uniffi_uniffi_automerge_checksum_method_networkadapter_send(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_PATCHOBSERVER_ON_PATCHES
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_PATCHOBSERVER_ON_PATCHES
+uint16_t uniffi_uniffi_automerge_checksum_method_patchobserver_on_patches(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_STORAGEADAPTER_LOAD
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_STORAGEADAPTER_LOAD
+uint16_t uniffi_uniffi_automerge_checksum_method_storageadapter_load(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_STORAGEADAPTER_SAVE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_STORAGEADAPTER_SAVE
+uint16_t uniffi_uniffi_automerge_checksum_method_storageadapter_save(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_STORAGEADAPTER_REMOVE
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_STORAGEADAPTER_REMOVE
+uint16_t uniffi_uniffi_automerge_checksum_method_storageadapter_remove(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_STORAGEADAPTER_LIST
+#define UNIFFI_FFIDEF_UNIFFI_UNIFFI_AUTOMERGE_CHECKSUM_METHOD_STORAGEADAPTER_LIST
+uint16_t uniffi_uniffi_automerge_checksum_method_storageadapter_list(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_FFI_UNIFFI_AUTOMERGE_UNIFFI_CONTRACT_VERSION
@@ -91,7 +91,7 @@ class ActorTests: XCTestCase {
         _ = doc.save()
 
         // Verify the changes
-        let changes = doc.getHistory().map { doc.change(hash: $0) }
+        let changes = try doc.getHistory().map { try doc.change(hash: $0) }
         XCTAssertEqual(changes.count, 2)
         XCTAssertEqual(changes[0]!.actorId, actor1)
         XCTAssertEqual(changes[1]!.actorId, actor2)
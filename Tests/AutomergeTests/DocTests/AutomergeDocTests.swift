@@ -233,7 +233,7 @@ final class AutomergeDocTests: XCTestCase {
 
         try doc.spliceText(obj: textId, start: start, delete: delete) // delete "👨‍👨‍👧‍👦"
 
-        let stringLength = doc.length(obj: textId)
+        let stringLength = try doc.length(obj: textId)
         XCTAssertEqual(stringLength, start + end)
 
         let text = try doc.text(obj: textId)
@@ -280,7 +280,7 @@ final class AutomergeDocTests: XCTestCase {
         let history = doc.getHistory()
         XCTAssertEqual(history.count, 5)
 
-        let changes = history.map { doc.change(hash: $0) }
+        let changes = try history.map { try doc.change(hash: $0) }
         XCTAssertEqual(changes.count, 5)
         XCTAssertEqual(changes[0]!.message, "Change 1")
         XCTAssertEqual(changes[0]!.timestamp, Date(timeIntervalSince1970: 10))
@@ -0,0 +1,55 @@
+import Automerge
+import XCTest
+
+class BlameTests: XCTestCase {
+    func testBlameReturnsTheChangeThatLastSetAProperty() throws {
+        let alice = Document()
+        try alice.put(obj: ObjId.ROOT, key: "title", value: .String("Draft"))
+        alice.commitWith(message: "Add a title", timestamp: Date(timeIntervalSince1970: 1000))
+        let bob = alice.fork()
+        try bob.put(obj: ObjId.ROOT, key: "title", value: .String("Final"))
+        bob.commitWith(message: "Rename", timestamp: Date(timeIntervalSince1970: 2000))
+        try alice.merge(other: bob)
+
+        let blame = try XCTUnwrap(alice.blame(obj: ObjId.ROOT, key: "title"))
+        XCTAssertEqual(blame.actor, bob.actor)
+        XCTAssertEqual(blame.change, bob.heads().first)
+        XCTAssertEqual(blame.seq, 1)
+        XCTAssertEqual(blame.message, "Rename")
+        XCTAssertEqual(blame.timestamp, Date(timeIntervalSince1970: 2000))
+        XCTAssertNil(try alice.blame(obj: ObjId.ROOT, key: "missing"))
+    }
+
+    func testUncommittedEditsHaveNoAttribution() throws {
+        let doc = Document()
+        let list = try doc.putObject(obj: ObjId.ROOT, key: "list", ty: .List)
+        try doc.insert(obj: list, index: 0, value: .Int(1))
+        XCTAssertNil(try doc.blame(obj: list, index: 0))
+
+        doc.commitWith(message: "Add a number")
+        XCTAssertEqual(try doc.blame(obj: list, index: 0)?.message, "Add a number")
+    }
+
+    func testBlameTextSplitsTextByChange() throws {
+        let alice = Document()
+        let text = try alice.putObject(obj: ObjId.ROOT, key: "text", ty: .Text)
+        try alice.spliceText(obj: text, start: 0, delete: 0, value: "hello world")
+        alice.commitWith(message: "Alice")
+        let bob = alice.fork()
+        try bob.spliceText(obj: text, start: 5, delete: 0, value: ", dear")
+        bob.commitWith(message: "Bob")
+        try alice.merge(other: bob)
+
+        let spans = try alice.blameText(obj: text)
+        XCTAssertEqual(spans.map(\.text), ["hello", ", dear", " world"])
+        XCTAssertEqual(spans.map(\.start), [0, 5, 11])
+        XCTAssertEqual(spans.map(\.end), [5, 11, 17])
+        XCTAssertEqual(spans.map(\.attribution?.message), ["Alice", "Bob", "Alice"])
+        XCTAssertEqual(spans.map(\.attribution?.actor), [alice.actor, bob.actor, alice.actor])
+    }
+
+    func testBlameTextOfAnotherObjectTypeThrows() throws {
+        let doc = Document()
+        XCTAssertThrowsError(try doc.blameText(obj: ObjId.ROOT))
+    }
+}
@@ -0,0 +1,51 @@
+import Automerge
+import XCTest
+
+class BranchTests: XCTestCase {
+    func testCheckingOutAndMergingABranch() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "title", value: .String("Draft"))
+        try doc.createBranch("review", heads: doc.heads())
+        XCTAssertEqual(doc.branches().map(\.name), ["review"])
+
+        let review = try doc.checkout(branch: "review")
+        try review.put(obj: ObjId.ROOT, key: "title", value: .String("Final"))
+        try doc.mergeBranch("review", from: review)
+
+        XCTAssertEqual(try doc.get(obj: ObjId.ROOT, key: "title"), .Scalar(.String("Final")))
+        XCTAssertEqual(doc.branches().first?.heads, review.heads())
+    }
+
+    func testBranchesAreSavedWithTheDocument() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "title", value: .String("Draft"))
+        let heads = doc.heads()
+        try doc.createBranch("review", heads: heads)
+
+        let loaded = try Document(doc.save())
+        XCTAssertEqual(loaded.branches().first?.heads, heads)
+        try loaded.deleteBranch("review")
+        XCTAssertEqual(loaded.branches(), [])
+    }
+
+    func testBranchesAreHiddenFromTheContent() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "title", value: .String("Draft"))
+        let before = doc.heads()
+        try doc.createBranch("review", heads: before)
+
+        XCTAssertEqual(try doc.keys(obj: ObjId.ROOT), ["title"])
+        XCTAssertEqual(try doc.length(obj: ObjId.ROOT), 1)
+        XCTAssertNil(try doc.get(obj: ObjId.ROOT, key: "automerge-swift:branches"))
+        XCTAssertEqual(try doc.toJSON(obj: ObjId.ROOT), #"{"title":"Draft"}"#)
+        XCTAssertEqual(try doc.difference(from: before, to: doc.heads()), [])
+        XCTAssertThrowsError(try doc.put(obj: ObjId.ROOT, key: "automerge-swift:branches", value: .Int(1)))
+    }
+
+    func testUnknownBranchesThrow() throws {
+        let doc = Document()
+        XCTAssertThrowsError(try doc.checkout(branch: "missing"))
+        XCTAssertThrowsError(try doc.deleteBranch("missing"))
+        XCTAssertThrowsError(try doc.mergeBranch("missing", from: doc.fork()))
+    }
+}
@@ -0,0 +1,37 @@
+import Automerge
+import XCTest
+
+class ChangeMetadataTestCase: XCTestCase {
+    func testChangesSinceHeads() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "a", value: .Int(1))
+        doc.commitWith(message: "first")
+        let heads = doc.heads()
+        try doc.put(obj: ObjId.ROOT, key: "b", value: .Int(2))
+        doc.commitWith(message: "second")
+
+        let all = try doc.changes(since: [])
+        XCTAssertEqual(all.map(\.message), ["first", "second"])
+        XCTAssertEqual(all.map(\.seq), [1, 2])
+        XCTAssertEqual(all.map(\.actorId), [doc.actor, doc.actor])
+
+        let recent = try doc.changes(since: heads)
+        XCTAssertEqual(recent.count, 1)
+        XCTAssertEqual(recent.first?.message, "second")
+        XCTAssertEqual(recent.first?.deps, Array(heads))
+    }
+
+    func testDecodeChange() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "a", value: .Int(1))
+        doc.commitWith(message: "first")
+        let change = try XCTUnwrap(doc.change(hash: try XCTUnwrap(doc.heads().first)))
+
+        let decoded = try Change(decoding: change.bytes)
+        XCTAssertEqual(decoded, change)
+    }
+
+    func testDecodeInvalidChangeThrows() {
+        XCTAssertThrowsError(try Change(decoding: Data([1, 2, 3])))
+    }
+}
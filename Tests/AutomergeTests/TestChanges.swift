@@ -40,7 +40,7 @@ class ChangeSetTests: XCTestCase {
         let before = doc.heads()
         try doc.spliceText(obj: textId, start: 5, delete: 0, value: " World 👨‍👩‍👧‍👦")
 
-        let patches = doc.difference(to: before)
+        let patches = try doc.difference(to: before)
         let length = UInt64(" World 👨‍👩‍👧‍👦".unicodeScalars.count)
         XCTAssertEqual(patches.count, 1)
         XCTAssertEqual(patches.first?.action, .DeleteSeq(DeleteSeq(obj: textId, index: 5, length: length)))
@@ -54,7 +54,7 @@ class ChangeSetTests: XCTestCase {
         let before = doc.heads()
         try doc.spliceText(obj: textId, start: 5, delete: 0, value: " World 👨‍👩‍👧‍👦")
 
-        let patches = doc.difference(since: before)
+        let patches = try doc.difference(since: before)
         XCTAssertEqual(patches.count, 1)
         XCTAssertEqual(patches.first?.action, .SpliceText(obj: textId, index: 5, value: " World 👨‍👩‍👧‍👦", marks: [:]))
     }
@@ -67,7 +67,7 @@ class ChangeSetTests: XCTestCase {
         try doc.spliceText(obj: textId, start: 5, delete: 0, value: " World 👨‍👩‍👧‍👦")
         let after = doc.heads()
 
-        let patches = doc.difference(from: before, to: after)
+        let patches = try doc.difference(from: before, to: after)
         XCTAssertEqual(patches.count, 1)
         XCTAssertEqual(patches.first?.action, .SpliceText(obj: textId, index: 0, value: "Hello World 👨‍👩‍👧‍👦", marks: [:]))
     }
@@ -79,8 +79,8 @@ class ChangeSetTests: XCTestCase {
         try doc.spliceText(obj: textId, start: 0, delete: 0, value: "Hello")
         try doc.spliceText(obj: textId, start: 5, delete: 0, value: " World 👨‍👩‍👧‍👦")
 
-        let patches1 = doc.difference(from: before, to: doc.heads())
-        let patches2 = doc.difference(since: before)
+        let patches1 = try doc.difference(from: before, to: doc.heads())
+        let patches2 = try doc.difference(since: before)
         XCTAssertEqual(patches1.count, 1)
         XCTAssertEqual(patches1, patches2)
     }
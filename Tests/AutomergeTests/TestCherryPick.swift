@@ -0,0 +1,70 @@
+import Automerge
+import XCTest
+
+class CherryPickTests: XCTestCase {
+    func testPickingAChangeAppliesItAndItsDependencies() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "title", value: .String("Draft"))
+        let base = doc.heads()
+        let review = doc.fork()
+
+        try review.put(obj: ObjId.ROOT, key: "author", value: .String("Alice"))
+        let author = try XCTUnwrap(review.heads().first)
+        try review.put(obj: ObjId.ROOT, key: "title", value: .String("Final"))
+        let title = try XCTUnwrap(review.heads().first)
+        try review.put(obj: ObjId.ROOT, key: "draft", value: .Boolean(false))
+
+        let picked = try doc.cherryPick(from: review, changes: [title])
+        XCTAssertEqual(picked.applied, [title])
+        XCTAssertEqual(picked.dependencies, [author])
+        XCTAssertEqual(try doc.get(obj: ObjId.ROOT, key: "title"), .Scalar(.String("Final")))
+        XCTAssertEqual(try doc.get(obj: ObjId.ROOT, key: "author"), .Scalar(.String("Alice")))
+        XCTAssertNil(try doc.get(obj: ObjId.ROOT, key: "draft"))
+        XCTAssertNotEqual(doc.heads(), base)
+    }
+
+    func testPickingChangesTheDocumentAlreadyHasAppliesNothing() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "title", value: .String("Draft"))
+        let review = doc.fork()
+        let picked = try doc.cherryPick(from: review, changes: Array(doc.heads()))
+        XCTAssertEqual(picked.applied, [])
+        XCTAssertEqual(picked.dependencies, [])
+    }
+
+    func testPickingFromTheSameDocumentReportsPendingEdits() throws {
+        let doc = Document()
+        var reported: [[Patch]] = []
+        doc.observePatches { reported.append($0) }
+        try doc.put(obj: ObjId.ROOT, key: "title", value: .String("Draft"))
+        XCTAssertTrue(reported.isEmpty)
+
+        try doc.cherryPick(from: doc, changes: [])
+        XCTAssertEqual(reported.count, 1)
+    }
+
+    func testPickingFromEncodedChanges() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "title", value: .String("Draft"))
+        let review = doc.fork()
+        let base = review.heads()
+        try review.put(obj: ObjId.ROOT, key: "title", value: .String("Final"))
+        let title = try XCTUnwrap(review.heads().first)
+
+        let picked = try doc.cherryPick(encoded: review.encodeChangesSince(heads: base), changes: [title])
+        XCTAssertEqual(picked.applied, [title])
+        XCTAssertEqual(picked.dependencies, [])
+        XCTAssertEqual(try doc.get(obj: ObjId.ROOT, key: "title"), .Scalar(.String("Final")))
+    }
+
+    func testPickingAnUnknownChangeThrowsAndLeavesTheDocumentAlone() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "title", value: .String("Draft"))
+        let heads = doc.heads()
+        let other = Document()
+        try other.put(obj: ObjId.ROOT, key: "title", value: .String("Other"))
+
+        XCTAssertThrowsError(try doc.cherryPick(from: Document(), changes: Array(other.heads())))
+        XCTAssertEqual(doc.heads(), heads)
+    }
+}
@@ -0,0 +1,101 @@
+import Automerge
+import XCTest
+
+class ConflictTests: XCTestCase {
+    func testConflictsIdentifyWhoWroteEachValue() throws {
+        let alice = Document()
+        try alice.put(obj: ObjId.ROOT, key: "title", value: .String("Draft"))
+        let bob = alice.fork()
+        try alice.put(obj: ObjId.ROOT, key: "title", value: .String("Alice's title"))
+        try bob.put(obj: ObjId.ROOT, key: "title", value: .String("Bob's title"))
+        let aliceChange = alice.heads().first
+        let bobChange = bob.heads().first
+        try alice.merge(other: bob)
+
+        let conflicts = try alice.conflicts(obj: ObjId.ROOT, key: "title")
+        XCTAssertEqual(conflicts.count, 2)
+        XCTAssertEqual(conflicts.filter(\.isWinner).count, 1)
+        XCTAssertTrue(conflicts.last!.isWinner)
+        XCTAssertEqual(try alice.get(obj: ObjId.ROOT, key: "title"), conflicts.last!.value)
+
+        let byActor = Dictionary(uniqueKeysWithValues: conflicts.map { ($0.actor, $0) })
+        XCTAssertEqual(byActor[alice.actor]?.value, .Scalar(.String("Alice's title")))
+        XCTAssertEqual(byActor[alice.actor]?.change, aliceChange)
+        XCTAssertEqual(byActor[bob.actor]?.value, .Scalar(.String("Bob's title")))
+        XCTAssertEqual(byActor[bob.actor]?.change, bobChange)
+        XCTAssertNotEqual(byActor[alice.actor]?.opId, byActor[bob.actor]?.opId)
+    }
+
+    func testAPropertyWithoutConflictsHasOneWinner() throws {
+        let doc = Document()
+        let list = try doc.putObject(obj: ObjId.ROOT, key: "list", ty: .List)
+        try doc.insert(obj: list, index: 0, value: .Int(1))
+
+        let conflicts = try doc.conflicts(obj: list, index: 0)
+        XCTAssertEqual(conflicts.map(\.value), [.Scalar(.Int(1))])
+        XCTAssertEqual(conflicts.map(\.isWinner), [true])
+        XCTAssertEqual(try doc.conflicts(obj: ObjId.ROOT, key: "missing"), [])
+    }
+
+    func testConflictsAtHeads() throws {
+        let alice = Document()
+        try alice.put(obj: ObjId.ROOT, key: "title", value: .String("Draft"))
+        let heads = alice.heads()
+        let bob = alice.fork()
+        try alice.put(obj: ObjId.ROOT, key: "title", value: .String("Alice's title"))
+        try bob.put(obj: ObjId.ROOT, key: "title", value: .String("Bob's title"))
+        try alice.merge(other: bob)
+
+        XCTAssertEqual(try alice.conflicts(obj: ObjId.ROOT, key: "title", heads: heads).map(\.value), [
+            .Scalar(.String("Draft")),
+        ])
+    }
+
+    func testResolvingAConflictKeepsTheChosenValue() throws {
+        let alice = Document()
+        try alice.put(obj: ObjId.ROOT, key: "title", value: .String("Draft"))
+        let bob = alice.fork()
+        try alice.put(obj: ObjId.ROOT, key: "title", value: .String("Alice's title"))
+        try bob.put(obj: ObjId.ROOT, key: "title", value: .String("Bob's title"))
+        try alice.merge(other: bob)
+
+        let loser = try XCTUnwrap(alice.conflicts(obj: ObjId.ROOT, key: "title").first { !$0.isWinner })
+        XCTAssertNil(try alice.resolveConflict(obj: ObjId.ROOT, key: "title", chosen: loser))
+        XCTAssertEqual(try alice.get(obj: ObjId.ROOT, key: "title"), loser.value)
+        XCTAssertEqual(try alice.conflicts(obj: ObjId.ROOT, key: "title").count, 1)
+
+        try bob.merge(other: alice)
+        XCTAssertEqual(try bob.get(obj: ObjId.ROOT, key: "title"), loser.value)
+    }
+
+    func testResolvingAConflictCopiesAChosenObject() throws {
+        let alice = Document()
+        let list = try alice.putObject(obj: ObjId.ROOT, key: "list", ty: .List)
+        try alice.insert(obj: list, index: 0, value: .Null)
+        let bob = alice.fork()
+        try alice.putHydrated(obj: list, index: 0, value: .Map(["name": .Scalar(.String("Alice"))]))
+        try bob.putHydrated(obj: list, index: 0, value: .Map(["name": .Scalar(.String("Bob")), "tags": .List([])]))
+        try alice.merge(other: bob)
+
+        let conflicts = try alice.conflicts(obj: list, index: 0)
+        let chosen = try XCTUnwrap(conflicts.first { $0.actor == bob.actor })
+        guard case let .Object(original, .Map) = chosen.value else {
+            return XCTFail("expected the chosen value to be a map")
+        }
+        let copy = try XCTUnwrap(alice.resolveConflict(obj: list, index: 0, chosen: chosen))
+
+        XCTAssertNotEqual(copy, original)
+        XCTAssertEqual(try alice.get(obj: list, index: 0), .Object(copy, .Map))
+        XCTAssertEqual(try alice.hydrate(obj: copy), try alice.hydrate(obj: original))
+        XCTAssertEqual(try alice.conflicts(obj: list, index: 0).count, 1)
+    }
+
+    func testResolvingAnUnknownOperationThrows() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "title", value: .String("Draft"))
+        let other = Document()
+        try other.put(obj: ObjId.ROOT, key: "title", value: .String("Other"))
+        let stranger = try XCTUnwrap(other.conflicts(obj: ObjId.ROOT, key: "title").first)
+        XCTAssertThrowsError(try doc.resolveConflict(obj: ObjId.ROOT, key: "title", chosen: stranger))
+    }
+}
@@ -0,0 +1,48 @@
+import Automerge
+import XCTest
+
+class ErrorsTestCase: XCTestCase {
+    func testLoadingChangesWithoutTheirDependenciesListsTheMissingChanges() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "a", value: .Int(1))
+        let first = doc.heads()
+        try doc.put(obj: ObjId.ROOT, key: "b", value: .Int(2))
+        let second = try doc.encodeChangesSince(heads: first)
+
+        XCTAssertThrowsError(try Document(second)) { error in
+            XCTAssertTrue(error is LoadError)
+            XCTAssertEqual(
+                error.localizedDescription,
+                "Error loading a document, 1 changes depend on changes which are missing"
+            )
+        }
+    }
+
+    func testDuplicateSequenceNumbersHaveTheirOwnError() throws {
+        let actor = ActorId()
+        let ours = Document()
+        ours.actor = actor
+        try ours.put(obj: ObjId.ROOT, key: "key", value: .String("ours"))
+        let theirs = Document()
+        theirs.actor = actor
+        try theirs.put(obj: ObjId.ROOT, key: "key", value: .String("theirs"))
+
+        XCTAssertThrowsError(try ours.applyEncodedChanges(encoded: theirs.save())) { error in
+            XCTAssertTrue(error is DocError)
+            XCTAssertTrue(error.localizedDescription.contains("DuplicateSeqNumber"))
+        }
+    }
+
+    func testReadingAnObjectFromAnotherDocumentThrows() throws {
+        let other = Document()
+        let list = try other.putObject(obj: ObjId.ROOT, key: "list", ty: .List)
+        let doc = Document()
+
+        XCTAssertThrowsError(try doc.keys(obj: list))
+        XCTAssertThrowsError(try doc.keysAt(obj: list, heads: doc.heads()))
+        XCTAssertThrowsError(try doc.length(obj: list))
+        XCTAssertThrowsError(try doc.lengthAt(obj: list, heads: doc.heads()))
+        XCTAssertThrowsError(try doc.objectType(obj: list))
+        XCTAssertThrowsError(try doc.difference(from: [], to: other.heads()))
+    }
+}
@@ -0,0 +1,104 @@
+import Automerge
+import XCTest
+
+class HydrateTestCase: XCTestCase {
+    func testPutHydratedCreatesNestedObjects() throws {
+        let doc = Document()
+        let contact = try XCTUnwrap(doc.putHydrated(obj: ObjId.ROOT, key: "contact", value: .Map([
+            "name": .Scalar(.String("Alice")),
+            "tags": .List([.Scalar(.String("friend")), .Scalar(.Int(3))]),
+            "notes": .Text("Met at the conference", marks: [
+                Mark(start: 7, end: 9, name: "bold", value: .Boolean(true)),
+            ]),
+        ])))
+
+        XCTAssertEqual(try doc.get(obj: ObjId.ROOT, key: "contact"), .Object(contact, .Map))
+        XCTAssertEqual(try doc.get(obj: contact, key: "name"), .Scalar(.String("Alice")))
+
+        guard case let .Object(tags, .List) = try doc.get(obj: contact, key: "tags") else {
+            return XCTFail("expected a list of tags")
+        }
+        XCTAssertEqual(try doc.length(obj: tags), 2)
+        XCTAssertEqual(try doc.get(obj: tags, index: 1), .Scalar(.Int(3)))
+
+        guard case let .Object(notes, .Text) = try doc.get(obj: contact, key: "notes") else {
+            return XCTFail("expected text notes")
+        }
+        XCTAssertEqual(try doc.text(obj: notes), "Met at the conference")
+        XCTAssertEqual(try doc.marks(obj: notes), [Mark(start: 7, end: 9, name: "bold", value: .Boolean(true))])
+    }
+
+    func testHydratedMarksExpandAsTheValueSays() throws {
+        let doc = Document()
+        let bold = Mark(start: 0, end: 5, name: "bold", value: .Boolean(true))
+        let notes = try XCTUnwrap(doc.putHydrated(
+            obj: ObjId.ROOT,
+            key: "notes",
+            value: .Text("hello", marks: [bold], expand: .none)
+        ))
+        try doc.spliceText(obj: notes, start: 5, delete: 0, value: "!")
+        XCTAssertEqual(try doc.marks(obj: notes), [bold])
+    }
+
+    func testPutHydratedCreatesOneChange() throws {
+        let doc = Document()
+        try doc.putHydrated(obj: ObjId.ROOT, key: "contact", value: .Map([
+            "name": .Scalar(.String("Alice")),
+            "email": .Scalar(.String("alice@example.com")),
+        ]))
+        XCTAssertEqual(doc.getHistory().count, 1)
+    }
+
+    func testHydratedScalarsReturnNoObject() throws {
+        let doc = Document()
+        XCTAssertNil(try doc.putHydrated(obj: ObjId.ROOT, key: "count", value: .Scalar(.Uint(1))))
+        XCTAssertEqual(try doc.get(obj: ObjId.ROOT, key: "count"), .Scalar(.Uint(1)))
+    }
+
+    func testHydratedValuesInLists() throws {
+        let doc = Document()
+        let list = try doc.putObject(obj: ObjId.ROOT, key: "list", ty: .List)
+        let first = try XCTUnwrap(doc.insertHydrated(obj: list, index: 0, value: .Map(["n": .Scalar(.Int(1))])))
+        try doc.insertHydrated(obj: list, index: 0, value: .Scalar(.Int(0)))
+        let second = try XCTUnwrap(doc.putHydrated(obj: list, index: 0, value: .List([.Scalar(.Int(2))])))
+
+        XCTAssertEqual(try doc.length(obj: list), 2)
+        XCTAssertEqual(try doc.get(obj: list, index: 0), .Object(second, .List))
+        XCTAssertEqual(try doc.get(obj: list, index: 1), .Object(first, .Map))
+        XCTAssertEqual(try doc.get(obj: first, key: "n"), .Scalar(.Int(1)))
+    }
+
+    func testPuttingAKeyInAListThrows() throws {
+        let doc = Document()
+        let list = try doc.putObject(obj: ObjId.ROOT, key: "list", ty: .List)
+        XCTAssertThrowsError(try doc.putHydrated(obj: list, key: "name", value: .Scalar(.String("Alice"))))
+    }
+
+    func testHydrateReadsAnObjectAndEverythingInIt() throws {
+        let doc = Document()
+        let value: HydrateValue = .Map([
+            "name": .Scalar(.String("Alice")),
+            "visits": .Scalar(.Counter(2)),
+            "tags": .List([
+                .Scalar(.String("friend")),
+                .Map(["since": .Scalar(.Timestamp(Date(timeIntervalSince1970: 0)))]),
+            ]),
+            "notes": .Text("Met at the conference", marks: [
+                Mark(start: 7, end: 9, name: "bold", value: .Boolean(true)),
+            ]),
+        ])
+        let contact = try XCTUnwrap(doc.putHydrated(obj: ObjId.ROOT, key: "contact", value: value))
+        XCTAssertEqual(try doc.hydrate(obj: contact), value)
+        XCTAssertEqual(try doc.hydrate(obj: ObjId.ROOT), .Map(["contact": value]))
+    }
+
+    func testHydrateReadsAnEarlierState() throws {
+        let doc = Document()
+        try doc.putHydrated(obj: ObjId.ROOT, key: "name", value: .Scalar(.String("Alice")))
+        let heads = doc.heads()
+        try doc.put(obj: ObjId.ROOT, key: "name", value: .String("Bob"))
+
+        XCTAssertEqual(try doc.hydrate(obj: ObjId.ROOT, heads: heads), .Map(["name": .Scalar(.String("Alice"))]))
+        XCTAssertEqual(try doc.hydrate(obj: ObjId.ROOT), .Map(["name": .Scalar(.String("Bob"))]))
+    }
+}
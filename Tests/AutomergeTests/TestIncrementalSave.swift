@@ -0,0 +1,37 @@
+import Automerge
+import XCTest
+
+class IncrementalSaveTestCase: XCTestCase {
+    func testAppendedIncrementalSavesLoad() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "a", value: .Int(1))
+        var stored = doc.save()
+
+        try doc.put(obj: ObjId.ROOT, key: "b", value: .Int(2))
+        stored.append(doc.saveIncremental())
+        XCTAssertTrue(doc.saveIncremental().isEmpty)
+
+        let loaded = try Document(stored)
+        XCTAssertEqual(try loaded.keys(obj: ObjId.ROOT), ["a", "b"])
+    }
+
+    func testEncodingNewChangesDoesNotSkipIncrementalSaves() throws {
+        let doc = Document()
+        var stored = doc.save()
+
+        try doc.put(obj: ObjId.ROOT, key: "a", value: .Int(1))
+        XCTAssertFalse(doc.encodeNewChanges().isEmpty)
+        stored.append(doc.saveIncremental())
+
+        let loaded = try Document(stored)
+        XCTAssertEqual(try loaded.get(obj: ObjId.ROOT, key: "a"), .Scalar(.Int(1)))
+    }
+
+    func testUncompressedSaveLoads() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "a", value: .Int(1))
+
+        let loaded = try Document(doc.saveUncompressed())
+        XCTAssertEqual(try loaded.get(obj: ObjId.ROOT, key: "a"), .Scalar(.Int(1)))
+    }
+}
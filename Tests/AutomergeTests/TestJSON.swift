@@ -0,0 +1,38 @@
+import Automerge
+import XCTest
+
+class JSONTestCase: XCTestCase {
+    func testTaggedValuesRoundTrip() throws {
+        let doc = Document()
+        let json = #"{"count":{"$counter":5},"text":{"$text":"hello"}}"#
+        try doc.putJSON(obj: ObjId.ROOT, key: "value", json: json)
+
+        guard case let .Object(value, .Map) = try doc.get(obj: ObjId.ROOT, key: "value") else {
+            return XCTFail("expected a map")
+        }
+        guard case let .Object(text, .Text) = try doc.get(obj: value, key: "text") else {
+            return XCTFail("expected a text object")
+        }
+        XCTAssertEqual(try doc.text(obj: text), "hello")
+        XCTAssertEqual(try doc.toJSON(obj: value), json)
+    }
+
+    func testKeysStartingWithADollarAreEscaped() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "$text", value: .String("not text"))
+        XCTAssertEqual(try doc.toJSON(obj: ObjId.ROOT), #"{"$$text":"not text"}"#)
+
+        let copy = Document()
+        try copy.putJSON(obj: ObjId.ROOT, key: "copy", json: try doc.toJSON(obj: ObjId.ROOT))
+        guard case let .Object(value, .Map) = try copy.get(obj: ObjId.ROOT, key: "copy") else {
+            return XCTFail("expected a map")
+        }
+        XCTAssertEqual(try copy.get(obj: value, key: "$text"), .Scalar(.String("not text")))
+    }
+
+    func testNonFiniteFloatsThrow() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "number", value: .F64(.nan))
+        XCTAssertThrowsError(try doc.toJSON(obj: ObjId.ROOT))
+    }
+}
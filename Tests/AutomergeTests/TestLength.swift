@@ -2,16 +2,16 @@ import Automerge
 import XCTest
 
 class LengthTestCase: XCTestCase {
-    func testLength() {
+    func testLength() throws {
         let doc = Document()
         let list = try! doc.putObject(obj: ObjId.ROOT, key: "list", ty: .List)
         try! doc.insert(obj: list, index: 0, value: .String("one"))
         try! doc.insert(obj: list, index: 1, value: .String("two"))
 
-        XCTAssertEqual(doc.length(obj: list), 2)
+        XCTAssertEqual(try doc.length(obj: list), 2)
     }
 
-    func testLengthAt() {
+    func testLengthAt() throws {
         let doc = Document()
         let list = try! doc.putObject(obj: ObjId.ROOT, key: "list", ty: .List)
         try! doc.insert(obj: list, index: 0, value: .String("one"))
@@ -21,6 +21,6 @@ class LengthTestCase: XCTestCase {
 
         try! doc.insert(obj: list, index: 2, value: .String("three"))
 
-        XCTAssertEqual(doc.lengthAt(obj: list, heads: heads), 2)
+        XCTAssertEqual(try doc.lengthAt(obj: list, heads: heads), 2)
     }
 }
@@ -0,0 +1,30 @@
+import Automerge
+import XCTest
+
+class LoadOptionsTestCase: XCTestCase {
+    func testPartialLoadCanBeIgnored() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "a", value: .Int(1))
+        let first = doc.heads()
+        try doc.put(obj: ObjId.ROOT, key: "b", value: .Int(2))
+        let second = try doc.encodeChangesSince(heads: first)
+
+        XCTAssertThrowsError(try Document(second, options: LoadOptions()))
+        let partial = try Document(second, options: LoadOptions(onPartialLoad: .ignore))
+        XCTAssertNil(try partial.get(obj: ObjId.ROOT, key: "b"))
+    }
+
+    func testActorAndTextEncoding() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "a", value: .Int(1))
+
+        let actor = ActorId()
+        let loaded = try Document(
+            doc.save(),
+            options: LoadOptions(verificationMode: .dontCheck, textEncoding: .utf16, actor: actor)
+        )
+        XCTAssertEqual(loaded.actor, actor)
+        XCTAssertEqual(loaded.textEncoding, .utf16)
+        XCTAssertEqual(try loaded.get(obj: ObjId.ROOT, key: "a"), .Scalar(.Int(1)))
+    }
+}
@@ -2,16 +2,16 @@ import Automerge
 import XCTest
 
 class MapKeysTests: XCTestCase {
-    func testMapKeys() {
+    func testMapKeys() throws {
         let doc = Document()
         try! doc.put(obj: ObjId.ROOT, key: "key1", value: .String("one"))
         try! doc.put(obj: ObjId.ROOT, key: "key2", value: .String("two"))
 
-        let keys = doc.keys(obj: ObjId.ROOT)
+        let keys = try doc.keys(obj: ObjId.ROOT)
         XCTAssertEqual(keys, ["key1", "key2"])
     }
 
-    func testMapKeysAt() {
+    func testMapKeysAt() throws {
         let doc = Document()
         try! doc.put(obj: ObjId.ROOT, key: "key1", value: .String("one"))
         try! doc.put(obj: ObjId.ROOT, key: "key2", value: .String("two"))
@@ -19,7 +19,7 @@ class MapKeysTests: XCTestCase {
         let heads = doc.heads()
 
         try! doc.put(obj: ObjId.ROOT, key: "key3", value: .String("two"))
-        let keys = doc.keysAt(obj: ObjId.ROOT, heads: heads)
+        let keys = try doc.keysAt(obj: ObjId.ROOT, heads: heads)
         XCTAssertEqual(keys, ["key1", "key2"])
     }
 }
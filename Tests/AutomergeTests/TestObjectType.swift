@@ -2,26 +2,26 @@ import Automerge
 import XCTest
 
 class ObjectTypeTestCase: XCTestCase {
-    func testRootObjectType() {
+    func testRootObjectType() throws {
         let doc = Document()
-        XCTAssertEqual(doc.objectType(obj: ObjId.ROOT), .Map)
+        XCTAssertEqual(try doc.objectType(obj: ObjId.ROOT), .Map)
     }
 
-    func testCheckingListObjectType() {
+    func testCheckingListObjectType() throws {
         let doc = Document()
         let list = try! doc.putObject(obj: ObjId.ROOT, key: "list", ty: .List)
-        XCTAssertEqual(doc.objectType(obj: list), .List)
+        XCTAssertEqual(try doc.objectType(obj: list), .List)
     }
 
-    func testCheckingMapObjectType() {
+    func testCheckingMapObjectType() throws {
         let doc = Document()
         let map = try! doc.putObject(obj: ObjId.ROOT, key: "map", ty: .Map)
-        XCTAssertEqual(doc.objectType(obj: map), .Map)
+        XCTAssertEqual(try doc.objectType(obj: map), .Map)
     }
 
-    func testCheckingTextObjectType() {
+    func testCheckingTextObjectType() throws {
         let doc = Document()
         let text = try! doc.putObject(obj: ObjId.ROOT, key: "map", ty: .Text)
-        XCTAssertEqual(doc.objectType(obj: text), .Text)
+        XCTAssertEqual(try doc.objectType(obj: text), .Text)
     }
 }
@@ -4,6 +4,21 @@ import Combine
 import XCTest
 
 class ObservableDocumentTestCase: XCTestCase {
+    func testUndoingSendsAnUpdate() throws {
+        let doc = Document()
+        let undoManager = try DocumentUndoManager(document: doc)
+        try doc.put(obj: ObjId.ROOT, key: "title", value: .String("Draft"))
+        undoManager.record()
+
+        var countOfWillChangeUpdates = 0
+        let willChangeHandle = doc.objectWillChange.sink {
+            countOfWillChangeUpdates += 1
+        }
+        try undoManager.undo()
+        XCTAssertEqual(countOfWillChangeUpdates, 1)
+        XCTAssertNotNil(willChangeHandle)
+    }
+
     func testCountingUpdatesReceivedWhileUpdatingDocument() throws {
         let doc = Document()
 
@@ -0,0 +1,52 @@
+import Automerge
+import XCTest
+
+class PatchObserverTestCase: XCTestCase {
+    func testLocalEditsAreReportedWhenCommitted() throws {
+        let doc = Document()
+        var reported: [[Patch]] = []
+        doc.observePatches { reported.append($0) }
+
+        try doc.put(obj: ObjId.ROOT, key: "a", value: .Int(1))
+        try doc.put(obj: ObjId.ROOT, key: "b", value: .Int(2))
+        XCTAssertTrue(reported.isEmpty)
+
+        doc.commitWith(message: "two edits")
+        XCTAssertEqual(reported.count, 1)
+        XCTAssertEqual(reported.first?.count, 2)
+        XCTAssertEqual(doc.getHistory().count, 1)
+    }
+
+    func testUnbatchedObserverSeesEveryEdit() throws {
+        let doc = Document()
+        var reported: [[Patch]] = []
+        doc.observePatches(batchPerCommit: false) { reported.append($0) }
+
+        try doc.put(obj: ObjId.ROOT, key: "a", value: .Int(1))
+        try doc.put(obj: ObjId.ROOT, key: "b", value: .Int(2))
+        XCTAssertEqual(reported.count, 2)
+    }
+
+    func testIncomingChangesAreReported() throws {
+        let doc = Document()
+        let other = doc.fork()
+        try other.put(obj: ObjId.ROOT, key: "remote", value: .String("hello"))
+
+        var reported: [Patch] = []
+        doc.observePatches { reported.append(contentsOf: $0) }
+        try doc.merge(other: other)
+
+        XCTAssertEqual(reported.count, 1)
+        XCTAssertEqual(reported.first?.action, .Put(ObjId.ROOT, .Key("remote"), .Scalar(.String("hello"))))
+    }
+
+    func testStopObservingPatches() throws {
+        let doc = Document()
+        var reported: [[Patch]] = []
+        doc.observePatches(batchPerCommit: false) { reported.append($0) }
+        doc.stopObservingPatches()
+
+        try doc.put(obj: ObjId.ROOT, key: "a", value: .Int(1))
+        XCTAssertTrue(reported.isEmpty)
+    }
+}
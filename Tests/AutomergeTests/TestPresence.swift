@@ -0,0 +1,68 @@
+import Automerge
+import XCTest
+
+class PresenceTestCase: XCTestCase {
+    func testNestedMapsRoundTrip() throws {
+        let doc = Document()
+        let text = try doc.putObject(obj: ObjId.ROOT, key: "text", ty: .Text)
+        try doc.spliceText(obj: text, start: 0, delete: 0, value: "hello")
+        let anchor = try doc.cursor(obj: text, position: 1)
+        let head = try doc.cursor(obj: text, position: 4)
+        let payload: [String: PresenceValue] = [
+            "name": .Scalar(.String("Alice")),
+            "selection": .Map(["anchor": .Cursor(anchor), "head": .Cursor(head), "color": .Scalar(.Uint(3))]),
+        ]
+
+        let alice = Presence(actor: ActorId(), ttl: 30)
+        let bob = Presence(actor: ActorId(), ttl: 30)
+        let now = Date()
+        try bob.receive(alice.encodeUpdate(payload, at: now))
+
+        let states = bob.states(at: now)
+        XCTAssertEqual(states.count, 1)
+        XCTAssertEqual(states.first?.payload, payload)
+        guard case let .Map(selection) = states.first?.payload["selection"],
+              case let .Cursor(cursor) = selection["head"]
+        else {
+            return XCTFail("expected a selection with a head cursor")
+        }
+        XCTAssertEqual(try doc.position(obj: text, cursor: cursor), 4)
+    }
+
+    func testKeysWhichLookLikeTagsAreKeptAsKeys() throws {
+        let payload: [String: PresenceValue] = [
+            "$cursor": .Map(["$counter": .Scalar(.String("not a counter"))]),
+        ]
+        let alice = Presence(actor: ActorId(), ttl: 30)
+        let bob = Presence(actor: ActorId(), ttl: 30)
+        let now = Date()
+        try bob.receive(alice.encodeUpdate(payload, at: now))
+        XCTAssertEqual(bob.states(at: now).first?.payload, payload)
+    }
+
+    func testUpdatesExpireAfterTheirTimeToLive() throws {
+        let actor = ActorId()
+        let alice = Presence(actor: actor, ttl: 10)
+        let bob = Presence(actor: ActorId(), ttl: 10)
+        let now = Date()
+        try bob.receive(alice.encodeUpdate(["name": .Scalar(.String("Alice"))], at: now))
+
+        XCTAssertEqual(bob.states(at: now.addingTimeInterval(5)).map(\.actor), [actor])
+        XCTAssertEqual(bob.states(at: now.addingTimeInterval(11)).count, 0)
+        XCTAssertEqual(bob.prune(at: now.addingTimeInterval(11)), [actor])
+    }
+
+    func testLeavingRemovesThePresence() throws {
+        let alice = Presence(actor: ActorId(), ttl: 30)
+        let bob = Presence(actor: ActorId(), ttl: 30)
+        let now = Date()
+        try bob.receive(alice.encodeUpdate(["name": .Scalar(.String("Alice"))], at: now))
+        try bob.receive(alice.encodeLeave(at: now.addingTimeInterval(1)))
+        XCTAssertEqual(bob.states(at: now.addingTimeInterval(1)).count, 0)
+    }
+
+    func testAnInvalidUpdateThrows() throws {
+        let presence = Presence(actor: ActorId(), ttl: 30)
+        XCTAssertThrowsError(try presence.receive(Data([0xFF])))
+    }
+}
@@ -0,0 +1,56 @@
+import Automerge
+import XCTest
+
+class RangeTests: XCTestCase {
+    func testListRangeReturnsAPage() throws {
+        let doc = Document()
+        let list = try doc.putObject(obj: ObjId.ROOT, key: "list", ty: .List)
+        for i in 0 ..< 10 {
+            try doc.insert(obj: list, index: UInt64(i), value: .Int(Int64(i)))
+        }
+
+        XCTAssertEqual(try doc.listRange(obj: list, range: 2 ..< 5), [2, 3, 4].map { .Scalar(.Int($0)) })
+        XCTAssertEqual(try doc.listRange(obj: list, range: 8 ..< 20), [8, 9].map { .Scalar(.Int($0)) })
+        XCTAssertEqual(try doc.listRange(obj: list, range: 3 ..< 3), [])
+    }
+
+    func testListRangeAtHeads() throws {
+        let doc = Document()
+        let list = try doc.putObject(obj: ObjId.ROOT, key: "list", ty: .List)
+        try doc.insert(obj: list, index: 0, value: .Int(1))
+        let heads = doc.heads()
+        try doc.insert(obj: list, index: 0, value: .Int(0))
+
+        XCTAssertEqual(try doc.listRange(obj: list, range: 0 ..< 2, heads: heads), [.Scalar(.Int(1))])
+        XCTAssertEqual(try doc.listRange(obj: list, range: 0 ..< 2), [.Scalar(.Int(0)), .Scalar(.Int(1))])
+    }
+
+    func testMapRangeReturnsPagesInKeyOrder() throws {
+        let doc = Document()
+        for key in ["d", "a", "c", "b", "e"] {
+            try doc.put(obj: ObjId.ROOT, key: key, value: .String(key))
+        }
+
+        XCTAssertEqual(try doc.mapRange(obj: ObjId.ROOT, limit: 2).map(\.0), ["a", "b"])
+        XCTAssertEqual(try doc.mapRange(obj: ObjId.ROOT, from: "b", limit: 2).map(\.0), ["b", "c"])
+        XCTAssertEqual(try doc.mapRange(obj: ObjId.ROOT, from: "b", to: "d").map(\.0), ["b", "c"])
+        XCTAssertEqual(try doc.mapRange(obj: ObjId.ROOT, to: "b").map(\.1), [.Scalar(.String("a"))])
+        XCTAssertEqual(try doc.mapRange(obj: ObjId.ROOT, from: "d", to: "b").count, 0)
+    }
+
+    func testMapRangeAtHeads() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "a", value: .Int(1))
+        let heads = doc.heads()
+        try doc.put(obj: ObjId.ROOT, key: "b", value: .Int(2))
+
+        XCTAssertEqual(try doc.mapRange(obj: ObjId.ROOT, heads: heads).map(\.0), ["a"])
+    }
+
+    func testRangesOfTheWrongObjectTypeThrow() throws {
+        let doc = Document()
+        let list = try doc.putObject(obj: ObjId.ROOT, key: "list", ty: .List)
+        XCTAssertThrowsError(try doc.listRange(obj: ObjId.ROOT, range: 0 ..< 1))
+        XCTAssertThrowsError(try doc.mapRange(obj: list))
+    }
+}
@@ -0,0 +1,189 @@
+import Automerge
+import XCTest
+
+final class MemoryStorage: StorageAdapter {
+    private(set) var values: [[String]: Data] = [:]
+
+    func load(key: [String]) -> Data? {
+        values[key]
+    }
+
+    func save(key: [String], data: Data) {
+        values[key] = data
+    }
+
+    func remove(key: [String]) {
+        values[key] = nil
+    }
+
+    func list(prefix: [String]) -> [[String]] {
+        values.keys.filter { $0.starts(with: prefix) }
+    }
+}
+
+/// An in-process stand-in for an automerge-repo sync server: a repo named `server` which every client joins, with the
+/// messages in flight held in a queue.
+final class InProcessNetwork {
+    final class Link: NetworkAdapter {
+        weak var network: InProcessNetwork?
+
+        func send(_ message: Data, to peer: String) {
+            network?.queue.append((peer, message))
+        }
+    }
+
+    var queue: [(peer: String, message: Data)] = []
+    /// The ephemeral messages each repo received, in the order they arrived.
+    private(set) var ephemeral: [(peer: String, message: EphemeralMessage)] = []
+    private(set) var repos: [String: Repo] = [:]
+    private var links: [Link] = []
+
+    init(clients: [String]) throws {
+        add("server")
+        for client in clients {
+            try join(add(client))
+        }
+    }
+
+    /// Connects a repo to the server.
+    func join(_ repo: Repo) throws {
+        queue.append(("server", repo.join()))
+        try run()
+    }
+
+    @discardableResult
+    func add(_ peerId: String) -> Repo {
+        let repo = Repo(peerId: peerId, storage: MemoryStorage())
+        let link = Link()
+        link.network = self
+        links.append(link)
+        repo.setNetworkAdapter(link)
+        repos[peerId] = repo
+        return repo
+    }
+
+    /// Delivers messages until none are left, returning how many were delivered.
+    @discardableResult
+    func run() throws -> Int {
+        var delivered = 0
+        while !queue.isEmpty {
+            let (peer, message) = queue.removeFirst()
+            if let ephemeral = try repos[peer]!.receiveMessage(message) {
+                self.ephemeral.append((peer, ephemeral))
+            }
+            delivered += 1
+        }
+        return delivered
+    }
+}
+
+class RepoTestCase: XCTestCase {
+    func testDocumentIdRoundTripsThroughItsURL() throws {
+        let id = DocumentId()
+        XCTAssertTrue(id.url.hasPrefix("automerge:"))
+        XCTAssertEqual(DocumentId(id.url), id)
+        XCTAssertEqual(DocumentId(id.description), id)
+        XCTAssertNil(DocumentId("not a document id"))
+    }
+
+    func testFindReturnsTheSameDocument() throws {
+        let repo = Repo(peerId: "alice", storage: MemoryStorage())
+        let id = repo.create()
+        let doc = try XCTUnwrap(repo.find(id))
+        XCTAssertTrue(try repo.find(id) === doc)
+        XCTAssertNil(try repo.find(DocumentId()))
+    }
+
+    func testSavedDocumentsLoadFromStorage() throws {
+        let storage = MemoryStorage()
+        let repo = Repo(peerId: "alice", storage: storage)
+        let id = repo.create()
+        try repo.find(id)!.put(obj: ObjId.ROOT, key: "title", value: .String("Notes"))
+        try repo.save(id)
+        XCTAssertFalse(storage.values.isEmpty)
+
+        let reopened = Repo(peerId: "alice", storage: storage)
+        XCTAssertEqual(reopened.storedDocumentIds, [id])
+        XCTAssertEqual(try reopened.find(id)?.get(obj: ObjId.ROOT, key: "title"), .Scalar(.String("Notes")))
+    }
+
+    func testDocumentsReachOtherClientsThroughTheServer() throws {
+        let network = try InProcessNetwork(clients: ["alice", "bob"])
+        XCTAssertEqual(network.repos["server"]!.peers, ["alice", "bob"])
+        XCTAssertEqual(network.repos["alice"]!.peers, ["server"])
+        let alice = network.repos["alice"]!
+        let id = alice.create()
+        try alice.find(id)!.put(obj: ObjId.ROOT, key: "title", value: .String("Notes"))
+        try alice.sync(id)
+        XCTAssertGreaterThan(try network.run(), 0)
+
+        let bob = network.repos["bob"]!
+        XCTAssertEqual(try bob.find(id)?.get(obj: ObjId.ROOT, key: "title"), .Scalar(.String("Notes")))
+        XCTAssertEqual(network.repos["server"]!.storedDocumentIds, [id])
+    }
+
+    func testARequestedDocumentIsAddedOnceAPeerSendsIt() throws {
+        let network = try InProcessNetwork(clients: ["alice"])
+        let alice = network.repos["alice"]!
+        let id = alice.create()
+        try alice.find(id)!.put(obj: ObjId.ROOT, key: "title", value: .String("Notes"))
+        try alice.sync(id)
+        try network.run()
+
+        let bob = network.add("bob")
+        try bob.request(id)
+        XCTAssertEqual(try bob.phase(of: id), .requesting)
+        XCTAssertNil(try bob.find(id))
+
+        try network.join(bob)
+        XCTAssertEqual(try bob.phase(of: id), .syncing)
+        XCTAssertEqual(try bob.find(id)?.get(obj: ObjId.ROOT, key: "title"), .Scalar(.String("Notes")))
+    }
+
+    func testADocumentNoPeerHasIsUnavailable() throws {
+        let network = try InProcessNetwork(clients: ["alice"])
+        let alice = network.repos["alice"]!
+        let id = DocumentId()
+        try alice.request(id)
+        try network.run()
+
+        XCTAssertEqual(try alice.phase(of: id), .unavailable)
+        XCTAssertNil(try alice.find(id))
+        XCTAssertNil(try network.repos["server"]!.phase(of: id))
+    }
+
+    func testBroadcastDataReachesEveryOtherClientOnce() throws {
+        let network = try InProcessNetwork(clients: ["alice", "bob", "carol"])
+        let id = DocumentId()
+        try network.repos["alice"]!.broadcast(Data([1, 2, 3]), for: id)
+        try network.run()
+
+        let received = network.ephemeral.filter { $0.peer != "server" }
+        XCTAssertEqual(received.map(\.peer).sorted(), ["bob", "carol"])
+        for (_, message) in received {
+            XCTAssertEqual(message.sender, "alice")
+            XCTAssertEqual(message.documentId, id)
+            XCTAssertEqual(message.data, Data([1, 2, 3]))
+        }
+    }
+
+    func testPresenceTravelsAsBroadcastData() throws {
+        let network = try InProcessNetwork(clients: ["alice", "bob"])
+        let id = DocumentId()
+        let alice = Presence(actor: ActorId(), ttl: 30)
+        let bob = Presence(actor: ActorId(), ttl: 30)
+        let now = Date()
+        try network.repos["alice"]!.broadcast(alice.encodeUpdate(["name": .Scalar(.String("Alice"))], at: now), for: id)
+        try network.run()
+
+        for (peer, message) in network.ephemeral where peer == "bob" {
+            try bob.receive(message.data)
+        }
+        XCTAssertEqual(bob.states(at: now).map(\.payload), [["name": .Scalar(.String("Alice"))]])
+    }
+
+    func testReceivingAnInvalidMessageThrows() throws {
+        let repo = Repo(peerId: "alice", storage: MemoryStorage())
+        XCTAssertThrowsError(try repo.receiveMessage(Data([0x01, 0x02])))
+    }
+}
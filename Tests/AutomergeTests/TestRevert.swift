@@ -0,0 +1,74 @@
+import Automerge
+import XCTest
+
+class RevertTests: XCTestCase {
+    func testRevertingWritesOneNewChange() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "title", value: .String("Draft"))
+        let text = try doc.putObject(obj: ObjId.ROOT, key: "text", ty: .Text)
+        try doc.spliceText(obj: text, start: 0, delete: 0, value: "hello")
+        let heads = doc.heads()
+        let before = try doc.toJSON(obj: ObjId.ROOT)
+
+        try doc.put(obj: ObjId.ROOT, key: "title", value: .String("Final"))
+        try doc.spliceText(obj: text, start: 0, delete: 1, value: "J")
+        try doc.mark(obj: text, start: 0, end: 2, expand: .none, name: "bold", value: .Boolean(true))
+        let edited = doc.heads()
+        let history = doc.getHistory().count
+
+        try doc.revert(to: heads)
+        XCTAssertEqual(try doc.toJSON(obj: ObjId.ROOT), before)
+        XCTAssertEqual(try doc.marks(obj: text), [])
+        XCTAssertEqual(doc.getHistory().count, history + 1)
+        let change = try XCTUnwrap(doc.change(hash: XCTUnwrap(doc.heads().first)))
+        XCTAssertEqual(Set(change.deps), edited)
+    }
+
+    func testRevertingAnObjectLeavesTheRestAlone() throws {
+        let doc = Document()
+        let contact = try doc.putObject(obj: ObjId.ROOT, key: "contact", ty: .Map)
+        try doc.put(obj: contact, key: "name", value: .String("Alice"))
+        let heads = doc.heads()
+        try doc.put(obj: contact, key: "name", value: .String("Bob"))
+        try doc.put(obj: ObjId.ROOT, key: "count", value: .Int(1))
+
+        try doc.revert(to: heads, obj: contact)
+        XCTAssertEqual(try doc.get(obj: contact, key: "name"), .Scalar(.String("Alice")))
+        XCTAssertEqual(try doc.get(obj: ObjId.ROOT, key: "count"), .Scalar(.Int(1)))
+    }
+
+    func testRevertingRecreatesDeletedObjects() throws {
+        let doc = Document()
+        let list = try doc.putObject(obj: ObjId.ROOT, key: "list", ty: .List)
+        try doc.insert(obj: list, index: 0, value: .Int(1))
+        let heads = doc.heads()
+        try doc.delete(obj: ObjId.ROOT, key: "list")
+
+        try doc.revert(to: heads)
+        guard case let .Object(restored, .List) = try doc.get(obj: ObjId.ROOT, key: "list") else {
+            return XCTFail("expected the list to be recreated")
+        }
+        XCTAssertNotEqual(restored, list)
+        XCTAssertEqual(try doc.values(obj: restored), [.Scalar(.Int(1))])
+    }
+
+    func testRevertingPutsMarksBackWithTheGivenExpand() throws {
+        let doc = Document()
+        let text = try doc.putObject(obj: ObjId.ROOT, key: "text", ty: .Text)
+        try doc.spliceText(obj: text, start: 0, delete: 0, value: "hello")
+        try doc.mark(obj: text, start: 0, end: 5, expand: .after, name: "bold", value: .Boolean(true))
+        let heads = doc.heads()
+        try doc.mark(obj: text, start: 0, end: 5, expand: .after, name: "bold", value: .Null)
+
+        try doc.revert(to: heads, expand: .none)
+        try doc.spliceText(obj: text, start: 5, delete: 0, value: "!")
+        XCTAssertEqual(try doc.marks(obj: text), [Mark(start: 0, end: 5, name: "bold", value: .Boolean(true))])
+    }
+
+    func testRevertingToUnknownHeadsThrows() throws {
+        let doc = Document()
+        let other = Document()
+        try other.put(obj: ObjId.ROOT, key: "title", value: .String("Other"))
+        XCTAssertThrowsError(try doc.revert(to: other.heads()))
+    }
+}
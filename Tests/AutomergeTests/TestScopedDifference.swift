@@ -0,0 +1,57 @@
+import Automerge
+import XCTest
+
+class ScopedDifferenceTests: XCTestCase {
+    func testDifferenceInOnlyReturnsPatchesToTheObject() throws {
+        let doc = Document()
+        let notes = try doc.putObject(obj: ObjId.ROOT, key: "notes", ty: .Map)
+        let first = try doc.putObject(obj: notes, key: "first", ty: .Map)
+        let second = try doc.putObject(obj: notes, key: "second", ty: .Map)
+        let before = doc.heads()
+        try doc.put(obj: first, key: "title", value: .String("First"))
+        try doc.put(obj: second, key: "title", value: .String("Second"))
+        try doc.put(obj: ObjId.ROOT, key: "updated", value: .Int(1))
+        let after = doc.heads()
+
+        let patches = try doc.difference(in: first, from: before, to: after)
+        XCTAssertEqual(patches.map(\.action), [.Put(first, .Key("title"), .Scalar(.String("First")))])
+        XCTAssertEqual(try doc.difference(in: notes, from: before, to: after).count, 2)
+        XCTAssertEqual(try doc.difference(in: notes, from: before, to: after, recursive: false).count, 0)
+    }
+
+    func testDifferenceSummaryCountsTheChangesToEachObject() throws {
+        let doc = Document()
+        let note = try doc.putObject(obj: ObjId.ROOT, key: "note", ty: .Map)
+        let text = try doc.putObject(obj: note, key: "text", ty: .Text)
+        try doc.spliceText(obj: text, start: 0, delete: 0, value: "hello")
+        let before = doc.heads()
+        try doc.put(obj: note, key: "title", value: .String("Greeting"))
+        try doc.delete(obj: note, key: "title")
+        try doc.put(obj: note, key: "author", value: .String("Alice"))
+        try doc.spliceText(obj: text, start: 0, delete: 1, value: "J")
+        try doc.spliceText(obj: text, start: 5, delete: 0, value: "!")
+        let after = doc.heads()
+
+        let summaries = try doc.differenceSummary(of: note, from: before, to: after)
+        let noteSummary = try XCTUnwrap(summaries.first { $0.obj == note })
+        XCTAssertEqual(noteSummary.changedKeys, ["author"])
+        XCTAssertEqual(noteSummary.path, [PathElement(obj: ObjId.ROOT, prop: .Key("note"))])
+
+        let textSummary = try XCTUnwrap(summaries.first { $0.obj == text })
+        XCTAssertEqual(textSummary.inserted, 2)
+        XCTAssertEqual(textSummary.removed, 1)
+
+        let shallow = try doc.differenceSummary(of: note, from: before, to: after, recursive: false)
+        XCTAssertEqual(shallow.map(\.obj), [note])
+    }
+
+    func testDifferenceInAMissingObjectThrows() throws {
+        let doc = Document()
+        let list = try doc.putObject(obj: ObjId.ROOT, key: "list", ty: .List)
+        let heads = doc.heads()
+        let other = Document()
+        let stranger = try other.putObject(obj: ObjId.ROOT, key: "map", ty: .Map)
+        XCTAssertNoThrow(try doc.difference(in: list, from: heads, to: heads))
+        XCTAssertThrowsError(try doc.difference(in: stranger, from: heads, to: heads))
+    }
+}
@@ -0,0 +1,43 @@
+import Automerge
+import XCTest
+
+class SyncMessageTestCase: XCTestCase {
+    func testDecodeFirstMessage() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "a", value: .Int(1))
+
+        let bytes = try XCTUnwrap(doc.generateSyncMessage(state: SyncState()))
+        let message = try SyncMessage(decoding: bytes)
+        XCTAssertEqual(message.heads, doc.heads())
+        XCTAssertTrue(message.need.isEmpty)
+        XCTAssertEqual(message.have.count, 1)
+        XCTAssertTrue(message.changes.isEmpty)
+    }
+
+    func testDecodeMessageCarryingChanges() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "a", value: .Int(1))
+        doc.commitWith(message: "add a")
+        let peer = Document()
+
+        let docState = SyncState()
+        let peerState = SyncState()
+        var changes: [Change] = []
+        for _ in 0 ..< 4 {
+            if let bytes = doc.generateSyncMessage(state: docState) {
+                changes.append(contentsOf: try SyncMessage(decoding: bytes).changes)
+                try peer.receiveSyncMessage(state: peerState, message: bytes)
+            }
+            if let bytes = peer.generateSyncMessage(state: peerState) {
+                try doc.receiveSyncMessage(state: docState, message: bytes)
+            }
+        }
+        XCTAssertEqual(changes.map(\.message), ["add a"])
+    }
+
+    func testDecodeInvalidMessageThrows() {
+        XCTAssertThrowsError(try SyncMessage(decoding: Data([0x42, 0x01]))) { error in
+            XCTAssertTrue(error is DecodeSyncMessageError)
+        }
+    }
+}
@@ -0,0 +1,62 @@
+import Automerge
+import XCTest
+
+class SyncSessionTestCase: XCTestCase {
+    /// Delivers messages between the sessions until none of them has anything to send.
+    func syncAll(_ sessions: [String: SyncSession]) throws {
+        for _ in 0 ..< 16 {
+            var sent = false
+            for (name, session) in sessions {
+                for (peer, message) in session.generateMessages() {
+                    try sessions[peer]?.receiveMessage(message, from: name)
+                    sent = true
+                }
+            }
+            if !sent { return }
+        }
+        XCTFail("sync did not finish")
+    }
+
+    func testSyncsWithSeveralPeers() throws {
+        let hub = Document()
+        try hub.put(obj: ObjId.ROOT, key: "hub", value: .Int(1))
+        let alice = Document()
+        try alice.put(obj: ObjId.ROOT, key: "alice", value: .Int(2))
+        let bob = Document()
+
+        let hubSession = SyncSession(document: hub)
+        hubSession.addPeer("alice")
+        hubSession.addPeer("bob")
+        let aliceSession = SyncSession(document: alice)
+        aliceSession.addPeer("hub")
+        let bobSession = SyncSession(document: bob)
+        bobSession.addPeer("hub")
+        XCTAssertEqual(Set(hubSession.peers), ["alice", "bob"])
+
+        try syncAll(["hub": hubSession, "alice": aliceSession, "bob": bobSession])
+
+        XCTAssertEqual(bob.heads(), hub.heads())
+        XCTAssertEqual(alice.heads(), hub.heads())
+        XCTAssertEqual(try bob.get(obj: ObjId.ROOT, key: "alice"), .Scalar(.Int(2)))
+        XCTAssertEqual(hubSession.state(for: "bob")?.sharedHeads, hub.heads())
+    }
+
+    func testUnknownPeerThrows() {
+        let session = SyncSession(document: Document())
+        XCTAssertThrowsError(try session.generateMessage(for: "nobody")) { error in
+            XCTAssertTrue(error is SyncSessionError)
+        }
+        XCTAssertNil(session.state(for: "nobody"))
+    }
+
+    func testRestoreKeepsPeers() throws {
+        let doc = Document()
+        let session = SyncSession(document: doc)
+        session.addPeer("alice")
+        session.removePeer("alice")
+        session.addPeer("bob", state: SyncState())
+
+        let restored = try SyncSession(document: doc, restoring: session.encode())
+        XCTAssertEqual(restored.peers, ["bob"])
+    }
+}
@@ -0,0 +1,48 @@
+import Automerge
+import XCTest
+
+class SyncStateIntrospectionTestCase: XCTestCase {
+    func testNewStateIsEmpty() {
+        let state = SyncState()
+        XCTAssertTrue(state.sharedHeads.isEmpty)
+        XCTAssertTrue(state.lastSentHeads.isEmpty)
+        XCTAssertNil(state.theirNeed)
+        XCTAssertNil(state.theirHave)
+        XCTAssertEqual(state.sentHashesCount, 0)
+        XCTAssertFalse(state.inFlight)
+    }
+
+    func testStateAfterSyncing() throws {
+        let doc = Document()
+        try doc.put(obj: ObjId.ROOT, key: "a", value: .Int(1))
+        let peer = Document()
+        let docState = SyncState()
+        let peerState = SyncState()
+
+        let first = try XCTUnwrap(doc.generateSyncMessage(state: docState))
+        XCTAssertTrue(docState.inFlight)
+        XCTAssertEqual(docState.lastSentHeads, doc.heads())
+
+        try peer.receiveSyncMessage(state: peerState, message: first)
+        XCTAssertNotNil(peerState.theirHave)
+
+        var rounds = 0
+        while rounds < 8 {
+            var sent = false
+            if let bytes = peer.generateSyncMessage(state: peerState) {
+                try doc.receiveSyncMessage(state: docState, message: bytes)
+                sent = true
+            }
+            if let bytes = doc.generateSyncMessage(state: docState) {
+                try peer.receiveSyncMessage(state: peerState, message: bytes)
+                sent = true
+            }
+            if !sent { break }
+            rounds += 1
+        }
+
+        XCTAssertEqual(docState.sharedHeads, doc.heads())
+        XCTAssertEqual(peerState.sharedHeads, doc.heads())
+        XCTAssertEqual(docState.sentHashesCount, 1)
+    }
+}
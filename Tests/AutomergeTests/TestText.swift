@@ -87,12 +87,12 @@ class TextTestCase: XCTestCase {
 
         let doc = Automerge.Document()
         let text = try doc.putObject(obj: ObjId.ROOT, key: "text", ty: .Text)
-        var stringLength = doc.length(obj: text)
+        var stringLength = try doc.length(obj: text)
         XCTAssertEqual(stringLength, 0)
 
         for _ in 0 ... 5000 {
             let stringToInsert = characterCollection.randomElement() ?? " "
-            stringLength = doc.length(obj: text)
+            stringLength = try doc.length(obj: text)
             // print("Adding '\(stringToInsert)' at \(stringLength)")
             try! doc.spliceText(obj: text, start: stringLength, delete: 0, value: stringToInsert)
             // print("Combined text: \(try doc.text(obj: text))")
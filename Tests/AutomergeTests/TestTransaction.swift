@@ -0,0 +1,68 @@
+import Automerge
+import XCTest
+
+class TransactionTestCase: XCTestCase {
+    func testStagedEditsAreHiddenUntilCommit() throws {
+        let doc = Document()
+        let transaction = doc.beginTransaction()
+        try transaction.put(obj: ObjId.ROOT, key: "key", value: .Int(1))
+        XCTAssertNil(try doc.get(obj: ObjId.ROOT, key: "key"))
+
+        let hash = try transaction.commit(message: "add key")
+        XCTAssertNotNil(hash)
+        XCTAssertEqual(try doc.get(obj: ObjId.ROOT, key: "key"), .Scalar(.Int(1)))
+    }
+
+    func testRollbackDiscardsStagedEdits() throws {
+        let doc = Document()
+        let transaction = doc.beginTransaction()
+        try transaction.put(obj: ObjId.ROOT, key: "key", value: .Int(1))
+        transaction.rollback()
+        XCTAssertNil(try doc.get(obj: ObjId.ROOT, key: "key"))
+        XCTAssertThrowsError(try transaction.put(obj: ObjId.ROOT, key: "key", value: .Int(2)))
+    }
+
+    func testCommitWithNothingStagedReturnsNil() throws {
+        let doc = Document()
+        let transaction = doc.beginTransaction()
+        XCTAssertNil(try transaction.commit())
+    }
+
+    func testDocumentCanMergeWhileATransactionIsOpen() throws {
+        let doc = Document()
+        let other = doc.fork()
+        let transaction = doc.beginTransaction()
+        let list = try transaction.putObject(obj: ObjId.ROOT, key: "list", ty: .List)
+        try transaction.insert(obj: list, index: 0, value: .String("staged"))
+
+        try other.put(obj: ObjId.ROOT, key: "merged", value: .Boolean(true))
+        try doc.merge(other: other)
+        try transaction.commit()
+
+        XCTAssertEqual(try doc.get(obj: ObjId.ROOT, key: "merged"), .Scalar(.Boolean(true)))
+        XCTAssertEqual(try doc.get(obj: list, index: 0), .Scalar(.String("staged")))
+    }
+
+    func testEditingTheDocumentWhileATransactionIsOpenFailsTheCommit() throws {
+        let doc = Document()
+        let transaction = doc.beginTransaction()
+        try transaction.put(obj: ObjId.ROOT, key: "staged", value: .Int(1))
+        try doc.put(obj: ObjId.ROOT, key: "direct", value: .Int(2))
+
+        XCTAssertThrowsError(try transaction.commit())
+        transaction.rollback()
+        XCTAssertNil(try doc.get(obj: ObjId.ROOT, key: "staged"))
+    }
+
+    func testCommittedTransactionsCanBeUndone() throws {
+        let doc = Document()
+        let undoManager = try DocumentUndoManager(document: doc)
+        let transaction = doc.beginTransaction()
+        try transaction.put(obj: ObjId.ROOT, key: "key", value: .Int(1))
+        try transaction.commit()
+
+        XCTAssertEqual(try doc.blame(obj: ObjId.ROOT, key: "key")?.actor, doc.actor)
+        XCTAssertTrue(try undoManager.undo())
+        XCTAssertNil(try doc.get(obj: ObjId.ROOT, key: "key"))
+    }
+}
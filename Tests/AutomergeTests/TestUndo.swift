@@ -0,0 +1,73 @@
+import Automerge
+import XCTest
+
+class UndoTests: XCTestCase {
+    func testUndoAndRedoEdits() throws {
+        let doc = Document()
+        let undoManager = try DocumentUndoManager(document: doc)
+        XCTAssertFalse(undoManager.canUndo)
+
+        try doc.put(obj: ObjId.ROOT, key: "title", value: .String("Draft"))
+        undoManager.record()
+        try doc.put(obj: ObjId.ROOT, key: "title", value: .String("Final"))
+        XCTAssertTrue(undoManager.canUndo)
+
+        XCTAssertTrue(try undoManager.undo())
+        XCTAssertEqual(try doc.get(obj: ObjId.ROOT, key: "title"), .Scalar(.String("Draft")))
+        XCTAssertTrue(try undoManager.undo())
+        XCTAssertNil(try doc.get(obj: ObjId.ROOT, key: "title"))
+        XCTAssertFalse(try undoManager.undo())
+
+        XCTAssertTrue(undoManager.canRedo)
+        XCTAssertTrue(try undoManager.redo())
+        XCTAssertTrue(try undoManager.redo())
+        XCTAssertEqual(try doc.get(obj: ObjId.ROOT, key: "title"), .Scalar(.String("Final")))
+        XCTAssertFalse(undoManager.canRedo)
+    }
+
+    func testGroupsMakeOneStep() throws {
+        let doc = Document()
+        let text = try doc.putObject(obj: ObjId.ROOT, key: "text", ty: .Text)
+        let undoManager = try DocumentUndoManager(document: doc)
+        undoManager.beginGroup()
+        try doc.spliceText(obj: text, start: 0, delete: 0, value: "hello")
+        undoManager.record()
+        try doc.spliceText(obj: text, start: 5, delete: 0, value: " world")
+        undoManager.endGroup()
+
+        try undoManager.undo()
+        XCTAssertEqual(try doc.text(obj: text), "")
+        XCTAssertFalse(undoManager.canUndo)
+    }
+
+    func testScopedUndoOnlyRevertsEditsInTheScope() throws {
+        let doc = Document()
+        let note = try doc.putObject(obj: ObjId.ROOT, key: "note", ty: .Map)
+        let undoManager = try DocumentUndoManager(document: doc, scope: note)
+        try doc.put(obj: note, key: "title", value: .String("Notes"))
+        try doc.put(obj: ObjId.ROOT, key: "count", value: .Int(1))
+
+        try undoManager.undo()
+        XCTAssertNil(try doc.get(obj: note, key: "title"))
+        XCTAssertEqual(try doc.get(obj: ObjId.ROOT, key: "count"), .Scalar(.Int(1)))
+    }
+
+    func testScopeFromAnotherDocumentThrows() throws {
+        let other = Document()
+        let note = try other.putObject(obj: ObjId.ROOT, key: "note", ty: .Map)
+        XCTAssertThrowsError(try DocumentUndoManager(document: Document(), scope: note))
+    }
+
+    func testOnlyLocalEditsAreUndone() throws {
+        let alice = Document()
+        let undoManager = try DocumentUndoManager(document: alice)
+        try alice.put(obj: ObjId.ROOT, key: "alice", value: .Int(1))
+        undoManager.record()
+        let bob = alice.fork()
+        try bob.put(obj: ObjId.ROOT, key: "bob", value: .Int(2))
+        try alice.merge(other: bob)
+
+        try undoManager.undo()
+        XCTAssertEqual(try alice.keys(obj: ObjId.ROOT), ["bob"])
+    }
+}
@@ -0,0 +1,74 @@
+use automerge::{self as am, ReadDoc};
+
+use crate::text_encoding::width;
+use crate::{ActorId, ChangeHash};
+
+/// The change which wrote a value.
+pub struct Attribution {
+    pub change: ChangeHash,
+    pub actor: ActorId,
+    pub seq: u64,
+    pub timestamp: i64,
+    pub message: Option<String>,
+}
+
+/// A run of text written by one change.
+pub struct TextSpan {
+    pub start: u64,
+    pub end: u64,
+    pub text: String,
+    /// `None` if the text has not been committed yet
+    pub attribution: Option<Attribution>,
+}
+
+/// The change containing the operation `id`, or `None` if it has not been
+/// committed yet.
+pub(crate) fn attribution(doc: &am::AutoCommit, id: &am::ObjId) -> Option<Attribution> {
+    let hash = doc.hash_for_opid(id)?;
+    let change = doc.get_change_by_hash(&hash)?;
+    Some(Attribution {
+        change: hash.into(),
+        actor: change.actor_id().into(),
+        seq: change.seq(),
+        timestamp: change.timestamp(),
+        message: change.message().cloned(),
+    })
+}
+
+/// Splits the text `obj` into runs of consecutive characters written by the
+/// same change. Positions are in the document's text encoding.
+pub(crate) fn text_spans(doc: &am::AutoCommit, obj: &am::ObjId) -> Vec<TextSpan> {
+    let encoding = doc.text_encoding();
+    let mut spans = Vec::new();
+    let mut current: Option<(Option<am::ChangeHash>, TextSpan)> = None;
+    let mut position = 0;
+    for am::iter::ListRangeItem { value, id, .. } in doc.list_range(obj, ..) {
+        // Block markers and other non-string values count as one object
+        // replacement character, as in `Doc::text`
+        let text = match &value {
+            am::Value::Scalar(s) => s.to_str().unwrap_or("\u{fffc}"),
+            am::Value::Object(_) => "\u{fffc}",
+        };
+        let width = width(encoding, text) as u64;
+        let hash = doc.hash_for_opid(&id);
+        match &mut current {
+            Some((current_hash, span)) if *current_hash == hash => {
+                span.end += width;
+                span.text.push_str(text);
+            }
+            _ => {
+                spans.extend(current.take().map(|(_, span)| span));
+                let span = TextSpan {
+                    start: position,
+                    end: position + width,
+                    text: text.to_string(),
+                    attribution: attribution(doc, &id),
+                };
+                current = Some((hash, span));
+            }
+        }
+        position += width;
+    }
+    spans.extend(current.map(|(_, span)| span));
+    spans
+}
@@ -0,0 +1,437 @@
+//! Branches are named heads stored in the document itself, so that they are
+//! saved and synchronised along with it.
+//!
+//! They live in a map under the root key [`BRANCHES`], which holds a list of
+//! hex encoded change hashes for each branch name. The key is namespaced so
+//! that it does not clash with content, and other implementations which want
+//! to share branches must use the same key and layout. Entries which cannot
+//! be read as a list of hashes are ignored.
+//!
+//! The key is not part of the document's content. This module is the only
+//! one which knows about it: [`Doc`] reads maps and patches through
+//! [`map_range`], [`get_all`], [`diff`] and [`diff_incremental`], which leave
+//! it out, and checks writes with [`check_writable`].
+//!
+//! [`Doc`]: crate::Doc
+
+use std::ops::RangeBounds;
+
+use automerge::{self as am, transaction::Transactable, ReadDoc};
+
+use crate::{ChangeHash, DocError};
+
+/// The root key of the map of branches.
+pub(crate) const BRANCHES: &str = "automerge-swift:branches";
+
+/// A named set of heads.
+pub struct Branch {
+    pub name: String,
+    pub heads: Vec<ChangeHash>,
+}
+
+/// The branches of `doc`, sorted by name.
+pub(crate) fn branches(doc: &am::AutoCommit) -> Vec<Branch> {
+    let Some(branches) = branches_map(doc) else {
+        return Vec::new();
+    };
+    doc.map_range(&branches, ..)
+        .filter_map(|item| {
+            let heads = read_heads(doc, item.value, &item.id)?;
+            Some(Branch {
+                name: item.key.to_string(),
+                heads: heads.into_iter().map(ChangeHash::from).collect(),
+            })
+        })
+        .collect()
+}
+
+/// The heads of the branch `name`, or `None` if there is no such branch.
+pub(crate) fn branch_heads(doc: &am::AutoCommit, name: &str) -> Option<Vec<am::ChangeHash>> {
+    let branches = branches_map(doc)?;
+    let (value, list) = doc.get(&branches, name).ok()??;
+    read_heads(doc, value, &list)
+}
+
+/// Points the branch `name` at `heads`, creating it if it does not exist.
+pub(crate) fn set_branch(
+    doc: &mut am::AutoCommit,
+    name: &str,
+    heads: &[am::ChangeHash],
+) -> Result<(), DocError> {
+    let branches = match doc.get(am::ROOT, BRANCHES)? {
+        Some((am::Value::Object(am::ObjType::Map), branches)) => branches,
+        None => doc.put_object(am::ROOT, BRANCHES, am::ObjType::Map)?,
+        // Written by someone who does not know about branches
+        Some(_) => {
+            return Err(DocError::ReservedKey {
+                key: BRANCHES.to_string(),
+            })
+        }
+    };
+    let list = match doc.get(&branches, name)? {
+        Some((am::Value::Object(am::ObjType::List), list)) => list,
+        _ => doc.put_object(&branches, name, am::ObjType::List)?,
+    };
+    // Edit the list rather than replacing it, so that moving a branch only
+    // writes the heads which changed, and concurrent moves of the same branch
+    // merge into the heads of both
+    let current = doc
+        .list_range(&list, ..)
+        .map(|item| item.value.to_str().map(str::to_string))
+        .collect::<Vec<_>>();
+    let heads = heads.iter().map(|h| h.to_string()).collect::<Vec<_>>();
+    for (i, head) in current.iter().enumerate().rev() {
+        if !head.as_ref().is_some_and(|head| heads.contains(head)) {
+            doc.delete(&list, i)?;
+        }
+    }
+    let mut length = doc.length(&list);
+    for head in heads {
+        if !current.contains(&Some(head.clone())) {
+            doc.insert(&list, length, head)?;
+            length += 1;
+        }
+    }
+    Ok(())
+}
+
+/// Removes the branch `name`, returning `false` if there was no such branch.
+pub(crate) fn delete_branch(doc: &mut am::AutoCommit, name: &str) -> Result<bool, DocError> {
+    if branch_heads(doc, name).is_none() {
+        return Ok(false);
+    }
+    if let Some(branches) = branches_map(doc) {
+        doc.delete(&branches, name)?;
+    }
+    Ok(true)
+}
+
+/// Fails if `key` of `obj` is the key which holds the branches.
+pub(crate) fn check_writable(obj: &am::ObjId, key: &str) -> Result<(), DocError> {
+    if is_reserved(obj, key) {
+        return Err(DocError::ReservedKey {
+            key: key.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// The entries of the map `obj` with keys in `range`, as of `heads` if
+/// given, leaving out the branches.
+pub(crate) fn map_range<'a, R, B>(
+    doc: &'a R,
+    obj: &am::ObjId,
+    range: B,
+    heads: Option<&[am::ChangeHash]>,
+) -> impl Iterator<Item = am::iter::MapRangeItem<'a>>
+where
+    R: ReadDoc,
+    B: RangeBounds<String> + 'a,
+{
+    let items = match heads {
+        Some(heads) => doc.map_range_at(obj, range, heads),
+        None => doc.map_range(obj, range),
+    };
+    let obj = obj.clone();
+    items.filter(move |item| !is_reserved(&obj, item.key))
+}
+
+/// Every value of `prop` of `obj`, as of `heads` if given, with the winning
+/// value last. The branches have no values.
+pub(crate) fn get_all<'a, R: ReadDoc>(
+    doc: &'a R,
+    obj: &am::ObjId,
+    prop: am::Prop,
+    heads: Option<&[am::ChangeHash]>,
+) -> Result<Vec<(am::Value<'a>, am::ObjId)>, am::AutomergeError> {
+    if let am::Prop::Map(key) = &prop {
+        if is_reserved(obj, key) {
+            return Ok(Vec::new());
+        }
+    }
+    match heads {
+        Some(heads) => doc.get_all_at(obj, prop, heads),
+        None => doc.get_all(obj, prop),
+    }
+}
+
+/// The patches which turn `from` into `to`, leaving out changes to the
+/// branches.
+pub(crate) fn diff(
+    doc: &mut am::AutoCommit,
+    from: &[am::ChangeHash],
+    to: &[am::ChangeHash],
+) -> Vec<am::Patch> {
+    without_branches(doc.diff(from, to))
+}
+
+/// The patches since the diff cursor, which is then moved to the current
+/// heads, leaving out changes to the branches.
+pub(crate) fn diff_incremental(doc: &mut am::AutoCommit) -> Vec<am::Patch> {
+    without_branches(doc.diff_incremental())
+}
+
+/// Whether `key` of `obj` is the key which holds the branches.
+fn is_reserved(obj: &am::ObjId, key: &str) -> bool {
+    *obj == am::ROOT && key == BRANCHES
+}
+
+fn without_branches(mut patches: Vec<am::Patch>) -> Vec<am::Patch> {
+    patches.retain(|patch| !is_branch_patch(patch));
+    patches
+}
+
+/// Whether `patch` is a change to the branches rather than to the content of
+/// the document.
+fn is_branch_patch(patch: &am::Patch) -> bool {
+    if let Some((obj, am::Prop::Map(key))) = patch.path.first() {
+        return is_reserved(obj, key);
+    }
+    match &patch.action {
+        am::PatchAction::PutMap { key, .. } | am::PatchAction::DeleteMap { key } => {
+            is_reserved(&patch.obj, key)
+        }
+        am::PatchAction::Increment {
+            prop: am::Prop::Map(key),
+            ..
+        }
+        | am::PatchAction::Conflict {
+            prop: am::Prop::Map(key),
+        } => is_reserved(&patch.obj, key),
+        _ => false,
+    }
+}
+
+fn branches_map(doc: &am::AutoCommit) -> Option<am::ObjId> {
+    match doc.get(am::ROOT, BRANCHES).ok()?? {
+        (am::Value::Object(am::ObjType::Map), id) => Some(id),
+        _ => None,
+    }
+}
+
+fn read_heads(
+    doc: &am::AutoCommit,
+    value: am::Value<'_>,
+    list: &am::ObjId,
+) -> Option<Vec<am::ChangeHash>> {
+    let am::Value::Object(am::ObjType::List) = value else {
+        return None;
+    };
+    doc.list_range(list, ..)
+        .map(|item| item.value.to_str()?.parse().ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::sync::Arc;
+
+    use automerge::{self as am, transaction::Transactable, ReadDoc};
+    use serde_json::{json, Value as Json};
+
+    use super::{branches_map, BRANCHES};
+    use crate::{
+        root, ChangeHash, Doc, DocError, ExpandMark, HydrateValue, ObjType, Prop, ScalarValue,
+    };
+
+    fn int(value: i64) -> ScalarValue {
+        ScalarValue::Int { value }
+    }
+
+    fn state(doc: &Doc) -> Json {
+        serde_json::from_str(&doc.to_json(root(), None).unwrap()).unwrap()
+    }
+
+    fn hashes(heads: Vec<ChangeHash>) -> HashSet<am::ChangeHash> {
+        heads.into_iter().map(am::ChangeHash::from).collect()
+    }
+
+    fn branch_heads(doc: &Doc, name: &str) -> HashSet<am::ChangeHash> {
+        let branch = doc
+            .list_branches()
+            .into_iter()
+            .find(|branch| branch.name == name)
+            .expect("no such branch");
+        hashes(branch.heads)
+    }
+
+    fn branch_list(doc: &Doc, name: &str) -> am::ObjId {
+        let doc = doc.0.read().unwrap();
+        let branches = branches_map(&doc).unwrap();
+        doc.get(&branches, name).unwrap().unwrap().1
+    }
+
+    fn example() -> Arc<Doc> {
+        let doc = Arc::new(Doc::new());
+        doc.put_in_map(root(), "a".to_string(), int(1)).unwrap();
+        doc.create_branch("draft".to_string(), doc.heads()).unwrap();
+        doc
+    }
+
+    #[test]
+    fn branches_are_hidden_from_reads() {
+        let doc = example();
+        let heads = doc.heads();
+        let key = || BRANCHES.to_string();
+
+        assert_eq!(doc.map_keys(root()).unwrap(), vec!["a".to_string()]);
+        assert_eq!(
+            doc.map_keys_at(root(), heads.clone()).unwrap(),
+            vec!["a".to_string()]
+        );
+        assert_eq!(doc.map_entries(root()).unwrap().len(), 1);
+        assert_eq!(doc.map_entries_at(root(), heads.clone()).unwrap().len(), 1);
+        assert_eq!(
+            doc.map_range(root(), None, None, None, None).unwrap().len(),
+            1
+        );
+        assert_eq!(doc.values(root()).unwrap().len(), 1);
+        assert_eq!(doc.values_at(root(), heads.clone()).unwrap().len(), 1);
+        assert_eq!(doc.length(root()).unwrap(), 1);
+        assert_eq!(doc.length_at(root(), heads.clone()).unwrap(), 1);
+        assert!(doc.get_in_map(root(), key()).unwrap().is_none());
+        assert!(doc
+            .get_at_in_map(root(), key(), heads.clone())
+            .unwrap()
+            .is_none());
+        assert!(doc.get_all_in_map(root(), key()).unwrap().is_empty());
+        assert!(doc
+            .get_all_at_in_map(root(), key(), heads)
+            .unwrap()
+            .is_empty());
+        let prop = || Prop::Key { value: key() };
+        assert!(doc.get_conflicts(root(), prop(), None).unwrap().is_empty());
+        assert!(doc.blame_property(root(), prop()).unwrap().is_none());
+        assert_eq!(state(&doc), json!({"a": 1}));
+        let Ok(HydrateValue::Map { value }) = doc.hydrate(root(), None) else {
+            panic!("expected the root to be a map");
+        };
+        assert_eq!(value.keys().collect::<Vec<_>>(), vec!["a"]);
+    }
+
+    #[test]
+    fn writing_the_reserved_key_is_an_error() {
+        let doc = example();
+        let key = || BRANCHES.to_string();
+        let reserved = |result: Result<(), DocError>| {
+            assert!(matches!(result, Err(DocError::ReservedKey { .. })));
+        };
+
+        reserved(doc.put_in_map(root(), key(), int(1)));
+        reserved(
+            doc.put_object_in_map(root(), key(), ObjType::Map)
+                .map(|_| ()),
+        );
+        reserved(doc.delete_in_map(root(), key()));
+        reserved(doc.increment_in_map(root(), key(), 1));
+        reserved(doc.put_json(root(), key(), "{}".to_string()));
+        assert_eq!(doc.list_branches().len(), 1);
+
+        let map = doc
+            .put_object_in_map(root(), "map".to_string(), ObjType::Map)
+            .unwrap();
+        doc.put_in_map(map, key(), int(1)).unwrap();
+        assert_eq!(state(&doc), json!({"a": 1, "map": {BRANCHES: 1}}));
+    }
+
+    #[test]
+    fn branches_are_not_written_over_other_values() {
+        let doc = Doc::new();
+        doc.0
+            .write()
+            .unwrap()
+            .put(am::ROOT, BRANCHES, "written by a peer")
+            .unwrap();
+
+        assert!(matches!(
+            doc.create_branch("draft".to_string(), doc.heads()),
+            Err(DocError::ReservedKey { .. })
+        ));
+        let inner = doc.0.read().unwrap();
+        let Ok(Some((am::Value::Scalar(value), _))) = inner.get(am::ROOT, BRANCHES) else {
+            panic!("expected the value to be kept");
+        };
+        assert_eq!(value.to_str(), Some("written by a peer"));
+        drop(inner);
+        assert!(doc.list_branches().is_empty());
+    }
+
+    #[test]
+    fn moving_branches_produces_no_patches() {
+        let doc = Arc::new(Doc::new());
+        doc.put_in_map(root(), "a".to_string(), int(1)).unwrap();
+        let before = doc.heads();
+        let other = doc.fork();
+        doc.create_branch("draft".to_string(), before.clone())
+            .unwrap();
+        let after = doc.heads();
+
+        assert!(doc
+            .difference(before.clone(), after.clone())
+            .unwrap()
+            .is_empty());
+        assert!(doc
+            .difference_in(root(), before, after, true)
+            .unwrap()
+            .is_empty());
+        assert!(other.merge_with_patches(doc).unwrap().is_empty());
+        assert_eq!(other.list_branches().len(), 1);
+    }
+
+    #[test]
+    fn moving_a_branch_edits_its_list() {
+        let doc = example();
+        let list = branch_list(&doc, "draft");
+        doc.put_in_map(root(), "b".to_string(), int(2)).unwrap();
+        let heads = doc.heads();
+        doc.create_branch("draft".to_string(), heads.clone())
+            .unwrap();
+
+        assert_eq!(branch_list(&doc, "draft"), list);
+        assert_eq!(branch_heads(&doc, "draft"), hashes(heads));
+    }
+
+    #[test]
+    fn concurrent_moves_keep_both_heads() {
+        let doc = example();
+        let other = doc.fork();
+        doc.put_in_map(root(), "b".to_string(), int(2)).unwrap();
+        let ours = doc.heads();
+        doc.create_branch("draft".to_string(), ours.clone())
+            .unwrap();
+        other.put_in_map(root(), "c".to_string(), int(3)).unwrap();
+        let theirs = other.heads();
+        other
+            .create_branch("draft".to_string(), theirs.clone())
+            .unwrap();
+
+        doc.merge(other).unwrap();
+        let both = hashes(ours).union(&hashes(theirs)).copied().collect();
+        assert_eq!(branch_heads(&doc, "draft"), both);
+    }
+
+    #[test]
+    fn reverting_keeps_branches() {
+        let doc = Arc::new(Doc::new());
+        doc.put_in_map(root(), "a".to_string(), int(1)).unwrap();
+        let heads = doc.heads();
+        doc.create_branch("draft".to_string(), heads.clone())
+            .unwrap();
+        doc.put_in_map(root(), "b".to_string(), int(2)).unwrap();
+
+        doc.revert_to(heads.clone(), None, ExpandMark::After)
+            .unwrap();
+        assert_eq!(state(&doc), json!({"a": 1}));
+        assert_eq!(branch_heads(&doc, "draft"), hashes(heads));
+    }
+
+    #[test]
+    fn branches_are_saved_with_the_document() {
+        let doc = example();
+        let heads = branch_heads(&doc, "draft");
+        let loaded = Doc::load(doc.save()).unwrap();
+        assert_eq!(branch_heads(&loaded, "draft"), heads);
+        assert_eq!(state(&loaded), json!({"a": 1}));
+    }
+}
@@ -0,0 +1,563 @@
+//! The subset of CBOR used by the automerge-repo wire protocol.
+//!
+//! Values are always encoded with definite lengths and the shortest integer
+//! encoding. Decoding additionally accepts indefinite length strings, arrays
+//! and maps, floats of any width, and skips tags.
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Cbor {
+    Uint(u64),
+    /// A negative integer, stored as `-1 - n`
+    Negative(u64),
+    Bytes(Vec<u8>),
+    Text(String),
+    Array(Vec<Cbor>),
+    Map(Vec<(Cbor, Cbor)>),
+    Bool(bool),
+    Null,
+    Undefined,
+    Float(f64),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum DecodeError {
+    #[error("unexpected end of input")]
+    NotEnoughInput,
+    #[error("unsupported CBOR item with initial byte {0:#04x}")]
+    Unsupported(u8),
+    #[error("invalid UTF-8 in text string")]
+    InvalidText,
+    #[error("unexpected data after the CBOR item")]
+    LeftoverData,
+    #[error("items are nested more than {MAX_DEPTH} deep")]
+    TooDeep,
+}
+
+impl Cbor {
+    pub(crate) fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.encode_into(&mut out);
+        out
+    }
+
+    fn encode_into(&self, out: &mut Vec<u8>) {
+        match self {
+            Cbor::Uint(n) => header(out, 0, *n),
+            Cbor::Negative(n) => header(out, 1, *n),
+            Cbor::Bytes(b) => {
+                header(out, 2, b.len() as u64);
+                out.extend_from_slice(b);
+            }
+            Cbor::Text(s) => {
+                header(out, 3, s.len() as u64);
+                out.extend_from_slice(s.as_bytes());
+            }
+            Cbor::Array(items) => {
+                header(out, 4, items.len() as u64);
+                for item in items {
+                    item.encode_into(out);
+                }
+            }
+            Cbor::Map(entries) => {
+                header(out, 5, entries.len() as u64);
+                for (key, value) in entries {
+                    key.encode_into(out);
+                    value.encode_into(out);
+                }
+            }
+            Cbor::Bool(false) => out.push(0xf4),
+            Cbor::Bool(true) => out.push(0xf5),
+            Cbor::Null => out.push(0xf6),
+            Cbor::Undefined => out.push(0xf7),
+            Cbor::Float(f) => {
+                out.push(0xfb);
+                out.extend_from_slice(&f.to_be_bytes());
+            }
+        }
+    }
+
+    pub(crate) fn decode(bytes: &[u8]) -> Result<Cbor, DecodeError> {
+        let mut decoder = Decoder {
+            bytes,
+            pos: 0,
+            depth: 0,
+        };
+        let value = decoder.item()?;
+        if decoder.pos != bytes.len() {
+            return Err(DecodeError::LeftoverData);
+        }
+        Ok(value)
+    }
+
+    pub(crate) fn int(value: i64) -> Cbor {
+        match u64::try_from(value) {
+            Ok(n) => Cbor::Uint(n),
+            Err(_) => Cbor::Negative(!(value as u64)),
+        }
+    }
+
+    /// Looks up a text key in a map.
+    pub(crate) fn get(&self, key: &str) -> Option<&Cbor> {
+        match self {
+            Cbor::Map(entries) => entries
+                .iter()
+                .find(|(k, _)| matches!(k, Cbor::Text(k) if k == key))
+                .map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            Cbor::Text(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Cbor::Bytes(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_u64(&self) -> Option<u64> {
+        match self {
+            Cbor::Uint(n) => Some(*n),
+            Cbor::Float(f) if f.fract() == 0.0 && *f >= 0.0 && *f <= u64::MAX as f64 => {
+                Some(*f as u64)
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_i64(&self) -> Option<i64> {
+        match self {
+            Cbor::Uint(n) => i64::try_from(*n).ok(),
+            Cbor::Negative(n) => i64::try_from(*n).ok().map(|n| -1 - n),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_bool(&self) -> Option<bool> {
+        match self {
+            Cbor::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_array(&self) -> Option<&[Cbor]> {
+        match self {
+            Cbor::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+/// Builds a map with text keys.
+pub(crate) fn map<'a>(entries: impl IntoIterator<Item = (&'a str, Cbor)>) -> Cbor {
+    Cbor::Map(
+        entries
+            .into_iter()
+            .map(|(k, v)| (Cbor::Text(k.to_string()), v))
+            .collect(),
+    )
+}
+
+fn header(out: &mut Vec<u8>, major: u8, n: u64) {
+    let major = major << 5;
+    if n < 24 {
+        out.push(major | n as u8);
+    } else if n <= u64::from(u8::MAX) {
+        out.push(major | 24);
+        out.push(n as u8);
+    } else if n <= u64::from(u16::MAX) {
+        out.push(major | 25);
+        out.extend_from_slice(&(n as u16).to_be_bytes());
+    } else if n <= u64::from(u32::MAX) {
+        out.push(major | 26);
+        out.extend_from_slice(&(n as u32).to_be_bytes());
+    } else {
+        out.push(major | 27);
+        out.extend_from_slice(&n.to_be_bytes());
+    }
+}
+
+const BREAK: u8 = 0xff;
+const MAX_DEPTH: usize = 64;
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    depth: usize,
+}
+
+impl Decoder<'_> {
+    fn take(&mut self, len: usize) -> Result<&[u8], DecodeError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .ok_or(DecodeError::NotEnoughInput)?;
+        let taken = self
+            .bytes
+            .get(self.pos..end)
+            .ok_or(DecodeError::NotEnoughInput)?;
+        self.pos = end;
+        Ok(taken)
+    }
+
+    fn byte(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn peek_break(&mut self) -> Result<bool, DecodeError> {
+        match self.bytes.get(self.pos) {
+            Some(&BREAK) => {
+                self.pos += 1;
+                Ok(true)
+            }
+            Some(_) => Ok(false),
+            None => Err(DecodeError::NotEnoughInput),
+        }
+    }
+
+    /// Reads the argument of an item, or `None` for an indefinite length.
+    fn argument(&mut self, initial: u8) -> Result<Option<u64>, DecodeError> {
+        let n = match initial & 0x1f {
+            n @ 0..=23 => u64::from(n),
+            24 => u64::from(self.byte()?),
+            25 => u64::from(u16::from_be_bytes(self.take(2)?.try_into().unwrap())),
+            26 => u64::from(u32::from_be_bytes(self.take(4)?.try_into().unwrap())),
+            27 => u64::from_be_bytes(self.take(8)?.try_into().unwrap()),
+            31 => return Ok(None),
+            _ => return Err(DecodeError::Unsupported(initial)),
+        };
+        Ok(Some(n))
+    }
+
+    fn len(&mut self, n: u64) -> Result<usize, DecodeError> {
+        // Every element takes at least one byte, so this also bounds allocations
+        usize::try_from(n)
+            .ok()
+            .filter(|n| *n <= self.bytes.len() - self.pos)
+            .ok_or(DecodeError::NotEnoughInput)
+    }
+
+    fn item(&mut self) -> Result<Cbor, DecodeError> {
+        if self.depth == MAX_DEPTH {
+            return Err(DecodeError::TooDeep);
+        }
+        self.depth += 1;
+        let item = self.item_inner();
+        self.depth -= 1;
+        item
+    }
+
+    fn item_inner(&mut self) -> Result<Cbor, DecodeError> {
+        let initial = self.byte()?;
+        let major = initial >> 5;
+        if major == 7 {
+            return self.simple(initial);
+        }
+        let argument = self.argument(initial)?;
+        match (major, argument) {
+            (0, Some(n)) => Ok(Cbor::Uint(n)),
+            (1, Some(n)) => Ok(Cbor::Negative(n)),
+            (2, _) => Ok(Cbor::Bytes(self.string(major, argument)?)),
+            (3, _) => String::from_utf8(self.string(major, argument)?)
+                .map(Cbor::Text)
+                .map_err(|_| DecodeError::InvalidText),
+            (4, Some(n)) => {
+                let len = self.len(n)?;
+                let mut items = Vec::with_capacity(len);
+                for _ in 0..len {
+                    items.push(self.item()?);
+                }
+                Ok(Cbor::Array(items))
+            }
+            (4, None) => {
+                let mut items = Vec::new();
+                while !self.peek_break()? {
+                    items.push(self.item()?);
+                }
+                Ok(Cbor::Array(items))
+            }
+            (5, Some(n)) => {
+                let len = self.len(n)?;
+                let mut entries = Vec::with_capacity(len);
+                for _ in 0..len {
+                    entries.push((self.item()?, self.item()?));
+                }
+                Ok(Cbor::Map(entries))
+            }
+            (5, None) => {
+                let mut entries = Vec::new();
+                while !self.peek_break()? {
+                    entries.push((self.item()?, self.item()?));
+                }
+                Ok(Cbor::Map(entries))
+            }
+            // Tags only annotate the item which follows
+            (6, Some(_)) => self.item(),
+            _ => Err(DecodeError::Unsupported(initial)),
+        }
+    }
+
+    fn string(&mut self, major: u8, argument: Option<u64>) -> Result<Vec<u8>, DecodeError> {
+        match argument {
+            Some(n) => {
+                let len = self.len(n)?;
+                Ok(self.take(len)?.to_vec())
+            }
+            None => {
+                let mut out = Vec::new();
+                while !self.peek_break()? {
+                    let initial = self.byte()?;
+                    let n = match self.argument(initial)? {
+                        Some(n) if initial >> 5 == major => n,
+                        _ => return Err(DecodeError::Unsupported(initial)),
+                    };
+                    let len = self.len(n)?;
+                    out.extend_from_slice(self.take(len)?);
+                }
+                Ok(out)
+            }
+        }
+    }
+
+    fn simple(&mut self, initial: u8) -> Result<Cbor, DecodeError> {
+        match initial {
+            0xf4 => Ok(Cbor::Bool(false)),
+            0xf5 => Ok(Cbor::Bool(true)),
+            0xf6 => Ok(Cbor::Null),
+            0xf7 => Ok(Cbor::Undefined),
+            0xf9 => {
+                let bits = u16::from_be_bytes(self.take(2)?.try_into().unwrap());
+                Ok(Cbor::Float(half_to_f64(bits)))
+            }
+            0xfa => {
+                let bits = u32::from_be_bytes(self.take(4)?.try_into().unwrap());
+                Ok(Cbor::Float(f64::from(f32::from_bits(bits))))
+            }
+            0xfb => {
+                let bits = u64::from_be_bytes(self.take(8)?.try_into().unwrap());
+                Ok(Cbor::Float(f64::from_bits(bits)))
+            }
+            _ => Err(DecodeError::Unsupported(initial)),
+        }
+    }
+}
+
+fn half_to_f64(bits: u16) -> f64 {
+    let sign = if bits & 0x8000 == 0 { 1.0 } else { -1.0 };
+    let exponent = i32::from((bits >> 10) & 0x1f);
+    let mantissa = f64::from(bits & 0x3ff);
+    sign * match exponent {
+        0 => mantissa * 2f64.powi(-24),
+        31 if mantissa == 0.0 => f64::INFINITY,
+        31 => f64::NAN,
+        _ => (1024.0 + mantissa) * 2f64.powi(exponent - 25),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repo_message::{decode_repo_message, encode, RepoMessage};
+
+    const DOCUMENT_ID: &str = "pEbmSWqJdBuPadRGm8tDZXgWR6";
+
+    // automerge-repo messages, with their keys in the order automerge-repo writes them
+    const JOIN: &str = "a46474797065646a6f696e6873656e646572496465616c6963656c706565724d65746164617461a26973746f7261676549646573746f72656b6973457068656d6572616cf47819737570706f7274656450726f746f636f6c56657273696f6e73816131";
+    const PEER: &str = "a5647479706564706565726873656e6465724964667365727665726c706565724d65746164617461a16b6973457068656d6572616cf47773656c656374656450726f746f636f6c56657273696f6e613168746172676574496465616c696365";
+    const REQUEST: &str = "a564747970656772657175657374687461726765744964667365727665726a646f63756d656e744964781a7045626d5357714a64427550616452476d3874445a586757523664646174614242016873656e646572496465616c696365";
+    const SYNC: &str = "a564747970656473796e6368746172676574496465616c6963656a646f63756d656e744964781a7045626d5357714a64427550616452476d3874445a586757523664646174614242016873656e646572496466736572766572";
+    const DOC_UNAVAILABLE: &str = "a464747970656f646f632d756e617661696c61626c656a646f63756d656e744964781a7045626d5357714a64427550616452476d3874445a586757523668746172676574496465616c6963656873656e646572496466736572766572";
+    const EPHEMERAL: &str = "a7647479706569657068656d6572616c6a646f63756d656e744964781a7045626d5357714a64427550616452476d3874445a5867575236646461746141a068746172676574496463626f6265636f756e74036973657373696f6e49646273316873656e646572496465616c696365";
+
+    fn decode_message(fixture: &str) -> RepoMessage {
+        decode_repo_message(hex::decode(fixture).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn repo_messages_round_trip_through_their_automerge_repo_bytes() {
+        for fixture in [JOIN, PEER, REQUEST, SYNC, DOC_UNAVAILABLE, EPHEMERAL] {
+            assert_eq!(hex::encode(encode(&decode_message(fixture))), fixture);
+        }
+    }
+
+    #[test]
+    fn join_and_peer_messages_decode() {
+        match decode_message(JOIN) {
+            RepoMessage::Join {
+                sender_id,
+                peer_metadata,
+                supported_protocol_versions,
+            } => {
+                assert_eq!(sender_id, "alice");
+                assert_eq!(peer_metadata.storage_id.as_deref(), Some("store"));
+                assert!(!peer_metadata.is_ephemeral);
+                assert_eq!(supported_protocol_versions, vec!["1"]);
+            }
+            _ => panic!("expected a join message"),
+        }
+        match decode_message(PEER) {
+            RepoMessage::Peer {
+                sender_id,
+                target_id,
+                peer_metadata,
+                selected_protocol_version,
+            } => {
+                assert_eq!(
+                    (sender_id.as_str(), target_id.as_str()),
+                    ("server", "alice")
+                );
+                assert_eq!(peer_metadata.storage_id, None);
+                assert_eq!(selected_protocol_version, "1");
+            }
+            _ => panic!("expected a peer message"),
+        }
+    }
+
+    #[test]
+    fn document_messages_decode() {
+        match decode_message(REQUEST) {
+            RepoMessage::Request {
+                sender_id,
+                target_id,
+                document_id,
+                data,
+            } => {
+                assert_eq!(
+                    (sender_id.as_str(), target_id.as_str()),
+                    ("alice", "server")
+                );
+                assert_eq!(document_id.as_str(), DOCUMENT_ID);
+                assert_eq!(data, vec![0x42, 0x01]);
+            }
+            _ => panic!("expected a request message"),
+        }
+        match decode_message(SYNC) {
+            RepoMessage::Sync {
+                sender_id,
+                target_id,
+                document_id,
+                data,
+            } => {
+                assert_eq!(
+                    (sender_id.as_str(), target_id.as_str()),
+                    ("server", "alice")
+                );
+                assert_eq!(document_id.as_str(), DOCUMENT_ID);
+                assert_eq!(data, vec![0x42, 0x01]);
+            }
+            _ => panic!("expected a sync message"),
+        }
+        match decode_message(DOC_UNAVAILABLE) {
+            RepoMessage::DocUnavailable {
+                sender_id,
+                target_id,
+                document_id,
+            } => {
+                assert_eq!(
+                    (sender_id.as_str(), target_id.as_str()),
+                    ("server", "alice")
+                );
+                assert_eq!(document_id.as_str(), DOCUMENT_ID);
+            }
+            _ => panic!("expected a doc-unavailable message"),
+        }
+        match decode_message(EPHEMERAL) {
+            RepoMessage::Ephemeral {
+                sender_id,
+                target_id,
+                document_id,
+                session_id,
+                count,
+                data,
+            } => {
+                assert_eq!((sender_id.as_str(), target_id.as_str()), ("alice", "bob"));
+                assert_eq!(document_id.as_str(), DOCUMENT_ID);
+                assert_eq!((session_id.as_str(), count), ("s1", 3));
+                assert_eq!(data, vec![0xa0]);
+            }
+            _ => panic!("expected an ephemeral message"),
+        }
+    }
+
+    #[test]
+    fn indefinite_length_items_decode() {
+        // {_ "a": [_ 1, 2], "b": (_ h'01', h'02'), "c": (_ "x", "y")}
+        let bytes = hex::decode("bf61619f0102ff61625f41014102ff61637f61786179ffff").unwrap();
+        let expected = map([
+            ("a", Cbor::Array(vec![Cbor::Uint(1), Cbor::Uint(2)])),
+            ("b", Cbor::Bytes(vec![1, 2])),
+            ("c", Cbor::Text("xy".to_string())),
+        ]);
+        assert_eq!(Cbor::decode(&bytes).unwrap(), expected);
+    }
+
+    #[test]
+    fn indefinite_strings_must_be_made_of_the_same_type() {
+        // (_ "x", h'01')
+        let bytes = hex::decode("7f61784101ff").unwrap();
+        assert!(matches!(
+            Cbor::decode(&bytes),
+            Err(DecodeError::Unsupported(0x41))
+        ));
+    }
+
+    #[test]
+    fn half_floats_decode() {
+        let half = |bits: &str| match Cbor::decode(&hex::decode(bits).unwrap()).unwrap() {
+            Cbor::Float(f) => f,
+            other => panic!("expected a float, got {:?}", other),
+        };
+        assert_eq!(half("f93c00"), 1.0);
+        assert_eq!(half("f9c400"), -4.0);
+        assert_eq!(half("f97bff"), 65504.0);
+        assert_eq!(half("f90001"), 2f64.powi(-24));
+        assert_eq!(half("f97c00"), f64::INFINITY);
+        assert_eq!(half("f9fc00"), f64::NEG_INFINITY);
+        assert!(half("f97e00").is_nan());
+    }
+
+    #[test]
+    fn nesting_is_limited() {
+        let nested = |depth: usize| {
+            let mut bytes = vec![0x81; depth - 1];
+            bytes.push(0x00);
+            bytes
+        };
+        assert!(Cbor::decode(&nested(MAX_DEPTH)).is_ok());
+        assert!(matches!(
+            Cbor::decode(&nested(MAX_DEPTH + 1)),
+            Err(DecodeError::TooDeep)
+        ));
+        // Indefinite length arrays never run out of input before the limit
+        assert!(matches!(
+            Cbor::decode(&[0x9f; 10_000]),
+            Err(DecodeError::TooDeep)
+        ));
+    }
+
+    #[test]
+    fn truncated_and_trailing_input_is_rejected() {
+        let join = hex::decode(JOIN).unwrap();
+        assert!(matches!(
+            Cbor::decode(&join[..join.len() - 1]),
+            Err(DecodeError::NotEnoughInput)
+        ));
+        let mut trailing = join;
+        trailing.push(0x00);
+        assert!(matches!(
+            Cbor::decode(&trailing),
+            Err(DecodeError::LeftoverData)
+        ));
+        // A length longer than the input is rejected before allocating
+        assert!(matches!(
+            Cbor::decode(&hex::decode("9bffffffffffffffff").unwrap()),
+            Err(DecodeError::NotEnoughInput)
+        ));
+    }
+}
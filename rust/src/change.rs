@@ -4,6 +4,7 @@ use crate::{ActorId, ChangeHash};
 
 pub struct Change {
     pub actor_id: ActorId,
+    pub seq: u64,
     pub message: Option<String>,
     pub deps: Vec<ChangeHash>,
     pub timestamp: i64,
@@ -15,8 +16,9 @@ impl From<am::Change> for Change {
     fn from(mut value: am::Change) -> Self {
         Change {
             actor_id: value.actor_id().into(),
+            seq: value.seq(),
             message: value.message().cloned(),
-            deps: value.deps().into_iter().map(ChangeHash::from).collect(),
+            deps: value.deps().iter().map(ChangeHash::from).collect(),
             timestamp: value.timestamp(),
             bytes: value.bytes().into_owned(),
             hash: value.hash().into(),
@@ -24,9 +26,11 @@ impl From<am::Change> for Change {
     }
 }
 
-impl From<Change> for am::Change {
-    fn from(value: Change) -> Self {
-        am::Change::try_from(value.bytes.as_slice()).unwrap()
+impl TryFrom<Change> for am::Change {
+    type Error = am::LoadChangeError;
+
+    fn try_from(value: Change) -> Result<Self, Self::Error> {
+        am::Change::try_from(value.bytes.as_slice())
     }
 }
 
@@ -36,7 +40,6 @@ pub enum DecodeChangeError {
     Internal(#[from] am::LoadChangeError),
 }
 
-#[allow(dead_code)]
 pub fn decode_change(bytes: Vec<u8>) -> Result<Change, DecodeChangeError> {
     am::Change::try_from(bytes.as_slice())
         .map(Change::from)
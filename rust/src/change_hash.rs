@@ -1,24 +1,28 @@
 use super::UniffiCustomTypeConverter;
 use automerge as am;
 
-pub struct ChangeHash(Vec<u8>);
+use crate::DocError;
+
+/// A change hash, which is checked when it is lifted from the FFI so that
+/// hashes of the wrong length are rejected there.
+#[derive(Debug, Clone)]
+pub struct ChangeHash(am::ChangeHash);
 
 impl From<ChangeHash> for am::ChangeHash {
     fn from(value: ChangeHash) -> Self {
-        let inner: [u8; 32] = value.0.try_into().unwrap();
-        am::ChangeHash(inner)
+        value.0
     }
 }
 
 impl From<am::ChangeHash> for ChangeHash {
     fn from(value: am::ChangeHash) -> Self {
-        Self(value.0.to_vec())
+        Self(value)
     }
 }
 
 impl<'a> From<&'a am::ChangeHash> for ChangeHash {
     fn from(value: &'a am::ChangeHash) -> Self {
-        Self(value.0.to_vec())
+        Self(*value)
     }
 }
 
@@ -29,10 +33,28 @@ impl UniffiCustomTypeConverter for ChangeHash {
     where
         Self: Sized,
     {
-        Ok(Self(val))
+        let len = val.len();
+        let hash: [u8; 32] = val.try_into().map_err(|_| DocError::InvalidChangeHash {
+            message: format!("expected 32 bytes but received {}", len),
+        })?;
+        Ok(Self(am::ChangeHash(hash)))
     }
 
     fn from_custom(obj: Self) -> Self::Builtin {
-        obj.0
+        obj.0 .0.to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lifting_checks_the_length() {
+        let hash = am::ChangeHash([7; 32]);
+        let lifted = ChangeHash::into_custom(vec![7; 32]).unwrap();
+        assert_eq!(am::ChangeHash::from(lifted), hash);
+
+        assert!(ChangeHash::into_custom(vec![7; 31]).is_err());
     }
 }
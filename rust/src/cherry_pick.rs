@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+
+use automerge as am;
+
+use crate::{ChangeHash, DocError};
+
+/// The changes applied by a cherry-pick.
+pub struct CherryPick {
+    /// The requested changes, in the order they were requested, leaving out
+    /// any the document already had
+    pub applied: Vec<ChangeHash>,
+    /// The changes which were not requested but had to be applied because
+    /// the requested ones depend on them
+    pub dependencies: Vec<ChangeHash>,
+}
+
+/// Applies the changes `hashes` and every change they depend on which `doc`
+/// does not have yet, looking them up with `source`.
+pub(crate) fn cherry_pick<F>(
+    doc: &mut am::AutoCommit,
+    hashes: Vec<am::ChangeHash>,
+    source: F,
+) -> Result<CherryPick, DocError>
+where
+    F: Fn(&am::ChangeHash) -> Option<am::Change>,
+{
+    let requested = hashes.iter().copied().collect::<HashSet<_>>();
+    let missing = hashes
+        .iter()
+        .filter(|hash| doc.get_change_by_hash(hash).is_none() && source(hash).is_none())
+        .map(|hash| ChangeHash::from(*hash))
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        return Err(DocError::ChangeNotFound { hashes: missing });
+    }
+
+    let mut seen = HashSet::new();
+    let mut changes = Vec::new();
+    let mut unavailable = Vec::new();
+    let mut stack = hashes.iter().rev().copied().collect::<Vec<_>>();
+    while let Some(hash) = stack.pop() {
+        if !seen.insert(hash) || doc.get_change_by_hash(&hash).is_some() {
+            continue;
+        }
+        let Some(change) = source(&hash) else {
+            unavailable.push(ChangeHash::from(hash));
+            continue;
+        };
+        stack.extend(change.deps().iter().rev().copied());
+        changes.push(change);
+    }
+    if !unavailable.is_empty() {
+        return Err(DocError::MissingDependencies {
+            hashes: unavailable,
+        });
+    }
+
+    let picked = changes.iter().map(|c| c.hash()).collect::<HashSet<_>>();
+    let applied = hashes
+        .iter()
+        .filter(|hash| picked.contains(hash))
+        .map(|hash| ChangeHash::from(*hash))
+        .collect();
+    let dependencies = changes
+        .iter()
+        .map(|c| c.hash())
+        .filter(|hash| !requested.contains(hash))
+        .map(ChangeHash::from)
+        .collect();
+    // Automerge holds back changes until their dependencies arrive, so the
+    // order they are applied in does not matter
+    doc.apply_changes(changes)
+        .map_err(|e| DocError::in_apply(&*doc, e))?;
+    Ok(CherryPick {
+        applied,
+        dependencies,
+    })
+}
@@ -0,0 +1,37 @@
+use automerge as am;
+
+use crate::{ActorId, ChangeHash, Value};
+
+/// One of the values written concurrently to a property.
+pub struct Conflict {
+    pub value: Value,
+    /// The id of the operation which wrote the value, as `counter@actor`
+    pub op_id: String,
+    pub actor: ActorId,
+    /// The change containing the operation, or `None` if it has not been
+    /// committed yet
+    pub change: Option<ChangeHash>,
+    /// Whether this is the value returned when reading the property
+    pub winner: bool,
+}
+
+impl Conflict {
+    pub(crate) fn new(
+        value: am::Value<'_>,
+        id: am::ObjId,
+        change: Option<am::ChangeHash>,
+        winner: bool,
+    ) -> Self {
+        let actor = match &id {
+            am::ObjId::Id(_, actor, _) => ActorId::from(actor),
+            am::ObjId::Root => unreachable!("the root is never the value of a property"),
+        };
+        Self {
+            op_id: id.to_string(),
+            value: Value::from((value, id)),
+            actor,
+            change: change.map(ChangeHash::from),
+            winner,
+        }
+    }
+}
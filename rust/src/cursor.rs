@@ -1,7 +1,11 @@
 use super::UniffiCustomTypeConverter;
 use automerge as am;
 
-pub struct Cursor(Vec<u8>);
+use crate::DocError;
+
+/// A cursor, which is parsed when it is lifted from the FFI so that
+/// malformed bytes are rejected there.
+pub struct Cursor(am::Cursor);
 
 pub enum Position {
     Cursor { position: Cursor },
@@ -10,13 +14,13 @@ pub enum Position {
 
 impl From<Cursor> for am::Cursor {
     fn from(value: Cursor) -> Self {
-        am::Cursor::try_from(value.0).unwrap()
+        value.0
     }
 }
 
 impl From<am::Cursor> for Cursor {
     fn from(value: am::Cursor) -> Self {
-        Cursor(value.to_bytes())
+        Cursor(value)
     }
 }
 
@@ -27,10 +31,32 @@ impl UniffiCustomTypeConverter for Cursor {
     where
         Self: Sized,
     {
-        Ok(Self(val))
+        let cursor = am::Cursor::try_from(val.as_slice()).map_err(|e| DocError::InvalidCursor {
+            message: e.to_string(),
+        })?;
+        Ok(Self(cursor))
     }
 
     fn from_custom(obj: Self) -> Self::Builtin {
-        obj.0
+        obj.0.to_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use am::{transaction::Transactable, ReadDoc};
+
+    #[test]
+    fn lifting_checks_the_bytes() {
+        let mut doc = am::AutoCommit::new();
+        let text = doc.put_object(am::ROOT, "text", am::ObjType::Text).unwrap();
+        doc.splice_text(&text, 0, 0, "hello").unwrap();
+        let cursor = doc.get_cursor(&text, 2, None).unwrap();
+
+        let lifted = Cursor::into_custom(cursor.to_bytes()).unwrap();
+        assert_eq!(am::Cursor::from(lifted), cursor);
+
+        assert!(Cursor::into_custom(vec![0xff]).is_err());
     }
 }
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use automerge as am;
+
+use crate::text_encoding::width;
+use crate::{ObjId, PathElement};
+
+/// A summary of the patches to one object.
+pub struct DiffSummary {
+    pub obj: ObjId,
+    pub path: Vec<PathElement>,
+    /// The map keys which were set, deleted or incremented, in the order
+    /// they were first changed
+    pub changed_keys: Vec<String>,
+    /// The number of list elements or text characters inserted
+    pub inserted: u64,
+    /// The number of list elements or text characters deleted
+    pub removed: u64,
+    /// The number of list elements overwritten or incremented, plus the
+    /// number of marks changed
+    pub updated: u64,
+}
+
+/// Whether `patch` changes `obj`, or with `recursive` any object inside it.
+pub(crate) fn is_within(patch: &am::Patch, obj: &am::ObjId, recursive: bool) -> bool {
+    &patch.obj == obj || (recursive && patch.path.iter().any(|(parent, _)| parent == obj))
+}
+
+/// Summarises `patches` per object, in the order the objects first appear.
+pub(crate) fn summarize(patches: Vec<am::Patch>, encoding: am::TextEncoding) -> Vec<DiffSummary> {
+    let mut summaries: Vec<DiffSummary> = Vec::new();
+    let mut index: HashMap<am::ObjId, usize> = HashMap::new();
+    for am::Patch { obj, path, action } in patches {
+        let i = *index.entry(obj.clone()).or_insert_with(|| {
+            summaries.push(DiffSummary {
+                obj: obj.into(),
+                path: path
+                    .into_iter()
+                    .map(|(obj, prop)| PathElement::new(prop, obj))
+                    .collect(),
+                changed_keys: Vec::new(),
+                inserted: 0,
+                removed: 0,
+                updated: 0,
+            });
+            summaries.len() - 1
+        });
+        let summary = &mut summaries[i];
+        let key = match action {
+            am::PatchAction::PutMap { key, .. } | am::PatchAction::DeleteMap { key } => Some(key),
+            am::PatchAction::Increment { prop, .. } | am::PatchAction::Conflict { prop } => {
+                match prop {
+                    am::Prop::Map(key) => Some(key),
+                    am::Prop::Seq(_) => {
+                        summary.updated += 1;
+                        None
+                    }
+                }
+            }
+            am::PatchAction::PutSeq { .. } => {
+                summary.updated += 1;
+                None
+            }
+            am::PatchAction::Insert { values, .. } => {
+                summary.inserted += values.len() as u64;
+                None
+            }
+            am::PatchAction::SpliceText { value, .. } => {
+                summary.inserted += width(encoding, &value.make_string()) as u64;
+                None
+            }
+            am::PatchAction::DeleteSeq { length, .. } => {
+                summary.removed += length as u64;
+                None
+            }
+            am::PatchAction::Mark { marks } => {
+                summary.updated += marks.len() as u64;
+                None
+            }
+        };
+        if let Some(key) = key {
+            if !summary.changed_keys.contains(&key) {
+                summary.changed_keys.push(key);
+            }
+        }
+    }
+    summaries
+}
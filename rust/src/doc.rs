@@ -1,41 +1,264 @@
-use std::sync::{Arc, RwLock, RwLockWriteGuard};
+use std::collections::HashMap;
+use std::ops::Bound;
+use std::sync::{Arc, Mutex, RwLock, RwLockWriteGuard};
 
 use automerge::{self as am, sync::SyncDoc, CursorPosition};
 use automerge::{transaction::Transactable, ReadDoc};
 
 use crate::actor_id::ActorId;
+use crate::blame::{self, Attribution, TextSpan};
+use crate::branch::{self, Branch};
+use crate::cherry_pick::{self, CherryPick};
+use crate::conflict::Conflict;
 use crate::cursor::Position;
+use crate::diff::{self, DiffSummary};
+use crate::hydrate::{self, HydrateValue};
+use crate::json;
+use crate::load_options::LoadOptions;
 use crate::mark::{ExpandMark, KeyValue, Mark};
-use crate::patches::Patch;
+use crate::patches::{Patch, PatchObserver};
+use crate::revert::{self, Restored};
+use crate::sync_message::split_changes;
 use crate::text_encoding::TextEncoding;
+use crate::transaction::Transaction;
 
 use crate::{
-    Change, ChangeHash, Cursor, ObjId, ObjType, PathElement, ScalarValue, SyncState, Value,
+    Change, ChangeHash, Cursor, ObjId, ObjType, PathElement, Prop, ScalarValue, SyncState, Value,
 };
 
 #[derive(Debug, thiserror::Error)]
 pub enum DocError {
-    #[error("WrongObjectType")]
-    WrongObjectType,
-    #[error("Internal error: {0}")]
-    Internal(#[from] automerge::AutomergeError),
+    #[error("Expected a {expected:?} object but found a {actual:?}")]
+    WrongObjectType {
+        obj: ObjId,
+        expected: ObjType,
+        actual: ObjType,
+    },
+    #[error("Object does not exist")]
+    ObjectNotFound { obj: ObjId },
+    #[error("Index {index} is out of bounds for an object of length {length}")]
+    IndexOutOfBounds { obj: ObjId, index: u64, length: u64 },
+    #[error("Heads contain hashes which are not in the document")]
+    InvalidHeads { hashes: Vec<ChangeHash> },
+    #[error("Changes depend on changes which are not in the document")]
+    MissingDependencies { hashes: Vec<ChangeHash> },
+    #[error("Corrupt data: {message}")]
+    CorruptData { message: String },
+    #[error("Actor {actor} has two different changes with sequence number {seq}")]
+    DuplicateSeqNumber { actor: String, seq: u64 },
+    #[error("Invalid object ID: {message}")]
+    InvalidObjId { message: String },
+    #[error("Invalid change hash: {message}")]
+    InvalidChangeHash { message: String },
+    #[error("Invalid cursor: {message}")]
+    InvalidCursor { message: String },
+    #[error("Transaction already committed or rolled back")]
+    TransactionClosed,
+    #[error("Invalid JSON: {message}")]
+    InvalidJson { message: String },
+    #[error("No value written by {op_id} is present")]
+    ConflictNotFound { op_id: String },
+    #[error("Changes are not available to apply")]
+    ChangeNotFound { hashes: Vec<ChangeHash> },
+    #[error("No branch named {name}")]
+    BranchNotFound { name: String },
+    #[error("The key {key} is reserved for branches")]
+    ReservedKey { key: String },
+    #[error("Internal error: {message}")]
+    Internal { message: String },
+}
+
+impl From<am::AutomergeError> for DocError {
+    fn from(error: am::AutomergeError) -> Self {
+        match error {
+            am::AutomergeError::InvalidHash(hash) | am::AutomergeError::MissingHash(hash) => {
+                DocError::InvalidHeads {
+                    hashes: vec![hash.into()],
+                }
+            }
+            am::AutomergeError::ChangeGraph(_) | am::AutomergeError::MissingDeps => {
+                DocError::MissingDependencies { hashes: Vec::new() }
+            }
+            am::AutomergeError::DuplicateSeqNumber(seq, actor) => DocError::DuplicateSeqNumber {
+                actor: actor.to_hex_string(),
+                seq,
+            },
+            am::AutomergeError::InvalidCursor(_) | am::AutomergeError::InvalidCursorFormat => {
+                DocError::InvalidCursor {
+                    message: error.to_string(),
+                }
+            }
+            error if is_corrupt_data(&error) => DocError::CorruptData {
+                message: error.to_string(),
+            },
+            error => DocError::Internal {
+                message: error.to_string(),
+            },
+        }
+    }
+}
+
+impl DocError {
+    /// Converts `error` with the object it was raised for, so that errors
+    /// about missing objects or indices can say which object was involved.
+    pub(crate) fn in_object<R: ReadDoc>(
+        doc: &R,
+        obj: &am::ObjId,
+        error: am::AutomergeError,
+    ) -> Self {
+        match error {
+            am::AutomergeError::NotAnObject | am::AutomergeError::InvalidObjId(_) => {
+                DocError::ObjectNotFound {
+                    obj: obj.clone().into(),
+                }
+            }
+            am::AutomergeError::InvalidIndex(index) => DocError::IndexOutOfBounds {
+                obj: obj.clone().into(),
+                index: index as u64,
+                length: doc.length(obj) as u64,
+            },
+            error => error.into(),
+        }
+    }
+
+    /// Converts `error` from applying changes to `doc`, listing the changes
+    /// `doc` is waiting for if dependencies are missing.
+    pub(crate) fn in_apply<R: ReadDoc>(doc: &R, error: am::AutomergeError) -> Self {
+        match DocError::from(error) {
+            DocError::MissingDependencies { .. } => DocError::MissingDependencies {
+                hashes: missing_deps(doc),
+            },
+            error => error,
+        }
+    }
+}
+
+fn missing_deps<R: ReadDoc>(doc: &R) -> Vec<ChangeHash> {
+    doc.get_missing_deps(&[])
+        .into_iter()
+        .map(ChangeHash::from)
+        .collect()
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum LoadError {
-    #[error(transparent)]
-    Internal(#[from] automerge::AutomergeError),
+    #[error("Corrupt data: {message}")]
+    CorruptData { message: String },
+    #[error("Changes depend on changes which are not in the document")]
+    MissingDependencies { hashes: Vec<ChangeHash> },
+    #[error("Actor {actor} has two different changes with sequence number {seq}")]
+    DuplicateSeqNumber { actor: String, seq: u64 },
+    #[error("{message}")]
+    Internal { message: String },
+}
+
+impl LoadError {
+    /// Converts `error` from loading `bytes`. Automerge does not say which
+    /// dependencies are missing, so they are found by loading what it can.
+    pub(crate) fn in_load(bytes: &[u8], error: am::AutomergeError) -> Self {
+        let am::AutomergeError::MissingDeps = error else {
+            return error.into();
+        };
+        let options = am::LoadOptions::new().on_partial_load(am::OnPartialLoad::Ignore);
+        match am::AutoCommit::load_with_options(bytes, options) {
+            Ok(doc) => LoadError::MissingDependencies {
+                hashes: missing_deps(&doc),
+            },
+            Err(_) => error.into(),
+        }
+    }
+}
+
+impl From<am::AutomergeError> for LoadError {
+    fn from(error: am::AutomergeError) -> Self {
+        match DocError::from(error) {
+            DocError::CorruptData { message } => LoadError::CorruptData { message },
+            DocError::MissingDependencies { hashes } => LoadError::MissingDependencies { hashes },
+            DocError::DuplicateSeqNumber { actor, seq } => {
+                LoadError::DuplicateSeqNumber { actor, seq }
+            }
+            error => LoadError::Internal {
+                message: error.to_string(),
+            },
+        }
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum ReceiveSyncError {
-    #[error(transparent)]
-    Internal(#[from] am::AutomergeError),
-    #[error("Invalid message")]
-    InvalidMessage,
+    #[error("Changes depend on changes which are not in the document")]
+    MissingDependencies { hashes: Vec<ChangeHash> },
+    #[error("{message}")]
+    Internal { message: String },
+    #[error("Invalid message: {message}")]
+    InvalidMessage { message: String },
+    #[error("Actor {actor} has two different changes with sequence number {seq}")]
+    DuplicateSeqNumber { actor: String, seq: u64 },
+}
+
+impl From<am::AutomergeError> for ReceiveSyncError {
+    fn from(error: am::AutomergeError) -> Self {
+        DocError::from(error).into()
+    }
+}
+
+impl From<DocError> for ReceiveSyncError {
+    fn from(error: DocError) -> Self {
+        match error {
+            DocError::MissingDependencies { hashes } => {
+                ReceiveSyncError::MissingDependencies { hashes }
+            }
+            DocError::DuplicateSeqNumber { actor, seq } => {
+                ReceiveSyncError::DuplicateSeqNumber { actor, seq }
+            }
+            DocError::CorruptData { message } => ReceiveSyncError::InvalidMessage { message },
+            error => ReceiveSyncError::Internal {
+                message: error.to_string(),
+            },
+        }
+    }
 }
 
-pub struct Doc(RwLock<automerge::AutoCommit>);
+fn is_corrupt_data(error: &am::AutomergeError) -> bool {
+    matches!(
+        error,
+        am::AutomergeError::Deflate(_)
+            | am::AutomergeError::Load(_)
+            | am::AutomergeError::LoadChangeError(_)
+            | am::AutomergeError::NonChangeCompressed
+            | am::AutomergeError::InvalidChangeHashBytes(_)
+    )
+}
+
+pub struct Doc(
+    pub(crate) RwLock<automerge::AutoCommit>,
+    RwLock<Option<Arc<Observer>>>,
+    /// The heads as of the last save, which [`Doc::save_incremental`] encodes
+    /// changes after. This is kept apart from automerge's own record, which
+    /// [`Doc::encode_new_changes`] moves on.
+    Mutex<Vec<am::ChangeHash>>,
+);
+
+struct Observer {
+    observer: Box<dyn PatchObserver>,
+    batch_per_commit: bool,
+}
+
+impl Observer {
+    fn deliver(&self, patches: Vec<am::Patch>) {
+        if !patches.is_empty() {
+            self.observer
+                .on_patches(patches.into_iter().map(Patch::from).collect());
+        }
+    }
+}
+
+impl From<am::AutoCommit> for Doc {
+    fn from(mut doc: am::AutoCommit) -> Self {
+        let saved = doc.get_heads();
+        Self(RwLock::new(doc), RwLock::new(None), Mutex::new(saved))
+    }
+}
 
 // These are okay because on the swift side we wrap all accesses of the
 // document to ensure they are only accessed from a single thread
@@ -44,19 +267,15 @@ unsafe impl Sync for Doc {}
 
 impl Doc {
     pub(crate) fn new() -> Self {
-        Self(RwLock::new(automerge::AutoCommit::default()))
+        Self::from(automerge::AutoCommit::default())
     }
 
     pub(crate) fn new_with_actor(actor: ActorId) -> Self {
-        Self(RwLock::new(
-            am::AutoCommit::default().with_actor(actor.into()),
-        ))
+        Self::from(am::AutoCommit::default().with_actor(actor.into()))
     }
 
     pub fn new_with_text_encoding(text_encoding: TextEncoding) -> Self {
-        Self(RwLock::new(am::AutoCommit::new_with_encoding(
-            text_encoding.into(),
-        )))
+        Self::from(am::AutoCommit::new_with_encoding(text_encoding.into()))
     }
 
     pub fn text_encoding(&self) -> TextEncoding {
@@ -75,7 +294,10 @@ impl Doc {
         let obj = am::ObjId::from(obj);
         let mut doc = self.0.write().unwrap();
         assert_map(&*doc, &obj)?;
-        doc.put(obj, key, value).map_err(|e| e.into())
+        branch::check_writable(&obj, &key)?;
+        doc.put(obj, key, value)?;
+        self.notify(doc, false);
+        Ok(())
     }
 
     pub fn put_object_in_map(
@@ -87,7 +309,9 @@ impl Doc {
         let obj = am::ObjId::from(obj);
         let mut doc = self.0.write().unwrap();
         assert_map(&*doc, &obj)?;
+        branch::check_writable(&obj, &key)?;
         let obj = doc.put_object(obj, key, value.into())?;
+        self.notify(doc, false);
         Ok(obj.into())
     }
 
@@ -95,7 +319,10 @@ impl Doc {
         let obj = am::ObjId::from(obj);
         let mut doc = self.0.write().unwrap();
         assert_list(&*doc, &obj)?;
-        doc.put(obj, index as usize, value).map_err(|e| e.into())
+        doc.put(&obj, index as usize, value)
+            .map_err(|e| DocError::in_object(&*doc, &obj, e))?;
+        self.notify(doc, false);
+        Ok(())
     }
 
     pub fn put_object_in_list(
@@ -107,8 +334,11 @@ impl Doc {
         let obj = am::ObjId::from(obj);
         let mut doc = self.0.write().unwrap();
         assert_list(&*doc, &obj)?;
-        let obj = doc.put_object(obj, index as usize, value.into())?;
-        Ok(obj.into())
+        let id = doc
+            .put_object(&obj, index as usize, value.into())
+            .map_err(|e| DocError::in_object(&*doc, &obj, e))?;
+        self.notify(doc, false);
+        Ok(id.into())
     }
 
     pub fn insert_in_list(
@@ -120,7 +350,10 @@ impl Doc {
         let obj = am::ObjId::from(obj);
         let mut doc = self.0.write().unwrap();
         assert_list(&*doc, &obj)?;
-        doc.insert(obj, index as usize, value).map_err(|e| e.into())
+        doc.insert(&obj, index as usize, value)
+            .map_err(|e| DocError::in_object(&*doc, &obj, e))?;
+        self.notify(doc, false);
+        Ok(())
     }
 
     pub fn insert_object_in_list(
@@ -132,43 +365,59 @@ impl Doc {
         let obj = am::ObjId::from(obj);
         let mut doc = self.0.write().unwrap();
         assert_list(&*doc, &obj)?;
-        let obj = doc.insert_object(obj, index as usize, value.into())?;
-        Ok(obj.into())
+        let id = doc
+            .insert_object(&obj, index as usize, value.into())
+            .map_err(|e| DocError::in_object(&*doc, &obj, e))?;
+        self.notify(doc, false);
+        Ok(id.into())
     }
 
     pub fn delete_in_map(&self, obj: ObjId, key: String) -> Result<(), DocError> {
         let obj = am::ObjId::from(obj);
         let mut doc = self.0.write().unwrap();
         assert_map(&*doc, &obj)?;
-        Ok(doc.delete(&obj, key)?)
+        branch::check_writable(&obj, &key)?;
+        doc.delete(&obj, key)?;
+        self.notify(doc, false);
+        Ok(())
     }
 
     pub fn delete_in_list(&self, obj: ObjId, index: u64) -> Result<(), DocError> {
         let obj = am::ObjId::from(obj);
         let mut doc = self.0.write().unwrap();
         assert_list(&*doc, &obj)?;
-        Ok(doc.delete(&obj, index as usize)?)
+        doc.delete(&obj, index as usize)
+            .map_err(|e| DocError::in_object(&*doc, &obj, e))?;
+        self.notify(doc, false);
+        Ok(())
     }
 
     pub fn increment_in_map(&self, obj: ObjId, key: String, by: i64) -> Result<(), DocError> {
         let obj = am::ObjId::from(obj);
         let mut doc = self.0.write().unwrap();
         assert_map(&*doc, &obj)?;
-        Ok(doc.increment(&obj, key, by)?)
+        branch::check_writable(&obj, &key)?;
+        doc.increment(&obj, key, by)?;
+        self.notify(doc, false);
+        Ok(())
     }
 
     pub fn increment_in_list(&self, obj: ObjId, index: u64, by: i64) -> Result<(), DocError> {
         let obj = am::ObjId::from(obj);
         let mut doc = self.0.write().unwrap();
         assert_list(&*doc, &obj)?;
-        Ok(doc.increment(&obj, index as usize, by)?)
+        doc.increment(&obj, index as usize, by)
+            .map_err(|e| DocError::in_object(&*doc, &obj, e))?;
+        self.notify(doc, false);
+        Ok(())
     }
 
     pub fn get_in_map(&self, obj: ObjId, key: String) -> Result<Option<Value>, DocError> {
         let obj = am::ObjId::from(obj);
         let doc = self.0.read().unwrap();
         assert_map(&*doc, &obj)?;
-        Ok(doc.get(obj, key)?.map(|v| v.into()))
+        let mut vals = branch::get_all(&*doc, &obj, am::Prop::Map(key), None)?;
+        Ok(vals.pop().map(Value::from))
     }
 
     pub fn get_in_list(&self, obj: ObjId, idx: u64) -> Result<Option<Value>, DocError> {
@@ -188,7 +437,8 @@ impl Doc {
         let doc = self.0.read().unwrap();
         let heads = heads.into_iter().map(|h| h.into()).collect::<Vec<_>>();
         assert_map(&*doc, &obj)?;
-        Ok(doc.get_at(obj, key, &heads)?.map(|v| v.into()))
+        let mut vals = branch::get_all(&*doc, &obj, am::Prop::Map(key), Some(&heads))?;
+        Ok(vals.pop().map(Value::from))
     }
 
     pub fn get_at_in_list(
@@ -208,7 +458,7 @@ impl Doc {
         let obj = am::ObjId::from(obj);
         let doc = self.0.read().unwrap();
         assert_map(&*doc, &obj)?;
-        let vals = doc.get_all(&obj, key)?;
+        let vals = branch::get_all(&*doc, &obj, am::Prop::Map(key), None)?;
         Ok(vals
             .into_iter()
             .map(|(v, id)| Value::from((v, id)))
@@ -239,7 +489,7 @@ impl Doc {
             .map(am::ChangeHash::from)
             .collect::<Vec<_>>();
         assert_map(&*doc, &obj)?;
-        let vals = doc.get_all_at(&obj, key, heads.as_slice())?;
+        let vals = branch::get_all(&*doc, &obj, am::Prop::Map(key), Some(&heads))?;
         Ok(vals.into_iter().map(Value::from).collect::<Vec<_>>())
     }
 
@@ -260,22 +510,138 @@ impl Doc {
         Ok(vals.into_iter().map(Value::from).collect::<Vec<_>>())
     }
 
-    pub fn map_keys(&self, obj: ObjId) -> Vec<String> {
-        self.0.read().unwrap().keys(am::ObjId::from(obj)).collect()
+    /// Every value currently written to `prop` of `obj`, as of `heads` if
+    /// given, along with who wrote it. The winning value comes last.
+    pub fn get_conflicts(
+        &self,
+        obj: ObjId,
+        prop: Prop,
+        heads: Option<Vec<ChangeHash>>,
+    ) -> Result<Vec<Conflict>, DocError> {
+        let obj = am::ObjId::from(obj);
+        let prop = am::Prop::from(prop);
+        let doc = self.0.read().unwrap();
+        match &prop {
+            am::Prop::Map(_) => assert_map(&*doc, &obj)?,
+            am::Prop::Seq(_) => assert_list(&*doc, &obj)?,
+        }
+        let heads = heads.map(|heads| {
+            heads
+                .into_iter()
+                .map(am::ChangeHash::from)
+                .collect::<Vec<_>>()
+        });
+        let vals = branch::get_all(&*doc, &obj, prop, heads.as_deref())?;
+        let winner = vals.len().saturating_sub(1);
+        Ok(vals
+            .into_iter()
+            .enumerate()
+            .map(|(i, (v, id))| {
+                let change = doc.hash_for_opid(&id);
+                Conflict::new(v, id, change, i == winner)
+            })
+            .collect())
+    }
+
+    /// Resolves a conflict on `prop` of `obj` by writing the value from
+    /// `chosen_op_id` again, so that it replaces every other value.
+    ///
+    /// Objects are copied along with everything inside them, and the id of
+    /// the copy is returned. Nothing is written if the property has no
+    /// conflict.
+    pub fn resolve_conflict(
+        &self,
+        obj: ObjId,
+        prop: Prop,
+        chosen_op_id: String,
+    ) -> Result<Option<ObjId>, DocError> {
+        let obj = am::ObjId::from(obj);
+        let prop = am::Prop::from(prop);
+        let mut doc = self.0.write().unwrap();
+        match &prop {
+            am::Prop::Map(key) => {
+                assert_map(&*doc, &obj)?;
+                branch::check_writable(&obj, key)?;
+            }
+            am::Prop::Seq(_) => assert_list(&*doc, &obj)?,
+        }
+        let vals = doc
+            .get_all(&obj, prop.clone())
+            .map_err(|e| DocError::in_object(&*doc, &obj, e))?;
+        let conflicted = vals.len() > 1;
+        let Some((value, id)) = vals
+            .into_iter()
+            .find(|(_, id)| id.to_string() == chosen_op_id)
+        else {
+            return Err(DocError::ConflictNotFound {
+                op_id: chosen_op_id,
+            });
+        };
+        if !conflicted {
+            return Ok(value.is_object().then(|| id.into()));
+        }
+        let value = match value {
+            am::Value::Object(_) => {
+                hydrate::export(&*doc, &id, None).map_err(|e| DocError::in_object(&*doc, &id, e))?
+            }
+            am::Value::Scalar(s) => HydrateValue::Scalar {
+                value: s.as_ref().into(),
+            },
+        };
+        let id = hydrate::put(&mut *doc, &obj, prop, value)?;
+        self.notify(doc, false);
+        Ok(id.map(ObjId::from))
+    }
+
+    /// The change which wrote the current value of `prop` of `obj`, or
+    /// `None` if the property is unset or was written by the pending change.
+    pub fn blame_property(&self, obj: ObjId, prop: Prop) -> Result<Option<Attribution>, DocError> {
+        let obj = am::ObjId::from(obj);
+        let prop = am::Prop::from(prop);
+        let doc = self.0.read().unwrap();
+        match &prop {
+            am::Prop::Map(_) => assert_map(&*doc, &obj)?,
+            am::Prop::Seq(_) => assert_list(&*doc, &obj)?,
+        }
+        let value = branch::get_all(&*doc, &obj, prop, None)
+            .map_err(|e| DocError::in_object(&*doc, &obj, e))?
+            .pop();
+        Ok(value.and_then(|(_, id)| blame::attribution(&doc, &id)))
+    }
+
+    /// Splits the text `obj` into spans attributed to the change which
+    /// inserted them.
+    pub fn blame_text(&self, obj: ObjId) -> Result<Vec<TextSpan>, DocError> {
+        let obj = am::ObjId::from(obj);
+        let doc = self.0.read().unwrap();
+        assert_text(&*doc, &obj)?;
+        Ok(blame::text_spans(&doc, &obj))
     }
 
-    pub fn map_keys_at(&self, obj: ObjId, heads: Vec<ChangeHash>) -> Vec<String> {
+    pub fn map_keys(&self, obj: ObjId) -> Result<Vec<String>, DocError> {
+        let obj = am::ObjId::from(obj);
+        let doc = self.0.read().unwrap();
+        object_type(&*doc, &obj)?;
+        Ok(branch::map_range(&*doc, &obj, .., None)
+            .map(|item| item.key.to_string())
+            .collect())
+    }
+
+    pub fn map_keys_at(&self, obj: ObjId, heads: Vec<ChangeHash>) -> Result<Vec<String>, DocError> {
         let obj = am::ObjId::from(obj);
         let heads = heads.into_iter().map(|h| h.into()).collect::<Vec<_>>();
-        self.0.read().unwrap().keys_at(&obj, &heads).collect()
+        let doc = self.0.read().unwrap();
+        object_type(&*doc, &obj)?;
+        Ok(branch::map_range(&*doc, &obj, .., Some(&heads))
+            .map(|item| item.key.to_string())
+            .collect())
     }
 
     pub fn map_entries(&self, obj: ObjId) -> Result<Vec<KeyValue>, DocError> {
         let doc = self.0.read().unwrap();
         let obj = am::ObjId::from(obj);
         assert_map(&*doc, &obj)?;
-        Ok(doc
-            .map_range(&obj, ..)
+        Ok(branch::map_range(&*doc, &obj, .., None)
             .map(|am::iter::MapRangeItem { key, value, id, .. }| KeyValue {
                 key: key.into(),
                 value: (value, id).into(),
@@ -292,8 +658,7 @@ impl Doc {
         let obj = am::ObjId::from(obj);
         let heads = heads.into_iter().map(|h| h.into()).collect::<Vec<_>>();
         assert_map(&*doc, &obj)?;
-        Ok(doc
-            .map_range_at(&obj, .., &heads)
+        Ok(branch::map_range(&*doc, &obj, .., Some(&heads))
             .map(|am::iter::MapRangeItem { key, value, id, .. }| KeyValue {
                 key: key.into(),
                 value: (value, id).into(),
@@ -304,6 +669,11 @@ impl Doc {
     pub fn values(&self, obj: ObjId) -> Result<Vec<Value>, DocError> {
         let doc = self.0.read().unwrap();
         let obj = am::ObjId::from(obj);
+        if let Ok(am::ObjType::Map | am::ObjType::Table) = doc.object_type(&obj) {
+            return Ok(branch::map_range(&*doc, &obj, .., None)
+                .map(|item| Value::from((item.value, item.id)))
+                .collect());
+        }
         Ok(doc.values(&obj).map(Value::from).collect::<Vec<_>>())
     }
 
@@ -314,32 +684,121 @@ impl Doc {
             .into_iter()
             .map(am::ChangeHash::from)
             .collect::<Vec<_>>();
+        if let Ok(am::ObjType::Map | am::ObjType::Table) = doc.object_type(&obj) {
+            return Ok(branch::map_range(&*doc, &obj, .., Some(&heads))
+                .map(|item| Value::from((item.value, item.id)))
+                .collect());
+        }
         Ok(doc
             .values_at(&obj, &heads)
             .map(Value::from)
             .collect::<Vec<_>>())
     }
 
-    pub fn length(&self, obj: ObjId) -> u64 {
+    /// The values of the list `obj` from index `start` up to but not
+    /// including `end`, as of `heads` if given.
+    pub fn list_range(
+        &self,
+        obj: ObjId,
+        start: u64,
+        end: u64,
+        heads: Option<Vec<ChangeHash>>,
+    ) -> Result<Vec<Value>, DocError> {
         let doc = self.0.read().unwrap();
         let obj = am::ObjId::from(obj);
-        doc.length(obj) as u64
+        assert_list(&*doc, &obj)?;
+        if start >= end {
+            return Ok(Vec::new());
+        }
+        let range = start as usize..end as usize;
+        let items = match heads {
+            Some(heads) => {
+                let heads = heads
+                    .into_iter()
+                    .map(am::ChangeHash::from)
+                    .collect::<Vec<_>>();
+                doc.list_range_at(&obj, range, &heads).collect::<Vec<_>>()
+            }
+            None => doc.list_range(&obj, range).collect::<Vec<_>>(),
+        };
+        Ok(items
+            .into_iter()
+            .map(|am::iter::ListRangeItem { value, id, .. }| (value, id).into())
+            .collect())
     }
 
-    pub fn length_at(&self, obj: ObjId, heads: Vec<ChangeHash>) -> u64 {
+    /// The entries of the map `obj` with keys from `from_key` up to but not
+    /// including `to_key`, in key order and at most `limit` of them, as of
+    /// `heads` if given. Missing bounds are unbounded.
+    pub fn map_range(
+        &self,
+        obj: ObjId,
+        from_key: Option<String>,
+        to_key: Option<String>,
+        limit: Option<u64>,
+        heads: Option<Vec<ChangeHash>>,
+    ) -> Result<Vec<KeyValue>, DocError> {
+        let doc = self.0.read().unwrap();
+        let obj = am::ObjId::from(obj);
+        assert_map(&*doc, &obj)?;
+        if let (Some(from), Some(to)) = (&from_key, &to_key) {
+            if from >= to {
+                return Ok(Vec::new());
+            }
+        }
+        let range = (
+            from_key.map_or(Bound::Unbounded, Bound::Included),
+            to_key.map_or(Bound::Unbounded, Bound::Excluded),
+        );
+        let limit = limit.map_or(usize::MAX, |limit| limit as usize);
+        let entry = |am::iter::MapRangeItem { key, value, id, .. }| KeyValue {
+            key: key.into(),
+            value: (value, id).into(),
+        };
+        let heads = heads.map(|heads| {
+            heads
+                .into_iter()
+                .map(am::ChangeHash::from)
+                .collect::<Vec<_>>()
+        });
+        Ok(branch::map_range(&*doc, &obj, range, heads.as_deref())
+            .take(limit)
+            .map(entry)
+            .collect())
+    }
+
+    pub fn length(&self, obj: ObjId) -> Result<u64, DocError> {
+        let doc = self.0.read().unwrap();
+        let obj = am::ObjId::from(obj);
+        let length = match object_type(&*doc, &obj)? {
+            am::ObjType::Map | am::ObjType::Table => {
+                branch::map_range(&*doc, &obj, .., None).count()
+            }
+            _ => doc.length(&obj),
+        };
+        Ok(length as u64)
+    }
+
+    pub fn length_at(&self, obj: ObjId, heads: Vec<ChangeHash>) -> Result<u64, DocError> {
         let doc = self.0.read().unwrap();
         let obj = am::ObjId::from(obj);
         let heads = heads
             .into_iter()
             .map(am::ChangeHash::from)
             .collect::<Vec<_>>();
-        doc.length_at(obj, &heads) as u64
+        let length = match object_type(&*doc, &obj)? {
+            am::ObjType::Map | am::ObjType::Table => {
+                branch::map_range(&*doc, &obj, .., Some(&heads)).count()
+            }
+            _ => doc.length_at(&obj, &heads),
+        };
+        Ok(length as u64)
     }
 
-    pub fn object_type(&self, obj: ObjId) -> ObjType {
+    pub fn object_type(&self, obj: ObjId) -> Result<ObjType, DocError> {
         let obj = am::ObjId::from(obj);
         let doc = self.0.read().unwrap();
-        doc.object_type(obj).unwrap().into()
+        Ok(object_type(&*doc, &obj)?.into())
     }
 
     pub fn cursor(&self, obj: ObjId, position: u64) -> Result<Cursor, DocError> {
@@ -353,7 +812,7 @@ impl Doc {
         };
         doc.get_cursor(&obj, position, None)
             .map(|c| c.into())
-            .map_err(|error| DocError::Internal(error))
+            .map_err(DocError::from)
     }
 
     pub fn cursor_at(
@@ -376,7 +835,7 @@ impl Doc {
         };
         doc.get_cursor(&obj, cursor_position, Some(&heads))
             .map(|c| c.into())
-            .map_err(|error| DocError::Internal(error))
+            .map_err(DocError::from)
     }
 
     pub fn cursor_position(&self, obj: ObjId, cursor: Cursor) -> Result<u64, DocError> {
@@ -384,7 +843,7 @@ impl Doc {
         let doc = self.0.read().unwrap();
         doc.get_cursor_position(obj, &cursor.into(), None)
             .map(|cursor| cursor as u64)
-            .map_err(|error| DocError::Internal(error))
+            .map_err(DocError::from)
     }
 
     pub fn cursor_position_at(
@@ -401,7 +860,7 @@ impl Doc {
             .collect::<Vec<_>>();
         doc.get_cursor_position(obj, &cursor.into(), Some(&heads))
             .map(|cursor| cursor as u64)
-            .map_err(|error| DocError::Internal(error))
+            .map_err(DocError::from)
     }
 
     pub fn text(&self, obj: ObjId) -> Result<String, DocError> {
@@ -432,7 +891,9 @@ impl Doc {
         let obj = am::ObjId::from(obj);
         let mut doc = self.0.write().unwrap();
         assert_text(&*doc, &obj)?;
-        doc.splice_text(&obj, start as usize, delete as isize, value.as_str())?;
+        doc.splice_text(&obj, start as usize, delete as isize, value.as_str())
+            .map_err(|e| DocError::in_object(&*doc, &obj, e))?;
+        self.notify(doc, false);
         Ok(())
     }
 
@@ -441,6 +902,7 @@ impl Doc {
         let mut doc = self.0.write().unwrap();
         assert_text(&*doc, &obj)?;
         doc.update_text(&obj, value)?;
+        self.notify(doc, false);
         Ok(())
     }
 
@@ -459,7 +921,9 @@ impl Doc {
             start as usize,
             delete as isize,
             values.into_iter().map(|i| i.into()),
-        )?;
+        )
+        .map_err(|e| DocError::in_object(&*doc, &obj, e))?;
+        self.notify(doc, false);
         Ok(())
     }
 
@@ -476,13 +940,15 @@ impl Doc {
         let mut doc = self.0.write().unwrap();
         assert_text(&*doc, &obj)?;
         let mark = am::marks::Mark::new(name, value, start as usize, end as usize);
-        doc.mark(obj, mark, expand.into())?;
+        doc.mark(&obj, mark, expand.into())
+            .map_err(|e| DocError::in_object(&*doc, &obj, e))?;
+        self.notify(doc, false);
         Ok(())
     }
 
     pub fn marks(&self, obj: ObjId) -> Result<Vec<Mark>, DocError> {
         let obj = am::ObjId::from(obj);
-        let doc = self.0.write().unwrap();
+        let doc = self.0.read().unwrap();
         assert_text(&*doc, &obj)?;
         Ok(doc
             .marks(obj)?
@@ -493,7 +959,7 @@ impl Doc {
 
     pub fn marks_at(&self, obj: ObjId, heads: Vec<ChangeHash>) -> Result<Vec<Mark>, DocError> {
         let obj = am::ObjId::from(obj);
-        let doc = self.0.write().unwrap();
+        let doc = self.0.read().unwrap();
         assert_text(&*doc, &obj)?;
         let heads = heads
             .into_iter()
@@ -521,40 +987,181 @@ impl Doc {
             .collect::<Vec<_>>();
         let index = match position {
             Position::Cursor { position: cursor } => doc
-                .get_cursor_position(obj.clone(), &cursor.into(), Some(&heads))
-                .unwrap() as usize,
+                .get_cursor_position(&obj, &cursor.into(), Some(&heads))
+                .map_err(|e| DocError::in_object(&*doc, &obj, e))?,
             Position::Index { position: index } => index as usize,
         };
-        let markset = doc.get_marks(obj, index, Some(&heads)).unwrap();
+        let markset = doc
+            .get_marks(&obj, index, Some(&heads))
+            .map_err(|e| DocError::in_object(&*doc, &obj, e))?;
         Ok(Mark::from_markset(markset, index as u64))
     }
 
+    pub fn to_json(&self, obj: ObjId, heads: Option<Vec<ChangeHash>>) -> Result<String, DocError> {
+        let obj = am::ObjId::from(obj);
+        let doc = self.0.read().unwrap();
+        let heads = heads.map(|heads| {
+            heads
+                .into_iter()
+                .map(am::ChangeHash::from)
+                .collect::<Vec<_>>()
+        });
+        let json = json::export(&*doc, &obj, heads.as_deref())?;
+        Ok(json.to_string())
+    }
+
+    pub fn put_json(&self, obj: ObjId, key: String, json: String) -> Result<(), DocError> {
+        let obj = am::ObjId::from(obj);
+        let json = json::parse(&json)?;
+        let mut doc = self.0.write().unwrap();
+        assert_map(&*doc, &obj)?;
+        branch::check_writable(&obj, &key)?;
+        json::put(&mut *doc, &obj, am::Prop::Map(key), &json)?;
+        self.notify(doc, false);
+        Ok(())
+    }
+
+    /// Reads `obj` and every object inside it, as of `heads` if given.
+    pub fn hydrate(
+        &self,
+        obj: ObjId,
+        heads: Option<Vec<ChangeHash>>,
+    ) -> Result<HydrateValue, DocError> {
+        let obj = am::ObjId::from(obj);
+        let doc = self.0.read().unwrap();
+        let heads = heads.map(|heads| {
+            heads
+                .into_iter()
+                .map(am::ChangeHash::from)
+                .collect::<Vec<_>>()
+        });
+        hydrate::export(&*doc, &obj, heads.as_deref())
+            .map_err(|e| DocError::in_object(&*doc, &obj, e))
+    }
+
+    /// Writes `value` to `prop` of `obj`, creating any nested objects, and
+    /// returns the id of the object created for `value` if it is not a scalar.
+    pub fn put_hydrated(
+        &self,
+        obj: ObjId,
+        prop: Prop,
+        value: HydrateValue,
+    ) -> Result<Option<ObjId>, DocError> {
+        let obj = am::ObjId::from(obj);
+        let prop = am::Prop::from(prop);
+        let mut doc = self.0.write().unwrap();
+        match &prop {
+            am::Prop::Map(key) => {
+                assert_map(&*doc, &obj)?;
+                branch::check_writable(&obj, key)?;
+            }
+            am::Prop::Seq(_) => assert_list(&*doc, &obj)?,
+        }
+        let id = hydrate::put(&mut *doc, &obj, prop, value)?;
+        self.notify(doc, false);
+        Ok(id.map(ObjId::from))
+    }
+
+    /// Inserts `value` at `index` of the list `obj`, creating any nested
+    /// objects, and returns the id of the object created for `value` if it is
+    /// not a scalar.
+    pub fn insert_hydrated(
+        &self,
+        obj: ObjId,
+        index: u64,
+        value: HydrateValue,
+    ) -> Result<Option<ObjId>, DocError> {
+        let obj = am::ObjId::from(obj);
+        let mut doc = self.0.write().unwrap();
+        assert_list(&*doc, &obj)?;
+        let id = hydrate::insert(&mut *doc, &obj, index as usize, value)?;
+        self.notify(doc, false);
+        Ok(id.map(ObjId::from))
+    }
+
     pub fn split_block(&self, obj: ObjId, index: u32) -> Result<ObjId, DocError> {
         let mut doc = self.0.write().unwrap();
         let obj = am::ObjId::from(obj);
-        let id = doc.split_block(obj, index.try_into().unwrap())?;
+        let id = doc
+            .split_block(&obj, index as usize)
+            .map_err(|e| DocError::in_object(&*doc, &obj, e))?;
+        self.notify(doc, false);
         Ok(id.into())
     }
 
     pub fn join_block(&self, obj: ObjId, index: u32) -> Result<(), DocError> {
         let mut doc = self.0.write().unwrap();
         let obj = am::ObjId::from(obj);
-        doc.join_block(obj, index.try_into().unwrap())?;
+        doc.join_block(&obj, index as usize)
+            .map_err(|e| DocError::in_object(&*doc, &obj, e))?;
+        self.notify(doc, false);
         Ok(())
     }
 
     pub fn merge(&self, other: Arc<Self>) -> Result<(), DocError> {
         let mut doc = self.0.write().unwrap();
         let mut other = other.0.write().unwrap();
-        doc.merge(&mut other)?;
+        doc.merge(&mut other)
+            .map_err(|e| DocError::in_apply(&*doc, e))?;
+        drop(other);
+        self.notify(doc, true);
         Ok(())
     }
 
+    /// Applies the changes `hashes` from `other`, along with the changes
+    /// they depend on, without merging the rest of its history.
+    pub fn cherry_pick(
+        &self,
+        other: Arc<Self>,
+        hashes: Vec<ChangeHash>,
+    ) -> Result<CherryPick, DocError> {
+        let hashes = hashes
+            .into_iter()
+            .map(am::ChangeHash::from)
+            .collect::<Vec<_>>();
+        let mut doc = self.0.write().unwrap();
+        // Every change of a document is already in it, so there is nothing to
+        // pick, and locking it again would deadlock
+        let picked = if std::ptr::eq(self, &*other) {
+            cherry_pick::cherry_pick(&mut doc, hashes, |_| None)?
+        } else {
+            let other = other.0.read().unwrap();
+            cherry_pick::cherry_pick(&mut doc, hashes, |hash| {
+                other.get_change_by_hash(hash).cloned()
+            })?
+        };
+        self.notify(doc, true);
+        Ok(picked)
+    }
+
+    /// Like [`Doc::cherry_pick`], but picks from `changes`, any concatenation
+    /// of encoded changes and saved documents.
+    pub fn cherry_pick_encoded(
+        &self,
+        changes: Vec<u8>,
+        hashes: Vec<ChangeHash>,
+    ) -> Result<CherryPick, DocError> {
+        let hashes = hashes
+            .into_iter()
+            .map(am::ChangeHash::from)
+            .collect::<Vec<_>>();
+        let changes = split_changes(&changes)
+            .map_err(|message| DocError::CorruptData { message })?
+            .into_iter()
+            .map(|change| (change.hash(), change))
+            .collect::<HashMap<_, _>>();
+        let mut doc = self.0.write().unwrap();
+        let picked = cherry_pick::cherry_pick(&mut doc, hashes, |hash| changes.get(hash).cloned())?;
+        self.notify(doc, true);
+        Ok(picked)
+    }
+
     pub fn merge_with_patches(&self, other: Arc<Self>) -> Result<Vec<Patch>, DocError> {
         let doc = self.0.write().unwrap();
         let mut other = other.0.write().unwrap();
-        Self::do_with_patches(doc, move |doc| {
-            doc.merge(&mut other)?;
+        self.do_with_patches(doc, move |doc| {
+            doc.merge(&mut other)
+                .map_err(|e| DocError::in_apply(&**doc, e))?;
             Ok(())
         })
     }
@@ -567,24 +1174,67 @@ impl Doc {
             options.set_message(message);
         }
         doc.commit_with(options);
+        self.notify(doc, true);
     }
 
     pub fn save(&self) -> Vec<u8> {
         let mut doc = self.0.write().unwrap();
-        doc.save()
+        let bytes = doc.save();
+        *self.2.lock().unwrap() = doc.get_heads();
+        self.notify(doc, true);
+        bytes
+    }
+
+    /// Encodes the changes made since the document was loaded or last saved
+    /// with [`Doc::save`], [`Doc::save_no_compress`] or
+    /// [`Doc::save_incremental`].
+    ///
+    /// The result can be appended to the output of a previous save, and the
+    /// concatenation passed to [`Doc::load`]. Use [`Doc::encode_changes_since`]
+    /// to encode the changes after some other heads.
+    pub fn save_incremental(&self) -> Vec<u8> {
+        let mut doc = self.0.write().unwrap();
+        let mut saved = self.2.lock().unwrap();
+        let bytes = doc.save_after(&saved);
+        *saved = doc.get_heads();
+        drop(saved);
+        self.notify(doc, true);
+        bytes
+    }
+
+    pub fn save_no_compress(&self) -> Vec<u8> {
+        let mut doc = self.0.write().unwrap();
+        let bytes = doc.save_nocompress();
+        *self.2.lock().unwrap() = doc.get_heads();
+        self.notify(doc, true);
+        bytes
     }
 
+    /// Loads a document from the output of [`Doc::save`], optionally followed
+    /// by any number of chunks from [`Doc::save_incremental`].
     pub fn load(bytes: Vec<u8>) -> Result<Self, LoadError> {
-        let ac = automerge::AutoCommit::load(bytes.as_slice())?;
-        Ok(Doc(RwLock::new(ac)))
+        let ac = automerge::AutoCommit::load(bytes.as_slice())
+            .map_err(|e| LoadError::in_load(&bytes, e))?;
+        Ok(Doc::from(ac))
+    }
+
+    pub fn load_with_options(bytes: Vec<u8>, mut options: LoadOptions) -> Result<Self, LoadError> {
+        let actor = options.actor.take();
+        let mut ac = am::AutoCommit::load_with_options(bytes.as_slice(), options.into())
+            .map_err(|e| LoadError::in_load(&bytes, e))?;
+        if let Some(actor) = actor {
+            ac.set_actor(actor.into());
+        }
+        Ok(Doc::from(ac))
     }
 
     pub fn generate_sync_message(&self, sync_state: Arc<SyncState>) -> Option<Vec<u8>> {
         let mut doc = self.0.write().unwrap();
         let mut state = sync_state.0.write().unwrap();
-        let sync = doc.sync();
-        sync.generate_sync_message(&mut state)
-            .map(|msg| msg.encode())
+        let message = doc.sync().generate_sync_message(&mut state);
+        drop(state);
+        self.notify(doc, true);
+        message.map(|msg| msg.encode())
     }
 
     pub fn receive_sync_message(
@@ -593,10 +1243,15 @@ impl Doc {
         message: Vec<u8>,
     ) -> Result<(), ReceiveSyncError> {
         let message =
-            am::sync::Message::decode(&message).map_err(|_| ReceiveSyncError::InvalidMessage)?;
+            am::sync::Message::decode(&message).map_err(|e| ReceiveSyncError::InvalidMessage {
+                message: e.to_string(),
+            })?;
         let mut doc = self.0.write().unwrap();
         let mut state = sync_state.0.write().unwrap();
-        doc.sync().receive_sync_message(&mut state, message)?;
+        let result = doc.sync().receive_sync_message(&mut state, message);
+        result.map_err(|e| DocError::in_apply(&*doc, e))?;
+        drop(state);
+        self.notify(doc, true);
         Ok(())
     }
 
@@ -606,18 +1261,33 @@ impl Doc {
         message: Vec<u8>,
     ) -> Result<Vec<Patch>, ReceiveSyncError> {
         let message =
-            am::sync::Message::decode(&message).map_err(|_| ReceiveSyncError::InvalidMessage)?;
+            am::sync::Message::decode(&message).map_err(|e| ReceiveSyncError::InvalidMessage {
+                message: e.to_string(),
+            })?;
         let doc = self.0.write().unwrap();
         let mut state = sync_state.0.write().unwrap();
-        Self::do_with_patches(doc, move |doc| {
-            doc.sync().receive_sync_message(&mut state, message)?;
+        self.do_with_patches(doc, move |doc| {
+            let result = doc.sync().receive_sync_message(&mut state, message);
+            result.map_err(|e| DocError::in_apply(&**doc, e))?;
             Ok(())
         })
     }
 
+    pub fn begin_transaction(self: Arc<Self>) -> Arc<Transaction> {
+        let (staged, base) = {
+            let mut doc = self.0.write().unwrap();
+            // Stage under the document's own actor, so that the committed
+            // change is local to it, like any other edit
+            let mut staged = doc.fork();
+            staged.set_actor(doc.get_actor().clone());
+            (staged, doc.get_heads())
+        };
+        Arc::new(Transaction::new(self, Doc::from(staged), base))
+    }
+
     pub fn fork(&self) -> Arc<Self> {
         let mut doc = self.0.write().unwrap();
-        Arc::new(Self(RwLock::new(doc.fork())))
+        Arc::new(Self::from(doc.fork()))
     }
 
     pub fn fork_at(&self, heads: Vec<ChangeHash>) -> Result<Arc<Self>, DocError> {
@@ -627,7 +1297,110 @@ impl Doc {
             .map(am::ChangeHash::from)
             .collect::<Vec<_>>();
         let new = doc.fork_at(&heads)?;
-        Ok(Arc::new(Self(RwLock::new(new))))
+        Ok(Arc::new(Self::from(new)))
+    }
+
+    /// Points the branch `name` at `heads`, replacing any branch with the
+    /// same name.
+    ///
+    /// Branches are stored in the document under the reserved root key
+    /// `_branches`, so they are saved and synchronised with it. The key is
+    /// left out of every read and patch, and writing to it is an error.
+    pub fn create_branch(&self, name: String, heads: Vec<ChangeHash>) -> Result<(), DocError> {
+        let heads = heads
+            .into_iter()
+            .map(am::ChangeHash::from)
+            .collect::<Vec<_>>();
+        let mut doc = self.0.write().unwrap();
+        assert_heads(&*doc, &heads)?;
+        branch::set_branch(&mut doc, &name, &heads)?;
+        self.notify(doc, false);
+        Ok(())
+    }
+
+    pub fn delete_branch(&self, name: String) -> Result<(), DocError> {
+        let mut doc = self.0.write().unwrap();
+        if !branch::delete_branch(&mut doc, &name)? {
+            return Err(DocError::BranchNotFound { name });
+        }
+        self.notify(doc, false);
+        Ok(())
+    }
+
+    pub fn list_branches(&self) -> Vec<Branch> {
+        let doc = self.0.read().unwrap();
+        branch::branches(&doc)
+    }
+
+    /// Forks the document at the heads of the branch `name`.
+    pub fn checkout(&self, name: String) -> Result<Arc<Self>, DocError> {
+        let mut doc = self.0.write().unwrap();
+        let heads = branch::branch_heads(&doc, &name).ok_or(DocError::BranchNotFound { name })?;
+        let new = doc.fork_at(&heads)?;
+        Ok(Arc::new(Self::from(new)))
+    }
+
+    /// Merges `other`, usually the result of [`Doc::checkout`] after
+    /// editing it, and moves the branch `name` to its heads.
+    pub fn merge_branch(&self, name: String, other: Arc<Self>) -> Result<(), DocError> {
+        let mut doc = self.0.write().unwrap();
+        if branch::branch_heads(&doc, &name).is_none() {
+            return Err(DocError::BranchNotFound { name });
+        }
+        let heads = if std::ptr::eq(self, &*other) {
+            doc.get_heads()
+        } else {
+            let mut other = other.0.write().unwrap();
+            doc.merge(&mut other)
+                .map_err(|e| DocError::in_apply(&*doc, e))?;
+            other.get_heads()
+        };
+        branch::set_branch(&mut doc, &name, &heads)?;
+        self.notify(doc, true);
+        Ok(())
+    }
+
+    /// Makes the document, or only `obj` and the objects inside it, look as
+    /// it did at `heads` by applying the difference as a new change, so that
+    /// history and concurrent changes are kept. Nothing is committed if the
+    /// document already looks that way, and nothing is written if reverting
+    /// fails.
+    ///
+    /// Objects which have been deleted since `heads` are recreated with new
+    /// ids, and marks which are put back expand as `expand` says.
+    pub fn revert_to(
+        &self,
+        heads: Vec<ChangeHash>,
+        obj: Option<ObjId>,
+        expand: ExpandMark,
+    ) -> Result<(), DocError> {
+        let heads = heads
+            .into_iter()
+            .map(am::ChangeHash::from)
+            .collect::<Vec<_>>();
+        let obj = obj.map(am::ObjId::from);
+        let mut doc = self.0.write().unwrap();
+        assert_heads(&*doc, &heads)?;
+        if let Some(obj) = &obj {
+            object_type(&*doc, obj)?;
+        }
+        let current = doc.get_heads();
+        let reverted = revert::revert(
+            &mut doc,
+            &current,
+            &heads,
+            obj.as_ref(),
+            expand.into(),
+            &mut Restored::default(),
+        );
+        if let Err(e) = reverted {
+            doc.rollback();
+            return Err(e);
+        }
+        if doc.commit().is_some() {
+            self.notify(doc, true);
+        }
+        Ok(())
     }
 
     pub fn heads(&self) -> Vec<ChangeHash> {
@@ -647,7 +1420,24 @@ impl Doc {
         changes.into_iter().map(|h| h.hash().into()).collect()
     }
 
-    pub fn difference(&self, before: Vec<ChangeHash>, after: Vec<ChangeHash>) -> Vec<Patch> {
+    pub fn get_changes(&self, since_heads: Vec<ChangeHash>) -> Result<Vec<Change>, DocError> {
+        let heads = since_heads
+            .into_iter()
+            .map(am::ChangeHash::from)
+            .collect::<Vec<_>>();
+        let mut doc = self.0.write().unwrap();
+        Ok(doc
+            .get_changes(&heads)
+            .into_iter()
+            .map(|c| Change::from(c.clone()))
+            .collect())
+    }
+
+    pub fn difference(
+        &self,
+        before: Vec<ChangeHash>,
+        after: Vec<ChangeHash>,
+    ) -> Result<Vec<Patch>, DocError> {
         let lhs = before
             .into_iter()
             .map(am::ChangeHash::from)
@@ -657,14 +1447,77 @@ impl Doc {
             .map(am::ChangeHash::from)
             .collect::<Vec<_>>();
         let mut doc = self.0.write().unwrap();
-        let patches = doc.diff(&lhs, &rhs);
-        patches.into_iter().map(Patch::from).collect()
+        assert_heads(&*doc, &lhs)?;
+        assert_heads(&*doc, &rhs)?;
+        let patches = branch::diff(&mut doc, &lhs, &rhs);
+        Ok(patches.into_iter().map(Patch::from).collect())
+    }
+
+    /// Like [`Doc::difference`], but only returns the patches to `obj`, and
+    /// with `recursive` the patches to the objects inside it.
+    ///
+    /// This costs as much as [`Doc::difference`]: automerge can only diff the
+    /// whole document, so every patch is generated and then filtered. The
+    /// last diff is cached, so calling this for several objects with the same
+    /// heads only diffs the document once.
+    pub fn difference_in(
+        &self,
+        obj: ObjId,
+        before: Vec<ChangeHash>,
+        after: Vec<ChangeHash>,
+        recursive: bool,
+    ) -> Result<Vec<Patch>, DocError> {
+        let patches = self.scoped_difference(obj, before, after, recursive)?;
+        Ok(patches.into_iter().map(Patch::from).collect())
     }
 
-    pub fn change_by_hash(&self, hash: ChangeHash) -> Option<Change> {
-        let doc = self.0.write().unwrap();
-        doc.get_change_by_hash(&am::ChangeHash::from(hash))
-            .map(|m| Change::from(m.clone()))
+    /// Summarises [`Doc::difference_in`] per object instead of returning
+    /// every patch. This saves copying the patches across the FFI, but
+    /// diffing costs the same.
+    pub fn difference_summary(
+        &self,
+        obj: ObjId,
+        before: Vec<ChangeHash>,
+        after: Vec<ChangeHash>,
+        recursive: bool,
+    ) -> Result<Vec<DiffSummary>, DocError> {
+        let encoding = self.0.read().unwrap().text_encoding();
+        let patches = self.scoped_difference(obj, before, after, recursive)?;
+        Ok(diff::summarize(patches, encoding))
+    }
+
+    fn scoped_difference(
+        &self,
+        obj: ObjId,
+        before: Vec<ChangeHash>,
+        after: Vec<ChangeHash>,
+        recursive: bool,
+    ) -> Result<Vec<am::Patch>, DocError> {
+        let obj = am::ObjId::from(obj);
+        let lhs = before
+            .into_iter()
+            .map(am::ChangeHash::from)
+            .collect::<Vec<_>>();
+        let rhs = after
+            .into_iter()
+            .map(am::ChangeHash::from)
+            .collect::<Vec<_>>();
+        let mut doc = self.0.write().unwrap();
+        object_type(&*doc, &obj)?;
+        assert_heads(&*doc, &lhs)?;
+        assert_heads(&*doc, &rhs)?;
+        // automerge has no diff of a single object, so diff the whole
+        // document (or reuse its cached diff) and drop the other patches.
+        let mut patches = branch::diff(&mut doc, &lhs, &rhs);
+        patches.retain(|patch| diff::is_within(patch, &obj, recursive));
+        Ok(patches)
+    }
+
+    pub fn change_by_hash(&self, hash: ChangeHash) -> Result<Option<Change>, DocError> {
+        let doc = self.0.read().unwrap();
+        Ok(doc
+            .get_change_by_hash(&am::ChangeHash::from(hash))
+            .map(|m| Change::from(m.clone())))
     }
 
     pub fn path(&self, obj: ObjId) -> Result<Vec<PathElement>, DocError> {
@@ -679,7 +1532,9 @@ impl Doc {
 
     pub fn encode_new_changes(&self) -> Vec<u8> {
         let mut doc = self.0.write().unwrap();
-        doc.save_incremental()
+        let bytes = doc.save_incremental();
+        self.notify(doc, true);
+        bytes
     }
 
     pub fn encode_changes_since(&self, heads: Vec<ChangeHash>) -> Result<Vec<u8>, DocError> {
@@ -698,8 +1553,12 @@ impl Doc {
 
     pub fn apply_encoded_changes(&self, changes: Vec<u8>) -> Result<(), DocError> {
         let mut doc = self.0.write().unwrap();
-        doc.reset_diff_cursor();
-        doc.load_incremental(&changes)?;
+        if self.1.read().unwrap().is_none() {
+            doc.reset_diff_cursor();
+        }
+        doc.load_incremental(&changes)
+            .map_err(|e| DocError::in_apply(&*doc, e))?;
+        self.notify(doc, true);
         Ok(())
     }
 
@@ -708,46 +1567,250 @@ impl Doc {
         changes: Vec<u8>,
     ) -> Result<Vec<Patch>, DocError> {
         let doc = self.0.write().unwrap();
-        Self::do_with_patches(doc, move |doc| {
-            doc.load_incremental(changes.as_slice())?;
+        self.do_with_patches(doc, move |doc| {
+            doc.load_incremental(changes.as_slice())
+                .map_err(|e| DocError::in_apply(&**doc, e))?;
             Ok(())
         })
     }
 
     fn do_with_patches<F, E>(
+        &self,
         mut doc: RwLockWriteGuard<am::AutoCommit>,
         f: F,
     ) -> Result<Vec<Patch>, E>
     where
         F: FnOnce(&mut RwLockWriteGuard<am::AutoCommit>) -> Result<(), E>,
     {
+        let observer = self.1.read().unwrap().clone();
+        // Hand any patches the observer hasn't seen yet to it along with the
+        // new ones, rather than discarding them when we move the diff cursor
+        let mut observed = match observer.as_ref() {
+            Some(_) => branch::diff_incremental(&mut doc),
+            None => {
+                doc.update_diff_cursor();
+                Vec::new()
+            }
+        };
         // Note no early return so we get a chance to pop the patches
-        doc.update_diff_cursor();
         let result = f(&mut doc);
-        let am_patches = doc.diff_incremental();
+        let am_patches = branch::diff_incremental(&mut doc);
+        drop(doc);
+        if let Some(observer) = observer.as_ref() {
+            observed.extend(am_patches.iter().cloned());
+            observer.deliver(observed);
+        }
         result?;
         let patches = am_patches.into_iter().map(|p| p.into()).collect();
         Ok(patches)
     }
+
+    /// Passes the patches accumulated since the last notification to the
+    /// registered [`PatchObserver`], if any.
+    ///
+    /// `committed` indicates that the caller closed a change, which is the
+    /// only point at which an observer that batches per commit is notified.
+    /// The document lock is released before the observer is called so that
+    /// it may read from the document.
+    pub(crate) fn notify(&self, mut doc: RwLockWriteGuard<am::AutoCommit>, committed: bool) {
+        let Some(observer) = self.1.read().unwrap().clone() else {
+            return;
+        };
+        if observer.batch_per_commit && !committed {
+            return;
+        }
+        let patches = branch::diff_incremental(&mut doc);
+        drop(doc);
+        observer.deliver(patches);
+    }
+
+    /// Registers an observer which receives the patches produced by every
+    /// subsequent local edit, merge, sync message and applied change.
+    ///
+    /// Reporting patches closes the pending change, so an observer that is
+    /// notified of every local edit turns each edit into a change of its own,
+    /// which bloats the history. By default `batch_per_commit` is set, and local
+    /// edits are instead reported once the pending change is closed: by
+    /// [`Doc::commit_with`], a save, [`Doc::encode_new_changes`],
+    /// [`Doc::generate_sync_message`], or alongside the next incoming changes.
+    /// Clear `batch_per_commit` only if the observer must see every edit as it
+    /// happens and the extra changes are acceptable.
+    pub fn set_patch_observer(&self, observer: Box<dyn PatchObserver>, batch_per_commit: bool) {
+        let mut doc = self.0.write().unwrap();
+        doc.update_diff_cursor();
+        *self.1.write().unwrap() = Some(Arc::new(Observer {
+            observer,
+            batch_per_commit,
+        }));
+    }
+
+    pub fn clear_patch_observer(&self) {
+        let mut doc = self.0.write().unwrap();
+        doc.reset_diff_cursor();
+        *self.1.write().unwrap() = None;
+    }
 }
 
 fn assert_map<R: am::ReadDoc>(doc: &R, obj: &am::ObjId) -> Result<(), DocError> {
-    match doc.object_type(obj)? {
+    match object_type(doc, obj)? {
         am::ObjType::Map | am::ObjType::Table => Ok(()),
-        _ => Err(DocError::WrongObjectType),
+        actual => Err(DocError::WrongObjectType {
+            obj: obj.clone().into(),
+            expected: ObjType::Map,
+            actual: actual.into(),
+        }),
     }
 }
 
 fn assert_list<R: am::ReadDoc>(doc: &R, obj: &am::ObjId) -> Result<(), DocError> {
-    match doc.object_type(obj)? {
+    match object_type(doc, obj)? {
         am::ObjType::List => Ok(()),
-        _ => Err(DocError::WrongObjectType),
+        actual => Err(DocError::WrongObjectType {
+            obj: obj.clone().into(),
+            expected: ObjType::List,
+            actual: actual.into(),
+        }),
     }
 }
 
 fn assert_text<R: am::ReadDoc>(doc: &R, obj: &am::ObjId) -> Result<(), DocError> {
-    match doc.object_type(obj)? {
+    match object_type(doc, obj)? {
         am::ObjType::Text => Ok(()),
-        _ => Err(DocError::WrongObjectType),
+        actual => Err(DocError::WrongObjectType {
+            obj: obj.clone().into(),
+            expected: ObjType::Text,
+            actual: actual.into(),
+        }),
+    }
+}
+
+fn assert_heads<R: am::ReadDoc>(doc: &R, heads: &[am::ChangeHash]) -> Result<(), DocError> {
+    let missing = heads
+        .iter()
+        .filter(|h| doc.get_change_by_hash(h).is_none())
+        .map(|h| ChangeHash::from(*h))
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        return Err(DocError::InvalidHeads { hashes: missing });
+    }
+    Ok(())
+}
+
+fn object_type<R: am::ReadDoc>(doc: &R, obj: &am::ObjId) -> Result<am::ObjType, DocError> {
+    doc.object_type(obj)
+        .map_err(|e| DocError::in_object(doc, obj, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hashes(hashes: &[ChangeHash]) -> Vec<am::ChangeHash> {
+        hashes.iter().cloned().map(am::ChangeHash::from).collect()
+    }
+
+    #[test]
+    fn loading_changes_without_their_dependencies_lists_the_missing_hashes() {
+        let mut doc = am::AutoCommit::new();
+        doc.put(am::ROOT, "a", 1).unwrap();
+        let first = doc.get_heads();
+        doc.put(am::ROOT, "b", 2).unwrap();
+        let second = doc.save_after(&first);
+
+        match Doc::load(second) {
+            Err(LoadError::MissingDependencies { hashes: missing }) => {
+                assert_eq!(hashes(&missing), first);
+            }
+            _ => panic!("expected missing dependencies"),
+        }
+    }
+
+    #[test]
+    fn two_changes_with_the_same_seq_are_reported_as_duplicates() {
+        let actor = am::ActorId::random();
+        let mut ours = am::AutoCommit::new().with_actor(actor.clone());
+        ours.put(am::ROOT, "key", "ours").unwrap();
+        let mut theirs = am::AutoCommit::new().with_actor(actor.clone());
+        theirs.put(am::ROOT, "key", "theirs").unwrap();
+
+        let doc = Doc::from(ours);
+        match doc.apply_encoded_changes(theirs.save()) {
+            Err(DocError::DuplicateSeqNumber {
+                actor: reported,
+                seq,
+            }) => {
+                assert_eq!(reported, actor.to_hex_string());
+                assert_eq!(seq, 1);
+            }
+            _ => panic!("expected a duplicate sequence number"),
+        }
+    }
+
+    #[test]
+    fn incremental_saves_are_not_affected_by_encoding_new_changes() {
+        let doc = Doc::new();
+        doc.put_in_map(
+            am::ROOT.into(),
+            "a".to_string(),
+            ScalarValue::Int { value: 1 },
+        )
+        .unwrap();
+        let mut saved = doc.save();
+
+        doc.put_in_map(
+            am::ROOT.into(),
+            "b".to_string(),
+            ScalarValue::Int { value: 2 },
+        )
+        .unwrap();
+        assert!(!doc.encode_new_changes().is_empty());
+        saved.extend(doc.save_incremental());
+        assert!(doc.save_incremental().is_empty());
+
+        let loaded = Doc::load(saved).unwrap();
+        assert_eq!(
+            loaded.map_keys(am::ROOT.into()).unwrap(),
+            vec!["a".to_string(), "b".to_string()]
+        );
+    }
+
+    #[test]
+    fn incremental_saves_start_from_the_loaded_heads() {
+        let doc = Doc::new();
+        doc.put_in_map(
+            am::ROOT.into(),
+            "a".to_string(),
+            ScalarValue::Int { value: 1 },
+        )
+        .unwrap();
+        let loaded = Doc::load(doc.save()).unwrap();
+        assert!(loaded.save_incremental().is_empty());
+    }
+
+    #[test]
+    fn unknown_objects_and_heads_are_errors() {
+        let other = Doc::new();
+        let list = other
+            .put_object_in_map(crate::root(), "list".to_string(), ObjType::List)
+            .unwrap();
+        let heads = other.heads();
+        let doc = Doc::new();
+
+        assert!(matches!(
+            doc.map_keys(list.clone()),
+            Err(DocError::ObjectNotFound { .. })
+        ));
+        assert!(matches!(
+            doc.length(list.clone()),
+            Err(DocError::ObjectNotFound { .. })
+        ));
+        assert!(matches!(
+            doc.length_at(list, Vec::new()),
+            Err(DocError::ObjectNotFound { .. })
+        ));
+        assert!(matches!(
+            doc.difference(Vec::new(), heads),
+            Err(DocError::InvalidHeads { .. })
+        ));
     }
 }
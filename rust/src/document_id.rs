@@ -0,0 +1,118 @@
+use super::UniffiCustomTypeConverter;
+use sha2::{Digest, Sha256};
+
+use crate::RepoError;
+
+const URL_PREFIX: &str = "automerge:";
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// The id of a document in a [`crate::Repo`].
+///
+/// This uses the automerge-repo format: a random UUID encoded with base58check.
+/// Ids are lifted from either the bare id or an `automerge:` URL.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DocumentId(String);
+
+impl DocumentId {
+    pub(crate) fn generate() -> Self {
+        Self(bs58check_encode(uuid::Uuid::new_v4().as_bytes()))
+    }
+
+    pub(crate) fn parse(value: &str) -> Result<Self, RepoError> {
+        let id = value.strip_prefix(URL_PREFIX).unwrap_or(value);
+        let invalid = |message: &str| RepoError::InvalidDocumentId {
+            message: format!("{}: {}", message, value),
+        };
+        let bytes = bs58check_decode(id).ok_or_else(|| invalid("not a base58check string"))?;
+        if bytes.len() != 16 {
+            return Err(invalid("expected a 16 byte id"));
+        }
+        Ok(Self(id.to_string()))
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for DocumentId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+pub fn generate_document_id() -> DocumentId {
+    DocumentId::generate()
+}
+
+pub fn automerge_url(document_id: DocumentId) -> Result<String, RepoError> {
+    Ok(format!("{}{}", URL_PREFIX, document_id.0))
+}
+
+fn checksum(bytes: &[u8]) -> [u8; 4] {
+    let hash = Sha256::digest(Sha256::digest(bytes));
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+fn bs58check_encode(bytes: &[u8]) -> String {
+    let mut input = bytes.to_vec();
+    input.extend_from_slice(&checksum(bytes));
+    // Each base58 digit is stored little endian
+    let mut digits: Vec<u8> = Vec::new();
+    for byte in &input {
+        let mut carry = u32::from(*byte);
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let zeros = input.iter().take_while(|b| **b == 0).count();
+    std::iter::repeat_n('1', zeros)
+        .chain(digits.iter().rev().map(|d| ALPHABET[*d as usize] as char))
+        .collect()
+}
+
+fn bs58check_decode(value: &str) -> Option<Vec<u8>> {
+    // Each byte is stored little endian
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in value.bytes() {
+        let mut carry = ALPHABET.iter().position(|a| *a == c)? as u32;
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    let zeros = value.bytes().take_while(|c| *c == b'1').count();
+    let mut decoded = vec![0; zeros];
+    decoded.extend(bytes.iter().rev());
+    if decoded.len() < 4 {
+        return None;
+    }
+    let (payload, check) = decoded.split_at(decoded.len() - 4);
+    (checksum(payload) == check).then(|| payload.to_vec())
+}
+
+impl UniffiCustomTypeConverter for DocumentId {
+    type Builtin = String;
+
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self>
+    where
+        Self: Sized,
+    {
+        Ok(DocumentId::parse(&val)?)
+    }
+
+    fn from_custom(obj: Self) -> Self::Builtin {
+        obj.0
+    }
+}
@@ -0,0 +1,247 @@
+use std::collections::{BTreeMap, HashMap};
+
+use automerge::{self as am, transaction::Transactable, ReadDoc};
+
+use crate::branch;
+use crate::{DocError, ExpandMark, Mark, ScalarValue};
+
+/// A value together with the contents of any objects inside it.
+pub enum HydrateValue {
+    Map {
+        value: HashMap<String, HydrateValue>,
+    },
+    List {
+        value: Vec<HydrateValue>,
+    },
+    Text {
+        value: String,
+        marks: Vec<Mark>,
+        /// How `marks` expand when text is inserted at their edges. This is
+        /// not stored in the document, so it is always `After` when read.
+        expand: ExpandMark,
+    },
+    Scalar {
+        value: ScalarValue,
+    },
+}
+
+impl HydrateValue {
+    fn obj_type(&self) -> Option<am::ObjType> {
+        match self {
+            HydrateValue::Map { .. } => Some(am::ObjType::Map),
+            HydrateValue::List { .. } => Some(am::ObjType::List),
+            HydrateValue::Text { .. } => Some(am::ObjType::Text),
+            HydrateValue::Scalar { .. } => None,
+        }
+    }
+}
+
+/// Writes `value` to `prop` of `obj`, returning the id of the created object
+/// if `value` is not a scalar.
+pub(crate) fn put<T: Transactable>(
+    doc: &mut T,
+    obj: &am::ObjId,
+    prop: am::Prop,
+    value: HydrateValue,
+) -> Result<Option<am::ObjId>, DocError> {
+    let Some(typ) = value.obj_type() else {
+        if let HydrateValue::Scalar { value } = value {
+            doc.put(obj, prop, value)
+                .map_err(|e| DocError::in_object(doc, obj, e))?;
+        }
+        return Ok(None);
+    };
+    let id = doc
+        .put_object(obj, prop, typ)
+        .map_err(|e| DocError::in_object(doc, obj, e))?;
+    populate(doc, &id, value)?;
+    Ok(Some(id))
+}
+
+/// Inserts `value` at `index` of the list `obj`, returning the id of the
+/// created object if `value` is not a scalar.
+pub(crate) fn insert<T: Transactable>(
+    doc: &mut T,
+    obj: &am::ObjId,
+    index: usize,
+    value: HydrateValue,
+) -> Result<Option<am::ObjId>, DocError> {
+    let Some(typ) = value.obj_type() else {
+        if let HydrateValue::Scalar { value } = value {
+            doc.insert(obj, index, value)
+                .map_err(|e| DocError::in_object(doc, obj, e))?;
+        }
+        return Ok(None);
+    };
+    let id = doc
+        .insert_object(obj, index, typ)
+        .map_err(|e| DocError::in_object(doc, obj, e))?;
+    populate(doc, &id, value)?;
+    Ok(Some(id))
+}
+
+/// Fills the newly created object `obj`.
+fn populate<T: Transactable>(
+    doc: &mut T,
+    obj: &am::ObjId,
+    value: HydrateValue,
+) -> Result<(), DocError> {
+    match value {
+        HydrateValue::Map { value } => {
+            // Sorted so the same value always creates the same ops
+            for (key, value) in value.into_iter().collect::<BTreeMap<_, _>>() {
+                put(doc, obj, am::Prop::Map(key), value)?;
+            }
+        }
+        HydrateValue::List { value } => {
+            for (index, value) in value.into_iter().enumerate() {
+                insert(doc, obj, index, value)?;
+            }
+        }
+        HydrateValue::Text {
+            value,
+            marks,
+            expand,
+        } => {
+            doc.splice_text(obj, 0, 0, &value)
+                .map_err(|e| DocError::in_object(doc, obj, e))?;
+            let expand: am::marks::ExpandMark = expand.into();
+            for Mark {
+                start,
+                end,
+                name,
+                value,
+            } in marks
+            {
+                let mark = am::marks::Mark::new(name, value, start as usize, end as usize);
+                doc.mark(obj, mark, expand)
+                    .map_err(|e| DocError::in_object(doc, obj, e))?;
+            }
+        }
+        HydrateValue::Scalar { .. } => {}
+    }
+    Ok(())
+}
+
+/// Reads `obj` and every object inside it, as of `heads` if given.
+///
+/// This is the only walk over a document's objects; `json::export` converts
+/// its result rather than reading the document again.
+pub(crate) fn export<R: ReadDoc>(
+    doc: &R,
+    obj: &am::ObjId,
+    heads: Option<&[am::ChangeHash]>,
+) -> Result<HydrateValue, am::AutomergeError> {
+    let value = match doc.object_type(obj)? {
+        am::ObjType::Map | am::ObjType::Table => {
+            let mut map = HashMap::new();
+            for am::iter::MapRangeItem { key, value, id, .. } in
+                branch::map_range(doc, obj, .., heads)
+            {
+                map.insert(key.to_string(), export_value(doc, value, &id, heads)?);
+            }
+            HydrateValue::Map { value: map }
+        }
+        am::ObjType::List => {
+            let items = match heads {
+                Some(heads) => doc.list_range_at(obj, .., heads),
+                None => doc.list_range(obj, ..),
+            };
+            let mut list = Vec::new();
+            for am::iter::ListRangeItem { value, id, .. } in items {
+                list.push(export_value(doc, value, &id, heads)?);
+            }
+            HydrateValue::List { value: list }
+        }
+        am::ObjType::Text => {
+            let (text, marks) = match heads {
+                Some(heads) => (doc.text_at(obj, heads)?, doc.marks_at(obj, heads)?),
+                None => (doc.text(obj)?, doc.marks(obj)?),
+            };
+            HydrateValue::Text {
+                value: text,
+                marks: marks.iter().map(Mark::from).collect(),
+                expand: ExpandMark::After,
+            }
+        }
+    };
+    Ok(value)
+}
+
+fn export_value<R: ReadDoc>(
+    doc: &R,
+    value: am::Value<'_>,
+    id: &am::ObjId,
+    heads: Option<&[am::ChangeHash]>,
+) -> Result<HydrateValue, am::AutomergeError> {
+    match value {
+        am::Value::Object(_) => export(doc, id, heads),
+        am::Value::Scalar(s) => Ok(HydrateValue::Scalar {
+            value: s.as_ref().into(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use automerge::{self as am, transaction::CommitOptions};
+
+    use super::{put, HydrateValue};
+    use crate::{root, Doc, ExpandMark, Mark, Prop, ScalarValue};
+
+    #[test]
+    fn maps_are_written_in_key_order() {
+        let heads = (0..2)
+            .map(|_| {
+                let value = (0..20)
+                    .map(|i| {
+                        let value = HydrateValue::Scalar {
+                            value: ScalarValue::Uint { value: i },
+                        };
+                        (format!("key{i}"), value)
+                    })
+                    .collect::<HashMap<_, _>>();
+                let mut doc = am::AutoCommit::new();
+                doc.set_actor(am::ActorId::from(vec![1]));
+                put(
+                    &mut doc,
+                    &am::ROOT,
+                    am::Prop::Map("map".to_string()),
+                    HydrateValue::Map { value },
+                )
+                .unwrap();
+                doc.commit_with(CommitOptions::default().with_time(0));
+                doc.get_heads()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(heads[0], heads[1]);
+    }
+
+    #[test]
+    fn marks_expand_as_the_value_says() {
+        for (expand, end) in [(ExpandMark::None, 5), (ExpandMark::After, 6)] {
+            let doc = Doc::new();
+            let value = HydrateValue::Text {
+                value: "hello".to_string(),
+                marks: vec![Mark {
+                    start: 0,
+                    end: 5,
+                    name: "bold".to_string(),
+                    value: ScalarValue::Boolean { value: true },
+                }],
+                expand,
+            };
+            let prop = Prop::Key {
+                value: "text".to_string(),
+            };
+            let text = doc.put_hydrated(root(), prop, value).unwrap().unwrap();
+            doc.splice_text(text.clone(), 5, 0, "!".to_string())
+                .unwrap();
+            let marks = doc.marks(text).unwrap();
+            assert_eq!(marks.len(), 1);
+            assert_eq!(marks[0].end, end);
+        }
+    }
+}
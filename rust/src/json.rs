@@ -0,0 +1,342 @@
+//! Conversion between document objects and JSON.
+//!
+//! Maps become JSON objects, lists become arrays, and strings, booleans,
+//! nulls and numbers map onto their JSON counterparts. Values which JSON
+//! cannot represent directly are written as an object with a single key
+//! starting with `$`:
+//!
+//! - text objects: `{"$text": "hello"}`
+//! - counters: `{"$counter": 5}`
+//! - timestamps, in milliseconds since the epoch: `{"$timestamp": 1700000000000}`
+//! - bytes, hex encoded: `{"$bytes": "00ff"}`
+//! - unknown scalars: `{"$unknown": {"type_code": 12, "bytes": "00ff"}}`
+//!
+//! So that these tags cannot be confused with user data, map keys starting
+//! with `$` are escaped by doubling the `$`: the key `$text` is written as
+//! `$$text`. When importing, a key starting with `$$` has one `$` removed and
+//! any other key is taken as it is.
+//!
+//! Objects are read with the same traversal as `hydrate::export`, and the
+//! resulting tree is written out as JSON. Marks on text are not included.
+//! Non-finite floats cannot be written as JSON and make the export fail. When
+//! importing, integers which fit in an `i64` become `Int`, larger ones
+//! `Uint`, and any other number `F64`.
+use automerge::{self as am, transaction::Transactable, ReadDoc};
+use serde_json::{Map, Number, Value as Json};
+
+use crate::hydrate::{self, HydrateValue};
+use crate::{DocError, ScalarValue};
+
+const TEXT: &str = "$text";
+const COUNTER: &str = "$counter";
+const TIMESTAMP: &str = "$timestamp";
+const BYTES: &str = "$bytes";
+const UNKNOWN: &str = "$unknown";
+const ESCAPE: char = '$';
+
+/// Reads `obj` and every object inside it, as of `heads` if given.
+pub(crate) fn export<R: ReadDoc>(
+    doc: &R,
+    obj: &am::ObjId,
+    heads: Option<&[am::ChangeHash]>,
+) -> Result<Json, DocError> {
+    let value = hydrate::export(doc, obj, heads).map_err(|e| DocError::in_object(doc, obj, e))?;
+    to_json(value)
+}
+
+fn to_json(value: HydrateValue) -> Result<Json, DocError> {
+    let json = match value {
+        HydrateValue::Map { value } => Json::Object(
+            value
+                .into_iter()
+                .map(|(key, value)| Ok((escape(&key), to_json(value)?)))
+                .collect::<Result<_, DocError>>()?,
+        ),
+        HydrateValue::List { value } => {
+            Json::Array(value.into_iter().map(to_json).collect::<Result<_, _>>()?)
+        }
+        HydrateValue::Text { value, .. } => tagged(TEXT, Json::String(value)),
+        HydrateValue::Scalar { value } => scalar_to_json(value)?,
+    };
+    Ok(json)
+}
+
+fn scalar_to_json(value: ScalarValue) -> Result<Json, DocError> {
+    let json =
+        match value {
+            ScalarValue::String { value } => Json::String(value),
+            ScalarValue::Int { value } => Json::from(value),
+            ScalarValue::Uint { value } => Json::from(value),
+            ScalarValue::F64 { value } => Json::Number(Number::from_f64(value).ok_or_else(
+                || DocError::InvalidJson {
+                    message: format!("{} cannot be represented in JSON", value),
+                },
+            )?),
+            ScalarValue::Boolean { value } => Json::Bool(value),
+            ScalarValue::Null => Json::Null,
+            ScalarValue::Counter { value } => tagged(COUNTER, Json::from(value)),
+            ScalarValue::Timestamp { value } => tagged(TIMESTAMP, Json::from(value)),
+            ScalarValue::Bytes { value } => tagged(BYTES, Json::String(hex::encode(value))),
+            ScalarValue::Unknown { type_code, data } => {
+                let mut inner = Map::new();
+                inner.insert("type_code".to_string(), Json::from(type_code));
+                inner.insert("bytes".to_string(), Json::String(hex::encode(data)));
+                tagged(UNKNOWN, Json::Object(inner))
+            }
+        };
+    Ok(json)
+}
+
+/// Doubles the `$` of a key which starts with one, so it cannot be read as a tag.
+pub(crate) fn escape(key: &str) -> String {
+    if key.starts_with(ESCAPE) {
+        format!("{}{}", ESCAPE, key)
+    } else {
+        key.to_string()
+    }
+}
+
+pub(crate) fn unescape(key: &str) -> &str {
+    match key.strip_prefix(ESCAPE) {
+        Some(rest) if rest.starts_with(ESCAPE) => rest,
+        _ => key,
+    }
+}
+
+fn tagged(tag: &str, value: Json) -> Json {
+    let mut map = Map::new();
+    map.insert(tag.to_string(), value);
+    Json::Object(map)
+}
+
+pub(crate) fn parse(json: &str) -> Result<Json, DocError> {
+    serde_json::from_str(json).map_err(|e| DocError::InvalidJson {
+        message: e.to_string(),
+    })
+}
+
+/// Writes `json` to `prop` of `obj`, creating any nested objects.
+pub(crate) fn put<T: Transactable>(
+    doc: &mut T,
+    obj: &am::ObjId,
+    prop: am::Prop,
+    json: &Json,
+) -> Result<(), DocError> {
+    match classify(json)? {
+        Node::Scalar(value) => doc.put(obj, prop, value)?,
+        Node::Object(typ) => {
+            let id = doc.put_object(obj, prop, typ)?;
+            populate(doc, &id, json)?;
+        }
+    }
+    Ok(())
+}
+
+fn insert<T: Transactable>(
+    doc: &mut T,
+    obj: &am::ObjId,
+    index: usize,
+    json: &Json,
+) -> Result<(), DocError> {
+    match classify(json)? {
+        Node::Scalar(value) => doc.insert(obj, index, value)?,
+        Node::Object(typ) => {
+            let id = doc.insert_object(obj, index, typ)?;
+            populate(doc, &id, json)?;
+        }
+    }
+    Ok(())
+}
+
+fn populate<T: Transactable>(doc: &mut T, obj: &am::ObjId, json: &Json) -> Result<(), DocError> {
+    match json {
+        Json::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                insert(doc, obj, index, item)?;
+            }
+        }
+        Json::Object(map) => match map.get(TEXT) {
+            Some(Json::String(text)) if map.len() == 1 => doc.splice_text(obj, 0, 0, text)?,
+            _ => {
+                for (key, value) in map {
+                    put(doc, obj, am::Prop::Map(unescape(key).to_string()), value)?;
+                }
+            }
+        },
+        _ => {}
+    }
+    Ok(())
+}
+
+enum Node {
+    Scalar(am::ScalarValue),
+    Object(am::ObjType),
+}
+
+fn classify(json: &Json) -> Result<Node, DocError> {
+    let scalar = match json {
+        Json::Null => am::ScalarValue::Null,
+        Json::Bool(b) => am::ScalarValue::Boolean(*b),
+        Json::String(s) => am::ScalarValue::Str(s.as_str().into()),
+        Json::Number(n) => number(n),
+        Json::Array(_) => return Ok(Node::Object(am::ObjType::List)),
+        Json::Object(map) => {
+            let Some((tag, value)) = map.iter().next().filter(|_| map.len() == 1) else {
+                return Ok(Node::Object(am::ObjType::Map));
+            };
+            match tag.as_str() {
+                TEXT => match value {
+                    Json::String(_) => return Ok(Node::Object(am::ObjType::Text)),
+                    _ => return Err(invalid(TEXT, "a string")),
+                },
+                COUNTER => value
+                    .as_i64()
+                    .map(am::ScalarValue::counter)
+                    .ok_or_else(|| invalid(COUNTER, "an integer"))?,
+                TIMESTAMP => value
+                    .as_i64()
+                    .map(am::ScalarValue::Timestamp)
+                    .ok_or_else(|| invalid(TIMESTAMP, "an integer"))?,
+                BYTES => value
+                    .as_str()
+                    .and_then(|s| hex::decode(s).ok())
+                    .map(am::ScalarValue::Bytes)
+                    .ok_or_else(|| invalid(BYTES, "a hex string"))?,
+                UNKNOWN => unknown(value)
+                    .ok_or_else(|| invalid(UNKNOWN, "an object with a type_code and hex bytes"))?,
+                _ => return Ok(Node::Object(am::ObjType::Map)),
+            }
+        }
+    };
+    Ok(Node::Scalar(scalar))
+}
+
+fn number(n: &Number) -> am::ScalarValue {
+    if let Some(i) = n.as_i64() {
+        am::ScalarValue::Int(i)
+    } else if let Some(u) = n.as_u64() {
+        am::ScalarValue::Uint(u)
+    } else {
+        am::ScalarValue::F64(n.as_f64().unwrap_or(f64::NAN))
+    }
+}
+
+fn unknown(value: &Json) -> Option<am::ScalarValue> {
+    let type_code = value.get("type_code")?.as_u64()?.try_into().ok()?;
+    let bytes = hex::decode(value.get("bytes")?.as_str()?).ok()?;
+    Some(am::ScalarValue::Unknown { type_code, bytes })
+}
+
+fn invalid(tag: &str, expected: &str) -> DocError {
+    DocError::InvalidJson {
+        message: format!("the value of {} must be {}", tag, expected),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn import(json: &str) -> am::AutoCommit {
+        let mut doc = am::AutoCommit::new();
+        let Json::Object(map) = parse(json).unwrap() else {
+            panic!("expected an object");
+        };
+        for (key, value) in &map {
+            put(
+                &mut doc,
+                &am::ROOT,
+                am::Prop::Map(unescape(key).to_string()),
+                value,
+            )
+            .unwrap();
+        }
+        doc
+    }
+
+    #[test]
+    fn user_keys_starting_with_a_dollar_are_escaped() {
+        let mut doc = am::AutoCommit::new();
+        doc.put(am::ROOT, "$text", "not text").unwrap();
+        doc.put(am::ROOT, "$$money", 5).unwrap();
+        let nested = doc
+            .put_object(am::ROOT, "nested", am::ObjType::Map)
+            .unwrap();
+        doc.put(&nested, "$counter", 1).unwrap();
+
+        let json = export(&doc, &am::ROOT, None).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "$$text": "not text",
+                "$$$money": 5,
+                "nested": {"$$counter": 1},
+            })
+        );
+    }
+
+    #[test]
+    fn escaped_keys_round_trip() {
+        let json = r#"{"$$text": "not text", "$$$money": 5, "nested": {"$$counter": 1}}"#;
+        let doc = import(json);
+        assert_eq!(export(&doc, &am::ROOT, None).unwrap(), parse(json).unwrap());
+
+        let (value, _) = doc.get(am::ROOT, "$text").unwrap().unwrap();
+        assert_eq!(value.to_str(), Some("not text"));
+        assert!(doc.get(am::ROOT, "$$money").unwrap().is_some());
+    }
+
+    #[test]
+    fn tagged_values_round_trip() {
+        let json = r#"{
+            "text": {"$text": "hello"},
+            "counter": {"$counter": 5},
+            "timestamp": {"$timestamp": 1700000000000},
+            "bytes": {"$bytes": "00ff"},
+            "unknown": {"$unknown": {"type_code": 12, "bytes": "00ff"}}
+        }"#;
+        let doc = import(json);
+        let (value, _) = doc.get(am::ROOT, "text").unwrap().unwrap();
+        assert!(matches!(value, am::Value::Object(am::ObjType::Text)));
+        assert_eq!(export(&doc, &am::ROOT, None).unwrap(), parse(json).unwrap());
+    }
+
+    #[test]
+    fn marks_are_left_out() {
+        let mut doc = am::AutoCommit::new();
+        let text = doc.put_object(am::ROOT, "text", am::ObjType::Text).unwrap();
+        doc.splice_text(&text, 0, 0, "hello").unwrap();
+        doc.mark(
+            &text,
+            am::marks::Mark::new("bold".to_string(), true, 0, 5),
+            am::marks::ExpandMark::None,
+        )
+        .unwrap();
+        assert_eq!(
+            export(&doc, &am::ROOT, None).unwrap(),
+            serde_json::json!({"text": {"$text": "hello"}})
+        );
+    }
+
+    #[test]
+    fn non_finite_floats_are_an_error() {
+        for f in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let mut doc = am::AutoCommit::new();
+            doc.put(am::ROOT, "number", f).unwrap();
+            assert!(matches!(
+                export(&doc, &am::ROOT, None),
+                Err(DocError::InvalidJson { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn missing_objects_are_not_found() {
+        let mut doc = am::AutoCommit::new();
+        let map = doc.put_object(am::ROOT, "map", am::ObjType::Map).unwrap();
+        assert!(matches!(
+            export(&am::AutoCommit::new(), &map, None),
+            Err(DocError::ObjectNotFound { .. })
+        ));
+    }
+}
@@ -1,15 +1,36 @@
+// The generated scaffolding trips this lint on the current toolchain
+#![allow(clippy::empty_line_after_doc_comments)]
+
 uniffi::include_scaffolding!("automerge");
 
 mod actor_id;
 use actor_id::ActorId;
+mod blame;
+use blame::{Attribution, TextSpan};
+mod branch;
+use branch::Branch;
 mod cursor;
 use cursor::{Cursor, Position};
+mod cbor;
+mod cherry_pick;
+use cherry_pick::CherryPick;
+mod conflict;
+use conflict::Conflict;
 mod change;
-use change::Change;
+use change::{decode_change, Change, DecodeChangeError};
 mod change_hash;
 use change_hash::ChangeHash;
+mod diff;
+use diff::DiffSummary;
 mod doc;
+mod document_id;
 use doc::{Doc, DocError, LoadError, ReceiveSyncError};
+use document_id::{automerge_url, generate_document_id, DocumentId};
+mod hydrate;
+use hydrate::HydrateValue;
+mod json;
+mod load_options;
+use load_options::{LoadOptions, OnPartialLoad, StringMigration, VerificationMode};
 mod mark;
 use mark::{ExpandMark, KeyValue, Mark};
 mod obj_id;
@@ -17,14 +38,34 @@ use obj_id::{root, ObjId};
 mod obj_type;
 use obj_type::ObjType;
 mod patches;
-use patches::{Patch, PatchAction};
+use patches::{Patch, PatchAction, PatchObserver};
 mod path;
 use path::{PathElement, Prop};
+mod presence;
+use presence::{DecodePresenceError, Presence, PresenceState, PresenceValue};
+mod repo;
+use repo::{DocumentSyncPhase, EphemeralMessage, NetworkAdapter, Repo, RepoError};
+mod repo_message;
+use repo_message::{decode_repo_message, encode_repo_message, PeerMetadata, RepoMessage};
+mod revert;
 mod scalar_value;
 use scalar_value::ScalarValue;
+mod storage;
+use storage::StorageAdapter;
+mod sync_session;
+use sync_session::{PeerMessage, SyncSession, SyncSessionError};
 mod sync_state;
 use sync_state::{DecodeSyncStateError, SyncState};
+mod sync_message;
+use sync_message::{
+    decode_sync_message, DecodeSyncMessageError, SyncCapability, SyncHave, SyncMessage,
+    SyncMessageVersion,
+};
+mod undo;
+use undo::UndoManager;
 mod value;
 use value::Value;
 mod text_encoding;
 use text_encoding::TextEncoding;
+mod transaction;
+use transaction::Transaction;
@@ -0,0 +1,66 @@
+use automerge as am;
+
+use crate::{ActorId, TextEncoding};
+
+pub enum OnPartialLoad {
+    Ignore,
+    Error,
+}
+
+impl From<OnPartialLoad> for am::OnPartialLoad {
+    fn from(value: OnPartialLoad) -> Self {
+        match value {
+            OnPartialLoad::Ignore => am::OnPartialLoad::Ignore,
+            OnPartialLoad::Error => am::OnPartialLoad::Error,
+        }
+    }
+}
+
+pub enum VerificationMode {
+    Check,
+    DontCheck,
+}
+
+impl From<VerificationMode> for am::VerificationMode {
+    fn from(value: VerificationMode) -> Self {
+        match value {
+            VerificationMode::Check => am::VerificationMode::Check,
+            VerificationMode::DontCheck => am::VerificationMode::DontCheck,
+        }
+    }
+}
+
+pub enum StringMigration {
+    NoMigration,
+    ConvertToText,
+}
+
+impl From<StringMigration> for am::StringMigration {
+    fn from(value: StringMigration) -> Self {
+        match value {
+            StringMigration::NoMigration => am::StringMigration::NoMigration,
+            StringMigration::ConvertToText => am::StringMigration::ConvertToText,
+        }
+    }
+}
+
+pub struct LoadOptions {
+    pub on_partial_load: OnPartialLoad,
+    pub verification_mode: VerificationMode,
+    pub string_migration: StringMigration,
+    pub text_encoding: Option<TextEncoding>,
+    pub actor: Option<ActorId>,
+}
+
+impl From<LoadOptions> for am::LoadOptions<'static> {
+    fn from(value: LoadOptions) -> Self {
+        let options = am::LoadOptions::new()
+            .on_partial_load(value.on_partial_load.into())
+            .verification_mode(value.verification_mode.into())
+            .migrate_strings(value.string_migration.into());
+        match value.text_encoding {
+            Some(text_encoding) => options.text_encoding(text_encoding.into()),
+            None => options,
+        }
+    }
+}
@@ -1,20 +1,22 @@
 use super::UniffiCustomTypeConverter;
 use automerge as am;
 
+use crate::DocError;
+
+/// An object id, which is parsed when it is lifted from the FFI so that
+/// malformed bytes are rejected there.
 #[derive(Debug, Clone)]
-pub struct ObjId(Vec<u8>);
+pub struct ObjId(am::ObjId);
 
 impl From<ObjId> for automerge::ObjId {
     fn from(value: ObjId) -> Self {
-        // There is no way to construct ObjId except in this library, where we always construct it
-        // from a valid object ID byte array am::ObjId::try_from(&value.0[..]).unwrap()
-        am::ObjId::try_from(value.0.as_slice()).unwrap()
+        value.0
     }
 }
 
 impl From<am::ObjId> for ObjId {
     fn from(value: am::ObjId) -> Self {
-        ObjId(value.to_bytes())
+        ObjId(value)
     }
 }
 
@@ -29,10 +31,28 @@ impl UniffiCustomTypeConverter for ObjId {
     where
         Self: Sized,
     {
-        Ok(Self(val))
+        let obj = am::ObjId::try_from(val.as_slice()).map_err(|e| DocError::InvalidObjId {
+            message: e.to_string(),
+        })?;
+        Ok(Self(obj))
     }
 
     fn from_custom(obj: Self) -> Self::Builtin {
-        obj.0
+        obj.0.to_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lifting_checks_the_bytes() {
+        let obj = ObjId::from(am::ObjId::Id(3, am::ActorId::random(), 0));
+        let bytes = ObjId::from_custom(obj.clone());
+        let lifted = ObjId::into_custom(bytes).unwrap();
+        assert_eq!(am::ObjId::from(lifted), am::ObjId::from(obj));
+
+        assert!(ObjId::into_custom(vec![0xff, 0xff]).is_err());
     }
 }
@@ -1,6 +1,6 @@
 use automerge as am;
 
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub enum ObjType {
     Map,
     List,
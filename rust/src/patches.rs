@@ -9,6 +9,10 @@ use crate::{
     value::Value,
 };
 
+pub trait PatchObserver: Send + Sync {
+    fn on_patches(&self, patches: Vec<Patch>);
+}
+
 pub struct Patch {
     pub path: Vec<PathElement>,
     pub action: PatchAction,
@@ -0,0 +1,439 @@
+//! Ephemeral presence shared between peers, such as cursors and selections.
+//!
+//! Presence is never written to a document. Each update carries the sender's
+//! actor, the time it was sent and how long it stays valid, and replaces any
+//! earlier update from the same actor. Updates are encoded as CBOR so they can
+//! be sent as the data of an automerge-repo ephemeral message.
+//!
+//! Payload values use the native CBOR type where there is one. Values CBOR
+//! cannot represent directly use the same `$` tags as the JSON export:
+//! `{"$counter": 5}`, `{"$timestamp": 1700000000000}`,
+//! `{"$unknown": {"type_code": 12, "bytes": h'00ff'}}`, and cursors are
+//! written as `{"$cursor": "<cursor string>"}`. Maps may be nested; as in the
+//! JSON export, a key which starts with `$` is written with a second `$` so
+//! it is never mistaken for a tag.
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+use automerge as am;
+
+use crate::cbor::{self, Cbor};
+use crate::json::{escape, unescape};
+use crate::{ActorId, Cursor, DocError, ScalarValue};
+
+const COUNTER: &str = "$counter";
+const TIMESTAMP: &str = "$timestamp";
+const UNKNOWN: &str = "$unknown";
+const CURSOR: &str = "$cursor";
+
+#[derive(Debug, thiserror::Error)]
+pub enum DecodePresenceError {
+    #[error("invalid presence message: {0}")]
+    InvalidMessage(String),
+}
+
+pub enum PresenceValue {
+    Scalar {
+        value: ScalarValue,
+    },
+    Cursor {
+        value: Cursor,
+    },
+    Map {
+        value: HashMap<String, PresenceValue>,
+    },
+}
+
+pub struct PresenceState {
+    pub actor: ActorId,
+    pub timestamp: i64,
+    pub expires_at: i64,
+    pub payload: HashMap<String, PresenceValue>,
+}
+
+#[derive(Clone)]
+enum StoredValue {
+    Scalar(am::ScalarValue),
+    Cursor(am::Cursor),
+    Map(BTreeMap<String, StoredValue>),
+}
+
+impl From<PresenceValue> for StoredValue {
+    fn from(value: PresenceValue) -> Self {
+        match value {
+            PresenceValue::Scalar { value } => StoredValue::Scalar(value.into()),
+            PresenceValue::Cursor { value } => StoredValue::Cursor(value.into()),
+            PresenceValue::Map { value } => StoredValue::Map(
+                value
+                    .into_iter()
+                    .map(|(k, v)| (k, StoredValue::from(v)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl From<StoredValue> for PresenceValue {
+    fn from(value: StoredValue) -> Self {
+        match value {
+            StoredValue::Scalar(value) => PresenceValue::Scalar {
+                value: (&value).into(),
+            },
+            StoredValue::Cursor(value) => PresenceValue::Cursor {
+                value: value.into(),
+            },
+            StoredValue::Map(value) => PresenceValue::Map {
+                value: value.into_iter().map(|(k, v)| (k, v.into())).collect(),
+            },
+        }
+    }
+}
+
+struct PeerPresence {
+    timestamp: i64,
+    ttl: i64,
+    payload: BTreeMap<String, StoredValue>,
+}
+
+/// The presence of every peer, as of the updates received from them.
+pub struct Presence {
+    actor: am::ActorId,
+    ttl: i64,
+    peers: Mutex<HashMap<am::ActorId, PeerPresence>>,
+}
+
+impl Presence {
+    /// Creates a presence for `actor` whose updates stay valid for `ttl`
+    /// milliseconds.
+    pub fn new(actor: ActorId, ttl: i64) -> Self {
+        Self {
+            actor: actor.into(),
+            ttl,
+            peers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Encodes an update announcing `payload` at `timestamp`, in milliseconds
+    /// since the epoch.
+    pub fn encode_update(
+        &self,
+        payload: HashMap<String, PresenceValue>,
+        timestamp: i64,
+    ) -> Result<Vec<u8>, DocError> {
+        let payload = payload
+            .into_iter()
+            .map(|(k, v)| (k, StoredValue::from(v)))
+            .collect();
+        Ok(self.encode(timestamp, self.ttl, &payload))
+    }
+
+    /// Encodes an update which removes this actor's presence.
+    pub fn encode_leave(&self, timestamp: i64) -> Vec<u8> {
+        self.encode(timestamp, 0, &BTreeMap::new())
+    }
+
+    /// Records an update from a peer.
+    ///
+    /// Updates from our own actor and updates older than the latest one from
+    /// the same actor are ignored.
+    pub fn receive(&self, message: Vec<u8>) -> Result<(), DecodePresenceError> {
+        let (actor, presence) = decode(&message)?;
+        if actor == self.actor {
+            return Ok(());
+        }
+        let mut peers = self.peers.lock().unwrap();
+        match peers.get(&actor) {
+            Some(existing) if existing.timestamp > presence.timestamp => {}
+            _ => {
+                peers.insert(actor, presence);
+            }
+        }
+        Ok(())
+    }
+
+    /// The presence of every peer whose latest update has not expired at `now`.
+    pub fn states(&self, now: i64) -> Vec<PresenceState> {
+        let peers = self.peers.lock().unwrap();
+        let mut states = peers
+            .iter()
+            .filter(|(_, presence)| presence.expires_at() > now)
+            .map(|(actor, presence)| PresenceState {
+                actor: actor.into(),
+                timestamp: presence.timestamp,
+                expires_at: presence.expires_at(),
+                payload: presence
+                    .payload
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.clone().into()))
+                    .collect(),
+            })
+            .collect::<Vec<_>>();
+        states.sort_by_key(|state| state.timestamp);
+        states
+    }
+
+    /// Forgets every peer whose latest update has expired at `now`, returning
+    /// their actors.
+    pub fn prune(&self, now: i64) -> Vec<ActorId> {
+        let mut peers = self.peers.lock().unwrap();
+        let expired = peers
+            .iter()
+            .filter(|(_, presence)| presence.expires_at() <= now)
+            .map(|(actor, _)| actor.clone())
+            .collect::<Vec<_>>();
+        for actor in &expired {
+            peers.remove(actor);
+        }
+        expired.iter().map(ActorId::from).collect()
+    }
+
+    fn encode(&self, timestamp: i64, ttl: i64, payload: &BTreeMap<String, StoredValue>) -> Vec<u8> {
+        cbor::map([
+            ("actor", Cbor::Bytes(self.actor.to_bytes().to_vec())),
+            ("timestamp", Cbor::int(timestamp)),
+            ("ttl", Cbor::int(ttl)),
+            ("payload", encode_map(payload)),
+        ])
+        .encode()
+    }
+}
+
+impl PeerPresence {
+    fn expires_at(&self) -> i64 {
+        self.timestamp.saturating_add(self.ttl)
+    }
+}
+
+fn encode_map(map: &BTreeMap<String, StoredValue>) -> Cbor {
+    Cbor::Map(
+        map.iter()
+            .map(|(k, v)| (Cbor::Text(escape(k)), encode_value(v)))
+            .collect(),
+    )
+}
+
+fn encode_value(value: &StoredValue) -> Cbor {
+    let scalar = match value {
+        StoredValue::Cursor(cursor) => return tagged(CURSOR, Cbor::Text(cursor.to_string())),
+        StoredValue::Map(map) => return encode_map(map),
+        StoredValue::Scalar(scalar) => scalar,
+    };
+    match scalar {
+        am::ScalarValue::Bytes(b) => Cbor::Bytes(b.clone()),
+        am::ScalarValue::Str(s) => Cbor::Text(s.to_string()),
+        am::ScalarValue::Int(i) => Cbor::int(*i),
+        am::ScalarValue::Uint(u) => Cbor::Uint(*u),
+        am::ScalarValue::F64(f) => Cbor::Float(*f),
+        am::ScalarValue::Counter(c) => tagged(COUNTER, Cbor::int(i64::from(c))),
+        am::ScalarValue::Timestamp(t) => tagged(TIMESTAMP, Cbor::int(*t)),
+        am::ScalarValue::Boolean(b) => Cbor::Bool(*b),
+        am::ScalarValue::Unknown { type_code, bytes } => tagged(
+            UNKNOWN,
+            cbor::map([
+                ("type_code", Cbor::Uint(u64::from(*type_code))),
+                ("bytes", Cbor::Bytes(bytes.clone())),
+            ]),
+        ),
+        am::ScalarValue::Null => Cbor::Null,
+    }
+}
+
+fn tagged(tag: &str, value: Cbor) -> Cbor {
+    cbor::map([(tag, value)])
+}
+
+fn decode(message: &[u8]) -> Result<(am::ActorId, PeerPresence), DecodePresenceError> {
+    let invalid = |message: &str| DecodePresenceError::InvalidMessage(message.to_string());
+    let value =
+        Cbor::decode(message).map_err(|e| DecodePresenceError::InvalidMessage(e.to_string()))?;
+    let actor = value
+        .get("actor")
+        .and_then(Cbor::as_bytes)
+        .ok_or_else(|| invalid("missing actor"))?;
+    let timestamp = value
+        .get("timestamp")
+        .and_then(Cbor::as_i64)
+        .ok_or_else(|| invalid("missing timestamp"))?;
+    let ttl = value
+        .get("ttl")
+        .and_then(Cbor::as_i64)
+        .ok_or_else(|| invalid("missing ttl"))?;
+    let Some(Cbor::Map(entries)) = value.get("payload") else {
+        return Err(invalid("missing payload"));
+    };
+    let payload = decode_map(entries)?;
+    let presence = PeerPresence {
+        timestamp,
+        ttl,
+        payload,
+    };
+    Ok((am::ActorId::from(actor), presence))
+}
+
+fn decode_map(
+    entries: &[(Cbor, Cbor)],
+) -> Result<BTreeMap<String, StoredValue>, DecodePresenceError> {
+    let mut map = BTreeMap::new();
+    for (key, value) in entries {
+        let key = key.as_str().ok_or_else(|| {
+            DecodePresenceError::InvalidMessage("payload keys must be strings".to_string())
+        })?;
+        map.insert(unescape(key).to_string(), decode_value(key, value)?);
+    }
+    Ok(map)
+}
+
+fn decode_value(key: &str, value: &Cbor) -> Result<StoredValue, DecodePresenceError> {
+    let decoded = match value {
+        Cbor::Map(entries) => match entries.as_slice() {
+            [(Cbor::Text(tag), inner)] if is_tag(tag) => decode_tagged(tag, inner),
+            _ => return decode_map(entries).map(StoredValue::Map),
+        },
+        value => decode_scalar(value),
+    };
+    decoded.ok_or_else(|| {
+        DecodePresenceError::InvalidMessage(format!("unsupported value for {}", key))
+    })
+}
+
+fn is_tag(key: &str) -> bool {
+    key.starts_with('$') && unescape(key) == key
+}
+
+fn decode_scalar(value: &Cbor) -> Option<StoredValue> {
+    let scalar = match value {
+        Cbor::Bytes(b) => am::ScalarValue::Bytes(b.clone()),
+        Cbor::Text(s) => am::ScalarValue::Str(s.as_str().into()),
+        Cbor::Uint(u) => match i64::try_from(*u) {
+            Ok(i) => am::ScalarValue::Int(i),
+            Err(_) => am::ScalarValue::Uint(*u),
+        },
+        Cbor::Negative(_) => am::ScalarValue::Int(value.as_i64()?),
+        Cbor::Float(f) => am::ScalarValue::F64(*f),
+        Cbor::Bool(b) => am::ScalarValue::Boolean(*b),
+        Cbor::Null | Cbor::Undefined => am::ScalarValue::Null,
+        Cbor::Array(_) | Cbor::Map(_) => return None,
+    };
+    Some(StoredValue::Scalar(scalar))
+}
+
+fn decode_tagged(tag: &str, inner: &Cbor) -> Option<StoredValue> {
+    let scalar = match tag {
+        CURSOR => {
+            let cursor = am::Cursor::try_from(inner.as_str()?).ok()?;
+            return Some(StoredValue::Cursor(cursor));
+        }
+        COUNTER => am::ScalarValue::counter(inner.as_i64()?),
+        TIMESTAMP => am::ScalarValue::Timestamp(inner.as_i64()?),
+        UNKNOWN => am::ScalarValue::Unknown {
+            type_code: u8::try_from(inner.get("type_code")?.as_u64()?).ok()?,
+            bytes: inner.get("bytes")?.as_bytes()?.to_vec(),
+        },
+        _ => return None,
+    };
+    Some(StoredValue::Scalar(scalar))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn presence() -> Presence {
+        Presence::new((&am::ActorId::random()).into(), 1000)
+    }
+
+    fn scalar(value: ScalarValue) -> PresenceValue {
+        PresenceValue::Scalar { value }
+    }
+
+    fn string(value: &str) -> PresenceValue {
+        scalar(ScalarValue::String {
+            value: value.to_string(),
+        })
+    }
+
+    fn map<const N: usize>(entries: [(&str, PresenceValue); N]) -> HashMap<String, PresenceValue> {
+        entries
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect()
+    }
+
+    fn entries(value: &PresenceValue) -> &HashMap<String, PresenceValue> {
+        match value {
+            PresenceValue::Map { value } => value,
+            _ => panic!("expected a map"),
+        }
+    }
+
+    fn as_str(value: &PresenceValue) -> &str {
+        match value {
+            PresenceValue::Scalar {
+                value: ScalarValue::String { value },
+            } => value,
+            _ => panic!("expected a string"),
+        }
+    }
+
+    /// Sends `payload` from one presence to another and returns what arrives.
+    fn round_trip(payload: HashMap<String, PresenceValue>) -> HashMap<String, PresenceValue> {
+        let (sender, receiver) = (presence(), presence());
+        let update = sender.encode_update(payload, 100).unwrap();
+        receiver.receive(update).unwrap();
+        receiver.states(100).pop().unwrap().payload
+    }
+
+    #[test]
+    fn nested_maps_round_trip() {
+        let head = PresenceValue::Map {
+            value: map([("offset", string("b"))]),
+        };
+        let selection = PresenceValue::Map {
+            value: map([("anchor", string("a")), ("head", head)]),
+        };
+        let payload = round_trip(map([("name", string("Alice")), ("selection", selection)]));
+
+        assert_eq!(as_str(&payload["name"]), "Alice");
+        let selection = entries(&payload["selection"]);
+        assert_eq!(as_str(&selection["anchor"]), "a");
+        assert_eq!(as_str(&entries(&selection["head"])["offset"]), "b");
+    }
+
+    #[test]
+    fn keys_which_look_like_tags_are_escaped() {
+        let counter = PresenceValue::Map {
+            value: map([("$counter", string("not a counter"))]),
+        };
+        let tagged = PresenceValue::Map {
+            value: map([("count", scalar(ScalarValue::Counter { value: 5 }))]),
+        };
+        let payload = round_trip(map([
+            ("$name", string("Alice")),
+            ("counter", counter),
+            ("tagged", tagged),
+        ]));
+
+        assert_eq!(as_str(&payload["$name"]), "Alice");
+        assert_eq!(
+            as_str(&entries(&payload["counter"])["$counter"]),
+            "not a counter"
+        );
+        assert!(matches!(
+            entries(&payload["tagged"])["count"],
+            PresenceValue::Scalar {
+                value: ScalarValue::Counter { value: 5 }
+            }
+        ));
+    }
+
+    #[test]
+    fn updates_expire_after_their_ttl() {
+        let (alice, bob) = (presence(), presence());
+        let update = alice.encode_update(map([("name", string("Alice"))]), 100);
+        bob.receive(update.unwrap()).unwrap();
+
+        assert_eq!(bob.states(1099).len(), 1);
+        assert!(bob.states(1100).is_empty());
+        assert_eq!(bob.prune(1100).len(), 1);
+    }
+}
@@ -0,0 +1,1087 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, Mutex, RwLock};
+
+use automerge as am;
+
+use crate::document_id::DocumentId;
+use crate::repo_message::{
+    decode_repo_message, encode, PeerMetadata, RepoMessage, PROTOCOL_VERSION,
+};
+use crate::storage::{self, StorageAdapter};
+use crate::{ChangeHash, Doc, DocError, LoadError, SyncSession, SyncSessionError, SyncState};
+
+#[derive(Debug, thiserror::Error)]
+pub enum RepoError {
+    #[error("Invalid document id: {message}")]
+    InvalidDocumentId { message: String },
+    #[error("No document with id {document_id}")]
+    DocumentNotFound { document_id: DocumentId },
+    #[error("Corrupt data: {message}")]
+    CorruptData { message: String },
+    #[error("Changes depend on changes which are not in the document")]
+    MissingDependencies { hashes: Vec<ChangeHash> },
+    #[error("Invalid message: {message}")]
+    InvalidMessage { message: String },
+    #[error("Peer reported an error: {message}")]
+    PeerError { message: String },
+    #[error("{message}")]
+    Internal { message: String },
+}
+
+impl From<am::AutomergeError> for RepoError {
+    fn from(error: am::AutomergeError) -> Self {
+        match DocError::from(error) {
+            DocError::CorruptData { message } => RepoError::CorruptData { message },
+            DocError::MissingDependencies { hashes } => RepoError::MissingDependencies { hashes },
+            error @ DocError::DuplicateSeqNumber { .. } => RepoError::CorruptData {
+                message: error.to_string(),
+            },
+            error => RepoError::Internal {
+                message: error.to_string(),
+            },
+        }
+    }
+}
+
+impl From<LoadError> for RepoError {
+    fn from(error: LoadError) -> Self {
+        match error {
+            LoadError::CorruptData { message } => RepoError::CorruptData { message },
+            LoadError::MissingDependencies { hashes } => RepoError::MissingDependencies { hashes },
+            LoadError::Internal { message } => RepoError::Internal { message },
+            error @ LoadError::DuplicateSeqNumber { .. } => RepoError::CorruptData {
+                message: error.to_string(),
+            },
+        }
+    }
+}
+
+impl From<SyncSessionError> for RepoError {
+    fn from(error: SyncSessionError) -> Self {
+        match error {
+            SyncSessionError::InvalidMessage { message } => RepoError::InvalidMessage { message },
+            SyncSessionError::MissingDependencies { hashes } => {
+                RepoError::MissingDependencies { hashes }
+            }
+            error => RepoError::Internal {
+                message: error.to_string(),
+            },
+        }
+    }
+}
+
+pub trait NetworkAdapter: Send + Sync {
+    /// Sends an encoded automerge-repo message to `peer_id`.
+    fn send(&self, peer_id: String, message: Vec<u8>);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentSyncPhase {
+    /// We do not have the document and have asked our peers for it
+    Requesting,
+    /// The repo has the document and exchanges sync messages about it
+    Syncing,
+    /// Every peer we asked does not have the document
+    Unavailable,
+}
+
+/// An ephemeral message about a document, such as a [`crate::Presence`] update.
+pub struct EphemeralMessage {
+    /// The peer which broadcast the message
+    pub sender_id: String,
+    pub document_id: DocumentId,
+    pub data: Vec<u8>,
+}
+
+struct Entry {
+    session: SyncSession,
+    /// The heads as of the last save, or `None` if the document has never been saved
+    saved_heads: Mutex<Option<Vec<am::ChangeHash>>>,
+}
+
+/// A document which has been asked for but which no peer has sent yet.
+struct Request {
+    entry: Arc<Entry>,
+    /// The peers which have said they do not have the document
+    unavailable: Mutex<BTreeSet<String>>,
+}
+
+impl Request {
+    fn phase(&self) -> DocumentSyncPhase {
+        let peers = self.entry.session.peers();
+        let unavailable = self.unavailable.lock().unwrap();
+        if !peers.is_empty() && peers.iter().all(|peer| unavailable.contains(peer)) {
+            DocumentSyncPhase::Unavailable
+        } else {
+            DocumentSyncPhase::Requesting
+        }
+    }
+}
+
+/// A collection of documents, keyed by [`DocumentId`], which are persisted
+/// through a [`StorageAdapter`] and synchronised with every connected peer
+/// through a [`NetworkAdapter`].
+///
+/// Peers exchange automerge-repo messages: each sync message is wrapped in a
+/// CBOR envelope naming the sender, the target peer and the document, so the
+/// repo can talk to a JS automerge-repo sync server. A peer is added when it
+/// answers [`Repo::join`] or joins itself, or with [`Repo::add_peer`].
+///
+/// Documents are loaded from storage the first time they are requested. A
+/// document the repo does not have is asked for with [`Repo::request`], and
+/// only added once a peer sends its changes. The adapters are never called
+/// while a document is locked.
+///
+/// Ephemeral messages, which are never saved, are sent with
+/// [`Repo::broadcast`] and returned from [`Repo::receive_message`]. Each is
+/// passed on to the other peers once, so clients of a sync server receive
+/// each other's messages.
+pub struct Repo {
+    peer_id: String,
+    peer_metadata: PeerMetadata,
+    storage: Box<dyn StorageAdapter>,
+    network: RwLock<Option<Arc<dyn NetworkAdapter>>>,
+    docs: Mutex<HashMap<DocumentId, Arc<Entry>>>,
+    requests: Mutex<HashMap<DocumentId, Arc<Request>>>,
+    peers: Mutex<BTreeSet<String>>,
+    /// Identifies the ephemeral messages sent by this instance of the repo
+    session_id: String,
+    ephemeral_count: Mutex<u64>,
+    /// The count of the latest ephemeral message received from each sender and session
+    ephemeral_seen: Mutex<HashMap<(String, String), u64>>,
+}
+
+impl Repo {
+    pub fn new(
+        peer_id: String,
+        peer_metadata: PeerMetadata,
+        storage: Box<dyn StorageAdapter>,
+    ) -> Self {
+        Self {
+            peer_id,
+            peer_metadata,
+            storage,
+            network: RwLock::new(None),
+            docs: Mutex::new(HashMap::new()),
+            requests: Mutex::new(HashMap::new()),
+            peers: Mutex::new(BTreeSet::new()),
+            session_id: uuid::Uuid::new_v4().simple().to_string(),
+            ephemeral_count: Mutex::new(0),
+            ephemeral_seen: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The id this repo sends its messages as.
+    pub fn peer_id(&self) -> String {
+        self.peer_id.clone()
+    }
+
+    pub fn set_network_adapter(&self, network: Box<dyn NetworkAdapter>) {
+        *self.network.write().unwrap() = Some(Arc::from(network));
+    }
+
+    /// The message which introduces this repo to a peer whose id is not yet
+    /// known, such as a sync server the network adapter has just connected to.
+    pub fn join(&self) -> Vec<u8> {
+        encode(&RepoMessage::Join {
+            sender_id: self.peer_id.clone(),
+            peer_metadata: self.peer_metadata.clone(),
+            supported_protocol_versions: vec![PROTOCOL_VERSION.to_string()],
+        })
+    }
+
+    /// Creates an empty document with a new id.
+    pub fn create(&self) -> DocumentId {
+        let id = DocumentId::generate();
+        self.insert(id.clone(), self.new_entry(am::AutoCommit::new(), None));
+        id
+    }
+
+    /// Adds a document from its saved bytes under a new id.
+    pub fn import(&self, bytes: Vec<u8>) -> Result<DocumentId, RepoError> {
+        let doc = am::AutoCommit::load(&bytes).map_err(|e| LoadError::in_load(&bytes, e))?;
+        let id = DocumentId::generate();
+        self.insert(id.clone(), self.new_entry(doc, None));
+        Ok(id)
+    }
+
+    /// Returns the document with `document_id`, loading it from storage if
+    /// necessary, or `None` if the repo has no such document.
+    pub fn find(&self, document_id: DocumentId) -> Result<Option<Arc<Doc>>, RepoError> {
+        Ok(self.entry(&document_id)?.map(|entry| entry.session.doc()))
+    }
+
+    /// Asks every peer for a document the repo does not have.
+    ///
+    /// The document is added to the repo once a peer sends its changes, and
+    /// can then be found with [`Repo::find`]. If the repo already has the
+    /// document, this sends any outstanding sync messages for it.
+    pub fn request(&self, document_id: DocumentId) -> Result<(), RepoError> {
+        if let Some(entry) = self.entry(&document_id)? {
+            self.send(&document_id, &entry.session, false);
+            return Ok(());
+        }
+        let request = Arc::new(Request {
+            entry: self.new_entry(am::AutoCommit::new(), None),
+            unavailable: Mutex::new(BTreeSet::new()),
+        });
+        self.requests
+            .lock()
+            .unwrap()
+            .insert(document_id.clone(), request.clone());
+        self.send(&document_id, &request.entry.session, true);
+        Ok(())
+    }
+
+    /// Where the repo is in synchronising a document, or `None` if it neither
+    /// has nor has asked for the document.
+    pub fn phase(&self, document_id: DocumentId) -> Result<Option<DocumentSyncPhase>, RepoError> {
+        if let Some(request) = self.requests.lock().unwrap().get(&document_id) {
+            return Ok(Some(request.phase()));
+        }
+        Ok(self
+            .entry(&document_id)?
+            .map(|_| DocumentSyncPhase::Syncing))
+    }
+
+    /// The ids of the documents which are currently loaded.
+    pub fn document_ids(&self) -> Vec<DocumentId> {
+        self.docs.lock().unwrap().keys().cloned().collect()
+    }
+
+    /// The ids of every document in storage.
+    pub fn stored_document_ids(&self) -> Vec<DocumentId> {
+        self.storage
+            .list(Vec::new())
+            .into_iter()
+            .filter_map(|key| DocumentId::parse(key.first()?).ok())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Saves the changes made since the document was last saved or loaded.
+    pub fn save(&self, document_id: DocumentId) -> Result<(), RepoError> {
+        let entry = self.require(&document_id)?;
+        let mut saved_heads = entry.saved_heads.lock().unwrap();
+        let (key, bytes, heads) = {
+            let doc = entry.session.doc();
+            let mut doc = doc.0.write().unwrap();
+            let heads = doc.get_heads();
+            match saved_heads.as_deref() {
+                Some(saved) if saved == heads.as_slice() => return Ok(()),
+                None if heads.is_empty() => return Ok(()),
+                Some(saved) => {
+                    let bytes = doc.save_after(saved);
+                    (storage::incremental_key(&document_id, &bytes), bytes, heads)
+                }
+                None => {
+                    let bytes = doc.save();
+                    (storage::snapshot_key(&document_id, &heads), bytes, heads)
+                }
+            }
+        };
+        self.storage.save(key, bytes);
+        *saved_heads = Some(heads);
+        Ok(())
+    }
+
+    /// Replaces the stored chunks of a document with a single snapshot.
+    pub fn compact(&self, document_id: DocumentId) -> Result<(), RepoError> {
+        let entry = self.require(&document_id)?;
+        let mut saved_heads = entry.saved_heads.lock().unwrap();
+        let (bytes, heads) = {
+            let doc = entry.session.doc();
+            let mut doc = doc.0.write().unwrap();
+            (doc.save(), doc.get_heads())
+        };
+        let key = storage::snapshot_key(&document_id, &heads);
+        let stale = storage::chunk_keys(self.storage.as_ref(), &document_id);
+        self.storage.save(key.clone(), bytes);
+        for stale in stale.into_iter().filter(|stale| *stale != key) {
+            self.storage.remove(stale);
+        }
+        *saved_heads = Some(heads);
+        Ok(())
+    }
+
+    /// Removes a document from the repo and from storage.
+    pub fn delete(&self, document_id: DocumentId) -> Result<(), RepoError> {
+        self.docs.lock().unwrap().remove(&document_id);
+        self.requests.lock().unwrap().remove(&document_id);
+        for key in self.storage.list(vec![document_id.as_str().to_string()]) {
+            self.storage.remove(key);
+        }
+        Ok(())
+    }
+
+    pub fn save_sync_state(
+        &self,
+        document_id: DocumentId,
+        storage_id: String,
+        state: Arc<SyncState>,
+    ) -> Result<(), RepoError> {
+        let key = storage::sync_state_key(&document_id, &storage_id);
+        self.storage.save(key, state.encode());
+        Ok(())
+    }
+
+    pub fn load_sync_state(
+        &self,
+        document_id: DocumentId,
+        storage_id: String,
+    ) -> Result<Option<Arc<SyncState>>, RepoError> {
+        let key = storage::sync_state_key(&document_id, &storage_id);
+        let Some(bytes) = self.storage.load(key) else {
+            return Ok(None);
+        };
+        let state = am::sync::State::decode(&bytes).map_err(|e| RepoError::CorruptData {
+            message: e.to_string(),
+        })?;
+        Ok(Some(Arc::new(SyncState::from(state))))
+    }
+
+    /// Starts synchronising every document with `peer_id`.
+    pub fn add_peer(&self, peer_id: String) {
+        self.peers.lock().unwrap().insert(peer_id.clone());
+        for entry in self.entries().iter().chain(&self.requested()) {
+            entry.session.add_peer(peer_id.clone());
+        }
+    }
+
+    pub fn remove_peer(&self, peer_id: String) {
+        self.peers.lock().unwrap().remove(&peer_id);
+        for entry in self.entries().iter().chain(&self.requested()) {
+            entry.session.remove_peer(peer_id.clone());
+        }
+    }
+
+    pub fn peers(&self) -> Vec<String> {
+        self.peers.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Sends any outstanding sync messages for a document to every peer.
+    pub fn sync(&self, document_id: DocumentId) -> Result<(), RepoError> {
+        let entry = self.require(&document_id)?;
+        self.send(&document_id, &entry.session, false);
+        Ok(())
+    }
+
+    /// Sends any outstanding sync messages for every loaded document, and
+    /// asks for every requested document again.
+    pub fn sync_all(&self) {
+        let docs = self.docs.lock().unwrap().clone();
+        for (document_id, entry) in docs {
+            self.send(&document_id, &entry.session, false);
+        }
+        let requests = self.requests.lock().unwrap().clone();
+        for (document_id, request) in requests {
+            self.send(&document_id, &request.entry.session, true);
+        }
+    }
+
+    /// Sends an ephemeral message about a document to every peer.
+    pub fn broadcast(&self, document_id: DocumentId, data: Vec<u8>) -> Result<(), RepoError> {
+        let count = {
+            let mut count = self.ephemeral_count.lock().unwrap();
+            *count += 1;
+            *count
+        };
+        for peer in self.peers() {
+            let message = RepoMessage::Ephemeral {
+                sender_id: self.peer_id.clone(),
+                target_id: peer.clone(),
+                document_id: document_id.clone(),
+                session_id: self.session_id.clone(),
+                count,
+                data: data.clone(),
+            };
+            self.send_to(peer, &message);
+        }
+        Ok(())
+    }
+
+    /// Handles an automerge-repo message from a peer.
+    ///
+    /// A peer which joins is answered and added, and a peer which leaves is
+    /// removed. The sync message in a sync or request message is applied, the
+    /// result saved, and any resulting messages are sent to every peer. A
+    /// request for a document the repo does not have is answered with
+    /// doc-unavailable.
+    ///
+    /// An ephemeral message is returned, unless it has been received before.
+    pub fn receive_message(&self, message: Vec<u8>) -> Result<Option<EphemeralMessage>, RepoError> {
+        let received = match decode_repo_message(message)? {
+            RepoMessage::Join {
+                sender_id,
+                supported_protocol_versions,
+                ..
+            } => {
+                if !supported_protocol_versions
+                    .iter()
+                    .any(|v| v == PROTOCOL_VERSION)
+                {
+                    let message = RepoMessage::Error {
+                        message: format!(
+                            "unsupported protocol versions, expected {}",
+                            PROTOCOL_VERSION
+                        ),
+                    };
+                    self.send_to(sender_id, &message);
+                    return Ok(None);
+                }
+                let message = RepoMessage::Peer {
+                    sender_id: self.peer_id.clone(),
+                    target_id: sender_id.clone(),
+                    peer_metadata: self.peer_metadata.clone(),
+                    selected_protocol_version: PROTOCOL_VERSION.to_string(),
+                };
+                self.send_to(sender_id.clone(), &message);
+                self.connect(sender_id);
+                Ok(())
+            }
+            RepoMessage::Peer {
+                sender_id,
+                target_id,
+                ..
+            } => {
+                self.check_target(&target_id)?;
+                self.connect(sender_id);
+                Ok(())
+            }
+            RepoMessage::Leave { sender_id } => {
+                self.remove_peer(sender_id);
+                Ok(())
+            }
+            RepoMessage::Error { message } => Err(RepoError::PeerError { message }),
+            RepoMessage::Request {
+                sender_id,
+                target_id,
+                document_id,
+                data,
+            } => {
+                self.check_target(&target_id)?;
+                let available = match self.entry(&document_id)? {
+                    Some(entry) => !entry.session.doc().heads().is_empty(),
+                    None => false,
+                };
+                if !available {
+                    let message = RepoMessage::DocUnavailable {
+                        sender_id: self.peer_id.clone(),
+                        target_id: sender_id.clone(),
+                        document_id,
+                    };
+                    self.send_to(sender_id, &message);
+                    return Ok(None);
+                }
+                self.receive_sync(sender_id, document_id, data)
+            }
+            RepoMessage::Sync {
+                sender_id,
+                target_id,
+                document_id,
+                data,
+            } => {
+                self.check_target(&target_id)?;
+                self.receive_sync(sender_id, document_id, data)
+            }
+            RepoMessage::DocUnavailable {
+                sender_id,
+                target_id,
+                document_id,
+            } => {
+                self.check_target(&target_id)?;
+                let request = self.requests.lock().unwrap().get(&document_id).cloned();
+                if let Some(request) = request {
+                    request
+                        .unavailable
+                        .lock()
+                        .unwrap()
+                        .insert(sender_id.clone());
+                    // Start afresh so a later sync asks the peer again
+                    request.entry.session.add_peer(sender_id);
+                }
+                Ok(())
+            }
+            RepoMessage::Ephemeral {
+                sender_id,
+                target_id,
+                document_id,
+                session_id,
+                count,
+                data,
+            } => {
+                self.check_target(&target_id)?;
+                {
+                    let mut seen = self.ephemeral_seen.lock().unwrap();
+                    let key = (sender_id.clone(), session_id.clone());
+                    if seen.get(&key).is_some_and(|seen| *seen >= count) {
+                        return Ok(None);
+                    }
+                    seen.insert(key, count);
+                }
+                let others = self.peers().into_iter().filter(|peer| *peer != sender_id);
+                for peer in others {
+                    let message = RepoMessage::Ephemeral {
+                        sender_id: sender_id.clone(),
+                        target_id: peer.clone(),
+                        document_id: document_id.clone(),
+                        session_id: session_id.clone(),
+                        count,
+                        data: data.clone(),
+                    };
+                    self.send_to(peer, &message);
+                }
+                return Ok(Some(EphemeralMessage {
+                    sender_id,
+                    document_id,
+                    data,
+                }));
+            }
+        };
+        received.map(|()| None)
+    }
+
+    /// Adds a peer which has just connected and sends it everything it needs.
+    fn connect(&self, peer_id: String) {
+        self.add_peer(peer_id);
+        self.sync_all();
+    }
+
+    fn receive_sync(
+        &self,
+        peer_id: String,
+        document_id: DocumentId,
+        message: Vec<u8>,
+    ) -> Result<(), RepoError> {
+        if !self.peers.lock().unwrap().contains(&peer_id) {
+            self.add_peer(peer_id.clone());
+        }
+        let request = self.requests.lock().unwrap().get(&document_id).cloned();
+        let entry = match request {
+            Some(request) => {
+                request.entry.session.receive_message(peer_id, message)?;
+                if request.entry.session.doc().heads().is_empty() {
+                    // The peer has nothing for us yet, so keep asking
+                    self.send(&document_id, &request.entry.session, true);
+                    return Ok(());
+                }
+                self.requests.lock().unwrap().remove(&document_id);
+                self.insert(document_id.clone(), request.entry.clone())
+            }
+            None => {
+                let entry = self.entry_or_create(&document_id)?;
+                entry.session.receive_message(peer_id, message)?;
+                entry
+            }
+        };
+        self.save(document_id.clone())?;
+        self.send(&document_id, &entry.session, false);
+        Ok(())
+    }
+
+    fn check_target(&self, target_id: &str) -> Result<(), RepoError> {
+        if target_id != self.peer_id {
+            return Err(RepoError::InvalidMessage {
+                message: format!("message is for {}", target_id),
+            });
+        }
+        Ok(())
+    }
+
+    /// Sends the outstanding sync messages of a document, as requests if the
+    /// repo does not have the document yet.
+    fn send(&self, document_id: &DocumentId, session: &SyncSession, requesting: bool) {
+        let Some(network) = self.network.read().unwrap().clone() else {
+            return;
+        };
+        for message in session.generate_messages() {
+            let (sender_id, target_id, document_id, data) = (
+                self.peer_id.clone(),
+                message.peer.clone(),
+                document_id.clone(),
+                message.message,
+            );
+            let envelope = if requesting {
+                RepoMessage::Request {
+                    sender_id,
+                    target_id,
+                    document_id,
+                    data,
+                }
+            } else {
+                RepoMessage::Sync {
+                    sender_id,
+                    target_id,
+                    document_id,
+                    data,
+                }
+            };
+            network.send(message.peer, encode(&envelope));
+        }
+    }
+
+    fn send_to(&self, peer_id: String, message: &RepoMessage) {
+        if let Some(network) = self.network.read().unwrap().clone() {
+            network.send(peer_id, encode(message));
+        }
+    }
+
+    fn entries(&self) -> Vec<Arc<Entry>> {
+        self.docs.lock().unwrap().values().cloned().collect()
+    }
+
+    fn requested(&self) -> Vec<Arc<Entry>> {
+        let requests = self.requests.lock().unwrap();
+        requests
+            .values()
+            .map(|request| request.entry.clone())
+            .collect()
+    }
+
+    fn require(&self, document_id: &DocumentId) -> Result<Arc<Entry>, RepoError> {
+        self.entry(document_id)?
+            .ok_or_else(|| RepoError::DocumentNotFound {
+                document_id: document_id.clone(),
+            })
+    }
+
+    fn entry_or_create(&self, document_id: &DocumentId) -> Result<Arc<Entry>, RepoError> {
+        match self.entry(document_id)? {
+            Some(entry) => Ok(entry),
+            None => {
+                let entry = self.new_entry(am::AutoCommit::new(), None);
+                Ok(self.insert(document_id.clone(), entry))
+            }
+        }
+    }
+
+    fn entry(&self, document_id: &DocumentId) -> Result<Option<Arc<Entry>>, RepoError> {
+        if let Some(entry) = self.docs.lock().unwrap().get(document_id) {
+            return Ok(Some(entry.clone()));
+        }
+        let Some(mut doc) = storage::load(self.storage.as_ref(), document_id)? else {
+            return Ok(None);
+        };
+        let heads = doc.get_heads();
+        let entry = self.new_entry(doc, Some(heads));
+        Ok(Some(self.insert(document_id.clone(), entry)))
+    }
+
+    fn new_entry(
+        &self,
+        doc: am::AutoCommit,
+        saved_heads: Option<Vec<am::ChangeHash>>,
+    ) -> Arc<Entry> {
+        let session = SyncSession::new(Arc::new(Doc::from(doc)));
+        for peer in self.peers() {
+            session.add_peer(peer);
+        }
+        Arc::new(Entry {
+            session,
+            saved_heads: Mutex::new(saved_heads),
+        })
+    }
+
+    /// Adds a document, returning the existing one if another thread got there first.
+    fn insert(&self, document_id: DocumentId, entry: Arc<Entry>) -> Arc<Entry> {
+        self.docs
+            .lock()
+            .unwrap()
+            .entry(document_id)
+            .or_insert(entry)
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, VecDeque};
+
+    use am::{sync::SyncDoc, transaction::Transactable, ReadDoc};
+
+    use super::*;
+    use crate::{Presence, PresenceValue};
+
+    #[derive(Default)]
+    struct MemoryStorage(Mutex<BTreeMap<Vec<String>, Vec<u8>>>);
+
+    impl StorageAdapter for MemoryStorage {
+        fn load(&self, key: Vec<String>) -> Option<Vec<u8>> {
+            self.0.lock().unwrap().get(&key).cloned()
+        }
+
+        fn save(&self, key: Vec<String>, data: Vec<u8>) {
+            self.0.lock().unwrap().insert(key, data);
+        }
+
+        fn remove(&self, key: Vec<String>) {
+            self.0.lock().unwrap().remove(&key);
+        }
+
+        fn list(&self, prefix: Vec<String>) -> Vec<Vec<String>> {
+            let storage = self.0.lock().unwrap();
+            storage
+                .keys()
+                .filter(|key| key.starts_with(&prefix))
+                .cloned()
+                .collect()
+        }
+    }
+
+    /// Messages in flight, as `(from, to, message)`.
+    type Queue = Arc<Mutex<VecDeque<(String, String, Vec<u8>)>>>;
+
+    struct Link {
+        from: String,
+        queue: Queue,
+    }
+
+    impl NetworkAdapter for Link {
+        fn send(&self, peer_id: String, message: Vec<u8>) {
+            let from = self.from.clone();
+            self.queue
+                .lock()
+                .unwrap()
+                .push_back((from, peer_id, message));
+        }
+    }
+
+    /// An in-process stand-in for an automerge-repo sync server: a repo
+    /// named `server` which every client joins.
+    struct Network {
+        queue: Queue,
+        repos: BTreeMap<String, Arc<Repo>>,
+        /// The ephemeral messages each repo has returned
+        ephemeral: Mutex<Vec<(String, EphemeralMessage)>>,
+    }
+
+    impl Network {
+        fn new(clients: &[&str]) -> Self {
+            let mut network = Self {
+                queue: Queue::default(),
+                repos: BTreeMap::new(),
+                ephemeral: Mutex::new(Vec::new()),
+            };
+            network.add("server");
+            for client in clients {
+                let join = network.add(client).join();
+                network.queue.lock().unwrap().push_back((
+                    client.to_string(),
+                    "server".to_string(),
+                    join,
+                ));
+            }
+            network.run();
+            network
+        }
+
+        fn add(&mut self, peer_id: &str) -> Arc<Repo> {
+            let repo = Arc::new(Repo::new(
+                peer_id.to_string(),
+                PeerMetadata::default(),
+                Box::new(MemoryStorage::default()),
+            ));
+            repo.set_network_adapter(Box::new(Link {
+                from: peer_id.to_string(),
+                queue: self.queue.clone(),
+            }));
+            self.repos.insert(peer_id.to_string(), repo.clone());
+            repo
+        }
+
+        fn repo(&self, peer_id: &str) -> Arc<Repo> {
+            self.repos[peer_id].clone()
+        }
+
+        /// Delivers messages until none are left, returning how many were delivered.
+        fn run(&self) -> usize {
+            let mut delivered = 0;
+            loop {
+                let Some((_, to, message)) = self.queue.lock().unwrap().pop_front() else {
+                    return delivered;
+                };
+                if let Some(message) = self.repos[&to].receive_message(message).unwrap() {
+                    self.ephemeral.lock().unwrap().push((to, message));
+                }
+                delivered += 1;
+            }
+        }
+    }
+
+    fn import(repo: &Repo, key: &str, value: &str) -> DocumentId {
+        let mut doc = am::AutoCommit::new();
+        doc.put(am::ROOT, key, value).unwrap();
+        repo.import(doc.save()).unwrap()
+    }
+
+    fn get(repo: &Repo, document_id: &DocumentId, key: &str) -> Option<String> {
+        let doc = repo.find(document_id.clone()).unwrap()?;
+        let doc = doc.0.read().unwrap();
+        let (value, _) = doc.get(am::ROOT, key).unwrap()?;
+        value.into_scalar().ok()?.into_string().ok()
+    }
+
+    #[test]
+    fn sync_messages_are_sent_in_repo_envelopes() {
+        let network = Network::new(&["alice"]);
+        let alice = network.repo("alice");
+        let id = import(&alice, "key", "value");
+        alice.sync(id.clone()).unwrap();
+
+        let (from, to, message) = network.queue.lock().unwrap().pop_front().unwrap();
+        assert_eq!((from.as_str(), to.as_str()), ("alice", "server"));
+        match decode_repo_message(message).unwrap() {
+            RepoMessage::Sync {
+                sender_id,
+                target_id,
+                document_id,
+                data,
+            } => {
+                assert_eq!(sender_id, "alice");
+                assert_eq!(target_id, "server");
+                assert_eq!(document_id, id);
+                assert!(am::sync::Message::decode(&data).is_ok());
+            }
+            _ => panic!("expected a sync message"),
+        }
+    }
+
+    #[test]
+    fn documents_reach_other_clients_through_the_server() {
+        let network = Network::new(&["alice", "bob"]);
+        let id = import(&network.repo("alice"), "key", "value");
+        network.repo("alice").sync(id.clone()).unwrap();
+        assert!(network.run() > 0);
+
+        assert_eq!(get(&network.repo("bob"), &id, "key").unwrap(), "value");
+        let server = network.repo("server");
+        assert_eq!(server.stored_document_ids(), vec![id.clone()]);
+        assert_eq!(get(&server, &id, "key").unwrap(), "value");
+    }
+
+    #[test]
+    fn importing_changes_without_their_dependencies_lists_the_missing_hashes() {
+        let repo = Repo::new(
+            "alice".to_string(),
+            PeerMetadata::default(),
+            Box::new(MemoryStorage::default()),
+        );
+        let mut doc = am::AutoCommit::new();
+        doc.put(am::ROOT, "a", 1).unwrap();
+        let first = doc.get_heads();
+        doc.put(am::ROOT, "b", 2).unwrap();
+        let second = doc.save_after(&first);
+
+        let Err(RepoError::MissingDependencies { hashes }) = repo.import(second) else {
+            panic!("expected the missing dependencies to be listed");
+        };
+        let hashes = hashes
+            .into_iter()
+            .map(am::ChangeHash::from)
+            .collect::<Vec<_>>();
+        assert_eq!(hashes, first);
+    }
+
+    #[test]
+    fn messages_for_another_peer_are_rejected() {
+        let repo = Repo::new(
+            "alice".to_string(),
+            PeerMetadata::default(),
+            Box::new(MemoryStorage::default()),
+        );
+        let message = encode(&RepoMessage::Sync {
+            sender_id: "bob".to_string(),
+            target_id: "carol".to_string(),
+            document_id: DocumentId::generate(),
+            data: am::AutoCommit::new()
+                .sync()
+                .generate_sync_message(&mut am::sync::State::new())
+                .unwrap()
+                .encode(),
+        });
+        assert!(matches!(
+            repo.receive_message(message),
+            Err(RepoError::InvalidMessage { .. })
+        ));
+    }
+
+    #[test]
+    fn joining_adds_both_peers() {
+        let network = Network::new(&["alice", "bob"]);
+        assert_eq!(network.repo("server").peers(), vec!["alice", "bob"]);
+        assert_eq!(network.repo("alice").peers(), vec!["server"]);
+    }
+
+    #[test]
+    fn a_join_with_an_unsupported_version_is_answered_with_an_error() {
+        let mut network = Network::new(&[]);
+        network.add("alice");
+        let join = encode(&RepoMessage::Join {
+            sender_id: "alice".to_string(),
+            peer_metadata: PeerMetadata::default(),
+            supported_protocol_versions: vec!["2".to_string()],
+        });
+        network.repo("server").receive_message(join).unwrap();
+        assert!(network.repo("server").peers().is_empty());
+
+        let (_, to, message) = network.queue.lock().unwrap().pop_front().unwrap();
+        assert_eq!(to, "alice");
+        assert!(matches!(
+            network.repo("alice").receive_message(message),
+            Err(RepoError::PeerError { .. })
+        ));
+    }
+
+    #[test]
+    fn a_requested_document_is_only_added_once_a_peer_sends_it() {
+        let mut network = Network::new(&["alice"]);
+        let id = import(&network.repo("alice"), "key", "value");
+        network.repo("alice").sync(id.clone()).unwrap();
+        network.run();
+
+        let bob = network.add("bob");
+        bob.request(id.clone()).unwrap();
+        assert_eq!(
+            bob.phase(id.clone()).unwrap(),
+            Some(DocumentSyncPhase::Requesting)
+        );
+        assert!(bob.find(id.clone()).unwrap().is_none());
+        assert!(bob.document_ids().is_empty());
+
+        let join = bob.join();
+        let server = "server".to_string();
+        let message = ("bob".to_string(), server, join);
+        network.queue.lock().unwrap().push_back(message);
+        network.run();
+        assert_eq!(
+            bob.phase(id.clone()).unwrap(),
+            Some(DocumentSyncPhase::Syncing)
+        );
+        assert_eq!(get(&bob, &id, "key").unwrap(), "value");
+        assert_eq!(bob.stored_document_ids(), vec![id]);
+    }
+
+    #[test]
+    fn a_document_no_peer_has_is_unavailable() {
+        let network = Network::new(&["alice"]);
+        let alice = network.repo("alice");
+        let id = DocumentId::generate();
+        alice.request(id.clone()).unwrap();
+        network.run();
+
+        assert_eq!(
+            alice.phase(id.clone()).unwrap(),
+            Some(DocumentSyncPhase::Unavailable)
+        );
+        assert!(alice.find(id.clone()).unwrap().is_none());
+        assert!(network.repo("server").find(id).unwrap().is_none());
+    }
+
+    #[test]
+    fn a_peer_which_leaves_is_removed() {
+        let network = Network::new(&["alice"]);
+        let leave = encode(&RepoMessage::Leave {
+            sender_id: "alice".to_string(),
+        });
+        network.repo("server").receive_message(leave).unwrap();
+        assert!(network.repo("server").peers().is_empty());
+    }
+
+    #[test]
+    fn ephemeral_messages_reach_every_other_client_once() {
+        let network = Network::new(&["alice", "bob"]);
+        let id = DocumentId::generate();
+        network
+            .repo("alice")
+            .broadcast(id.clone(), vec![1, 2, 3])
+            .unwrap();
+        let (_, _, sent) = network.queue.lock().unwrap().front().cloned().unwrap();
+        network.run();
+
+        let received = network.ephemeral.lock().unwrap();
+        let receivers = received
+            .iter()
+            .map(|(to, _)| to.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(receivers, vec!["server", "bob"]);
+        for (_, message) in received.iter() {
+            assert_eq!(message.sender_id, "alice");
+            assert_eq!(message.document_id, id);
+            assert_eq!(message.data, vec![1, 2, 3]);
+        }
+        assert!(network
+            .repo("server")
+            .receive_message(sent)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn stale_and_repeated_ephemeral_counts_are_dropped() {
+        let network = Network::new(&["alice"]);
+        let server = network.repo("server");
+        let id = DocumentId::generate();
+        let passed = [5, 3, 5]
+            .into_iter()
+            .map(|count| {
+                let message = encode(&RepoMessage::Ephemeral {
+                    sender_id: "alice".to_string(),
+                    target_id: "server".to_string(),
+                    document_id: id.clone(),
+                    session_id: "session".to_string(),
+                    count,
+                    data: vec![],
+                });
+                server.receive_message(message).unwrap().is_some()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(passed, vec![true, false, false]);
+    }
+
+    #[test]
+    fn a_broadcast_sends_one_count_to_every_peer() {
+        let network = Network::new(&["alice", "bob"]);
+        let server = network.repo("server");
+        server.broadcast(DocumentId::generate(), vec![]).unwrap();
+        let counts = network
+            .queue
+            .lock()
+            .unwrap()
+            .iter()
+            .map(
+                |(_, _, message)| match decode_repo_message(message.clone()) {
+                    Ok(RepoMessage::Ephemeral { count, .. }) => count,
+                    _ => panic!("expected an ephemeral message"),
+                },
+            )
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![1, 1]);
+    }
+
+    #[test]
+    fn presence_updates_travel_as_ephemeral_messages() {
+        let network = Network::new(&["alice", "bob"]);
+        let id = DocumentId::generate();
+        let alice = Presence::new((&am::ActorId::random()).into(), 1000);
+        let bob = Presence::new((&am::ActorId::random()).into(), 1000);
+        let payload = HashMap::from([(
+            "name".to_string(),
+            PresenceValue::Scalar {
+                value: crate::ScalarValue::String {
+                    value: "Alice".to_string(),
+                },
+            },
+        )]);
+        let update = alice.encode_update(payload, 100).unwrap();
+        network.repo("alice").broadcast(id, update).unwrap();
+        network.run();
+
+        for (to, message) in network.ephemeral.lock().unwrap().drain(..) {
+            if to == "bob" {
+                bob.receive(message.data).unwrap();
+            }
+        }
+        assert_eq!(bob.states(100).len(), 1);
+    }
+}
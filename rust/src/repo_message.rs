@@ -0,0 +1,262 @@
+//! The automerge-repo wire protocol.
+//!
+//! Each message is a CBOR map with a `type` key. Sync and request messages
+//! carry an encoded sync message in `data`, ephemeral messages an arbitrary
+//! CBOR payload. Keys are written in the order automerge-repo writes them
+//! and may be read in any order.
+use crate::cbor::{self, Cbor};
+use crate::document_id::DocumentId;
+use crate::RepoError;
+
+pub(crate) const PROTOCOL_VERSION: &str = "1";
+
+#[derive(Debug, Clone, Default)]
+pub struct PeerMetadata {
+    pub storage_id: Option<String>,
+    pub is_ephemeral: bool,
+}
+
+pub enum RepoMessage {
+    Join {
+        sender_id: String,
+        peer_metadata: PeerMetadata,
+        supported_protocol_versions: Vec<String>,
+    },
+    Peer {
+        sender_id: String,
+        target_id: String,
+        peer_metadata: PeerMetadata,
+        selected_protocol_version: String,
+    },
+    Leave {
+        sender_id: String,
+    },
+    Error {
+        message: String,
+    },
+    Request {
+        sender_id: String,
+        target_id: String,
+        document_id: DocumentId,
+        data: Vec<u8>,
+    },
+    Sync {
+        sender_id: String,
+        target_id: String,
+        document_id: DocumentId,
+        data: Vec<u8>,
+    },
+    DocUnavailable {
+        sender_id: String,
+        target_id: String,
+        document_id: DocumentId,
+    },
+    Ephemeral {
+        sender_id: String,
+        target_id: String,
+        document_id: DocumentId,
+        session_id: String,
+        count: u64,
+        data: Vec<u8>,
+    },
+}
+
+/// Encodes `message`. This only fails if a document id in the message cannot
+/// be lifted from the FFI.
+pub fn encode_repo_message(message: RepoMessage) -> Result<Vec<u8>, RepoError> {
+    Ok(encode(&message))
+}
+
+pub(crate) fn encode(message: &RepoMessage) -> Vec<u8> {
+    let text = |s: &str| Cbor::Text(s.to_string());
+    let id = |id: &DocumentId| Cbor::Text(id.to_string());
+    let entries = match message {
+        RepoMessage::Join {
+            sender_id,
+            peer_metadata,
+            supported_protocol_versions,
+        } => vec![
+            ("type", text("join")),
+            ("senderId", text(sender_id)),
+            ("peerMetadata", encode_metadata(peer_metadata)),
+            (
+                "supportedProtocolVersions",
+                Cbor::Array(
+                    supported_protocol_versions
+                        .iter()
+                        .map(|v| text(v))
+                        .collect(),
+                ),
+            ),
+        ],
+        RepoMessage::Peer {
+            sender_id,
+            target_id,
+            peer_metadata,
+            selected_protocol_version,
+        } => vec![
+            ("type", text("peer")),
+            ("senderId", text(sender_id)),
+            ("peerMetadata", encode_metadata(peer_metadata)),
+            ("selectedProtocolVersion", text(selected_protocol_version)),
+            ("targetId", text(target_id)),
+        ],
+        RepoMessage::Leave { sender_id } => {
+            vec![("type", text("leave")), ("senderId", text(sender_id))]
+        }
+        RepoMessage::Error { message } => vec![("type", text("error")), ("message", text(message))],
+        RepoMessage::Request {
+            sender_id,
+            target_id,
+            document_id,
+            data,
+        }
+        | RepoMessage::Sync {
+            sender_id,
+            target_id,
+            document_id,
+            data,
+        } => vec![
+            (
+                "type",
+                text(match message {
+                    RepoMessage::Request { .. } => "request",
+                    _ => "sync",
+                }),
+            ),
+            ("targetId", text(target_id)),
+            ("documentId", id(document_id)),
+            ("data", Cbor::Bytes(data.clone())),
+            ("senderId", text(sender_id)),
+        ],
+        RepoMessage::DocUnavailable {
+            sender_id,
+            target_id,
+            document_id,
+        } => vec![
+            ("type", text("doc-unavailable")),
+            ("documentId", id(document_id)),
+            ("targetId", text(target_id)),
+            ("senderId", text(sender_id)),
+        ],
+        RepoMessage::Ephemeral {
+            sender_id,
+            target_id,
+            document_id,
+            session_id,
+            count,
+            data,
+        } => vec![
+            ("type", text("ephemeral")),
+            ("documentId", id(document_id)),
+            ("data", Cbor::Bytes(data.clone())),
+            ("targetId", text(target_id)),
+            ("count", Cbor::Uint(*count)),
+            ("sessionId", text(session_id)),
+            ("senderId", text(sender_id)),
+        ],
+    };
+    cbor::map(entries).encode()
+}
+
+pub fn decode_repo_message(bytes: Vec<u8>) -> Result<RepoMessage, RepoError> {
+    let value = Cbor::decode(&bytes).map_err(|e| RepoError::InvalidMessage {
+        message: e.to_string(),
+    })?;
+    let invalid = |message: String| RepoError::InvalidMessage { message };
+    let text = |key: &str| {
+        value
+            .get(key)
+            .and_then(Cbor::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| invalid(format!("missing string {}", key)))
+    };
+    let bytes = |key: &str| {
+        value
+            .get(key)
+            .and_then(Cbor::as_bytes)
+            .map(<[u8]>::to_vec)
+            .ok_or_else(|| invalid(format!("missing bytes {}", key)))
+    };
+    let document_id = || DocumentId::parse(&text("documentId")?);
+    let message = match text("type")?.as_str() {
+        "join" => RepoMessage::Join {
+            sender_id: text("senderId")?,
+            peer_metadata: decode_metadata(value.get("peerMetadata")),
+            supported_protocol_versions: value
+                .get("supportedProtocolVersions")
+                .and_then(Cbor::as_array)
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect(),
+        },
+        "peer" => RepoMessage::Peer {
+            sender_id: text("senderId")?,
+            target_id: text("targetId")?,
+            peer_metadata: decode_metadata(value.get("peerMetadata")),
+            selected_protocol_version: text("selectedProtocolVersion")?,
+        },
+        "leave" => RepoMessage::Leave {
+            sender_id: text("senderId")?,
+        },
+        "error" => RepoMessage::Error {
+            message: text("message")?,
+        },
+        "request" => RepoMessage::Request {
+            sender_id: text("senderId")?,
+            target_id: text("targetId")?,
+            document_id: document_id()?,
+            data: bytes("data")?,
+        },
+        "sync" => RepoMessage::Sync {
+            sender_id: text("senderId")?,
+            target_id: text("targetId")?,
+            document_id: document_id()?,
+            data: bytes("data")?,
+        },
+        "doc-unavailable" => RepoMessage::DocUnavailable {
+            sender_id: text("senderId")?,
+            target_id: text("targetId")?,
+            document_id: document_id()?,
+        },
+        "ephemeral" => RepoMessage::Ephemeral {
+            sender_id: text("senderId")?,
+            target_id: text("targetId")?,
+            document_id: document_id()?,
+            session_id: text("sessionId")?,
+            count: value
+                .get("count")
+                .and_then(Cbor::as_u64)
+                .ok_or_else(|| invalid("missing integer count".to_string()))?,
+            data: bytes("data")?,
+        },
+        other => return Err(invalid(format!("unknown message type {}", other))),
+    };
+    Ok(message)
+}
+
+fn encode_metadata(metadata: &PeerMetadata) -> Cbor {
+    let mut entries = Vec::new();
+    if let Some(storage_id) = &metadata.storage_id {
+        entries.push(("storageId", Cbor::Text(storage_id.clone())));
+    }
+    entries.push(("isEphemeral", Cbor::Bool(metadata.is_ephemeral)));
+    cbor::map(entries)
+}
+
+fn decode_metadata(value: Option<&Cbor>) -> PeerMetadata {
+    let Some(value) = value else {
+        return PeerMetadata::default();
+    };
+    PeerMetadata {
+        storage_id: value
+            .get("storageId")
+            .and_then(Cbor::as_str)
+            .map(str::to_string),
+        is_ephemeral: value
+            .get("isEphemeral")
+            .and_then(Cbor::as_bool)
+            .unwrap_or(false),
+    }
+}
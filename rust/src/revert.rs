@@ -0,0 +1,680 @@
+//! Makes the current state of a document look like another state by applying
+//! the difference between the two as new edits.
+//!
+//! The difference is computed with [`am::AutoCommit::diff`], whose patches
+//! use indices into the state they start from. If the document has changed
+//! since that state, sequence elements are tracked with cursors so that each
+//! edit lands on the element it was computed for, and elements which have
+//! since been deleted or overwritten by someone else are left alone.
+use std::collections::HashMap;
+
+use automerge::{self as am, transaction::Transactable, ReadDoc};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::text_encoding::width;
+use crate::DocError;
+
+/// Edits `doc` so that everything which differs between `from` and `to`
+/// looks as it did at `to`, returning whether anything was edited.
+///
+/// With `scope` only `scope` and the objects inside it are edited. Objects
+/// and sequence elements which existed at `to` but not at `from` are
+/// recreated, and added to `restored`. Edits to objects already in
+/// `restored` are redirected to the object which replaced them, using the
+/// indices they were computed for, and elements which were deleted are
+/// looked up in `restored` before being skipped.
+///
+/// Map properties which have been changed since `from` by someone else are
+/// left alone. Marks which are put back expand as `expand` says.
+pub(crate) fn revert(
+    doc: &mut am::AutoCommit,
+    from: &[am::ChangeHash],
+    to: &[am::ChangeHash],
+    scope: Option<&am::ObjId>,
+    expand: am::marks::ExpandMark,
+    restored: &mut Restored,
+) -> Result<bool, DocError> {
+    // Branches are moved explicitly, never by reverting
+    let mut patches = crate::branch::diff(doc, from, to);
+    if let Some(scope) = scope {
+        patches.retain(|patch| crate::diff::is_within(patch, scope, true));
+    }
+    if patches.is_empty() {
+        return Ok(false);
+    }
+    let rebase = (doc.get_heads() != from).then(|| from.to_vec());
+    let mut reverter = Reverter {
+        doc,
+        rebase,
+        to,
+        expand,
+        restored,
+        sequences: HashMap::new(),
+    };
+    for patch in patches {
+        reverter.apply(patch)?;
+    }
+    Ok(true)
+}
+
+/// What earlier reverts recreated, so that later ones can find it.
+#[derive(Default)]
+pub(crate) struct Restored {
+    /// The recreated objects, by the id of the object they replace
+    objects: HashMap<am::ObjId, am::ObjId>,
+    /// The reinserted sequence elements, by the cursor of the element they
+    /// replace
+    elements: HashMap<String, am::Cursor>,
+}
+
+/// A position in a sequence as of the state the patches start from.
+#[derive(Clone)]
+enum Anchor {
+    /// An index into the sequence at that state
+    Index(usize),
+    /// An element written by an earlier patch
+    Cursor(am::Cursor),
+}
+
+struct Reverter<'a> {
+    doc: &'a mut am::AutoCommit,
+    /// The heads the patches start from, if the document has changed since
+    rebase: Option<Vec<am::ChangeHash>>,
+    /// The heads the patches end at
+    to: &'a [am::ChangeHash],
+    /// How the marks which are put back expand
+    expand: am::marks::ExpandMark,
+    restored: &'a mut Restored,
+    /// The anchors of every element of the sequences edited so far, with
+    /// one entry per unit of the text encoding for text
+    sequences: HashMap<am::ObjId, Vec<Anchor>>,
+}
+
+impl Reverter<'_> {
+    fn apply(&mut self, patch: am::Patch) -> Result<(), DocError> {
+        let am::Patch {
+            obj: original,
+            action,
+            ..
+        } = patch;
+        let mut target = original.clone();
+        let mut anchored = self.rebase.is_some();
+        while let Some(id) = self.restored.objects.get(&target) {
+            target = id.clone();
+            anchored = false;
+        }
+        let obj = &target;
+        match action {
+            am::PatchAction::PutMap { key, value, .. } => {
+                if !anchored || self.unchanged(obj, &key)? {
+                    self.put(obj, am::Prop::Map(key), value)?;
+                }
+            }
+            am::PatchAction::DeleteMap { key } => {
+                if anchored && !self.unchanged(obj, &key)? {
+                    return Ok(());
+                }
+                self.doc
+                    .delete(obj, key)
+                    .map_err(|e| DocError::in_object(self.doc, obj, e))?;
+            }
+            am::PatchAction::Increment { prop, value } => {
+                let prop = match prop {
+                    am::Prop::Seq(index) if anchored => match self.element(obj, index)? {
+                        Some(index) => am::Prop::Seq(index),
+                        None => return Ok(()),
+                    },
+                    am::Prop::Map(key) if anchored && !self.unchanged(obj, &key)? => {
+                        return Ok(());
+                    }
+                    prop => prop,
+                };
+                self.doc
+                    .increment(obj, prop, value)
+                    .map_err(|e| DocError::in_object(self.doc, obj, e))?;
+            }
+            am::PatchAction::PutSeq { index, value, .. } => {
+                let position = if anchored {
+                    match self.element(obj, index)? {
+                        Some(position) => position,
+                        None => return Ok(()),
+                    }
+                } else {
+                    index
+                };
+                self.put(obj, am::Prop::Seq(position), value)?;
+                if anchored {
+                    let cursor = self.cursor(obj, position)?;
+                    self.anchors(obj)?[index] = cursor;
+                }
+            }
+            am::PatchAction::Insert { index, values } => {
+                let position = self.insertion_point(obj, index, anchored)?;
+                for (offset, (value, id, _)) in values.iter().enumerate() {
+                    self.insert(obj, position + offset, value.clone(), id.clone())?;
+                }
+                let offsets = (0..values.len()).collect::<Vec<_>>();
+                self.remember(&original, index, obj, position, &offsets)?;
+                if anchored {
+                    let cursors = (0..values.len())
+                        .map(|offset| self.cursor(obj, position + offset))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    self.anchors(obj)?.splice(index..index, cursors);
+                }
+            }
+            am::PatchAction::SpliceText {
+                index,
+                value,
+                marks,
+            } => {
+                let text = value.make_string();
+                let position = self.insertion_point(obj, index, anchored)?;
+                self.doc
+                    .splice_text(obj, position, 0, &text)
+                    .map_err(|e| DocError::in_object(self.doc, obj, e))?;
+                let end = position + width(self.doc.text_encoding(), &text);
+                self.set_marks(obj, position, end, marks)?;
+                let offsets = self.character_offsets(&text);
+                self.remember(&original, index, obj, position, &offsets)?;
+                if anchored {
+                    let cursors = self.text_cursors(obj, position, &text)?;
+                    self.anchors(obj)?.splice(index..index, cursors);
+                }
+            }
+            am::PatchAction::DeleteSeq { index, length } => {
+                if anchored {
+                    let removed = self
+                        .anchors(obj)?
+                        .drain(index..index + length)
+                        .collect::<Vec<_>>();
+                    for anchor in removed {
+                        if let Some(position) = self.resolve(obj, &anchor)?.filter(|p| p.1) {
+                            self.doc
+                                .delete(obj, position.0)
+                                .map_err(|e| DocError::in_object(self.doc, obj, e))?;
+                        }
+                    }
+                } else if self.doc.object_type(obj)? == am::ObjType::Text {
+                    self.doc
+                        .splice_text(obj, index, length as isize, "")
+                        .map_err(|e| DocError::in_object(self.doc, obj, e))?;
+                } else {
+                    self.doc
+                        .splice(obj, index, length as isize, [])
+                        .map_err(|e| DocError::in_object(self.doc, obj, e))?;
+                }
+            }
+            am::PatchAction::Mark { marks } => {
+                for mark in marks {
+                    let (start, end) = if anchored {
+                        let start = self.insertion_point(obj, mark.start, true)?;
+                        let end = self.insertion_point(obj, mark.end, true)?;
+                        (start, end)
+                    } else {
+                        (mark.start, mark.end)
+                    };
+                    if start >= end {
+                        continue;
+                    }
+                    let result = match mark.value() {
+                        am::ScalarValue::Null => {
+                            self.doc.unmark(obj, mark.name(), start, end, self.expand)
+                        }
+                        value => {
+                            let mark = am::marks::Mark::new(
+                                mark.name().to_string(),
+                                value.clone(),
+                                start,
+                                end,
+                            );
+                            self.doc.mark(obj, mark, self.expand)
+                        }
+                    };
+                    result.map_err(|e| DocError::in_object(self.doc, obj, e))?;
+                }
+            }
+            // The value which wins is put by its own patch
+            am::PatchAction::Conflict { .. } => {}
+        }
+        Ok(())
+    }
+
+    /// Makes the marks on the text inserted between `start` and `end` match
+    /// `marks`, removing any it picked up from expanding neighbours.
+    fn set_marks(
+        &mut self,
+        obj: &am::ObjId,
+        start: usize,
+        end: usize,
+        marks: Option<am::marks::MarkSet>,
+    ) -> Result<(), DocError> {
+        if start == end {
+            return Ok(());
+        }
+        let current = self
+            .doc
+            .get_marks(obj, start, None)
+            .map_err(|e| DocError::in_object(self.doc, obj, e))?;
+        let current = current
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect::<HashMap<_, _>>();
+        let wanted = marks
+            .iter()
+            .flat_map(|marks| marks.iter())
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect::<HashMap<_, _>>();
+        for name in current.keys() {
+            if !wanted.contains_key(name) {
+                self.doc
+                    .unmark(obj, name, start, end, self.expand)
+                    .map_err(|e| DocError::in_object(self.doc, obj, e))?;
+            }
+        }
+        for (name, value) in wanted {
+            if current.get(&name) != Some(&value) {
+                let mark = am::marks::Mark::new(name, value, start, end);
+                self.doc
+                    .mark(obj, mark, self.expand)
+                    .map_err(|e| DocError::in_object(self.doc, obj, e))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn put(
+        &mut self,
+        obj: &am::ObjId,
+        prop: am::Prop,
+        (value, id): (am::Value<'static>, am::ObjId),
+    ) -> Result<(), DocError> {
+        match value {
+            am::Value::Object(typ) => {
+                let new = self
+                    .doc
+                    .put_object(obj, prop, typ)
+                    .map_err(|e| DocError::in_object(self.doc, obj, e))?;
+                self.restored.objects.insert(id, new);
+            }
+            am::Value::Scalar(s) => {
+                self.doc
+                    .put(obj, prop, s.into_owned())
+                    .map_err(|e| DocError::in_object(self.doc, obj, e))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn insert(
+        &mut self,
+        obj: &am::ObjId,
+        index: usize,
+        value: am::Value<'static>,
+        id: am::ObjId,
+    ) -> Result<(), DocError> {
+        match value {
+            am::Value::Object(typ) => {
+                let new = self
+                    .doc
+                    .insert_object(obj, index, typ)
+                    .map_err(|e| DocError::in_object(self.doc, obj, e))?;
+                self.restored.objects.insert(id, new);
+            }
+            am::Value::Scalar(s) => {
+                self.doc
+                    .insert(obj, index, s.into_owned())
+                    .map_err(|e| DocError::in_object(self.doc, obj, e))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn anchors(&mut self, obj: &am::ObjId) -> Result<&mut Vec<Anchor>, DocError> {
+        if !self.sequences.contains_key(obj) {
+            let from = self.rebase.as_deref().unwrap_or_default();
+            let length = self.doc.length_at(obj, from);
+            self.sequences
+                .insert(obj.clone(), (0..length).map(Anchor::Index).collect());
+        }
+        Ok(self.sequences.get_mut(obj).unwrap())
+    }
+
+    /// The current position of `anchor`, and whether its element is still
+    /// there. If it is not, the position is that of the next element.
+    fn resolve(&self, obj: &am::ObjId, anchor: &Anchor) -> Result<Option<(usize, bool)>, DocError> {
+        let cursor = match anchor {
+            Anchor::Index(index) => {
+                let from = self.rebase.as_deref();
+                match self.doc.get_cursor(obj, *index, from) {
+                    Ok(cursor) => cursor,
+                    Err(am::AutomergeError::InvalidIndex(_)) => return Ok(None),
+                    Err(e) => return Err(DocError::in_object(self.doc, obj, e)),
+                }
+            }
+            Anchor::Cursor(cursor) => cursor.clone(),
+        };
+        let mut cursor = cursor;
+        loop {
+            let position = match self.doc.get_cursor_position(obj, &cursor, None) {
+                Ok(position) => position,
+                Err(am::AutomergeError::InvalidCursor(_)) => return Ok(None),
+                Err(e) => return Err(DocError::in_object(self.doc, obj, e)),
+            };
+            // An element overwritten by someone else gets a cursor for the
+            // new value, so it counts as gone
+            let present = self.doc.get_cursor(obj, position, None).ok().as_ref() == Some(&cursor);
+            match self.restored.elements.get(&cursor.to_string()) {
+                Some(reinserted) if !present => cursor = reinserted.clone(),
+                _ => return Ok(Some((position, present))),
+            }
+        }
+    }
+
+    /// Whether the value of `key` in the map `obj` is the one it had at the
+    /// state the patches start from, or a copy of it made by a revert.
+    fn unchanged(&self, obj: &am::ObjId, key: &str) -> Result<bool, DocError> {
+        let from = self.rebase.as_deref().unwrap_or_default();
+        let current = self
+            .doc
+            .get(obj, key)
+            .map_err(|e| DocError::in_object(self.doc, obj, e))?;
+        let before = self
+            .doc
+            .get_at(obj, key, from)
+            .map_err(|e| DocError::in_object(self.doc, obj, e))?;
+        Ok(match (current, before) {
+            (None, None) => true,
+            (Some((am::Value::Scalar(now), _)), Some((am::Value::Scalar(then), _))) => now == then,
+            (Some((am::Value::Object(_), now)), Some((am::Value::Object(_), then))) => {
+                let mut id = then;
+                while id != now {
+                    match self.restored.objects.get(&id) {
+                        Some(next) => id = next.clone(),
+                        None => return Ok(false),
+                    }
+                }
+                true
+            }
+            _ => false,
+        })
+    }
+
+    /// Records the elements just inserted at `position` of `obj` as
+    /// replacing the elements at `index` of `original` as of the heads the
+    /// patches end at, one per offset.
+    fn remember(
+        &mut self,
+        original: &am::ObjId,
+        index: usize,
+        obj: &am::ObjId,
+        position: usize,
+        offsets: &[usize],
+    ) -> Result<(), DocError> {
+        for offset in offsets {
+            let Ok(old) = self.doc.get_cursor(original, index + offset, Some(self.to)) else {
+                continue;
+            };
+            let new = self
+                .doc
+                .get_cursor(obj, position + offset, None)
+                .map_err(|e| DocError::in_object(self.doc, obj, e))?;
+            self.restored.elements.insert(old.to_string(), new);
+        }
+        Ok(())
+    }
+
+    /// The offset of each character of `text`, in the document's encoding.
+    fn character_offsets(&self, text: &str) -> Vec<usize> {
+        let encoding = self.doc.text_encoding();
+        let mut offset = 0;
+        characters(encoding, text)
+            .map(|character| {
+                let start = offset;
+                offset += width(encoding, character);
+                start
+            })
+            .collect()
+    }
+
+    /// The current index of the element at `index`, if it is still there.
+    fn element(&mut self, obj: &am::ObjId, index: usize) -> Result<Option<usize>, DocError> {
+        let Some(anchor) = self.anchors(obj)?.get(index).cloned() else {
+            return Ok(None);
+        };
+        Ok(self
+            .resolve(obj, &anchor)?
+            .filter(|(_, present)| *present)
+            .map(|(position, _)| position))
+    }
+
+    /// The current position at which to insert before the element at
+    /// `index`, or at the end if `index` is the length of the sequence.
+    fn insertion_point(
+        &mut self,
+        obj: &am::ObjId,
+        index: usize,
+        anchored: bool,
+    ) -> Result<usize, DocError> {
+        if !anchored {
+            return Ok(index);
+        }
+        let next = self.anchors(obj)?.get(index).cloned();
+        let resolved = match next {
+            Some(anchor) => self.resolve(obj, &anchor)?,
+            None => None,
+        };
+        Ok(match resolved {
+            Some((position, _)) => position,
+            None => self.doc.length(obj),
+        })
+    }
+
+    fn cursor(&self, obj: &am::ObjId, position: usize) -> Result<Anchor, DocError> {
+        self.doc
+            .get_cursor(obj, position, None)
+            .map(Anchor::Cursor)
+            .map_err(|e| DocError::in_object(self.doc, obj, e))
+    }
+
+    /// The anchors of `text` inserted at `position`, one per unit.
+    fn text_cursors(
+        &self,
+        obj: &am::ObjId,
+        position: usize,
+        text: &str,
+    ) -> Result<Vec<Anchor>, DocError> {
+        let encoding = self.doc.text_encoding();
+        let mut anchors = Vec::new();
+        let mut offset = position;
+        for character in characters(encoding, text) {
+            let units = width(encoding, character);
+            let cursor = self.cursor(obj, offset)?;
+            anchors.extend(std::iter::repeat_n(cursor, units));
+            offset += units;
+        }
+        Ok(anchors)
+    }
+}
+
+/// The characters of `text` as `encoding` counts them.
+fn characters(encoding: am::TextEncoding, text: &str) -> Box<dyn Iterator<Item = &str> + '_> {
+    match encoding {
+        am::TextEncoding::GraphemeCluster => Box::new(text.graphemes(true)),
+        _ => Box::new(text.char_indices().map(|(i, c)| &text[i..i + c.len_utf8()])),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use automerge as am;
+    use serde_json::{json, Value as Json};
+
+    use crate::{root, ChangeHash, Doc, DocError, ExpandMark, ObjId, ObjType, ScalarValue, Value};
+
+    fn int(value: i64) -> ScalarValue {
+        ScalarValue::Int { value }
+    }
+
+    fn state(doc: &Doc) -> Json {
+        serde_json::from_str(&doc.to_json(root(), None).unwrap()).unwrap()
+    }
+
+    fn object(doc: &Doc, key: &str) -> ObjId {
+        match doc.get_in_map(root(), key.to_string()).unwrap() {
+            Some(Value::Object { id, .. }) => id,
+            _ => panic!("expected an object at {}", key),
+        }
+    }
+
+    fn hashes(heads: Vec<ChangeHash>) -> Vec<am::ChangeHash> {
+        heads.into_iter().map(am::ChangeHash::from).collect()
+    }
+
+    fn example() -> Arc<Doc> {
+        let doc = Arc::new(Doc::new());
+        doc.put_in_map(root(), "a".to_string(), int(1)).unwrap();
+        let list = doc
+            .put_object_in_map(root(), "list".to_string(), ObjType::List)
+            .unwrap();
+        doc.insert_in_list(list.clone(), 0, int(1)).unwrap();
+        doc.insert_in_list(list, 1, int(2)).unwrap();
+        let text = doc
+            .put_object_in_map(root(), "text".to_string(), ObjType::Text)
+            .unwrap();
+        doc.splice_text(text, 0, 0, "hello".to_string()).unwrap();
+        let contact = doc
+            .put_object_in_map(root(), "contact".to_string(), ObjType::Map)
+            .unwrap();
+        doc.put_in_map(contact, "name".to_string(), int(3)).unwrap();
+        doc
+    }
+
+    #[test]
+    fn reverting_writes_one_change() {
+        let doc = example();
+        let heads = doc.heads();
+        let before = state(&doc);
+
+        doc.put_in_map(root(), "a".to_string(), int(2)).unwrap();
+        doc.put_in_map(root(), "b".to_string(), int(3)).unwrap();
+        doc.delete_in_map(root(), "contact".to_string()).unwrap();
+        let list = object(&doc, "list");
+        doc.delete_in_list(list.clone(), 0).unwrap();
+        doc.insert_in_list(list, 1, int(4)).unwrap();
+        let text = object(&doc, "text");
+        doc.splice_text(text.clone(), 0, 1, "J".to_string())
+            .unwrap();
+        doc.mark(
+            text,
+            0,
+            2,
+            ExpandMark::None,
+            "bold".to_string(),
+            ScalarValue::Boolean { value: true },
+        )
+        .unwrap();
+        let edited = doc.heads();
+        let changes = doc.changes().len();
+
+        doc.revert_to(heads, None, ExpandMark::After).unwrap();
+        assert_eq!(state(&doc), before);
+        assert_eq!(doc.changes().len(), changes + 1);
+        let reverted = doc.heads();
+        assert_eq!(reverted.len(), 1);
+        let change = doc.change_by_hash(reverted[0].clone()).unwrap().unwrap();
+        assert_eq!(hashes(change.deps), hashes(edited));
+        let text = object(&doc, "text");
+        assert!(doc.marks(text).unwrap().is_empty());
+    }
+
+    #[test]
+    fn marks_are_put_back_with_the_given_expand() {
+        for (expand, end) in [(ExpandMark::None, 5), (ExpandMark::After, 6)] {
+            let doc = example();
+            let text = object(&doc, "text");
+            let bold = |value| {
+                let name = "bold".to_string();
+                doc.mark(text.clone(), 0, 5, ExpandMark::None, name, value)
+                    .unwrap();
+            };
+            bold(ScalarValue::Boolean { value: true });
+            let heads = doc.heads();
+            bold(ScalarValue::Null);
+
+            doc.revert_to(heads, None, expand).unwrap();
+            doc.splice_text(text.clone(), 5, 0, "!".to_string())
+                .unwrap();
+            let marks = doc.marks(text.clone()).unwrap();
+            assert_eq!(marks.len(), 1);
+            assert_eq!(marks[0].end, end);
+        }
+    }
+
+    #[test]
+    fn later_edits_are_a_separate_change() {
+        let doc = example();
+        let heads = doc.heads();
+        doc.put_in_map(root(), "a".to_string(), int(2)).unwrap();
+        doc.commit_with(None, 0);
+        let changes = doc.changes().len();
+
+        doc.revert_to(heads, None, ExpandMark::After).unwrap();
+        doc.put_in_map(root(), "b".to_string(), int(3)).unwrap();
+        doc.commit_with(None, 0);
+        assert_eq!(doc.changes().len(), changes + 2);
+    }
+
+    #[test]
+    fn reverting_to_the_current_state_writes_nothing() {
+        let doc = example();
+        let heads = doc.heads();
+        doc.revert_to(heads.clone(), None, ExpandMark::After)
+            .unwrap();
+        assert_eq!(hashes(doc.heads()), hashes(heads));
+    }
+
+    #[test]
+    fn reverting_an_object_leaves_the_rest_alone() {
+        let doc = example();
+        let heads = doc.heads();
+        let contact = object(&doc, "contact");
+        doc.put_in_map(contact.clone(), "name".to_string(), int(4))
+            .unwrap();
+        doc.put_in_map(root(), "a".to_string(), int(2)).unwrap();
+
+        doc.revert_to(heads, Some(contact), ExpandMark::After)
+            .unwrap();
+        let state = state(&doc);
+        assert_eq!(state["contact"], json!({"name": 3}));
+        assert_eq!(state["a"], json!(2));
+    }
+
+    #[test]
+    fn concurrent_changes_are_kept() {
+        let doc = example();
+        let heads = doc.heads();
+        doc.put_in_map(root(), "a".to_string(), int(2)).unwrap();
+        let other = doc.fork();
+        other.put_in_map(root(), "c".to_string(), int(5)).unwrap();
+
+        doc.revert_to(heads, None, ExpandMark::After).unwrap();
+        doc.merge(other).unwrap();
+        let state = state(&doc);
+        assert_eq!(state["a"], json!(1));
+        assert_eq!(state["c"], json!(5));
+    }
+
+    #[test]
+    fn unknown_heads_are_an_error() {
+        let doc = example();
+        let heads = doc.heads();
+        let other = example();
+        assert!(matches!(
+            doc.revert_to(other.heads(), None, ExpandMark::After),
+            Err(DocError::InvalidHeads { .. })
+        ));
+        assert_eq!(hashes(doc.heads()), hashes(heads));
+    }
+}
@@ -0,0 +1,75 @@
+//! Persistence of [`crate::Repo`] documents using the automerge-repo key layout.
+//!
+//! Every key starts with the document id, followed by the kind of data:
+//!
+//! - `[document_id, "snapshot", heads_hash]`: a full save of the document,
+//!   keyed by the SHA-256 of its concatenated hex encoded heads
+//! - `[document_id, "incremental", bytes_hash]`: changes saved since an
+//!   earlier chunk, keyed by the SHA-256 of the chunk
+//! - `[document_id, "sync-state", storage_id]`: the sync state with a peer
+//!
+//! Hashes are hex encoded. A document is loaded by applying every snapshot
+//! and then every incremental chunk under its id.
+use automerge as am;
+use sha2::{Digest, Sha256};
+
+use crate::document_id::DocumentId;
+use crate::RepoError;
+
+const SNAPSHOT: &str = "snapshot";
+const INCREMENTAL: &str = "incremental";
+const SYNC_STATE: &str = "sync-state";
+
+pub trait StorageAdapter: Send + Sync {
+    fn load(&self, key: Vec<String>) -> Option<Vec<u8>>;
+    fn save(&self, key: Vec<String>, data: Vec<u8>);
+    fn remove(&self, key: Vec<String>);
+    /// Returns every key which starts with `prefix`.
+    fn list(&self, prefix: Vec<String>) -> Vec<Vec<String>>;
+}
+
+pub(crate) fn snapshot_key(id: &DocumentId, heads: &[am::ChangeHash]) -> Vec<String> {
+    let heads = heads.iter().map(|h| h.to_string()).collect::<String>();
+    key(id, SNAPSHOT, key_hash(heads.as_bytes()))
+}
+
+pub(crate) fn incremental_key(id: &DocumentId, bytes: &[u8]) -> Vec<String> {
+    key(id, INCREMENTAL, key_hash(bytes))
+}
+
+pub(crate) fn sync_state_key(id: &DocumentId, storage_id: &str) -> Vec<String> {
+    key(id, SYNC_STATE, storage_id.to_string())
+}
+
+fn key(id: &DocumentId, kind: &str, name: String) -> Vec<String> {
+    vec![id.as_str().to_string(), kind.to_string(), name]
+}
+
+fn key_hash(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+/// The keys of every snapshot and incremental chunk of a document.
+pub(crate) fn chunk_keys(storage: &dyn StorageAdapter, id: &DocumentId) -> Vec<Vec<String>> {
+    let mut keys = storage.list(vec![id.as_str().to_string(), SNAPSHOT.to_string()]);
+    keys.extend(storage.list(vec![id.as_str().to_string(), INCREMENTAL.to_string()]));
+    keys
+}
+
+/// Loads a document from its stored chunks, returning `None` if there are none.
+pub(crate) fn load(
+    storage: &dyn StorageAdapter,
+    id: &DocumentId,
+) -> Result<Option<am::AutoCommit>, RepoError> {
+    let keys = chunk_keys(storage, id);
+    if keys.is_empty() {
+        return Ok(None);
+    }
+    let mut doc = am::AutoCommit::new();
+    for key in keys {
+        if let Some(bytes) = storage.load(key) {
+            doc.load_incremental(&bytes)?;
+        }
+    }
+    Ok(Some(doc))
+}
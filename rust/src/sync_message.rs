@@ -0,0 +1,139 @@
+use automerge as am;
+
+use crate::{Change, ChangeHash};
+
+const MAGIC_BYTES: [u8; 4] = [0x85, 0x6f, 0x4a, 0x83];
+const CHUNK_TYPE_DOCUMENT: u8 = 0;
+
+#[derive(Debug, thiserror::Error)]
+pub enum DecodeSyncMessageError {
+    #[error("invalid sync message: {0}")]
+    InvalidMessage(#[from] am::sync::ReadMessageError),
+    #[error("invalid changes in sync message: {0}")]
+    InvalidChanges(String),
+}
+
+pub enum SyncMessageVersion {
+    V1,
+    V2,
+}
+
+pub enum SyncCapability {
+    MessageV1,
+    MessageV2,
+    Unknown { code: u8 },
+}
+
+impl From<am::sync::Capability> for SyncCapability {
+    fn from(value: am::sync::Capability) -> Self {
+        match value {
+            am::sync::Capability::MessageV1 => SyncCapability::MessageV1,
+            am::sync::Capability::MessageV2 => SyncCapability::MessageV2,
+            am::sync::Capability::Unknown(code) => SyncCapability::Unknown { code },
+        }
+    }
+}
+
+pub struct SyncHave {
+    pub last_sync: Vec<ChangeHash>,
+    /// The encoded bloom filter of the changes the sender has since `last_sync`
+    pub bloom: Vec<u8>,
+    /// The number of changes summarised by `bloom`
+    pub bloom_entries: u64,
+}
+
+impl From<am::sync::Have> for SyncHave {
+    fn from(value: am::sync::Have) -> Self {
+        let bloom = value.bloom.to_bytes();
+        // An empty filter encodes to no bytes, otherwise the entry count comes first
+        let bloom_entries = read_uleb(&bloom).map_or(0, |(entries, _)| entries);
+        SyncHave {
+            last_sync: value.last_sync.into_iter().map(ChangeHash::from).collect(),
+            bloom,
+            bloom_entries,
+        }
+    }
+}
+
+pub struct SyncMessage {
+    pub version: SyncMessageVersion,
+    pub heads: Vec<ChangeHash>,
+    pub need: Vec<ChangeHash>,
+    pub have: Vec<SyncHave>,
+    pub changes: Vec<Change>,
+    pub supported_capabilities: Option<Vec<SyncCapability>>,
+}
+
+/// Decodes a sync message without applying the changes it carries.
+pub fn decode_sync_message(bytes: Vec<u8>) -> Result<SyncMessage, DecodeSyncMessageError> {
+    let message = am::sync::Message::decode(&bytes)?;
+    let mut changes = Vec::new();
+    for chunk in message.changes.iter() {
+        decode_changes(chunk, &mut changes)?;
+    }
+    Ok(SyncMessage {
+        version: match message.version {
+            am::sync::MessageVersion::V1 => SyncMessageVersion::V1,
+            am::sync::MessageVersion::V2 => SyncMessageVersion::V2,
+        },
+        heads: message.heads.into_iter().map(ChangeHash::from).collect(),
+        need: message.need.into_iter().map(ChangeHash::from).collect(),
+        have: message.have.into_iter().map(SyncHave::from).collect(),
+        changes,
+        supported_capabilities: message
+            .supported_capabilities
+            .map(|caps| caps.into_iter().map(SyncCapability::from).collect()),
+    })
+}
+
+/// Decodes the changes in `bytes`, which is a V1 change or the V2 encoding
+/// of either a whole document or a sequence of changes.
+fn decode_changes(bytes: &[u8], out: &mut Vec<Change>) -> Result<(), DecodeSyncMessageError> {
+    let changes = split_changes(bytes).map_err(DecodeSyncMessageError::InvalidChanges)?;
+    out.extend(changes.into_iter().map(Change::from));
+    Ok(())
+}
+
+/// Splits `bytes`, any concatenation of encoded changes and saved documents,
+/// into its changes.
+pub(crate) fn split_changes(mut bytes: &[u8]) -> Result<Vec<am::Change>, String> {
+    let mut out = Vec::new();
+    while !bytes.is_empty() {
+        let (chunk_type, len) =
+            chunk_header(bytes).ok_or_else(|| "truncated chunk header".to_string())?;
+        let (chunk, rest) = bytes.split_at(len);
+        if chunk_type == CHUNK_TYPE_DOCUMENT {
+            let doc = am::Automerge::load(chunk).map_err(|e| e.to_string())?;
+            out.extend(doc.get_changes(&[]).into_iter().cloned());
+        } else {
+            out.push(am::Change::try_from(chunk).map_err(|e| e.to_string())?);
+        }
+        bytes = rest;
+    }
+    Ok(out)
+}
+
+/// Returns the type and total length of the chunk at the start of `bytes`.
+fn chunk_header(bytes: &[u8]) -> Option<(u8, usize)> {
+    if bytes.get(..4)? != MAGIC_BYTES {
+        return None;
+    }
+    let chunk_type = *bytes.get(8)?;
+    let (data_len, header_len) = read_uleb(bytes.get(9..)?)?;
+    let len = usize::try_from(data_len)
+        .ok()?
+        .checked_add(9 + header_len)?;
+    (len <= bytes.len()).then_some((chunk_type, len))
+}
+
+/// Reads an unsigned LEB128 integer, returning it and the number of bytes read.
+fn read_uleb(bytes: &[u8]) -> Option<(u64, usize)> {
+    let mut value = 0_u64;
+    for (i, byte) in bytes.iter().enumerate().take(10) {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+    }
+    None
+}
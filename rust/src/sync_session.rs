@@ -0,0 +1,155 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use automerge as am;
+use serde_json::{Map, Value as Json};
+
+use crate::{ChangeHash, Doc, ReceiveSyncError, SyncState};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SyncSessionError {
+    #[error("No peer named {peer}")]
+    UnknownPeer { peer: String },
+    #[error("Invalid message: {message}")]
+    InvalidMessage { message: String },
+    #[error("Changes depend on changes which are not in the document")]
+    MissingDependencies { hashes: Vec<ChangeHash> },
+    #[error("Invalid peer states: {message}")]
+    InvalidPeerStates { message: String },
+    #[error("{message}")]
+    Internal { message: String },
+}
+
+impl From<ReceiveSyncError> for SyncSessionError {
+    fn from(error: ReceiveSyncError) -> Self {
+        match error {
+            ReceiveSyncError::MissingDependencies { hashes } => {
+                SyncSessionError::MissingDependencies { hashes }
+            }
+            ReceiveSyncError::InvalidMessage { message } => {
+                SyncSessionError::InvalidMessage { message }
+            }
+            ReceiveSyncError::Internal { message } => SyncSessionError::Internal { message },
+            error @ ReceiveSyncError::DuplicateSeqNumber { .. } => {
+                SyncSessionError::InvalidMessage {
+                    message: error.to_string(),
+                }
+            }
+        }
+    }
+}
+
+pub struct PeerMessage {
+    pub peer: String,
+    pub message: Vec<u8>,
+}
+
+/// Synchronises one document with any number of named peers.
+///
+/// Each peer has its own [`SyncState`]. The states are only locked while a
+/// message is generated or received for that peer, so a [`crate::PatchObserver`]
+/// on the document may call back into the session.
+pub struct SyncSession {
+    doc: Arc<Doc>,
+    peers: Mutex<BTreeMap<String, Arc<SyncState>>>,
+}
+
+impl SyncSession {
+    pub fn new(doc: Arc<Doc>) -> Self {
+        Self {
+            doc,
+            peers: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Creates a session with the peers and states saved by [`SyncSession::encode`].
+    pub fn restore(doc: Arc<Doc>, bytes: Vec<u8>) -> Result<Self, SyncSessionError> {
+        let invalid = |message: String| SyncSessionError::InvalidPeerStates { message };
+        let json: Map<String, Json> =
+            serde_json::from_slice(&bytes).map_err(|e| invalid(e.to_string()))?;
+        let mut peers = BTreeMap::new();
+        for (peer, state) in json {
+            let state = state
+                .as_str()
+                .and_then(|s| hex::decode(s).ok())
+                .ok_or_else(|| invalid(format!("the state of {} is not a hex string", peer)))?;
+            let state = am::sync::State::decode(&state).map_err(|e| invalid(e.to_string()))?;
+            peers.insert(peer, Arc::new(SyncState::from(state)));
+        }
+        Ok(Self {
+            doc,
+            peers: Mutex::new(peers),
+        })
+    }
+
+    pub fn doc(&self) -> Arc<Doc> {
+        self.doc.clone()
+    }
+
+    /// Adds a peer with a fresh sync state, replacing any existing peer of the same name.
+    pub fn add_peer(&self, peer: String) {
+        self.add_peer_with_state(peer, Arc::new(SyncState::new()))
+    }
+
+    pub fn add_peer_with_state(&self, peer: String, state: Arc<SyncState>) {
+        self.peers.lock().unwrap().insert(peer, state);
+    }
+
+    pub fn remove_peer(&self, peer: String) {
+        self.peers.lock().unwrap().remove(&peer);
+    }
+
+    pub fn peers(&self) -> Vec<String> {
+        self.peers.lock().unwrap().keys().cloned().collect()
+    }
+
+    pub fn peer_state(&self, peer: String) -> Option<Arc<SyncState>> {
+        self.peers.lock().unwrap().get(&peer).cloned()
+    }
+
+    /// Generates the next message for `peer`, if there is anything to send.
+    pub fn generate_message(&self, peer: String) -> Result<Option<Vec<u8>>, SyncSessionError> {
+        let state = self.state(&peer)?;
+        Ok(self.doc.generate_sync_message(state))
+    }
+
+    /// Generates the next message for every peer which has something to send.
+    pub fn generate_messages(&self) -> Vec<PeerMessage> {
+        let peers = self.peers.lock().unwrap().clone();
+        peers
+            .into_iter()
+            .filter_map(|(peer, state)| {
+                let message = self.doc.generate_sync_message(state)?;
+                Some(PeerMessage { peer, message })
+            })
+            .collect()
+    }
+
+    pub fn receive_message(&self, peer: String, message: Vec<u8>) -> Result<(), SyncSessionError> {
+        let state = self.state(&peer)?;
+        self.doc.receive_sync_message(state, message)?;
+        Ok(())
+    }
+
+    /// Encodes the sync state of every peer so the session can be restored
+    /// with [`SyncSession::restore`].
+    pub fn encode(&self) -> Vec<u8> {
+        let peers = self.peers.lock().unwrap();
+        let json = peers
+            .iter()
+            .map(|(peer, state)| (peer.clone(), Json::String(hex::encode(state.encode()))))
+            .collect::<Map<_, _>>();
+        Json::Object(json).to_string().into_bytes()
+    }
+
+    fn state(&self, peer: &str) -> Result<Arc<SyncState>, SyncSessionError> {
+        self.peers
+            .lock()
+            .unwrap()
+            .get(peer)
+            .cloned()
+            .ok_or_else(|| SyncSessionError::UnknownPeer {
+                peer: peer.to_string(),
+            })
+    }
+}
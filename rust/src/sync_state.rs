@@ -2,7 +2,7 @@ use std::sync::RwLock;
 
 use automerge as am;
 
-use crate::ChangeHash;
+use crate::{ChangeHash, SyncHave};
 
 #[derive(Debug, thiserror::Error)]
 pub enum DecodeSyncStateError {
@@ -40,6 +40,50 @@ impl SyncState {
             .as_ref()
             .map(|heads| heads.iter().map(ChangeHash::from).collect())
     }
+
+    /// The heads which we know both peers have
+    pub fn shared_heads(&self) -> Vec<ChangeHash> {
+        let sync = self.0.read().unwrap();
+        sync.shared_heads.iter().map(ChangeHash::from).collect()
+    }
+
+    /// The heads we last sent
+    pub fn last_sent_heads(&self) -> Vec<ChangeHash> {
+        let sync = self.0.read().unwrap();
+        sync.last_sent_heads.iter().map(ChangeHash::from).collect()
+    }
+
+    /// The changes the peer last said they needed
+    pub fn their_need(&self) -> Option<Vec<ChangeHash>> {
+        let sync = self.0.read().unwrap();
+        sync.their_need
+            .as_ref()
+            .map(|need| need.iter().map(ChangeHash::from).collect())
+    }
+
+    /// The summaries of the changes the peer last said they have
+    pub fn their_have(&self) -> Option<Vec<SyncHave>> {
+        let sync = self.0.read().unwrap();
+        sync.their_have
+            .as_ref()
+            .map(|have| have.iter().cloned().map(SyncHave::from).collect())
+    }
+
+    /// The number of changes sent to the peer in this session
+    pub fn sent_hashes_count(&self) -> u64 {
+        self.0.read().unwrap().sent_hashes.len() as u64
+    }
+
+    /// Whether a message has been sent which the peer has not yet responded to
+    pub fn in_flight(&self) -> bool {
+        self.0.read().unwrap().in_flight
+    }
+}
+
+impl From<am::sync::State> for SyncState {
+    fn from(state: am::sync::State) -> Self {
+        Self(RwLock::new(state))
+    }
 }
 
 impl Default for SyncState {
@@ -1,4 +1,5 @@
 use automerge as am;
+use unicode_segmentation::UnicodeSegmentation;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum TextEncoding {
@@ -40,3 +41,13 @@ impl std::fmt::Display for TextEncoding {
         }
     }
 }
+
+/// The length of `text` in `encoding`, as automerge counts text positions.
+pub(crate) fn width(encoding: am::TextEncoding, text: &str) -> usize {
+    match encoding {
+        am::TextEncoding::UnicodeCodePoint => text.chars().count(),
+        am::TextEncoding::Utf8CodeUnit => text.len(),
+        am::TextEncoding::Utf16CodeUnit => text.encode_utf16().count(),
+        am::TextEncoding::GraphemeCluster => text.graphemes(true).count(),
+    }
+}
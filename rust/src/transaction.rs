@@ -0,0 +1,315 @@
+use std::sync::{Arc, Mutex};
+
+use automerge as am;
+
+use crate::mark::ExpandMark;
+use crate::{ChangeHash, Doc, DocError, ObjId, ObjType, ScalarValue};
+
+/// A set of edits to a [`Doc`] which are applied atomically.
+///
+/// Edits are staged on a private fork of the document, so they are not
+/// visible through the document until [`Transaction::commit`] is called.
+/// [`Transaction::rollback`] discards the staged edits without touching the
+/// document.
+///
+/// The fork has the document's actor, so the committed change is recorded by
+/// an [`UndoManager`] and attributed to the document like any other edit.
+/// Object ids returned while staging stay valid once the transaction is
+/// committed.
+///
+/// The document can keep syncing and merging while the transaction is open,
+/// but editing it directly makes a change with the sequence number the staged
+/// change needs, so committing then fails with
+/// [`DocError::DuplicateSeqNumber`] and the transaction has to be rolled
+/// back.
+///
+/// [`UndoManager`]: crate::UndoManager
+pub struct Transaction {
+    doc: Arc<Doc>,
+    staged: Mutex<Option<Doc>>,
+    /// The heads of the document when the transaction began
+    base: Vec<am::ChangeHash>,
+}
+
+impl Transaction {
+    pub(crate) fn new(doc: Arc<Doc>, staged: Doc, base: Vec<am::ChangeHash>) -> Self {
+        Self {
+            doc,
+            staged: Mutex::new(Some(staged)),
+            base,
+        }
+    }
+
+    pub fn put_in_map(&self, obj: ObjId, key: String, value: ScalarValue) -> Result<(), DocError> {
+        self.with_staged(|doc| doc.put_in_map(obj, key, value))
+    }
+
+    pub fn put_object_in_map(
+        &self,
+        obj: ObjId,
+        key: String,
+        value: ObjType,
+    ) -> Result<ObjId, DocError> {
+        self.with_staged(|doc| doc.put_object_in_map(obj, key, value))
+    }
+
+    pub fn put_in_list(&self, obj: ObjId, index: u64, value: ScalarValue) -> Result<(), DocError> {
+        self.with_staged(|doc| doc.put_in_list(obj, index, value))
+    }
+
+    pub fn put_object_in_list(
+        &self,
+        obj: ObjId,
+        index: u64,
+        value: ObjType,
+    ) -> Result<ObjId, DocError> {
+        self.with_staged(|doc| doc.put_object_in_list(obj, index, value))
+    }
+
+    pub fn insert_in_list(
+        &self,
+        obj: ObjId,
+        index: u64,
+        value: ScalarValue,
+    ) -> Result<(), DocError> {
+        self.with_staged(|doc| doc.insert_in_list(obj, index, value))
+    }
+
+    pub fn insert_object_in_list(
+        &self,
+        obj: ObjId,
+        index: u64,
+        value: ObjType,
+    ) -> Result<ObjId, DocError> {
+        self.with_staged(|doc| doc.insert_object_in_list(obj, index, value))
+    }
+
+    pub fn delete_in_map(&self, obj: ObjId, key: String) -> Result<(), DocError> {
+        self.with_staged(|doc| doc.delete_in_map(obj, key))
+    }
+
+    pub fn delete_in_list(&self, obj: ObjId, index: u64) -> Result<(), DocError> {
+        self.with_staged(|doc| doc.delete_in_list(obj, index))
+    }
+
+    pub fn increment_in_map(&self, obj: ObjId, key: String, by: i64) -> Result<(), DocError> {
+        self.with_staged(|doc| doc.increment_in_map(obj, key, by))
+    }
+
+    pub fn increment_in_list(&self, obj: ObjId, index: u64, by: i64) -> Result<(), DocError> {
+        self.with_staged(|doc| doc.increment_in_list(obj, index, by))
+    }
+
+    pub fn splice_text(
+        &self,
+        obj: ObjId,
+        start: u64,
+        delete: i64,
+        value: String,
+    ) -> Result<(), DocError> {
+        self.with_staged(|doc| doc.splice_text(obj, start, delete, value))
+    }
+
+    pub fn update_text(&self, obj: ObjId, value: String) -> Result<(), DocError> {
+        self.with_staged(|doc| doc.update_text(obj, value))
+    }
+
+    pub fn splice(
+        &self,
+        obj: ObjId,
+        start: u64,
+        delete: i64,
+        values: Vec<ScalarValue>,
+    ) -> Result<(), DocError> {
+        self.with_staged(|doc| doc.splice(obj, start, delete, values))
+    }
+
+    pub fn mark(
+        &self,
+        obj: ObjId,
+        start: u64,
+        end: u64,
+        expand: ExpandMark,
+        name: String,
+        value: ScalarValue,
+    ) -> Result<(), DocError> {
+        self.with_staged(|doc| doc.mark(obj, start, end, expand, name, value))
+    }
+
+    /// Applies the staged edits to the document as a single change.
+    ///
+    /// Returns `None` if no edits were staged. If the change can't be applied
+    /// the transaction stays open, so it can be committed again or rolled
+    /// back.
+    pub fn commit(
+        &self,
+        message: Option<String>,
+        time: i64,
+    ) -> Result<Option<ChangeHash>, DocError> {
+        let mut slot = self.staged.lock().unwrap();
+        let staged = slot.as_ref().ok_or(DocError::TransactionClosed)?;
+        let mut staged = staged.0.write().unwrap();
+        let mut options = am::transaction::CommitOptions::default();
+        options.set_time(time);
+        if let Some(message) = message {
+            options.set_message(message);
+        }
+        staged.commit_with(options);
+        // Includes a change committed by an earlier attempt which failed to
+        // apply
+        let changes = staged
+            .get_changes(&self.base)
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        drop(staged);
+        let Some(hash) = changes.last().map(|change| change.hash()) else {
+            *slot = None;
+            return Ok(None);
+        };
+        let mut doc = self.doc.0.write().unwrap();
+        doc.apply_changes(changes)
+            .map_err(|e| DocError::in_apply(&*doc, e))?;
+        *slot = None;
+        drop(slot);
+        self.doc.notify(doc, true);
+        Ok(Some(hash.into()))
+    }
+
+    /// Discards the staged edits.
+    pub fn rollback(&self) {
+        self.staged.lock().unwrap().take();
+    }
+
+    fn with_staged<F, T>(&self, f: F) -> Result<T, DocError>
+    where
+        F: FnOnce(&Doc) -> Result<T, DocError>,
+    {
+        let staged = self.staged.lock().unwrap();
+        let doc = staged.as_ref().ok_or(DocError::TransactionClosed)?;
+        f(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use automerge as am;
+
+    use crate::{root, Doc, DocError, ExpandMark, ObjType, Prop, ScalarValue, UndoManager, Value};
+
+    fn int(value: i64) -> ScalarValue {
+        ScalarValue::Int { value }
+    }
+
+    fn get(doc: &Doc, key: &str) -> Option<i64> {
+        match doc.get_in_map(root(), key.to_string()).unwrap()? {
+            Value::Scalar {
+                value: ScalarValue::Int { value },
+            } => Some(value),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn staged_edits_are_hidden_until_commit() {
+        let doc = Arc::new(Doc::new());
+        let tx = doc.clone().begin_transaction();
+        tx.put_in_map(root(), "a".to_string(), int(1)).unwrap();
+        assert!(get(&doc, "a").is_none());
+
+        let hash = tx.commit(Some("edit".to_string()), 0).unwrap().unwrap();
+        assert_eq!(get(&doc, "a"), Some(1));
+        let change = doc.change_by_hash(hash).unwrap().unwrap();
+        assert_eq!(change.message.as_deref(), Some("edit"));
+    }
+
+    #[test]
+    fn rollback_discards_staged_edits() {
+        let doc = Arc::new(Doc::new());
+        let tx = doc.clone().begin_transaction();
+        tx.put_in_map(root(), "a".to_string(), int(1)).unwrap();
+        tx.rollback();
+        assert!(get(&doc, "a").is_none());
+        assert!(doc.heads().is_empty());
+        assert!(matches!(
+            tx.put_in_map(root(), "b".to_string(), int(2)),
+            Err(DocError::TransactionClosed)
+        ));
+        assert!(matches!(
+            tx.commit(None, 0),
+            Err(DocError::TransactionClosed)
+        ));
+    }
+
+    #[test]
+    fn commit_with_nothing_staged_returns_none() {
+        let doc = Arc::new(Doc::new());
+        let tx = doc.clone().begin_transaction();
+        assert!(tx.commit(None, 0).unwrap().is_none());
+        assert!(matches!(
+            tx.commit(None, 0),
+            Err(DocError::TransactionClosed)
+        ));
+    }
+
+    #[test]
+    fn document_can_merge_while_a_transaction_is_open() {
+        let doc = Arc::new(Doc::new());
+        doc.put_in_map(root(), "a".to_string(), int(1)).unwrap();
+        doc.commit_with(None, 0);
+        let other = doc.fork();
+
+        let tx = doc.clone().begin_transaction();
+        let list = tx
+            .put_object_in_map(root(), "list".to_string(), ObjType::List)
+            .unwrap();
+        tx.insert_in_list(list.clone(), 0, int(2)).unwrap();
+
+        other.put_in_map(root(), "b".to_string(), int(3)).unwrap();
+        doc.merge(other).unwrap();
+
+        tx.commit(None, 0).unwrap().unwrap();
+        assert_eq!(get(&doc, "b"), Some(3));
+        // The object created while staging keeps its id
+        assert_eq!(doc.length(list).unwrap(), 1);
+    }
+
+    #[test]
+    fn editing_the_document_while_a_transaction_is_open_fails_the_commit() {
+        let doc = Arc::new(Doc::new());
+        let tx = doc.clone().begin_transaction();
+        tx.put_in_map(root(), "a".to_string(), int(1)).unwrap();
+        doc.put_in_map(root(), "b".to_string(), int(2)).unwrap();
+
+        assert!(matches!(
+            tx.commit(None, 0),
+            Err(DocError::DuplicateSeqNumber { .. })
+        ));
+        tx.rollback();
+        assert_eq!(get(&doc, "a"), None);
+        assert_eq!(get(&doc, "b"), Some(2));
+    }
+
+    #[test]
+    fn committed_transactions_are_local_edits() {
+        let doc = Arc::new(Doc::new());
+        let undo = UndoManager::new(doc.clone(), None, ExpandMark::After).unwrap();
+        let tx = doc.clone().begin_transaction();
+        tx.put_in_map(root(), "a".to_string(), int(1)).unwrap();
+        tx.commit(None, 0).unwrap().unwrap();
+
+        let prop = Prop::Key {
+            value: "a".to_string(),
+        };
+        let attribution = doc.blame_property(root(), prop).unwrap().unwrap();
+        assert_eq!(
+            am::ActorId::from(attribution.actor),
+            am::ActorId::from(doc.actor_id())
+        );
+
+        assert!(undo.undo().unwrap());
+        assert_eq!(get(&doc, "a"), None);
+    }
+}
@@ -0,0 +1,539 @@
+use std::collections::{BTreeSet, HashSet};
+use std::sync::{Arc, Mutex};
+
+use automerge as am;
+
+use crate::revert::{revert, Restored};
+use crate::{Doc, DocError, ExpandMark, ObjId};
+
+/// A step which can be undone: undoing it makes the document look as it did
+/// at `to` wherever it differs between `to` and `from`.
+struct Step {
+    from: Vec<am::ChangeHash>,
+    to: Vec<am::ChangeHash>,
+}
+
+struct Group {
+    start: Vec<am::ChangeHash>,
+    local: HashSet<am::ChangeHash>,
+    depth: u32,
+}
+
+#[derive(Default)]
+struct History {
+    /// The heads as of the last time local changes were recorded
+    seen: Vec<am::ChangeHash>,
+    group: Option<Group>,
+    undo: Vec<Step>,
+    redo: Vec<Step>,
+    /// The objects and elements recreated by undoing or redoing, so that
+    /// older steps can still find them
+    restored: Restored,
+}
+
+/// Undo and redo for the local changes to a [`Doc`].
+///
+/// Changes made with the document's actor are recorded as undo steps by
+/// [`UndoManager::record`], which is also called before undoing. Changes
+/// from other actors are never undone, so undoing while collaborating only
+/// reverts the local edits. Undoing and redoing write the inverse edits as a
+/// new change, which is synchronised like any other.
+///
+/// With a scope only edits to that object and the objects inside it are
+/// recorded and undone. Marks which are put back expand as `expand` says.
+pub struct UndoManager {
+    doc: Arc<Doc>,
+    scope: Option<am::ObjId>,
+    expand: am::marks::ExpandMark,
+    history: Mutex<History>,
+}
+
+impl UndoManager {
+    pub fn new(doc: Arc<Doc>, scope: Option<ObjId>, expand: ExpandMark) -> Result<Self, DocError> {
+        if let Some(scope) = &scope {
+            doc.object_type(scope.clone())?;
+        }
+        let seen = doc.0.write().unwrap().get_heads();
+        Ok(Self {
+            doc,
+            scope: scope.map(am::ObjId::from),
+            expand: expand.into(),
+            history: Mutex::new(History {
+                seen,
+                ..Default::default()
+            }),
+        })
+    }
+
+    /// Records the local changes made since the last call as one undo step,
+    /// or adds them to the current group.
+    pub fn record(&self) {
+        let mut history = self.history.lock().unwrap();
+        let mut doc = self.doc.0.write().unwrap();
+        self.record_changes(&mut doc, &mut history);
+    }
+
+    /// Starts grouping recorded changes into a single undo step. Groups may
+    /// be nested, in which case the outermost group makes the step.
+    pub fn begin_group(&self) {
+        let mut history = self.history.lock().unwrap();
+        let mut doc = self.doc.0.write().unwrap();
+        self.record_changes(&mut doc, &mut history);
+        match &mut history.group {
+            Some(group) => group.depth += 1,
+            None => {
+                history.group = Some(Group {
+                    start: history.seen.clone(),
+                    local: HashSet::new(),
+                    depth: 1,
+                })
+            }
+        }
+    }
+
+    pub fn end_group(&self) {
+        let mut history = self.history.lock().unwrap();
+        let mut doc = self.doc.0.write().unwrap();
+        self.record_changes(&mut doc, &mut history);
+        if let Some(group) = &mut history.group {
+            group.depth -= 1;
+            if group.depth == 0 {
+                self.close_group(&mut doc, &mut history);
+            }
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.record();
+        !self.history.lock().unwrap().undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.record();
+        !self.history.lock().unwrap().redo.is_empty()
+    }
+
+    /// Reverts the most recent undo step, closing any open group first.
+    ///
+    /// Returns `false` if there was nothing to undo.
+    pub fn undo(&self) -> Result<bool, DocError> {
+        self.step(true)
+    }
+
+    /// Reapplies the most recently undone step.
+    ///
+    /// Returns `false` if there was nothing to redo. Recording new local
+    /// changes discards the steps which could be redone.
+    pub fn redo(&self) -> Result<bool, DocError> {
+        self.step(false)
+    }
+
+    /// Forgets every undo and redo step.
+    pub fn clear(&self) {
+        let mut history = self.history.lock().unwrap();
+        let mut doc = self.doc.0.write().unwrap();
+        history.seen = doc.get_heads();
+        history.group = None;
+        history.undo.clear();
+        history.redo.clear();
+        history.restored = Restored::default();
+    }
+
+    fn step(&self, undo: bool) -> Result<bool, DocError> {
+        let mut history = self.history.lock().unwrap();
+        let mut doc = self.doc.0.write().unwrap();
+        self.record_changes(&mut doc, &mut history);
+        if history.group.is_some() {
+            self.close_group(&mut doc, &mut history);
+        }
+        loop {
+            let step = if undo {
+                history.undo.pop()
+            } else {
+                history.redo.pop()
+            };
+            let Some(step) = step else {
+                return Ok(false);
+            };
+            let before = doc.get_heads();
+            let reverted = revert(
+                &mut doc,
+                &step.from,
+                &step.to,
+                self.scope.as_ref(),
+                self.expand,
+                &mut history.restored,
+            );
+            if let Err(e) = reverted {
+                doc.rollback();
+                return Err(e);
+            }
+            // Steps whose edits have all been overwritten have nothing left
+            // to revert
+            if doc.commit().is_none() {
+                continue;
+            }
+            let after = doc.get_heads();
+            let inverse = Step {
+                from: after.clone(),
+                to: before,
+            };
+            if undo {
+                history.redo.push(inverse);
+            } else {
+                history.undo.push(inverse);
+            }
+            history.seen = after;
+            drop(history);
+            self.doc.notify(doc, true);
+            return Ok(true);
+        }
+    }
+
+    fn record_changes(&self, doc: &mut am::AutoCommit, history: &mut History) {
+        let heads = doc.get_heads();
+        if heads == history.seen {
+            return;
+        }
+        let actor = doc.get_actor().clone();
+        let local = doc
+            .get_changes(&history.seen)
+            .into_iter()
+            .filter(|change| change.actor_id() == &actor)
+            .map(|change| change.hash())
+            .collect::<HashSet<_>>();
+        let start = std::mem::replace(&mut history.seen, heads);
+        if local.is_empty() {
+            return;
+        }
+        match &mut history.group {
+            Some(group) => group.local.extend(local),
+            None => self.push_step(doc, history, &start, &local),
+        }
+    }
+
+    fn close_group(&self, doc: &mut am::AutoCommit, history: &mut History) {
+        if let Some(group) = history.group.take() {
+            if !group.local.is_empty() {
+                self.push_step(doc, history, &group.start, &group.local);
+            }
+        }
+    }
+
+    fn push_step(
+        &self,
+        doc: &mut am::AutoCommit,
+        history: &mut History,
+        start: &[am::ChangeHash],
+        local: &HashSet<am::ChangeHash>,
+    ) {
+        let step = Step {
+            from: doc.get_heads(),
+            to: heads_without(doc, start, local),
+        };
+        if let Some(scope) = &self.scope {
+            let touches_scope = doc
+                .diff(&step.from, &step.to)
+                .iter()
+                .any(|patch| crate::diff::is_within(patch, scope, true));
+            if !touches_scope {
+                return;
+            }
+        }
+        history.undo.push(step);
+        history.redo.clear();
+    }
+}
+
+/// The heads of the document made of every change since `start` except
+/// `excluded` and the changes which depend on them.
+fn heads_without(
+    doc: &mut am::AutoCommit,
+    start: &[am::ChangeHash],
+    excluded: &HashSet<am::ChangeHash>,
+) -> Vec<am::ChangeHash> {
+    let mut excluded = excluded.clone();
+    let mut heads = start.iter().copied().collect::<BTreeSet<_>>();
+    for change in doc.get_changes(start) {
+        let hash = change.hash();
+        if excluded.contains(&hash) || change.deps().iter().any(|dep| excluded.contains(dep)) {
+            excluded.insert(hash);
+            continue;
+        }
+        for dep in change.deps() {
+            heads.remove(dep);
+        }
+        heads.insert(hash);
+    }
+    heads.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use serde_json::{json, Value as Json};
+
+    use super::UndoManager;
+    use crate::{root, Doc, DocError, ExpandMark, ObjType, ScalarValue};
+
+    fn int(value: i64) -> ScalarValue {
+        ScalarValue::Int { value }
+    }
+
+    fn state(doc: &Doc) -> Json {
+        serde_json::from_str(&doc.to_json(root(), None).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn map_edits_are_undone_and_redone() {
+        let doc = Arc::new(Doc::new());
+        let undo = UndoManager::new(doc.clone(), None, ExpandMark::After).unwrap();
+        doc.put_in_map(root(), "a".to_string(), int(1)).unwrap();
+        undo.record();
+        doc.put_in_map(root(), "a".to_string(), int(2)).unwrap();
+        doc.put_in_map(root(), "b".to_string(), int(3)).unwrap();
+        undo.record();
+
+        assert!(undo.undo().unwrap());
+        assert_eq!(state(&doc), json!({"a": 1}));
+        assert!(undo.undo().unwrap());
+        assert_eq!(state(&doc), json!({}));
+        assert!(!undo.undo().unwrap());
+
+        assert!(undo.redo().unwrap());
+        assert_eq!(state(&doc), json!({"a": 1}));
+        assert!(undo.redo().unwrap());
+        assert_eq!(state(&doc), json!({"a": 2, "b": 3}));
+        assert!(!undo.redo().unwrap());
+    }
+
+    #[test]
+    fn list_edits_are_undone_and_redone() {
+        let doc = Arc::new(Doc::new());
+        let list = doc
+            .put_object_in_map(root(), "list".to_string(), ObjType::List)
+            .unwrap();
+        let undo = UndoManager::new(doc.clone(), None, ExpandMark::After).unwrap();
+        for (index, value) in [1, 2, 3].into_iter().enumerate() {
+            doc.insert_in_list(list.clone(), index as u64, int(value))
+                .unwrap();
+        }
+        undo.record();
+        doc.delete_in_list(list.clone(), 1).unwrap();
+        undo.record();
+
+        undo.undo().unwrap();
+        assert_eq!(state(&doc), json!({"list": [1, 2, 3]}));
+        undo.undo().unwrap();
+        assert_eq!(state(&doc), json!({"list": []}));
+        undo.redo().unwrap();
+        undo.redo().unwrap();
+        assert_eq!(state(&doc), json!({"list": [1, 3]}));
+    }
+
+    #[test]
+    fn text_edits_are_undone_and_redone() {
+        let doc = Arc::new(Doc::new());
+        let text = doc
+            .put_object_in_map(root(), "text".to_string(), ObjType::Text)
+            .unwrap();
+        doc.splice_text(text.clone(), 0, 0, "hello".to_string())
+            .unwrap();
+        let undo = UndoManager::new(doc.clone(), None, ExpandMark::After).unwrap();
+        doc.splice_text(text.clone(), 5, 0, " world".to_string())
+            .unwrap();
+        undo.record();
+        doc.splice_text(text.clone(), 0, 1, "J".to_string())
+            .unwrap();
+        undo.record();
+
+        undo.undo().unwrap();
+        assert_eq!(doc.text(text.clone()).unwrap(), "hello world");
+        undo.undo().unwrap();
+        assert_eq!(doc.text(text.clone()).unwrap(), "hello");
+        undo.redo().unwrap();
+        undo.redo().unwrap();
+        assert_eq!(doc.text(text).unwrap(), "Jello world");
+    }
+
+    #[test]
+    fn reinserted_text_is_found_by_older_steps() {
+        let doc = Arc::new(Doc::new());
+        let text = doc
+            .put_object_in_map(root(), "text".to_string(), ObjType::Text)
+            .unwrap();
+        let undo = UndoManager::new(doc.clone(), None, ExpandMark::After).unwrap();
+        doc.splice_text(text.clone(), 0, 0, "hello".to_string())
+            .unwrap();
+        undo.record();
+        doc.splice_text(text.clone(), 1, 3, String::new()).unwrap();
+        undo.record();
+
+        undo.undo().unwrap();
+        assert_eq!(doc.text(text.clone()).unwrap(), "hello");
+        undo.undo().unwrap();
+        assert_eq!(doc.text(text.clone()).unwrap(), "");
+        undo.redo().unwrap();
+        assert_eq!(doc.text(text.clone()).unwrap(), "hello");
+        undo.redo().unwrap();
+        assert_eq!(doc.text(text).unwrap(), "ho");
+    }
+
+    #[test]
+    fn nested_groups_make_one_step() {
+        let doc = Arc::new(Doc::new());
+        let undo = UndoManager::new(doc.clone(), None, ExpandMark::After).unwrap();
+        undo.begin_group();
+        doc.put_in_map(root(), "a".to_string(), int(1)).unwrap();
+        undo.begin_group();
+        doc.put_in_map(root(), "b".to_string(), int(2)).unwrap();
+        undo.end_group();
+        doc.put_in_map(root(), "c".to_string(), int(3)).unwrap();
+        undo.end_group();
+
+        assert!(undo.undo().unwrap());
+        assert_eq!(state(&doc), json!({}));
+        assert!(!undo.can_undo());
+        assert!(undo.redo().unwrap());
+        assert_eq!(state(&doc), json!({"a": 1, "b": 2, "c": 3}));
+    }
+
+    #[test]
+    fn undoing_closes_an_open_group() {
+        let doc = Arc::new(Doc::new());
+        let undo = UndoManager::new(doc.clone(), None, ExpandMark::After).unwrap();
+        undo.begin_group();
+        doc.put_in_map(root(), "a".to_string(), int(1)).unwrap();
+        doc.put_in_map(root(), "b".to_string(), int(2)).unwrap();
+
+        assert!(undo.undo().unwrap());
+        assert_eq!(state(&doc), json!({}));
+    }
+
+    #[test]
+    fn a_new_edit_clears_redo() {
+        let doc = Arc::new(Doc::new());
+        let undo = UndoManager::new(doc.clone(), None, ExpandMark::After).unwrap();
+        doc.put_in_map(root(), "a".to_string(), int(1)).unwrap();
+        undo.record();
+        undo.undo().unwrap();
+        assert!(undo.can_redo());
+
+        doc.put_in_map(root(), "b".to_string(), int(2)).unwrap();
+        assert!(!undo.can_redo());
+        assert!(!undo.redo().unwrap());
+        assert_eq!(state(&doc), json!({"b": 2}));
+    }
+
+    #[test]
+    fn scoped_undo_ignores_edits_outside_the_scope() {
+        let doc = Arc::new(Doc::new());
+        let notes = doc
+            .put_object_in_map(root(), "notes".to_string(), ObjType::Map)
+            .unwrap();
+        let undo = UndoManager::new(doc.clone(), Some(notes.clone()), ExpandMark::After).unwrap();
+        doc.put_in_map(notes.clone(), "title".to_string(), int(1))
+            .unwrap();
+        undo.record();
+        doc.put_in_map(root(), "other".to_string(), int(2)).unwrap();
+        undo.record();
+        undo.begin_group();
+        doc.put_in_map(root(), "other".to_string(), int(3)).unwrap();
+        doc.put_in_map(notes.clone(), "body".to_string(), int(4))
+            .unwrap();
+        undo.end_group();
+
+        assert!(undo.undo().unwrap());
+        assert_eq!(state(&doc), json!({"notes": {"title": 1}, "other": 3}));
+        assert!(undo.undo().unwrap());
+        assert_eq!(state(&doc), json!({"notes": {}, "other": 3}));
+        assert!(!undo.undo().unwrap());
+    }
+
+    #[test]
+    fn only_local_edits_are_undone() {
+        let alice = Arc::new(Doc::new());
+        let undo = UndoManager::new(alice.clone(), None, ExpandMark::After).unwrap();
+        alice
+            .put_in_map(root(), "alice".to_string(), int(1))
+            .unwrap();
+        undo.record();
+
+        let bob = alice.fork();
+        bob.put_in_map(root(), "bob".to_string(), int(2)).unwrap();
+        alice.merge(bob).unwrap();
+        undo.record();
+
+        assert!(undo.undo().unwrap());
+        assert_eq!(state(&alice), json!({"bob": 2}));
+        assert!(!undo.undo().unwrap());
+        assert!(undo.redo().unwrap());
+        assert_eq!(state(&alice), json!({"alice": 1, "bob": 2}));
+    }
+
+    #[test]
+    fn remote_overwrites_are_kept() {
+        let alice = Arc::new(Doc::new());
+        let undo = UndoManager::new(alice.clone(), None, ExpandMark::After).unwrap();
+        alice
+            .put_in_map(root(), "title".to_string(), int(1))
+            .unwrap();
+        undo.record();
+
+        let bob = alice.fork();
+        bob.put_in_map(root(), "title".to_string(), int(2)).unwrap();
+        alice.merge(bob).unwrap();
+
+        assert!(!undo.undo().unwrap());
+        assert_eq!(state(&alice), json!({"title": 2}));
+    }
+
+    #[test]
+    fn a_scope_from_another_document_is_an_error() {
+        let other = Doc::new();
+        let notes = other
+            .put_object_in_map(root(), "notes".to_string(), ObjType::Map)
+            .unwrap();
+        let doc = Arc::new(Doc::new());
+        assert!(matches!(
+            UndoManager::new(doc, Some(notes), ExpandMark::After),
+            Err(DocError::ObjectNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn created_objects_are_removed_and_recreated() {
+        let doc = Arc::new(Doc::new());
+        let undo = UndoManager::new(doc.clone(), None, ExpandMark::After).unwrap();
+        let contact = doc
+            .put_object_in_map(root(), "contact".to_string(), ObjType::Map)
+            .unwrap();
+        doc.put_in_map(contact.clone(), "name".to_string(), int(1))
+            .unwrap();
+        let tags = doc
+            .put_object_in_map(contact.clone(), "tags".to_string(), ObjType::List)
+            .unwrap();
+        doc.insert_in_list(tags.clone(), 0, int(2)).unwrap();
+        undo.record();
+        doc.put_in_map(contact.clone(), "name".to_string(), int(3))
+            .unwrap();
+        undo.record();
+
+        undo.undo().unwrap();
+        undo.undo().unwrap();
+        assert_eq!(state(&doc), json!({}));
+
+        undo.redo().unwrap();
+        let created = json!({"contact": {"name": 1, "tags": [2]}});
+        assert_eq!(state(&doc), created);
+        // The edit to the original object lands on the recreated one
+        undo.redo().unwrap();
+        assert_eq!(state(&doc), json!({"contact": {"name": 3, "tags": [2]}}));
+
+        undo.undo().unwrap();
+        assert_eq!(state(&doc), created);
+        undo.undo().unwrap();
+        assert_eq!(state(&doc), json!({}));
+    }
+}